@@ -1,51 +1,12 @@
 #![allow(non_camel_case_types)]
 
-extern crate alloc;
-
 /// [struct GNU::GIOChannel](https://github.com/GNOME/glib/blob/master/glib/giochannel.h)
 ///
 /// Based on a comment in [#RFC-1449 Add language support for bitfields](https://github.com/rust-lang/rfcs/pull/1449#issuecomment-318482265).
 ///
-/// Layout (only the flags):
-///
-/// ```
-///  7       3     0
-/// ╔═══╤═╤═╪═╤═╤═╤═╗
-/// ║   │I│I│I│C│D│U║
-/// ║   │S│W│R│O│E│B║ Flags
-/// ║0 0│ │ │ │U│ │ ║
-/// ╚═══╧═╧═╧═╧═╧═╧═╝
-/// UB  = Use Buffer
-/// DE  = Do Encode
-/// COU = Close On Unref
-/// IR  = Is Readable
-/// IW  = Is Writable
-/// IS  = Is Seekable
-/// ```
-#[bitfield::bitfield(8)]
-#[derive(Display)]
-struct Flags(Flag);
-
-#[derive(Copy, Clone, Debug, bitfield::Flags)]
-#[repr(u8)]
-enum Flag {
-    /// The encoding uses the buffers.
-    UseBuffer,
-    /// The encoding uses the `GIConv` converters.
-    DoEncode,
-    /// Close the channel on final unref.
-    CloseOnUnref,
-    /// Cached `GIOFlag`.
-    IsReadable,
-    /// Cached `GIOFlag`.
-    IsWritable,
-    /// Cached `GIOFlag`.
-    IsSeekable
-    // Bits 6 - 7 are reserved.
-}
-
-/// See https://github.com/GNOME/glib/blob/master/glib/giochannel.h
-#[repr(C)]
+/// The C bit field members are declared with `#[bits(...)]` and are packed like a C compiler for
+/// the target would pack them.
+#[bitfield::c_struct]
 struct GIOChannel {
     ref_count: gint,
     funcs: *const u8,
@@ -69,7 +30,18 @@ struct GIOChannel {
     partial_write_buf: [gchar; 6],
 
     // Group the flags together, immediately after `partial_write_buf`, to save memory.
-    flags: Flags,
+    /// The encoding uses the buffers.
+    #[bits(1)] use_buffer: guint,
+    /// The encoding uses the `GIConv` converters.
+    #[bits(1)] do_encode: guint,
+    /// Close the channel on final unref.
+    #[bits(1)] close_on_unref: guint,
+    /// Cached `GIOFlag`.
+    #[bits(1)] is_readable: guint,
+    /// Cached `GIOFlag`.
+    #[bits(1)] is_writeable: guint,
+    /// Cached `GIOFlag`.
+    #[bits(1)] is_seekable: guint,
 
     reserved1: gpointer,
     reserved2: gpointer
@@ -94,27 +66,25 @@ struct GString {
 }
 
 fn main() {
-    let mut channel = GIOChannel {
-        ref_count: 0, funcs: 0 as _, encoding: 0 as _, read_cd: 0 as _, write_cd: 0 as _,
-        line_term: 0 as _, line_term_len: 0, buf_size: 0, read_buf: 0 as _,
-        encoded_read_buf: 0 as _, write_buf: 0 as _, partial_write_buf: [0; 6],
-        reserved1: 0 as _, reserved2: 0 as _,
-
-        flags: Flags::new()
-            + Flag::DoEncode   // Same as: `.set_flag(Flag::DoEncode, true)`
-            + Flag::IsWritable // Same as: `.set_flag(Flag::IsWritable, true)`
-    };
+    let mut channel = GIOChannel::new(
+        0, 0 as _, 0 as _, 0 as _, 0 as _, 0 as _, 0, 0, 0 as _, 0 as _, 0 as _, [0; 6], 0 as _, 0 as _
+    );
+    channel.set_do_encode(1);
+    channel.set_is_writeable(1);
 
     // Check and update flag.
-    if channel.flags.has(Flag::DoEncode) {
-        channel.flags -= Flag::DoEncode; // Same as: `.set_flag(Flag::DoEncode, false)`
+    if channel.do_encode() != 0 {
+        channel.set_do_encode(0);
     }
     // Invert flag.
-    channel.flags ^= Flag::CloseOnUnref; // Same as: `.invert_flag(Flag::CloseOnUnref)`
+    channel.set_close_on_unref(channel.close_on_unref() ^ 1);
 
-    assert_eq!(&format!("{}", &channel.flags), "CloseOnUnref | IsWritable");
+    assert_eq!(
+        (channel.use_buffer(), channel.do_encode(), channel.close_on_unref(), channel.is_writeable()),
+        (0, 0, 1, 1)
+    );
 
-    println!("Flags: {}", &channel.flags);
+    println!("Close on unref: {}, is writeable: {}", channel.close_on_unref(), channel.is_writeable());
 }
 
 #[cfg(test)]
@@ -138,7 +108,7 @@ mod tests {
             4 +     // encoded_read_buf: *const GString,
             4 +     // write_buf: *const GString,
             6 +     // partial_write_buf: [gchar; 6],
-            1 +     // flags: Flags,
+            1 +     // use_buffer ... is_seekable: guint : 1,
             (1) +   // -- alignment --
             4 +     // reserved1: gpointer,
             4       // reserved2: gpointer
@@ -161,7 +131,7 @@ mod tests {
             8 +     // encoded_read_buf: *const GString,
             8 +     // write_buf: *const GString,
             6 +     // partial_write_buf: [gchar; 6],
-            1 +     // flags: Flags,
+            1 +     // use_buffer ... is_seekable: guint : 1,
             (1) +   // -- alignment --
             8 +     // reserved1: gpointer,
             8       // reserved2: gpointer
@@ -1,7 +1,5 @@
 #![allow(unused)]
 
-extern crate alloc;
-
 /// [Writing an OS in Rust - VGA Text Mode](https://os.phil-opp.com/vga-text-mode/)
 ///
 /// The contents of a single screen character.
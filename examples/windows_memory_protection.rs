@@ -1,7 +1,5 @@
 #![allow(unused)]
 
-extern crate alloc;
-
 /// This could also be solved by using a generic bit field for `FlagAlloc` and `FlagProtect`.
 ///
 /// [Microsoft Docs: Memory Protection Constants](https://docs.microsoft.com/en-us/windows/desktop/Memory/memory-protection-constants)
@@ -36,7 +34,8 @@ extern crate alloc;
 #[bitfield::bitfield(32, allow_overlaps)]
 #[derive(Debug)]
 struct Protection {
-    #[field(size = 8)]
+    // Generates `access_is_copy_on_write()`, `access_is_execute()`, ...
+    #[field(size = 8, groups(copy_on_write, execute, read, write))]
     access: Access,
     flag: Flag,
     flag_alloc: FlagAlloc,
@@ -45,66 +44,25 @@ struct Protection {
     flag_unknown: FlagUnknown
 }
 
-// Getters for specific access flags.
-impl Protection {
-    // TODO: Add `const` when https://github.com/rust-lang/rust-project-goals/issues/106 is merged.
-    fn copy_on_write(&self) -> bool {
-        match self.access() {
-            Ok(Access::ReadWriteCopy) |
-            Ok(Access::ExecuteReadWriteCopy) => true,
-            _ => false
-        }
-    }
-
-    // TODO: Add `const` when https://github.com/rust-lang/rust-project-goals/issues/106 is merged.
-    fn execute(&self) -> bool {
-        match self.access() {
-            Ok(Access::Execute) |
-            Ok(Access::ExecuteRead) |
-            Ok(Access::ExecuteReadWrite) |
-            Ok(Access::ExecuteReadWriteCopy) => true,
-            _ => false
-        }
-    }
-
-    // TODO: Add `const` when https://github.com/rust-lang/rust-project-goals/issues/106 is merged.
-    fn read(&self) -> bool {
-        match self.access() {
-            Ok(Access::Read) |
-            Ok(Access::ReadWrite) |
-            Ok(Access::ReadWriteCopy) |
-            Ok(Access::ExecuteRead) |
-            Ok(Access::ExecuteReadWrite) |
-            Ok(Access::ExecuteReadWriteCopy) => true,
-            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-            Ok(Access::Execute) => true,
-            _ => false
-        }
-    }
-
-    // TODO: Add `const` when https://github.com/rust-lang/rust-project-goals/issues/106 is merged.
-    fn write(&self) -> bool {
-        match self.access() {
-            Ok(Access::ReadWrite) |
-            Ok(Access::ReadWriteCopy) |
-            Ok(Access::ExecuteReadWrite) |
-            Ok(Access::ExecuteReadWriteCopy) => true,
-            _ => false
-        }
-    }
-}
-
 #[derive(Clone, Copy, Debug, bitfield::Field)]
 #[repr(u8)]
 enum Access {
     // Variant 0 is reserved.
     None = 1 << 0,
+    #[group(read)]
     Read = 1 << 1,
+    #[group(read, write)]
     ReadWrite = 1 << 2,
+    #[group(copy_on_write, read, write)]
     ReadWriteCopy = 1 << 3,
+    #[cfg_attr(any(target_arch = "x86", target_arch = "x86_64"), group(read))]
+    #[group(execute)]
     Execute = 1 << 4,
+    #[group(execute, read)]
     ExecuteRead = 1 << 5,
+    #[group(execute, read, write)]
     ExecuteReadWrite = 1 << 6,
+    #[group(copy_on_write, execute, read, write)]
     ExecuteReadWriteCopy = 1 << 7
 }
 
@@ -153,6 +111,9 @@ fn main() {
         + Flag::NoCache              // Same as: `.set_flag(Flag::NoCache, true)`
         + FlagAlloc::TargetsInvalid; // Same as: `.set_flag_alloc(FlagAlloc::TargetsInvalid, true)`
 
+    assert!(protection.access_is_execute() && protection.access_is_write());
+    assert!(!protection.access_is_copy_on_write());
+
     assert_eq!(
         protection.0,
 
@@ -1,5 +1,3 @@
-extern crate alloc;
-
 /// [Microsoft Docs: MessageBox function](https://docs.microsoft.com/en-us/windows/desktop/api/winuser/nf-winuser-messagebox)
 ///
 /// The contents and behavior of the dialog box.
@@ -25,11 +23,12 @@ extern crate alloc;
 /// RTL    = Right To Left Reading
 /// SN     = Service Notification
 /// ```
-#[bitfield::bitfield(32)]
-#[derive(Debug)]
+#[bitfield::bitfield(32, parts)]
+#[derive(Debug, Display)]
+#[display("{button}/{icon}/{default_button} modal={modality} [{style}]", separator = ", ")]
 struct Styles {
-    #[field(size = 4)] button: Button,
-    #[field(size = 4)] icon: Icon,
+    #[field(size = 4, required)] button: Button,
+    #[field(size = 4, required)] icon: Option<Icon>,
     #[field(size = 4)] default_button: DefaultButton,
     #[field(size = 2)] modality: Modality,
     style: Style
@@ -75,8 +74,8 @@ enum DefaultButton {
 #[derive(Clone, Copy, Debug, bitfield::Field)]
 #[repr(u8)]
 enum Icon {
-    None,
-    Stop,
+    // Variant 0 is stored for `None`.
+    Stop = 1,
     Question,
     Exclamation,
     Information
@@ -93,9 +92,11 @@ enum Modality {
 }
 
 fn main() {
-    let styles = Styles::new()
-        + Button::CancelTryContinue // Same as: `.set_button(Button::CancelTryContinue)`
-        + Icon::Exclamation         // Same as: `.set_icon(Icon::Exclamation)`
+    // `build` is only available once the required `button` and `icon` are set.
+    let styles = Styles::builder()
+        .set_button(Button::CancelTryContinue)
+        .set_icon(Some(Icon::Exclamation))
+        .build()
         + DefaultButton::Two        // Same as: `.set_default_button(DefaultButton::Two)`
         + Modality::Task            // Same as: `.set_modality(Modality::Task)`
         + Style::Foreground         // Same as: `.set_style(Style::Foreground, true)`
@@ -115,7 +116,9 @@ fn main() {
     assert_eq!(&format!("{:#?}", &styles),
 "Styles {
     button: CancelTryContinue,
-    icon: Exclamation,
+    icon: Some(
+        Exclamation,
+    ),
     default_button: Two,
     modality: Task,
     style: Style {
@@ -130,5 +133,13 @@ fn main() {
 }"
     );
 
+    assert_eq!(
+        &format!("{}", &styles),
+        "CancelTryContinue/Some(Exclamation)/Two modal=Task [Foreground, TopMost]"
+    );
+
+    let StylesParts { icon, button, .. } = styles.to_parts();
+    assert!(matches!((button, icon), (Ok(Button::CancelTryContinue), Ok(Some(Icon::Exclamation)))));
+
     println!("{:#?}", &styles);
 }
\ No newline at end of file
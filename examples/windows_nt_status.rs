@@ -1,5 +1,3 @@
-extern crate alloc;
-
 /// [Microsoft Docs: NTSTATUS](https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-erref/87fba13e-bf06-450e-83b1-9241dc81e781)
 ///
 /// System-supplied status codes.
@@ -1,5 +1,3 @@
-extern crate alloc;
-
 /// [Microsoft Docs: Access Mask Format](https://docs.microsoft.com/en-us/windows/desktop/secauthz/access-mask-format)
 ///
 /// An access right is a bit flag that corresponds to a particular set of operations that a thread
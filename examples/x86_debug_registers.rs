@@ -1,5 +1,3 @@
-extern crate alloc;
-
 /// [Intel 64 and IA-32 Architectures Software Developer's Manual, Vol. 3A](https://software.intel.com/en-us/articles/intel-sdm)
 ///
 /// # 17.2.3 Debug Status Register (DR6)
@@ -80,7 +78,7 @@ enum Status {
 /// Typ3 = Debug Register 3 Type
 /// Len3 = Debug Register 3 Length
 /// ```
-#[bitfield::bitfield(size)]
+#[bitfield::bitfield(size, keys)]
 #[derive(Debug)]
 struct DebugControl {
     flag: Control,
@@ -136,10 +134,13 @@ enum BreakPointLength {
 fn main() {
     // Set a break point:
     let control = DebugControl::new() // = GetDR7();
-        .set_type0(BreakPointType::Execute) // Not possible as `+ BreakPointType::Execute`, because `BreakPointType` is used more than once.
-        .set_length0(BreakPointLength::One) // Not possible as `+ BreakPointLength::One`, because `BreakPointLength` is used more than once.
-        + Control::ExactInstructionLocal // Same as: `.set_flag(Control::ExactInstructionLocal, true)`
-        + Control::DebugRegister0Local;  // Same as: `.set_flag(Control::DebugRegister0Local, true)`
+        + DebugControlType0(BreakPointType::Execute) // Same as: `.set_type0(BreakPointType::Execute)`
+        + DebugControlLength0(BreakPointLength::One) // Same as: `.set_length0(BreakPointLength::One)`
+        + Control::ExactInstructionLocal             // Same as: `.set_flag(Control::ExactInstructionLocal, true)`
+        + Control::DebugRegister0Local;              // Same as: `.set_flag(Control::DebugRegister0Local, true)`
+
+    // Same as: `control.type0()`
+    assert!(matches!(control.get::<DebugControlType0>(), BreakPointType::Execute));
 
     println!("{:#?}", &control);
 
@@ -107,29 +107,48 @@ impl super::BitField {
         }
     }
 
+    /// Combines the getter, setter and inverter of an entry, leaving out the accessors its access
+    /// mode does not allow.
+    fn combine_accessors(
+        entry: &super::Entry,
+        getter: proc_macro2::TokenStream,
+        setter: proc_macro2::TokenStream,
+        inverter: proc_macro2::TokenStream
+    ) -> proc_macro2::TokenStream {
+        let getter = entry.is_readable().then_some(getter);
+        let setter = entry.is_writable().then_some(setter);
+        let inverter = (entry.is_readable() && entry.is_writable()).then_some(inverter);
+
+        quote::quote! {
+            #getter
+            #setter
+            #inverter
+        }
+    }
+
     /// Generates the accessors for a single entry.
     fn generate_accessor(
         &self,
         entry: &super::Entry,
-        getter: &syn::Ident,
-        setter: &syn::Ident,
-        inverter: &syn::Ident,
+        accessors: &super::Accessors,
         span: proc_macro2::Span,
         is_only_entry: bool
     ) -> proc_macro2::TokenStream {
+        let super::Accessors { getter, setter, inverter, .. } = accessors;
         let attrs = &entry.attrs;
         let base_type = &self.attr.base_type;
         let vis = &entry.vis;
         let ty = &entry.ty;
 
+        let result = self.generate_stored_result();
         let (constructor, constructor_all, constructor_type, destructor) = if !self.attr.is_non_zero {(
-            quote::quote!(Self(result)),
-            quote::quote!(Self(result)),
+            self.generate_new(quote::quote!(Self), &result),
+            self.generate_new(quote::quote!(Self), &result),
             quote::quote!(Self),
             quote::quote!(self.0)
         )} else {(
-            quote::quote!(match #base_type::new(result) { Some(result) => Some(Self(result)), None => None }),
-            quote::quote!(Self(unsafe { #base_type::new_unchecked(result) })),
+            self.generate_new_non_zero(),
+            self.generate_new(quote::quote!(Self), quote::quote!(unsafe { #base_type::new_unchecked(result) })),
             quote::quote!(::core::option::Option<Self>),
             quote::quote!(self.0.get())
         )};
@@ -138,11 +157,76 @@ impl super::BitField {
             let bit = field.bit.as_ref().unwrap().base10_parse::<u8>().unwrap();
             let size = field.size.as_ref().unwrap().base10_parse::<u8>().unwrap();
 
+            // Special handling for `Option<NonZero*>`, which stores `None` as `0`.
+            if let Some(field_size) = entry.non_zero_bits() {
+                let primitive_type_unsigned = crate::primitive::type_from_bits(field_size, false, field.size.span());
+                let inner = entry.optional_type().unwrap();
+
+                let value = quote::quote! {
+                    match value {
+                        ::core::option::Option::Some(value) => value.get(),
+                        ::core::option::Option::None => 0
+                    }
+                };
+
+                let getter = quote::quote_spanned! { span =>
+                    #(#attrs)*
+                    /// Gets the value of the field, `None` if all bits of the field are cleared.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #vis const fn #getter(&self) -> #ty {
+                        #inner::new(self._field(#bit, #size) as _)
+                    }
+                };
+
+                let setter = if entry.is_partial() {
+                    let optional_set_field = match self.attr.is_non_zero {
+                        false => quote::quote!(Some(self._set_field(#bit, #size, raw as _))),
+                        true => quote::quote!(self._set_field(#bit, #size, raw as _)),
+                    };
+
+                    // Fields with a size < bits_of(FieldPrimitive).
+                    quote::quote_spanned! { span =>
+                        #(#attrs)*
+                        /// Creates a copy of the bit field with the new value, `None` clears all
+                        /// bits of the field.
+                        ///
+                        /// Returns `None` if `value` is bigger than the specified amount of
+                        /// bits the field can store.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                        #vis const fn #setter(&self, value: #ty) -> ::core::option::Option<Self> {
+                            let raw = #value;
+                            if raw >= (1 as #primitive_type_unsigned).wrapping_shl(#size as u32) {
+                                return None;
+                            }
+
+                            #optional_set_field
+                        }
+                    }
+                } else {
+                    // Fields with a size == bits_of(FieldPrimitive).
+                    quote::quote_spanned! { span =>
+                        #(#attrs)*
+                        /// Creates a copy of the bit field with the new value, `None` clears all
+                        /// bits of the field.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                        #vis const fn #setter(&self, value: #ty) -> #constructor_type {
+                            self._set_field(#bit, #size, #value as _)
+                        }
+                    }
+                };
+
+                return Self::combine_accessors(entry, getter, setter, proc_macro2::TokenStream::new());
+            }
 
             // Special handling for primitive types.
             if let Some(ty) = ty.get_ident() {
                 if crate::primitive::is_bool(ty) {
-                    return quote::quote_spanned! { span =>
+                    return Self::combine_accessors(entry, quote::quote_spanned! { span =>
                         #(#attrs)*
                         /// Gets the value of the field.
                         #[allow(unused)]
@@ -150,7 +234,7 @@ impl super::BitField {
                         #vis const fn #getter(&self) -> #ty {
                             self._bit(#bit)
                         }
-
+                    }, quote::quote_spanned! { span =>
                         #(#attrs)*
                         /// Creates a copy of the bit field with the new value.
                         #[allow(unused)]
@@ -159,7 +243,7 @@ impl super::BitField {
                         #vis const fn #setter(&self, value: #ty) -> #constructor_type {
                             self._set_bit(#bit, value)
                         }
-
+                    }, quote::quote_spanned! { span =>
                         #(#attrs)*
                         /// Creates a copy of the bit field with the value of the field inverted.
                         #[allow(unused)]
@@ -168,11 +252,11 @@ impl super::BitField {
                         #vis const fn #inverter(&self) -> #constructor_type {
                             self._invert_bit(#bit)
                         }
-                    };
+                    });
                 } else if crate::primitive::is_signed_primitive(ty) {
                     let primitive_type_unsigned = crate::primitive::type_from_bits(size, false, field.size.span());
 
-                    return quote::quote_spanned! { span =>
+                    return Self::combine_accessors(entry, quote::quote_spanned! { span =>
                         #(#attrs)*
                         /// Gets the value of the field.
                         #[allow(unused)]
@@ -180,7 +264,7 @@ impl super::BitField {
                         #vis const fn #getter(&self) -> #ty {
                             self._field(#bit, #size) as _
                         }
-
+                    }, quote::quote_spanned! { span =>
                         #(#attrs)*
                         /// Creates a copy of the bit field with the new value.
                         #[allow(unused)]
@@ -189,24 +273,26 @@ impl super::BitField {
                         #vis const fn #setter(&self, value: #ty) -> #constructor_type {
                             self._set_field(#bit, #size, value as #primitive_type_unsigned as _)
                         }
-                    };
+                    }, proc_macro2::TokenStream::new());
                 } else if crate::primitive::is_unsigned_primitive(ty) {
                     let optional_set_field = match self.attr.is_non_zero {
                         false => quote::quote!(Some(self._set_field(#bit, #size, value as _))),
                         true => quote::quote!(self._set_field(#bit, #size, value as _)),
                     };
 
-                    return if crate::primitive::primitive_bits(ty).unwrap() != size {
+                    let getter = quote::quote_spanned! { span =>
+                        #(#attrs)*
+                        /// Gets the value of the field.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        #vis const fn #getter(&self) -> #ty {
+                            self._field(#bit, #size) as _
+                        }
+                    };
+
+                    let setter = if crate::primitive::primitive_bits(ty).unwrap() != size {
                         // Fields with a size < bits_of(FieldPrimitive).
                         quote::quote_spanned! { span =>
-                            #(#attrs)*
-                            /// Gets the value of the field.
-                            #[allow(unused)]
-                            #[inline(always)]
-                            #vis const fn #getter(&self) -> #ty {
-                                self._field(#bit, #size) as _
-                            }
-
                             // TODO: Use ranged integers when they land: https://github.com/rust-lang/rfcs/issues/671.
                             #(#attrs)*
                             /// Creates a copy of the bit field with the new value.
@@ -227,14 +313,6 @@ impl super::BitField {
                     } else {
                         // Fields with a size == bits_of(FieldPrimitive).
                         quote::quote_spanned! { span =>
-                            #(#attrs)*
-                            /// Gets the value of the field.
-                            #[allow(unused)]
-                            #[inline(always)]
-                            #vis const fn #getter(&self) -> #ty {
-                                self._field(#bit, #size) as _
-                            }
-
                             #(#attrs)*
                             /// Creates a copy of the bit field with the new value.
                             #[allow(unused)]
@@ -244,87 +322,95 @@ impl super::BitField {
                                 self._set_field(#bit, #size, value as _)
                             }
                         }
-                    }
+                    };
+
+                    return Self::combine_accessors(entry, getter, setter, proc_macro2::TokenStream::new());
                 }
             }
 
             // Handling for non-primitive types.
 
             // Generate the minimal primitive type the field needs.
-            let primitive_type_signed = crate::primitive::type_from_bits(size, true, field.size.span());
             let primitive_type_unsigned = crate::primitive::type_from_bits(size, false, field.size.span());
 
             let body_span = ty.span();
-
-            // This is a workaround to automatically call the correct `TryFrom<iX / uX> for Enum` implementation.
-            let body = quote::quote_spanned! { body_span =>
-                #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: #primitive_type_signed) -> ::core::result::Result<Self, #primitive_type_signed>; }
-                #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: #primitive_type_unsigned) -> ::core::result::Result<Self, #primitive_type_unsigned>; }
-
-                impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<#primitive_type_signed, Error = #primitive_type_signed> {
-                    fn _bitfield_extract_primitive(value: #primitive_type_signed) -> ::core::result::Result<Self, #primitive_type_signed> {
-                        <T as ::core::convert::TryFrom<#primitive_type_signed>>::try_from(value)
-                    }
-                }
-
-                impl<T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<#primitive_type_unsigned, Error = #primitive_type_unsigned> {
-                    fn _bitfield_extract_primitive(value: #primitive_type_unsigned) -> ::core::result::Result<Self, #primitive_type_unsigned> {
-                        <T as ::core::convert::TryFrom<#primitive_type_unsigned>>::try_from(value)
-                    }
-                }
-
-                #ty::_bitfield_extract_primitive(self._field(#bit, #size) as _)
-            };
-
-            let (body, getter_type, doc) = match field.complete.is_some() {
-                false => (
-                    quote::quote_spanned!(body_span => #body.map_err(|e| e as #primitive_type_unsigned)),
+            let optional = entry.optional_type();
+            let stored_ty = entry.stored_type();
+
+            // Enumerations are converted with the `const fn from_repr` generated by `bitfield::Field`.
+            let (body, getter_type, doc) = match (field.complete.is_some(), optional.is_some()) {
+                (false, false) => (
+                    quote::quote_spanned!(body_span =>
+                        let value = self._field(#bit, #size);
+                        match #stored_ty::from_repr(value as _) {
+                            ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                            ::core::option::Option::None => ::core::result::Result::Err(value as #primitive_type_unsigned)
+                        }
+                    ),
                     quote::quote_spanned!(body_span => ::core::result::Result<#ty, #primitive_type_unsigned>),
                     quote::quote_spanned!(body_span => #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."])
                 ),
-                true => (
-                    quote::quote_spanned!(body_span => unsafe { #body.unwrap_unchecked() }),
+                (true, false) => (
+                    quote::quote_spanned!(body_span => unsafe {
+                        #stored_ty::from_repr(self._field(#bit, #size) as _).unwrap_unchecked()
+                    }),
                     quote::quote_spanned!(body_span => #ty),
                     quote::quote!()
+                ),
+                (false, true) => (
+                    quote::quote_spanned!(body_span =>
+                        let value = self._field(#bit, #size);
+                        match #stored_ty::from_repr(value as _) {
+                            ::core::option::Option::Some(variant) => ::core::result::Result::Ok(::core::option::Option::Some(variant)),
+                            ::core::option::Option::None if value == 0 => ::core::result::Result::Ok(::core::option::Option::None),
+                            ::core::option::Option::None => ::core::result::Result::Err(value as #primitive_type_unsigned)
+                        }
+                    ),
+                    quote::quote_spanned!(body_span => ::core::result::Result<#ty, #primitive_type_unsigned>),
+                    quote::quote_spanned!(body_span =>
+                        #[doc = "Returns `None` if all bits of the field are cleared, and the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
+                    )
+                ),
+                // No variant uses `0` and all other values are used, so only `0` is `None`.
+                (true, true) => (
+                    quote::quote_spanned!(body_span => #stored_ty::from_repr(self._field(#bit, #size) as _)),
+                    quote::quote_spanned!(body_span => #ty),
+                    quote::quote_spanned!(body_span => #[doc = "Returns `None` if all bits of the field are cleared."])
                 )
             };
 
-            quote::quote_spanned! { span =>
-                // TODO: Add `const` when https://github.com/rust-lang/rust-project-goals/issues/106 is merged.
+            // `None` is stored as `0`.
+            let value = match optional {
+                Some(_) => quote::quote_spanned!(body_span => match value {
+                    ::core::option::Option::Some(value) => value as #primitive_type_unsigned,
+                    ::core::option::Option::None => 0
+                }),
+                None => quote::quote_spanned!(body_span => value as #primitive_type_unsigned)
+            };
+
+            Self::combine_accessors(entry, quote::quote_spanned! { span =>
                 #(#attrs)*
                 /// Gets the value of the field.
                 #doc
                 #[allow(unused)]
                 #[inline(always)]
-                #vis fn #getter(&self) -> #getter_type { #body }
-
+                #vis const fn #getter(&self) -> #getter_type { #body }
+            }, quote::quote_spanned! { span =>
                 #(#attrs)*
                 /// Creates a copy of the bit field with the new value.
                 #[allow(unused)]
                 #[inline(always)]
                 #[must_use = "leaves `self` unmodified and returns a modified variant"]
                 #vis const fn #setter(&self, value: #ty) -> #constructor_type {
-                    self._set_field(#bit, #size, value as #primitive_type_unsigned as _)
+                    self._set_field(#bit, #size, #value as _)
                 }
-            }
+            }, proc_macro2::TokenStream::new())
         } else {
-            let unraw = getter.unraw();
-
-            let getter_mask = syn::Ident::new(
-                &format!("{}_mask", &unraw), getter.span()
-            );
-            let getter_all = syn::Ident::new(
-                &format!("{}_all", &unraw), getter.span()
-            );
-            let getter_any = syn::Ident::new(
-                &format!("{}_any", &unraw), getter.span()
-            );
-            let setter_all = syn::Ident::new(
-                &format!("{}_all", setter), setter.span()
-            );
-            let setter_none = syn::Ident::new(
-                &format!("{}_none", setter), setter.span()
-            );
+            let getter_mask = super::Accessors::suffixed(getter, "mask");
+            let getter_all = super::Accessors::suffixed(getter, "all");
+            let getter_any = super::Accessors::suffixed(getter, "any");
+            let setter_all = super::Accessors::suffixed(setter, "all");
+            let setter_none = super::Accessors::suffixed(setter, "none");
 
             let primitive_type = &self.attr.primitive_type;
 
@@ -343,7 +429,10 @@ impl super::BitField {
                 true => proc_macro2::TokenStream::new()
             };
 
-            quote::quote_spanned! { span =>
+            let is_readable = entry.is_readable();
+            let is_writable = entry.is_writable();
+
+            let getters = is_readable.then(|| quote::quote_spanned! { span =>
                 #(#attrs)*
                 /// Returns `true` if the specified `flag` is set.
                 #[allow(unused)]
@@ -351,24 +440,9 @@ impl super::BitField {
                 #vis const fn #getter(&self, flag: #ty) -> bool {
                     self._bit(flag as _)
                 }
+            });
 
-                #(#attrs)*
-                /// Returns a bit mask of all possible flags.
-                #[allow(unused)]
-                #[inline(always)]
-                #vis const fn #getter_mask() -> #primitive_type {
-                    let mut mask = 0;
-
-                    let mut i = 0;
-                    while i < #ty::iter().len() {
-                        mask |= 1 << (#ty::iter()[i] as #primitive_type);
-
-                        i += 1;
-                    }
-
-                    mask
-                }
-
+            let getters_all = is_readable.then(|| quote::quote_spanned! { span =>
                 #(#attrs)*
                 /// Returns `true` if all flags are set.
                 #[allow(unused)]
@@ -384,7 +458,9 @@ impl super::BitField {
                 #vis const fn #getter_any(&self) -> bool {
                     (#destructor & Self::#getter_mask()) != 0
                 }
+            });
 
+            let setters = is_writable.then(|| quote::quote_spanned! { span =>
                 #(#attrs)*
                 /// Creates a copy of the bit field with the new value for the specified flag.
                 #[allow(unused)]
@@ -405,7 +481,9 @@ impl super::BitField {
                 }
 
                 #setter_none
+            });
 
+            let inverter = (is_readable && is_writable).then(|| quote::quote_spanned! { span =>
                 #(#attrs)*
                 /// Creates a copy of the bit field with the value of the specified flag inverted.
                 #[allow(unused)]
@@ -414,63 +492,257 @@ impl super::BitField {
                 #vis const fn #inverter(&self, flag: #ty) -> #constructor_type {
                     self._invert_bit(flag as _)
                 }
+            });
+
+            let clearer = (entry.access_mode() == super::AccessMode::WriteOneToClear).then(|| {
+                let clearer = accessors.prefixed("clear");
+
+                // Writing back other pending write-1-to-clear flags would clear them as well.
+                let masks = self.data.entries().into_iter().zip(self.accessors()).filter(
+                    |(entry, _)| entry.access_mode() == super::AccessMode::WriteOneToClear
+                ).map(|(_, accessors)| {
+                    let mask = super::Accessors::suffixed(&accessors.getter, "mask");
+                    quote::quote!(Self::#mask())
+                });
+
+                quote::quote_spanned! { span =>
+                    #(#attrs)*
+                    /// Creates a copy of the bit field to clear the specified write-1-to-clear `flag`,
+                    /// in which it is the only set write-1-to-clear flag.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                    #vis const fn #clearer(&self, flag: #ty) -> Self {
+                        let result = (#destructor & !(0 #(| #masks)*)) | (1 << (flag as #primitive_type));
+                        #constructor_all
+                    }
+                }
+            });
+
+            quote::quote_spanned! { span =>
+                #getters
+
+                #(#attrs)*
+                /// Returns a bit mask of all possible flags.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis const fn #getter_mask() -> #primitive_type {
+                    let mut mask = 0;
+
+                    let mut i = 0;
+                    while i < #ty::iter().len() {
+                        mask |= 1 << (#ty::iter()[i] as #primitive_type);
+
+                        i += 1;
+                    }
+
+                    mask
+                }
+
+                #getters_all
+                #setters
+                #inverter
+                #clearer
             }
         }
     }
 
-    /// Generates the getters and setters for all fields and flags.
-    fn generate_accessors(&self) -> proc_macro2::TokenStream {
-        let fields = match &self.data {
-            super::Data::Named(entries) => {
-                let mut fields = vec!();
-
-                for entry in entries {
-                    let unraw = entry.ident.unraw();
+    /// Generates the `&mut self` variants of the setters for a single entry.
+    ///
+    /// Mutators whose copy-returning setter returns an `Option<Self>` are generated with a `try_`
+    /// prefix. They return the rejected input in an `Err` and leave the bit field unmodified. This
+    /// applies to fields which do not use all bits of their primitive type, and to all mutators of
+    /// `NonZero` bit fields which could clear the last set bit.
+    fn generate_accessor_in_place(
+        &self,
+        entry: &super::Entry,
+        accessors: &super::Accessors,
+        span: proc_macro2::Span
+    ) -> proc_macro2::TokenStream {
+        let super::Accessors { getter, setter, inverter, .. } = accessors;
+        let attrs = &entry.attrs;
+        let base_type = &self.attr.base_type;
+        let primitive_type = &self.attr.primitive_type;
+        let vis = &entry.vis;
+        let ty = &entry.ty;
 
-                    fields.push(Self::generate_accessor(
-                        &self, &entry.entry, &entry.ident, &syn::Ident::new(
-                            &format!("set_{}", &unraw), entry.ident.span()
-                        ), &syn::Ident::new(
-                            &format!("invert_{}", &unraw), entry.ident.span()
-                        ), entry.ident.span(),
-                        entries.len() == 1
-                    ));
+        // Generates an infallible mutator, or a `try_` mutator if `reason` describes when `result`
+        // is `None`, which returns `rejected` in that case.
+        let mutator = |
+            ident: syn::Ident, doc: &str, parameters: proc_macro2::TokenStream,
+            result: proc_macro2::TokenStream, reason: Option<&str>,
+            rejected: proc_macro2::TokenStream, rejected_type: proc_macro2::TokenStream
+        | match reason {
+            None => {
+                quote::quote_spanned! { span =>
+                    #(#attrs)*
+                    #[doc = #doc]
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #vis const fn #ident(&mut self #parameters) {
+                        *self = #result;
+                    }
                 }
-
-                fields
             },
 
-            super::Data::Tuple(entry) => {
-                vec!(Self::generate_accessor(
-                    &self, entry,
-                    &syn::Ident::new(
-                        if entry.field.is_some() { "get" } else { "has" },
-                        entry.ty.span()
-                    ),
-                    &syn::Ident::new("set", entry.ty.span()),
-                    &syn::Ident::new("invert", entry.ty.span()),
-                    entry.ty.span(),
-                    true
-                ))
+            Some(reason) => {
+                let ident = quote::format_ident!("try_{}", ident);
+                let reason = format!(
+                    " Returns the rejected value in `Err` and leaves the bit field unmodified, {}.", reason
+                );
+
+                quote::quote_spanned! { span =>
+                    #(#attrs)*
+                    #[doc = #doc]
+                    #[doc = ""]
+                    #[doc = #reason]
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #vis const fn #ident(&mut self #parameters) -> ::core::result::Result<(), #rejected_type> {
+                        match #result {
+                            ::core::option::Option::Some(result) => {
+                                *self = result;
+                                ::core::result::Result::Ok(())
+                            },
+                            ::core::option::Option::None => ::core::result::Result::Err(#rejected)
+                        }
+                    }
+                }
             }
         };
 
+        const TOO_BIG: &str = "if `value` is bigger than the specified amount of bits the field can store";
+        const ZERO: &str = "if all bits of the bit field would be cleared";
+
+        let is_non_zero = self.attr.is_non_zero;
+
+        // All mutators write to the bit field.
+        if !entry.is_writable() {
+            return proc_macro2::TokenStream::new();
+        }
+
+        if entry.field.is_some() {
+            let primitive = ty.get_ident().filter(|ty| crate::primitive::is_primitive(ty));
+
+            let reason = match (entry.is_partial(), is_non_zero) {
+                (false, false) => None,
+                (true, false) => Some(TOO_BIG.to_string()),
+                (false, true) => Some(ZERO.to_string()),
+                (true, true) => Some(format!("{}, or {}", TOO_BIG, ZERO))
+            };
+
+            let setter = mutator(
+                super::Accessors::suffixed(setter, "in_place"), " Sets the field to the new value.",
+                quote::quote!(, value: #ty), quote::quote!(self.#setter(value)),
+                reason.as_deref(), quote::quote!(value), quote::quote!(#ty)
+            );
+
+            let inverter = match primitive.map(crate::primitive::is_bool).unwrap_or_default() && entry.is_readable() {
+                false => proc_macro2::TokenStream::new(),
+                true => mutator(
+                    super::Accessors::suffixed(inverter, "in_place"), " Inverts the value of the field.",
+                    quote::quote!(), quote::quote!(self.#inverter()),
+                    is_non_zero.then_some(ZERO), quote::quote!(!self.#getter()), quote::quote!(bool)
+                )
+            };
+
+            return quote::quote! {
+                #setter
+                #inverter
+            };
+        }
+
+        let result = self.generate_stored_result();
+        let (constructor_all, destructor) = match is_non_zero {
+            false => (self.generate_new(quote::quote!(Self), &result), quote::quote!(self.0)),
+            true => (
+                self.generate_new(quote::quote!(Self), quote::quote!(unsafe { #base_type::new_unchecked(result) })),
+                quote::quote!(self.0.get())
+            )
+        };
+
+        let insert = accessors.prefixed("insert");
+        let setter_in_place = mutator(
+            super::Accessors::suffixed(setter, "in_place"), " Sets the specified `flag` to the new value.",
+            quote::quote!(, flag: #ty, value: bool), quote::quote!(self.#setter(flag, value)),
+            is_non_zero.then_some(ZERO), quote::quote!(flag), quote::quote!(#ty)
+        );
+        let remove = mutator(
+            accessors.prefixed("remove"), " Clears the specified `flag`.",
+            quote::quote!(, flag: #ty), quote::quote!(self.#setter(flag, false)),
+            is_non_zero.then_some(ZERO), quote::quote!(flag), quote::quote!(#ty)
+        );
+        let toggle = entry.is_readable().then(|| mutator(
+            accessors.prefixed("toggle"), " Inverts the value of the specified `flag`.",
+            quote::quote!(, flag: #ty), quote::quote!(self.#inverter(flag)),
+            is_non_zero.then_some(ZERO), quote::quote!(flag), quote::quote!(#ty)
+        ));
+
+        quote::quote_spanned! { span =>
+            #setter_in_place
+
+            #(#attrs)*
+            /// Sets the specified `flag`.
+            #[allow(unused)]
+            #[inline(always)]
+            #vis const fn #insert(&mut self, flag: #ty) {
+                let result = #destructor | (1 << (flag as #primitive_type));
+                *self = #constructor_all;
+            }
+
+            #remove
+            #toggle
+        }
+    }
+
+    /// Generates the getters and setters for all fields and flags.
+    fn generate_accessors(&self) -> proc_macro2::TokenStream {
+        let entries = self.data.entries();
+        let is_only_entry = entries.len() == 1;
+
+        let fields: Vec<_> = entries.iter().zip(self.accessors()).map(|(entry, accessors)| {
+            let span = accessors.name.as_ref().map(|name| name.span()).unwrap_or_else(|| entry.ty.span());
+            Self::generate_accessor(&self, entry, &accessors, span, is_only_entry)
+        }).collect();
+
         if fields.is_empty() {
             return proc_macro2::TokenStream::new();
         }
 
         let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
         quote::quote! {
-            impl #ident {
+            impl #impl_generics #ident #ty_generics #where_clause {
                 #(#fields)*
             }
         }
     }
 
+    /// Generates the `&mut self` variants of the setters for all fields and flags.
+    fn generate_accessors_in_place(&self) -> proc_macro2::TokenStream {
+        let mutators: Vec<_> = self.data.entries().iter().zip(self.accessors()).map(|(entry, accessors)| {
+            let span = accessors.name.as_ref().map(|name| name.span()).unwrap_or_else(|| entry.ty.span());
+            Self::generate_accessor_in_place(&self, entry, &accessors, span)
+        }).filter(|mutators| !mutators.is_empty()).collect();
+
+        if mutators.is_empty() {
+            return proc_macro2::TokenStream::new();
+        }
+
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        quote::quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #(#mutators)*
+            }
+        }
+    }
+
     /// Generates `::core::ops::*` implementations for flags, but only if the flag visibility is equal
     /// or higher than the bit field visibility, and for fields, if the type of the field is only
-    /// used once.
+    /// used once. Entries whose access mode does not allow the needed accessors are skipped.
     /// Also no generation is done for `NonZero` bitfields as the implementations can not return `Option<Self>`.
     fn generate_accessors_ops(&self) -> proc_macro2::TokenStream {
         if self.attr.is_non_zero {
@@ -483,7 +755,9 @@ impl super::BitField {
         for ty in self.data
             .entries()
             .iter()
-            .filter_map(|x| x.field.is_some().then(|| &x.ty))
+            .enumerate()
+            .filter(|(i, _)| !self.data.is_repeated(*i))
+            .filter_map(|(_, x)| x.field.is_some().then(|| &x.ty))
         {
             let ty = quote::quote!(#ty).to_string();
 
@@ -497,73 +771,41 @@ impl super::BitField {
         // Collect all operator implementations.
         let mut implementations = vec!();
 
-        match &self.data {
-            super::Data::Named(entries) => {
-                for entry in entries {
-                    let unraw = entry.ident.unraw();
+        for (entry, accessors) in self.data.entries().into_iter().zip(self.accessors()) {
+            let span = accessors.name.as_ref().map(|name| name.span()).unwrap_or_else(|| entry.ty.span());
 
-                    if entry.entry.field.is_some() {
-                        if let Some(ident) = entry.entry.ty.get_ident() {
-                            if crate::primitive::is_primitive(ident) {
-                                continue;
-                            }
-                        }
+            // The operators need the setter, and for flags also the inverter.
+            if !entry.is_writable() || (entry.field.is_none() && !entry.is_readable()) {
+                continue;
+            }
 
-                        let ty = &entry.entry.ty;
-                        let ty = quote::quote!(#ty).to_string();
-
-                        if let Some(occurrences) = field_type_occurrences.get(&ty) {
-                            if *occurrences == 1 {
-                                implementations.push(Self::generate_accessor_ops_field(
-                                    &self, &entry.entry.ty, &syn::Ident::new(
-                                        &format!("set_{}", &unraw), entry.ident.span()
-                                    ), entry.ident.span()
-                                ));
-                            }
-                        }
-                    } else {
-                        if Self::cmp_vis(&entry.entry.vis, &self.vis) >= 0 {
-                            implementations.push(Self::generate_accessor_ops_flags(
-                                &self, &entry.entry.ty, &syn::Ident::new(
-                                    &format!("set_{}", &unraw), entry.ident.span()
-                                ),&syn::Ident::new(
-                                    &format!("invert_{}", &unraw), entry.ident.span()
-                                ), entry.ident.span()
-                            ));
-                        }
+            if entry.field.is_some() {
+                if let Some(ident) = entry.ty.get_ident() {
+                    if crate::primitive::is_primitive(ident) {
+                        continue;
                     }
                 }
-            },
 
-            super::Data::Tuple(entry) => {
-                if entry.field.is_some() {
-                    if !entry.ty.get_ident()
-                        .map(|i| crate::primitive::is_primitive(i))
-                        .unwrap_or_default()
-                    {
-                        let ty = &entry.ty;
-                        let ty = quote::quote!(#ty).to_string();
-
-                        if let Some(occurrences) = field_type_occurrences.get(&ty) {
-                            if *occurrences == 1 {
-                                implementations.push(Self::generate_accessor_ops_field(
-                                    &self, &entry.ty,
-                                    &syn::Ident::new("set", entry.ty.span()),
-                                    entry.ty.span()
-                                ));
-                            }
-                        }
-                    }
-                } else {
-                    if Self::cmp_vis(&entry.vis, &self.vis) >= 0 {
-                        implementations.push(Self::generate_accessor_ops_flags(
-                            &self, &entry.ty,
-                            &syn::Ident::new("set", entry.ty.span()),
-                            &syn::Ident::new("invert", entry.ty.span()),
-                            entry.ty.span()
+                if entry.non_zero_bits().is_some() {
+                    continue;
+                }
+
+                let ty = &entry.ty;
+                let ty = quote::quote!(#ty).to_string();
+
+                if let Some(occurrences) = field_type_occurrences.get(&ty) {
+                    if *occurrences == 1 {
+                        implementations.push(Self::generate_accessor_ops_field(
+                            &self, &entry.cfgs(), &entry.ty, &accessors.setter, span
                         ));
                     }
                 }
+            } else {
+                if Self::cmp_vis(&entry.vis, &self.vis) >= 0 {
+                    implementations.push(Self::generate_accessor_ops_flags(
+                        &self, &entry.cfgs(), &entry.ty, &accessors.setter, &accessors.inverter, span
+                    ));
+                }
             }
         }
 
@@ -576,17 +818,21 @@ impl super::BitField {
         }
     }
 
-    /// Generates `::core::ops::*` implementations for a single field in a bit field.
+    /// Generates `::core::ops::*` implementations for a single field in a bit field, which only
+    /// exist in the configurations of `cfgs`.
     fn generate_accessor_ops_field(
         &self,
+        cfgs: &[&syn::Attribute],
         field: &syn::Path,
         setter: &syn::Ident,
         span: proc_macro2::Span
     ) -> proc_macro2::TokenStream {
         let ty = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
         quote::quote_spanned! { span =>
-            impl ::core::ops::Add<#field> for #ty {
+            #(#cfgs)*
+            impl #impl_generics ::core::ops::Add<#field> for #ty #ty_generics #where_clause {
                 type Output = Self;
 
                 #[inline(always)]
@@ -595,7 +841,8 @@ impl super::BitField {
                 }
             }
 
-            impl ::core::ops::AddAssign<#field> for #ty {
+            #(#cfgs)*
+            impl #impl_generics ::core::ops::AddAssign<#field> for #ty #ty_generics #where_clause {
                 #[inline(always)]
                 fn add_assign(&mut self, value: #field) {
                     self.0 = self.#setter(value).0;
@@ -604,18 +851,22 @@ impl super::BitField {
         }
     }
 
-    /// Generates `::core::ops::*` implementations for a single flag type in a bit field.
+    /// Generates `::core::ops::*` implementations for a single flag type in a bit field, which only
+    /// exist in the configurations of `cfgs`.
     fn generate_accessor_ops_flags(
         &self,
+        cfgs: &[&syn::Attribute],
         flags: &syn::Path,
         setter: &syn::Ident,
         inverter: &syn::Ident,
         span: proc_macro2::Span
     ) -> proc_macro2::TokenStream {
         let ty = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
         quote::quote_spanned! { span =>
-            impl ::core::ops::Add<#flags> for #ty {
+            #(#cfgs)*
+            impl #impl_generics ::core::ops::Add<#flags> for #ty #ty_generics #where_clause {
                 type Output = Self;
 
                 #[inline(always)]
@@ -624,14 +875,16 @@ impl super::BitField {
                 }
             }
 
-            impl ::core::ops::AddAssign<#flags> for #ty {
+            #(#cfgs)*
+            impl #impl_generics ::core::ops::AddAssign<#flags> for #ty #ty_generics #where_clause {
                 #[inline(always)]
                 fn add_assign(&mut self, flag: #flags) {
                     self.0 = self.#setter(flag, true).0;
                 }
             }
 
-            impl ::core::ops::BitXor<#flags> for #ty {
+            #(#cfgs)*
+            impl #impl_generics ::core::ops::BitXor<#flags> for #ty #ty_generics #where_clause {
                 type Output = Self;
 
                 #[inline(always)]
@@ -640,14 +893,16 @@ impl super::BitField {
                 }
             }
 
-            impl ::core::ops::BitXorAssign<#flags> for #ty {
+            #(#cfgs)*
+            impl #impl_generics ::core::ops::BitXorAssign<#flags> for #ty #ty_generics #where_clause {
                 #[inline(always)]
                 fn bitxor_assign(&mut self, flag: #flags) {
                     self.0 = self.#inverter(flag).0;
                 }
             }
 
-            impl ::core::ops::Sub<#flags> for #ty {
+            #(#cfgs)*
+            impl #impl_generics ::core::ops::Sub<#flags> for #ty #ty_generics #where_clause {
                 type Output = Self;
 
                 #[inline(always)]
@@ -656,7 +911,8 @@ impl super::BitField {
                 }
             }
 
-            impl ::core::ops::SubAssign<#flags> for #ty {
+            #(#cfgs)*
+            impl #impl_generics ::core::ops::SubAssign<#flags> for #ty #ty_generics #where_clause {
                 #[inline(always)]
                 fn sub_assign(&mut self, flag: #flags) {
                     self.0 = self.#setter(flag, false).0;
@@ -665,574 +921,2690 @@ impl super::BitField {
         }
     }
 
-    /// Generates the accessors that directly work on the primitive bit field type.
-    fn generate_accessors_low(&self) -> proc_macro2::TokenStream {
-        let ident = &self.ident;
-        let base_type = &self.attr.base_type;
-        let primitive_type = &self.attr.primitive_type;
-
-        let (constructor, constructor_type, destructor) = if !self.attr.is_non_zero {(
-            quote::quote!(Self(result)),
-            quote::quote!(Self),
-            quote::quote!(self.0)
-        )} else {(
-            quote::quote!(match #base_type::new(result) { Some(result) => Some(Self(result)), None => None }),
-            quote::quote!(::core::option::Option<Self>),
-            quote::quote!(self.0.get())
-        )};
-
-        quote::quote! {
-            impl #ident {
-                /// Returns a boolean value whether the specified flag is set.
-                #[inline(always)]
-                const fn _bit(&self, position: u8) -> bool {
-                    ((#destructor >> position) & 1) != 0
-                }
-
-                /// Returns a modified instance with the flag set to the specified value.
-                #[inline(always)]
-                const fn _set_bit(&self, position: u8, value: bool) -> #constructor_type {
-                    let cleared = #destructor & !(1 << position);
-                    let result = cleared | ((value as #primitive_type) << position);
-                    #constructor
-                }
-
-                /// Returns a modified instance with the bit value inverted.
-                #[inline(always)]
-                const fn _invert_bit(&self, position: u8) -> #constructor_type {
-                    let result = #destructor ^ ((1 as #primitive_type) << position);
-                    #constructor
-                }
-
-                /// Returns a field (subset of bits) from the internal value.
-                #[inline(always)]
-                const fn _field(&self, position: u8, size: u8) -> #primitive_type {
-                    let shifted = #destructor >> position;
+    /// Generates a `#FIELD _is_ #GROUP` predicate for every variant group of the enumeration fields,
+    /// which forwards to the `is_#GROUP` method generated by `bitfield::Field`. Values which are no
+    /// variant, and `None`, belong to no group.
+    fn generate_predicates(&self) -> proc_macro2::TokenStream {
+        let predicates: Vec<_> = self.data.entries().into_iter().zip(self.accessors()).filter_map(|(entry, accessors)| {
+            let groups = entry.groups.as_ref()?;
+            let field = entry.field.as_ref()?;
+            let attrs = &entry.attrs;
+            let vis = &entry.vis;
+            let getter = &accessors.getter;
+
+            let pattern = match (entry.is_fallible(), entry.optional_type().is_some()) {
+                (false, false) => None,
+                (true, false) => Some(quote::quote!(::core::result::Result::Ok(value))),
+                (false, true) => Some(quote::quote!(::core::option::Option::Some(value))),
+                (true, true) => Some(quote::quote!(::core::result::Result::Ok(::core::option::Option::Some(value))))
+            };
 
-                    let rest = size as #primitive_type % (::core::mem::size_of::<#primitive_type>() * 8) as #primitive_type;
-                    let bit = (rest > 0) as #primitive_type;
+            let predicates = groups.idents.iter().map(|group| {
+                let predicate = accessors.predicate(group);
+                let is_group = syn::Ident::new(&format!("is_{}", group.unraw()), group.span());
+                let doc = format!(" Returns `true` if the field is a variant of the group `{}`.", group.unraw());
 
-                    let limit = bit.wrapping_shl(rest as u32);
-                    let mask = limit.wrapping_sub((size > 0) as _);
-                    let result = shifted & mask;
+                let body = match &pattern {
+                    Some(pattern) => quote::quote! {
+                        match self.#getter() {
+                            #pattern => value.#is_group(),
+                            _ => false
+                        }
+                    },
+                    None => quote::quote!(self.#getter().#is_group())
+                };
 
-                    result
+                quote::quote_spanned! { field.span =>
+                    #(#attrs)*
+                    #[doc = #doc]
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #vis const fn #predicate(&self) -> bool {
+                        #body
+                    }
                 }
+            });
 
-                /// Returns a modified variant with the field set to the specified value.
-                #[inline(always)]
-                const fn _set_field(&self, position: u8, size: u8, value: #primitive_type) -> #constructor_type {
-                    let rest = size as #primitive_type % (::core::mem::size_of::<#primitive_type>() * 8) as #primitive_type;
-                    let bit = (rest > 0) as #primitive_type;
-
-                    let limit = bit.wrapping_shl(rest as u32);
-                    let negative_mask = limit.wrapping_sub((size > 0) as _);
-                    let positioned_used_bits = negative_mask << position;
-                    let positioned_mask = !positioned_used_bits;
-                    let cleared = #destructor & positioned_mask;
+            Some(quote::quote!(#(#predicates)*))
+        }).collect();
 
-                    let shifted_value = value << position;
+        if predicates.is_empty() {
+            return proc_macro2::TokenStream::new();
+        }
 
-                    let result = cleared | shifted_value;
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
-                    #constructor
-                }
+        quote::quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #(#predicates)*
             }
         }
     }
 
-    /// Generates constant assertions about field type sizes and flag overlaps.
-    fn generate_assertions(&self) -> proc_macro2::TokenStream {
-        /// Generates a constant assertion for a constant expression.
-        fn generate_assertion(name: &syn::Ident, message: &str, expression: proc_macro2::TokenStream, span: proc_macro2::Span) -> proc_macro2::TokenStream {
-            quote::quote_spanned!(span =>
-                const #name: [(); if #expression { 0 } else { panic!(#message) }] = [];
-            )
-        }
+    /// Generates the `#NAME Key` trait with the `get` method and the `#NAME KeyMut` trait with the
+    /// `with` method of the bit field, and for every field a `#NAME #FIELD` key type. Readable fields
+    /// implement `#NAME Key`, writable fields `#NAME KeyMut`, and writable fields whose setter can
+    /// not fail have `::core::ops::*` implementations like the field type, for which their key type
+    /// wraps a value of the field. Other key types are unit structs.
+    fn generate_keys(&self) -> proc_macro2::TokenStream {
+        let span = match &self.attr.keys {
+            Some(keys) => keys.span(),
+            None => return proc_macro2::TokenStream::new()
+        };
 
+        let vis = &self.vis;
         let ident = &self.ident;
-        let base_type = &self.attr.base_type;
-
-        let entries = self.data.entries();
-        let mut assertions = entries.iter().enumerate().map(|(i, entry)| {
+        let key = syn::Ident::new(&format!("{}Key", ident.unraw()), ident.span());
+        let key_mut = syn::Ident::new(&format!("{}KeyMut", ident.unraw()), ident.span());
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let keys: Vec<_> = self.data.entries().into_iter().zip(self.accessors()).filter_map(|(entry, accessors)| {
+            let field = entry.field.as_ref()?;
+            let name = accessors.name.as_ref()?;
+            let cfgs = entry.cfgs();
+            let field_vis = &entry.vis;
             let ty = &entry.ty;
-            if let Some(field) = &entry.field {
-                let bit = field.bit.as_ref().unwrap();
-                let size = field.size.as_ref().unwrap();
-                let size_value: u8 = size.base10_parse().unwrap();
+            let key_type = self.key_type(name);
+            let super::Accessors { getter, setter, .. } = &accessors;
 
-                // `size_of(FieldType)` must not be < `field.size`.
-                let field_assertion = generate_assertion(
-                    &syn::Ident::new(&format!("_TYPE_IN_FIELD_{i}_IS_SMALLER_THAN_THE_SPECIFIED_SIZE_OF_{size}_BIT{}", if size_value > 1 { "S" } else { "" }), ty.span()),
-                    &format!("Type is smaller than the specified size of {size} bit{}", if size_value > 1 { "s" } else { "" }),
-                    quote::quote! { ::core::mem::size_of::<#ty>() * 8 >= #size },
-                    ty.span()
-                );
+            let get = entry.is_readable().then(|| {
+                let getter_type = Self::generate_field_getter_type(entry, field);
 
-                // Only generate this check for non-primitive types.
-                let non_primitive_assertion = ty.get_ident()
-                    .map(|ident| !crate::primitive::is_primitive(ident))
-                    .unwrap_or_default()
-                    .then(|| {
-                        // `bit_count(FieldType)` must be <= `field.size`.
-                        let size_assertion = generate_assertion(
-                            &syn::Ident::new(&format!("_TYPE_IN_FIELD_{i}_EXCEEDS_FIELD_SIZE_OF_{size}_BIT{}", if size_value > 1 { "S" } else { "" }), ty.span()),
-                            &format!("Size of the field type exceeds the specified field size of {size} bit{}", if size_value > 1 { "s" } else { "" }),
-                            quote::quote! { #ty::size() <= #size },
-                            ty.span()
-                        );
+                quote::quote_spanned! { name.span() =>
+                    #(#cfgs)*
+                    impl #impl_generics #key #ty_generics for #key_type #where_clause {
+                        type Output = #getter_type;
 
-                        Some(quote::quote! {
-                            #size_assertion
-                        })
-                    }).unwrap_or_default();
+                        #[inline(always)]
+                        fn get(bitfield: &#ident #ty_generics) -> Self::Output {
+                            bitfield.#getter()
+                        }
+                    }
+                }
+            });
 
-                // Only generate this check for complete fields.
-                let complete_no_gap = field.complete.as_ref().map(|complete| {
-                    let ty = &entry.ty;
+            let with = entry.is_writable().then(|| {
+                let modified = match entry.is_partial() {
+                    false => quote::quote!(#ident #ty_generics),
+                    true => quote::quote!(::core::option::Option<#ident #ty_generics>)
+                };
 
-                    generate_assertion(
-                        &syn::Ident::new(&format!("_COMPLETE_FIELD_{i}_MUST_NOT_HAVE_GAPS"), complete.span()),
-                        "Complete field must not have gaps",
-                        quote::quote! {{
-                            // Enumerations can not contain multiple variants with the same discriminator, so using the length should be fine.
-                            #ty::iter().len() == 1_usize << #size
-                        }},
-                        complete.span()
-                    )
-                });
+                quote::quote_spanned! { name.span() =>
+                    #(#cfgs)*
+                    impl #impl_generics #key_mut #ty_generics for #key_type #where_clause {
+                        type Value = #ty;
+                        type Modified = #modified;
 
-                // Only generate the next assertions if this can not be checked in the parsing phase,
-                // aka. when the primitive base type is `usize`.
-                if self.attr.bits.is_some() {
-                    return quote::quote! {
-                        #field_assertion
+                        #[inline(always)]
+                        fn with(bitfield: &#ident #ty_generics, value: Self::Value) -> Self::Modified {
+                            bitfield.#setter(value)
+                        }
+                    }
+                }
+            });
 
-                        #non_primitive_assertion
+            // Setters that can fail return `Option<Self>`, which is not possible for the operators.
+            let ops = (entry.is_writable() && !entry.is_partial()).then(|| quote::quote_spanned! { name.span() =>
+                #(#cfgs)*
+                impl #impl_generics ::core::ops::Add<#key_type> for #ident #ty_generics #where_clause {
+                    type Output = Self;
 
-                        #complete_no_gap
+                    #[inline(always)]
+                    fn add(self, key: #key_type) -> Self::Output {
+                        self.#setter(key.0)
                     }
                 }
 
-                // `bits_of(BitField)` must not be < `bits_of(Field) + size_of(Field)`.
-                let size_assertion = generate_assertion(
-                    &syn::Ident::new(&format!("_FIELD_{i}_EXCEEDS_THE_BITFIELD_SIZE"), field.span),
-                    &format!("Field exceeds the bitfield size"),
-                    quote::quote! { ::core::mem::size_of::<#base_type>() * 8 >= #bit + #size },
-                    field.span
-                );
+                #(#cfgs)*
+                impl #impl_generics ::core::ops::AddAssign<#key_type> for #ident #ty_generics #where_clause {
+                    #[inline(always)]
+                    fn add_assign(&mut self, key: #key_type) {
+                        self.0 = self.#setter(key.0).0;
+                    }
+                }
+            });
 
-                // `bits_of(BitField)` must not be == `size_of(Field)`.
-                let size_not_equal_assertion = generate_assertion(
-                    &syn::Ident::new(&format!("_FIELD_{i}_HAS_THE_SIZE_OF_THE_WHOLE_BITFIELD"), field.span),
-                    "Field has the size of the whole bitfield",
-                    quote::quote! { ::core::mem::size_of::<#base_type>() * 8 != #size },
-                    field.span
-                );
+            // Only the operators need the value, other keys are unit structs.
+            let value = ops.is_some().then(|| quote::quote!((#field_vis #ty)));
 
-                quote::quote! {
-                    #field_assertion
+            let doc = match value {
+                Some(_) => format!(" The key of the field `{}`, which wraps a value of the field.", name.unraw()),
+                None => format!(" The key of the field `{}`.", name.unraw())
+            };
 
-                    #non_primitive_assertion
+            Some(quote::quote_spanned! { name.span() =>
+                #(#cfgs)*
+                #[doc = #doc]
+                #[derive(Clone, Copy)]
+                #field_vis struct #key_type #value;
 
-                    #complete_no_gap
+                #get
+                #with
+                #ops
+            })
+        }).collect();
 
-                    #size_assertion
+        let doc = format!(" A readable field of `{0}`, which is used with `{0}::get`.", ident.unraw());
+        let doc_mut = format!(" A writable field of `{0}`, which is used with `{0}::with`.", ident.unraw());
 
-                    #size_not_equal_assertion
-                }
-            } else {
-                // `bits_of(Flags)` must be `bits_of(u8)`.
-                let size_assertion = generate_assertion(
-                    &syn::Ident::new(&format!("_FLAGS_IN_FIELD_{i}_MUST_BE_REPR_U8"), entry.ty.span()),
-                    "Flags must be #[repr(u8)]",
-                    quote::quote! { ::core::mem::size_of::<#ty>() == 1 },
-                    entry.ty.span()
-                );
+        quote::quote_spanned! { span =>
+            #[doc = #doc]
+            #vis trait #key #impl_generics #where_clause {
+                /// The return type of the getter of the field.
+                type Output;
 
-                // `max_variant_discriminant(Flags)` must not be >= `bits_of(BitField)`.
-                let max_assertion = generate_assertion(
-                    &syn::Ident::new(&format!("_FLAGS_IN_FIELD_{i}_EXCEED_THE_BITFIELD_SIZE"), entry.ty.span()),
-                    "Flags exceed the bitfield size",
-                    quote::quote! { ::core::mem::size_of::<#base_type>() * 8 > {
-                        let mut i = 0;
-                        let mut max = #ty::iter()[i];
+                /// Gets the value of the field.
+                fn get(bitfield: &#ident #ty_generics) -> Self::Output;
+            }
 
-                        while i < #ty::iter().len() {
-                            let current = #ty::iter()[i];
-                            if current as u8 > max as u8 {
-                                max = current;
-                            }
+            #[doc = #doc_mut]
+            #vis trait #key_mut #impl_generics #where_clause {
+                /// The type of the field.
+                type Value;
 
-                            i += 1;
-                        }
+                /// The return type of the setter of the field, `Option` if the value can exceed the
+                /// field.
+                type Modified;
 
-                        max
-                    } as usize },
-                    entry.ty.span()
-                );
+                /// Creates a copy of the bit field with the new value.
+                fn with(bitfield: &#ident #ty_generics, value: Self::Value) -> Self::Modified;
+            }
 
-                quote::quote! {
-                    #size_assertion
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Gets the value of the field `K`.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn get<K: #key #ty_generics>(&self) -> K::Output {
+                    K::get(self)
+                }
 
-                    #max_assertion
+                /// Creates a copy of the bit field with the new value of the field `K`.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                #vis fn with<K: #key_mut #ty_generics>(&self, value: K::Value) -> K::Modified {
+                    K::with(self, value)
                 }
             }
-        }).collect::<Vec<_>>();
 
-        // Add flag assertions.
-        if let super::Data::Named(entries) = &self.data {
-            for (i, entry) in entries.iter().enumerate() {
-                if entry.entry.field.is_some() { continue; }
+            #(#keys)*
+        }
+    }
 
-                let flag_assertions = entries.iter().enumerate().map(|(inner_i, inner)| {
-                    if i == inner_i { return proc_macro2::TokenStream::new(); }
+    /// Generates the `#NAME Parts` struct with a member for every readable entry, the `to_parts` and
+    /// `from_parts` methods and the conversions between both types. Flags are stored as `[bool; N]`
+    /// in the order of `#FLAGS::iter()`. `from_parts` ignores the members of entries which are not
+    /// writable, and returns `Option<Self>` if the value of a member can exceed its field.
+    fn generate_parts(&self) -> proc_macro2::TokenStream {
+        let span = match &self.attr.parts {
+            Some(parts) => parts.span(),
+            None => return proc_macro2::TokenStream::new()
+        };
 
-                    // Do not check flag overlapping if overlapping is allowed.
-                    if self.attr.allow_overlaps.is_some() {
-                        return proc_macro2::TokenStream::new();
-                    }
+        let vis = &self.vis;
+        let ident = &self.ident;
+        let parts = syn::Ident::new(&format!("{}Parts", ident.unraw()), ident.span());
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
-                    let span = entry.ident.span();
-                    let entry_ty = &entry.entry.ty;
-                    let inner_ty = &inner.entry.ty;
+        let mut is_fallible = false;
+        let mut members = vec!();
+        let mut getters = vec!();
+        let mut setters = vec!();
 
-                    if let Some(field) = &inner.entry.field {
-                        let name = syn::Ident::new(&format!(
-                            "_FLAGS_IN_FIELD_{}_OVERLAP_WITH_FIELD_{}", i, inner_i
-                        ), entry.ident.span());
+        for (entry, accessors) in self.data.entries().into_iter().zip(self.accessors()) {
+            if !entry.is_readable() {
+                continue;
+            }
 
-                        let fn_name = syn::Ident::new(
-                            &name.to_string().to_ascii_lowercase(), name.span()
-                        );
+            let attrs = &entry.attrs;
+            let cfgs = entry.cfgs();
+            let entry_vis = &entry.vis;
+            let ty = &entry.ty;
+            let name = accessors.name.as_ref().unwrap();
+            let super::Accessors { getter, setter, .. } = &accessors;
 
-                        let assertion = generate_assertion(
-                            &name,
-                            &format!("Flags in field \"{}\" overlap with field \"{}\"", entry.ident.unraw(), inner.ident.unraw()),
-                            quote::quote! { !Self::#fn_name() },
-                            name.span()
-                        );
+            if let Some(field) = &entry.field {
+                let bit = field.bit.as_ref().unwrap().base10_parse::<u8>().unwrap();
+                let size = field.size.as_ref().unwrap().base10_parse::<u8>().unwrap();
+                let member_type = Self::generate_field_getter_type(entry, field);
 
-                        let bit = field.bit.as_ref().unwrap().base10_parse::<u8>().unwrap();
-                        let size = field.size.as_ref().unwrap().base10_parse::<u8>().unwrap();
+                members.push(quote::quote!(#(#attrs)* #entry_vis #name: #member_type));
+                getters.push(quote::quote!(#(#cfgs)* #name: self.#getter()));
 
-                        quote::quote_spanned! { span =>
-                            const fn #fn_name() -> bool {
-                                let flags = #entry_ty::iter();
+                // `from_parts` ignores entries which are not writable, so they stay `0`.
+                if !entry.is_writable() {
+                    continue;
+                }
 
-                                let mut i = 0;
-                                while i < flags.len() {
-                                    let flag = flags[i] as u8;
-                                    if flag >= #bit && flag < #bit + #size {
-                                        return true;
-                                    }
+                let set = if entry.is_fallible() {
+                    let primitive_type_unsigned = crate::primitive::type_from_bits(size, false, field.size.span());
 
-                                    i += 1;
-                                }
+                    // Values which are not a variant are written as they are, if they fit.
+                    let check = (crate::primitive::field_primitive_size(size) != size).then(|| {
+                        is_fallible = true;
 
-                                false
+                        quote::quote! {
+                            if value >= (1 as #primitive_type_unsigned).wrapping_shl(#size as u32) {
+                                return ::core::option::Option::None;
                             }
-
+                        }
+                    });
+
+                    quote::quote! {
+                        match parts.#name {
+                            ::core::result::Result::Ok(value) => bitfield.#setter(value),
+                            ::core::result::Result::Err(value) => {
+                                #check
+                                bitfield._set_field(#bit, #size, value as _)
+                            }
+                        }
+                    }
+                } else if entry.is_partial() {
+                    is_fallible = true;
+
+                    quote::quote! {
+                        match bitfield.#setter(parts.#name) {
+                            ::core::option::Option::Some(bitfield) => bitfield,
+                            ::core::option::Option::None => return ::core::option::Option::None
+                        }
+                    }
+                } else {
+                    quote::quote!(bitfield.#setter(parts.#name))
+                };
+
+                setters.push(quote::quote!(#(#cfgs)* let bitfield = #set;));
+            } else {
+                members.push(quote::quote!(#(#attrs)* #entry_vis #name: [bool; #ty::iter().len()]));
+                getters.push(quote::quote! {
+                    #(#cfgs)*
+                    #name: {
+                        let mut flags = [false; #ty::iter().len()];
+                        let mut i = 0;
+                        while i < flags.len() {
+                            flags[i] = self.#getter(#ty::iter()[i]);
+                            i += 1;
+                        }
+
+                        flags
+                    }
+                });
+
+                if !entry.is_writable() {
+                    continue;
+                }
+
+                setters.push(quote::quote! {
+                    #(#cfgs)*
+                    let bitfield = {
+                        let mut bitfield = bitfield;
+                        let mut i = 0;
+                        while i < #ty::iter().len() {
+                            bitfield = bitfield.#setter(#ty::iter()[i], parts.#name[i]);
+                            i += 1;
+                        }
+
+                        bitfield
+                    };
+                });
+            }
+        }
+
+        let debug = self.debug.map(|span| quote::quote_spanned!(span => #[derive(Debug)]));
+        let doc = format!(" The readable entries of `{0}`, see `{0}::to_parts` and `{0}::from_parts`.", ident.unraw());
+
+        let (from_parts, conversion) = match is_fallible {
+            false => (
+                quote::quote_spanned! { span =>
+                    /// Creates a bit field from the values of all writable entries, the others stay `0`.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #vis const fn from_parts(parts: #parts) -> Self {
+                        let bitfield = Self::new();
+                        #(#setters)*
+                        bitfield
+                    }
+                },
+                quote::quote_spanned! { span =>
+                    impl #impl_generics ::core::convert::From<#parts> for #ident #ty_generics #where_clause {
+                        #[inline(always)]
+                        fn from(parts: #parts) -> Self {
+                            Self::from_parts(parts)
+                        }
+                    }
+                }
+            ),
+            true => (
+                quote::quote_spanned! { span =>
+                    /// Creates a bit field from the values of all writable entries, the others stay `0`.
+                    ///
+                    /// Returns `None` if a value is bigger than the specified amount of bits its
+                    /// field can store.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #vis const fn from_parts(parts: #parts) -> ::core::option::Option<Self> {
+                        let bitfield = Self::new();
+                        #(#setters)*
+                        ::core::option::Option::Some(bitfield)
+                    }
+                },
+                quote::quote_spanned! { span =>
+                    impl #impl_generics ::core::convert::TryFrom<#parts> for #ident #ty_generics #where_clause {
+                        type Error = #parts;
+
+                        #[inline(always)]
+                        fn try_from(parts: #parts) -> ::core::result::Result<Self, Self::Error> {
+                            match Self::from_parts(parts) {
+                                ::core::option::Option::Some(bitfield) => ::core::result::Result::Ok(bitfield),
+                                ::core::option::Option::None => ::core::result::Result::Err(parts)
+                            }
+                        }
+                    }
+                }
+            )
+        };
+
+        quote::quote_spanned! { span =>
+            #[doc = #doc]
+            #debug
+            #[derive(Clone, Copy)]
+            #vis struct #parts {
+                #(#members),*
+            }
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns the values of all readable entries.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis const fn to_parts(&self) -> #parts {
+                    #parts {
+                        #(#getters),*
+                    }
+                }
+
+                #from_parts
+            }
+
+            impl #impl_generics ::core::convert::From<#ident #ty_generics> for #parts #where_clause {
+                #[inline(always)]
+                fn from(bitfield: #ident #ty_generics) -> Self {
+                    bitfield.to_parts()
+                }
+            }
+
+            #conversion
+        }
+    }
+
+    /// Generates the `#NAME Builder` typestate, which wraps the bit field and has a `const` parameter
+    /// `#FIELD _IS_SET: bool` for every required field. The setters of a required field set its
+    /// parameter to `true`, and `build` is only implemented once all parameters are `true`.
+    fn generate_builder(&self) -> proc_macro2::TokenStream {
+        let entries = self.data.entries();
+        let accessors = self.accessors();
+
+        // Alternatives share the parameter of the first entry with their name.
+        let mut required: Vec<(Option<&syn::Ident>, syn::Ident)> = vec!();
+        for (entry, accessors) in entries.iter().zip(&accessors) {
+            let span = match &entry.required {
+                Some(required) => required.span(),
+                None => continue
+            };
+
+            let name = accessors.name.as_ref();
+            if required.iter().any(|(other, _)| other.map(|o| o.unraw()) == name.map(|n| n.unraw())) {
+                continue;
+            }
+
+            let state = match name {
+                Some(name) => format!("{}_IS_SET", name.unraw().to_string().to_uppercase()),
+                None => "IS_SET".to_string()
+            };
+
+            required.push((name, syn::Ident::new(&state, span)));
+        }
+
+        let span = match required.first() {
+            Some((_, state)) => state.span(),
+            None => return proc_macro2::TokenStream::new()
+        };
+
+        let vis = &self.vis;
+        let ident = &self.ident;
+        let builder = syn::Ident::new(&format!("{}Builder", ident.unraw()), ident.span());
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let params: Vec<&dyn quote::ToTokens> = self.generics.params.iter().map(|param| match param {
+            syn::GenericParam::Lifetime(param) => &param.lifetime as _,
+            syn::GenericParam::Type(param) => &param.ident as _,
+            syn::GenericParam::Const(param) => &param.ident as _
+        }).collect();
+
+        // Parameters with defaults must be trailing, so the defaults are left out.
+        let mut generics = self.generics.clone();
+        for param in generics.params.iter_mut() {
+            match param {
+                syn::GenericParam::Type(param) => { param.eq_token = None; param.default = None; },
+                syn::GenericParam::Const(param) => { param.eq_token = None; param.default = None; },
+                syn::GenericParam::Lifetime(_) => ()
+            }
+        }
+
+        let states: Vec<_> = required.iter().map(|(_, state)| state).collect();
+        for state in &states {
+            generics.params.push(syn::parse_quote!(const #state: bool));
+        }
+
+        let (builder_impl_generics, _, _) = generics.split_for_impl();
+        let builder_type = |states: Vec<proc_macro2::TokenStream>| quote::quote!(#builder<#(#params,)* #(#states),*>);
+
+        let methods = entries.iter().zip(&accessors).filter(|(entry, _)| entry.is_writable()).map(|(entry, accessors)| {
+            let attrs = &entry.attrs;
+            let entry_vis = &entry.vis;
+            let ty = &entry.ty;
+            let setter = &accessors.setter;
+            let name = accessors.name.as_ref().map(|name| name.unraw());
+
+            let output = builder_type(required.iter().map(|(other, state)| match other.map(|o| o.unraw()) == name {
+                true => quote::quote!(true),
+                false => quote::quote!(#state)
+            }).collect());
+
+            if entry.field.is_none() {
+                quote::quote_spanned! { span =>
+                    #(#attrs)*
+                    /// Sets the new value for the specified flag.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #entry_vis const fn #setter(self, flag: #ty, value: bool) -> #output {
+                        #builder(self.0 . #setter(flag, value))
+                    }
+                }
+            } else if entry.is_partial() {
+                quote::quote_spanned! { span =>
+                    #(#attrs)*
+                    /// Sets the new value of the field.
+                    ///
+                    /// Returns `None` if `value` is bigger than the specified amount of bits the
+                    /// field can store.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #entry_vis const fn #setter(self, value: #ty) -> ::core::option::Option<#output> {
+                        match self.0 . #setter(value) {
+                            ::core::option::Option::Some(bitfield) => ::core::option::Option::Some(#builder(bitfield)),
+                            ::core::option::Option::None => ::core::option::Option::None
+                        }
+                    }
+                }
+            } else {
+                quote::quote_spanned! { span =>
+                    #(#attrs)*
+                    /// Sets the new value of the field.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #entry_vis const fn #setter(self, value: #ty) -> #output {
+                        #builder(self.0 . #setter(value))
+                    }
+                }
+            }
+        });
+
+        let initial = builder_type(states.iter().map(|_| quote::quote!(false)).collect());
+        let current = builder_type(states.iter().map(|state| quote::quote!(#state)).collect());
+        let complete = builder_type(states.iter().map(|_| quote::quote!(true)).collect());
+
+        let mut names: Vec<_> = required.iter().map(|(name, _)| match name {
+            Some(name) => format!("`{}`", name.unraw()),
+            None => "the field".to_string()
+        }).collect();
+        let names = match names.pop() {
+            Some(last) if !names.is_empty() => format!("{} and {} are", names.join(", "), last),
+            last => format!("{} is", last.unwrap_or_default())
+        };
+
+        let doc = format!(" Builds `{0}`, see `{0}::builder`. `build` is only available once {1} set.", ident.unraw(), names);
+        let doc_builder = format!(" Creates a builder with all flags and fields cleared. `build` is only available once {} set.", names);
+
+        quote::quote_spanned! { span =>
+            #[doc = #doc]
+            #[must_use = "builders do nothing unless `build` is called"]
+            #vis struct #builder #builder_impl_generics (#ident #ty_generics) #where_clause;
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[doc = #doc_builder]
+                #[allow(unused)]
+                #[inline(always)]
+                #vis const fn builder() -> #initial {
+                    #builder(Self::new())
+                }
+            }
+
+            impl #builder_impl_generics #current #where_clause {
+                #(#methods)*
+            }
+
+            impl #impl_generics #complete #where_clause {
+                /// Returns the bit field with all required fields set.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis const fn build(self) -> #ident #ty_generics {
+                    self.0
+                }
+            }
+        }
+    }
+
+    /// Generates the return type of the getter of a field, which is a `Result` for fallible fields.
+    fn generate_field_getter_type(entry: &super::Entry, field: &super::FieldDetails) -> proc_macro2::TokenStream {
+        let ty = &entry.ty;
+
+        match entry.is_fallible() {
+            false => quote::quote!(#ty),
+            true => {
+                let size = field.size.as_ref().unwrap().base10_parse::<u8>().unwrap();
+                let primitive_type_unsigned = crate::primitive::type_from_bits(size, false, field.size.span());
+
+                quote::quote!(::core::result::Result<#ty, #primitive_type_unsigned>)
+            }
+        }
+    }
+
+    /// Generates the accessors that directly work on the primitive bit field type.
+    fn generate_accessors_low(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let primitive_type = &self.attr.primitive_type;
+
+        let result = self.generate_stored_result();
+        let (constructor, constructor_type, destructor) = if !self.attr.is_non_zero {(
+            self.generate_new(quote::quote!(Self), &result),
+            quote::quote!(Self),
+            quote::quote!(self.0)
+        )} else {(
+            self.generate_new_non_zero(),
+            quote::quote!(::core::option::Option<Self>),
+            quote::quote!(self.0.get())
+        )};
+
+        quote::quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns a boolean value whether the specified flag is set.
+                #[inline(always)]
+                const fn _bit(&self, position: u8) -> bool {
+                    ((#destructor >> position) & 1) != 0
+                }
+
+                /// Returns a modified instance with the flag set to the specified value.
+                #[inline(always)]
+                const fn _set_bit(&self, position: u8, value: bool) -> #constructor_type {
+                    let cleared = #destructor & !(1 << position);
+                    let result = cleared | ((value as #primitive_type) << position);
+                    #constructor
+                }
+
+                /// Returns a modified instance with the bit value inverted.
+                #[inline(always)]
+                const fn _invert_bit(&self, position: u8) -> #constructor_type {
+                    let result = #destructor ^ ((1 as #primitive_type) << position);
+                    #constructor
+                }
+
+                /// Returns a field (subset of bits) from the internal value.
+                #[inline(always)]
+                const fn _field(&self, position: u8, size: u8) -> #primitive_type {
+                    let shifted = #destructor >> position;
+
+                    let rest = size as #primitive_type % (::core::mem::size_of::<#primitive_type>() * 8) as #primitive_type;
+                    let bit = (rest > 0) as #primitive_type;
+
+                    let limit = bit.wrapping_shl(rest as u32);
+                    let mask = limit.wrapping_sub((size > 0) as _);
+                    let result = shifted & mask;
+
+                    result
+                }
+
+                /// Returns a modified variant with the field set to the specified value.
+                #[inline(always)]
+                const fn _set_field(&self, position: u8, size: u8, value: #primitive_type) -> #constructor_type {
+                    let rest = size as #primitive_type % (::core::mem::size_of::<#primitive_type>() * 8) as #primitive_type;
+                    let bit = (rest > 0) as #primitive_type;
+
+                    let limit = bit.wrapping_shl(rest as u32);
+                    let negative_mask = limit.wrapping_sub((size > 0) as _);
+                    let positioned_used_bits = negative_mask << position;
+                    let positioned_mask = !positioned_used_bits;
+                    let cleared = #destructor & positioned_mask;
+
+                    let shifted_value = value << position;
+
+                    let result = cleared | shifted_value;
+
+                    #constructor
+                }
+            }
+        }
+    }
+
+    /// Generates the value which setters store for the computed `result`: `result` without the
+    /// reserved bits with the `zero` policy. These are not supported for `NonZero` bit fields.
+    fn generate_stored_result(&self) -> proc_macro2::TokenStream {
+        let primitive_type = &self.attr.primitive_type;
+
+        let mask = self.data.layout().into_iter().filter(
+            |entry| entry.reserved.as_ref().map(|r| r.policy == super::ReservedPolicy::Zero).unwrap_or_default()
+        ).fold(0u128, |mask, entry| {
+            let field = entry.field.as_ref().unwrap();
+            let bit = field.bit.as_ref().unwrap().base10_parse::<u8>().unwrap();
+            let size = field.size.as_ref().unwrap().base10_parse::<u8>().unwrap();
+
+            mask | (u128::MAX >> (128 - size as u32) << bit)
+        });
+
+        match mask {
+            0 => quote::quote!(result),
+            mask => {
+                let mask = syn::LitInt::new(&format!("{:#x}{}", mask, primitive_type), self.ident.span());
+                quote::quote!((result & !#mask))
+            }
+        }
+    }
+
+    /// Generates constant assertions about field type sizes and flag overlaps.
+    fn generate_assertions(&self) -> proc_macro2::TokenStream {
+        /// Generates a constant assertion for a constant expression.
+        fn generate_assertion(name: &syn::Ident, message: &str, expression: proc_macro2::TokenStream, span: proc_macro2::Span) -> proc_macro2::TokenStream {
+            quote::quote_spanned!(span =>
+                const #name: [(); if #expression { 0 } else { panic!(#message) }] = [];
+            )
+        }
+
+        /// Generates a constant assertion for a constant expression which returns the offending bit,
+        /// or `u8::MAX` if there is none. Each bit in `bits` panics with its own message, other bits
+        /// panic with `message`.
+        fn generate_bit_assertion(
+            name: &syn::Ident, message: &str, bits: Vec<(u8, String)>, expression: proc_macro2::TokenStream,
+            span: proc_macro2::Span
+        ) -> proc_macro2::TokenStream {
+            let (bits, messages): (Vec<_>, Vec<_>) = bits.into_iter().map(
+                |(bit, message)| (proc_macro2::Literal::u8_unsuffixed(bit), message)
+            ).unzip();
+
+            quote::quote_spanned!(span =>
+                const #name: [(); match #expression {
+                    u8::MAX => 0,
+                    #(#bits => panic!(#messages),)*
+                    _ => panic!(#message)
+                }] = [];
+            )
+        }
+
+        let ident = &self.ident;
+        let base_type = &self.attr.base_type;
+
+        let entries = self.data.layout();
+        let assertions = entries.iter().enumerate().map(|(i, entry)| {
+            let ty = entry.stored_type();
+            if let Some(field) = &entry.field {
+                let bit = field.bit.as_ref().unwrap();
+                let size = field.size.as_ref().unwrap();
+                let size_value: u8 = size.base10_parse().unwrap();
+
+                // `size_of(FieldType)` must not be < `field.size`.
+                let field_assertion = generate_assertion(
+                    &syn::Ident::new(&format!("_TYPE_IN_FIELD_{i}_IS_SMALLER_THAN_THE_SPECIFIED_SIZE_OF_{size}_BIT{}", if size_value > 1 { "S" } else { "" }), ty.span()),
+                    &format!("Type is smaller than the specified size of {size} bit{}", if size_value > 1 { "s" } else { "" }),
+                    quote::quote! { ::core::mem::size_of::<#ty>() * 8 >= #size },
+                    ty.span()
+                );
+
+                // Only generate this check for non-primitive types.
+                let non_primitive_assertion = ty.get_ident()
+                    .map(|ident| !crate::primitive::is_primitive(ident) && entry.non_zero_bits().is_none())
+                    .unwrap_or_default()
+                    .then(|| {
+                        // `bit_count(FieldType)` must be <= `field.size`.
+                        let size_assertion = generate_assertion(
+                            &syn::Ident::new(&format!("_TYPE_IN_FIELD_{i}_EXCEEDS_FIELD_SIZE_OF_{size}_BIT{}", if size_value > 1 { "S" } else { "" }), ty.span()),
+                            &format!("Size of the field type exceeds the specified field size of {size} bit{}", if size_value > 1 { "s" } else { "" }),
+                            quote::quote! { #ty::size() <= #size },
+                            ty.span()
+                        );
+
+                        // No variant of the type of an `Option` field may use `0`, which is `None`.
+                        let non_zero_assertion = entry.optional_type().map(|_| generate_assertion(
+                            &syn::Ident::new(&format!("_TYPE_IN_OPTIONAL_FIELD_{i}_MUST_NOT_USE_THE_DISCRIMINANT_0"), ty.span()),
+                            "Type of an optional field must not use the discriminant 0, which is stored for `None`",
+                            quote::quote! { #ty::_bitfield_is_non_zero() },
+                            ty.span()
+                        ));
+
+                        Some(quote::quote! {
+                            #size_assertion
+
+                            #non_zero_assertion
+                        })
+                    }).unwrap_or_default();
+
+                // Only generate this check for complete fields, where `None` uses one value of
+                // `Option` fields.
+                let complete_no_gap = field.complete.as_ref().map(|complete| {
+                    let none = entry.optional_type().map(|_| quote::quote!(+ 1));
+
+                    generate_assertion(
+                        &syn::Ident::new(&format!("_COMPLETE_FIELD_{i}_MUST_NOT_HAVE_GAPS"), complete.span()),
+                        "Complete field must not have gaps",
+                        quote::quote! {{
+                            // Enumerations can not contain multiple variants with the same discriminator, so using the length should be fine.
+                            #ty::iter().len() #none == 1_usize << #size
+                        }},
+                        complete.span()
+                    )
+                });
+
+                // Only generate the next assertions if this can not be checked in the parsing phase,
+                // aka. when the primitive base type is `usize`.
+                if self.attr.bits.is_some() {
+                    return quote::quote! {
+                        #field_assertion
+
+                        #non_primitive_assertion
+
+                        #complete_no_gap
+                    }
+                }
+
+                // `bits_of(BitField)` must not be < `bits_of(Field) + size_of(Field)`.
+                let size_assertion = generate_assertion(
+                    &syn::Ident::new(&format!("_FIELD_{i}_EXCEEDS_THE_BITFIELD_SIZE"), field.span),
+                    &format!("Field exceeds the bitfield size"),
+                    quote::quote! { ::core::mem::size_of::<#base_type>() * 8 >= #bit + #size },
+                    field.span
+                );
+
+                // `bits_of(BitField)` must not be == `size_of(Field)`.
+                let size_not_equal_assertion = generate_assertion(
+                    &syn::Ident::new(&format!("_FIELD_{i}_HAS_THE_SIZE_OF_THE_WHOLE_BITFIELD"), field.span),
+                    "Field has the size of the whole bitfield",
+                    quote::quote! { ::core::mem::size_of::<#base_type>() * 8 != #size },
+                    field.span
+                );
+
+                quote::quote! {
+                    #field_assertion
+
+                    #non_primitive_assertion
+
+                    #complete_no_gap
+
+                    #size_assertion
+
+                    #size_not_equal_assertion
+                }
+            } else {
+                // `bits_of(Flags)` must be `bits_of(u8)`.
+                let size_assertion = generate_assertion(
+                    &syn::Ident::new(&format!("_FLAGS_IN_FIELD_{i}_MUST_BE_REPR_U8"), entry.ty.span()),
+                    "Flags must be #[repr(u8)]",
+                    quote::quote! { ::core::mem::size_of::<#ty>() == 1 },
+                    entry.ty.span()
+                );
+
+                // `max_variant_discriminant(Flags)` must not be >= `bits_of(BitField)`.
+                let max_assertion = generate_assertion(
+                    &syn::Ident::new(&format!("_FLAGS_IN_FIELD_{i}_EXCEED_THE_BITFIELD_SIZE"), entry.ty.span()),
+                    "Flags exceed the bitfield size",
+                    quote::quote! { ::core::mem::size_of::<#base_type>() * 8 > {
+                        let mut i = 0;
+                        let mut max = #ty::iter()[i];
+
+                        while i < #ty::iter().len() {
+                            let current = #ty::iter()[i];
+                            if current as u8 > max as u8 {
+                                max = current;
+                            }
+
+                            i += 1;
+                        }
+
+                        max
+                    } as usize },
+                    entry.ty.span()
+                );
+
+                quote::quote! {
+                    #size_assertion
+
+                    #max_assertion
+                }
+            }
+        }).collect::<Vec<_>>();
+
+        // The assertions of conditional entries are only generated in the configurations in which
+        // all of these entries exist.
+        let mut conditional = vec!();
+        let mut assertions = entries.iter().zip(assertions).filter_map(|(entry, assertion)| {
+            match entry.is_conditional() {
+                false => Some(assertion),
+                true => { conditional.push((entry.cfgs(), assertion)); None }
+            }
+        }).collect::<Vec<_>>();
+
+        // Add overlap assertions for fields, if either is conditional. All other fields are already
+        // checked in the parsing phase.
+        if let (super::Data::Named(entries), None) = (&self.data, &self.attr.allow_overlaps) {
+            for (i, entry) in entries.iter().enumerate() {
+                let Some(field) = &entry.entry.field else { continue; };
+
+                for (inner_i, inner) in entries.iter().enumerate().skip(i + 1) {
+                    let Some(inner_field) = &inner.entry.field else { continue; };
+
+                    if
+                        !(entry.entry.is_conditional() || inner.entry.is_conditional()) ||
+                        super::EntryNamed::are_alternatives(entry, inner) ||
+                        !super::BitField::overlaps(field, inner_field).unwrap_or_default()
+                    {
+                        continue;
+                    }
+
+                    let range = |field: &super::FieldDetails| (
+                        field.bit.as_ref().unwrap().base10_parse::<u8>().unwrap(),
+                        field.size.as_ref().unwrap().base10_parse::<u8>().unwrap()
+                    );
+                    let kind = |entry: &super::EntryNamed| match entry.entry.reserved {
+                        Some(_) => "reserved bits",
+                        None => "field"
+                    };
+
+                    let entry_name = format!("\"{}\"", entry.ident.unraw());
+                    let inner_name = format!("\"{}\"", inner.ident.unraw());
+                    let ((entry_bit, entry_size), (inner_bit, inner_size)) = (range(field), range(inner_field));
+
+                    let message = format!("Field {} overlaps with {} {}", inner_name, kind(entry), entry_name);
+                    let message = format!("{}\n{}", message, self.explain_overlap(
+                        inner_i, (&inner_name, inner_bit, inner_size), (&entry_name, entry_bit, entry_size)
+                    ));
+
+                    let assertion = generate_assertion(
+                        &syn::Ident::new(&format!("_FIELD_{inner_i}_OVERLAPS_WITH_FIELD_{i}"), inner_field.span),
+                        &message,
+                        quote::quote! { false },
+                        inner_field.span
+                    );
+
+                    let cfgs = entry.entry.cfgs().into_iter().chain(inner.entry.cfgs()).collect();
+                    conditional.push((cfgs, assertion));
+                }
+            }
+        }
+
+        // Add flag assertions.
+        if let super::Data::Named(entries) = &self.data {
+            for (i, entry) in entries.iter().enumerate() {
+                if entry.entry.field.is_some() { continue; }
+
+                let flag_assertions = entries.iter().enumerate().map(|(inner_i, inner)| {
+                    if i == inner_i || super::EntryNamed::are_alternatives(entry, inner) {
+                        return proc_macro2::TokenStream::new();
+                    }
+
+                    // Do not check flag overlapping if overlapping is allowed.
+                    if self.attr.allow_overlaps.is_some() {
+                        return proc_macro2::TokenStream::new();
+                    }
+
+                    let span = entry.ident.span();
+                    let entry_ty = &entry.entry.ty;
+                    let inner_ty = &inner.entry.ty;
+
+                    let entry_name = format!("\"{}\"", entry.ident.unraw());
+                    let inner_name = format!("\"{}\"", inner.ident.unraw());
+
+                    if let Some(field) = &inner.entry.field {
+                        let name = syn::Ident::new(&format!(
+                            "_FLAGS_IN_FIELD_{}_OVERLAP_WITH_FIELD_{}", i, inner_i
+                        ), entry.ident.span());
+
+                        let kind = if inner.entry.reserved.is_some() { "reserved bits" } else { "field" };
+
+                        let fn_name = syn::Ident::new(
+                            &name.to_string().to_ascii_lowercase(), name.span()
+                        );
+
+                        let bit = field.bit.as_ref().unwrap().base10_parse::<u8>().unwrap();
+                        let size = field.size.as_ref().unwrap().base10_parse::<u8>().unwrap();
+
+                        // Only flags in the bits of the field can overlap with it.
+                        let message = format!("Flags in field {} overlap with {} {}", entry_name, kind, inner_name);
+                        let bits = (bit..bit.saturating_add(size)).map(|flag| (flag, format!(
+                            "{}\n{}", message, self.explain_overlap(i, (&entry_name, flag, 1), (&inner_name, bit, size))
+                        ))).collect();
+
+                        let assertion = generate_bit_assertion(
+                            &name, &message, bits, quote::quote! { Self::#fn_name() }, name.span()
+                        );
+
+                        quote::quote_spanned! { span =>
+                            const fn #fn_name() -> u8 {
+                                let flags = #entry_ty::iter();
+
+                                let mut i = 0;
+                                while i < flags.len() {
+                                    let flag = flags[i] as u8;
+                                    if flag >= #bit && flag < #bit + #size {
+                                        return flag;
+                                    }
+
+                                    i += 1;
+                                }
+
+                                u8::MAX
+                            }
+
+                            #assertion
+                        }
+                    } else {
+                        // Skip flags overlap flags check for previously defined flags, as they
+                        // already check for flags that are defined after them.
+                        if i > inner_i { return proc_macro2::TokenStream::new(); }
+
+                        let name = syn::Ident::new(&format!("_FLAGS_IN_FIELD_{i}_OVERLAP_WITH_FLAGS_IN_FIELD_{inner_i}"), entry.ident.span());
+
+                        let fn_name = syn::Ident::new(
+                            &name.to_string().to_ascii_lowercase(), name.span()
+                        );
+
+                        // Flags can overlap in any bit of the bit field, which is only known for
+                        // fixed size bit fields.
+                        let message = format!("Flags in field {} overlap with flags in field {}", entry_name, inner_name);
+                        let bits = (0..self.attr.bits.unwrap_or_default()).map(|flag| (flag, format!(
+                            "{}\n{}", message, self.explain_overlap(i, (&entry_name, flag, 1), (&inner_name, flag, 1))
+                        ))).collect();
+
+                        let assertion = generate_bit_assertion(
+                            &name, &message, bits, quote::quote! { Self::#fn_name() }, name.span()
+                        );
+
+                        quote::quote_spanned! { span =>
+                            const fn #fn_name() -> u8 {
+                                let f1 = #entry_ty::iter();
+                                let f2 = #inner_ty::iter();
+
+                                let mut i1 = 0;
+                                while i1 < f1.len() {
+                                    let mut i2 = 0;
+                                    while i2 < f2.len() {
+                                        if (f1[i1] as u8) == (f2[i2] as u8) {
+                                            return f1[i1] as u8;
+                                        }
+
+                                        i2 += 1;
+                                    }
+
+                                    i1 += 1;
+                                }
+
+                                u8::MAX
+                            }
+
                             #assertion
                         }
-                    } else {
-                        // Skip flags overlap flags check for previously defined flags, as they
-                        // already check for flags that are defined after them.
-                        if i > inner_i { return proc_macro2::TokenStream::new(); }
+                    }
+                });
+
+                for (inner, flag_assertion) in entries.iter().zip(flag_assertions) {
+                    match entry.entry.is_conditional() || inner.entry.is_conditional() {
+                        false => assertions.push(flag_assertion),
+                        true if flag_assertion.is_empty() => (),
+                        true => conditional.push((
+                            entry.entry.cfgs().into_iter().chain(inner.entry.cfgs()).collect(), flag_assertion
+                        ))
+                    }
+                }
+            }
+        }
+
+        // Ensure every bit is used by a field, flags or reserved bits, if this can not be checked in
+        // the parsing phase, aka. when the bit field contains flags or the primitive base type is
+        // `usize`.
+        if let Some(exhaustive) = self.attr.exhaustive.as_ref().filter(
+            |_| self.attr.bits.is_none() || entries.iter().any(|entry| entry.field.is_none() || entry.is_conditional())
+        ) {
+            let primitive_type = &self.attr.primitive_type;
+            let span = exhaustive.span();
+            let one = syn::LitInt::new(&format!("1{}", primitive_type), span);
+
+            let used = entries.iter().map(|entry| {
+                let ty = &entry.ty;
+                let cfgs = entry.cfgs();
+
+                match &entry.field {
+                    Some(field) if !cfgs.is_empty() => {
+                        let bit = field.bit.as_ref().unwrap();
+                        let size = field.size.as_ref().unwrap();
+
+                        quote::quote! {
+                            #(#cfgs)* { used |= ((#one << #size) - 1) << #bit; }
+                        }
+                    },
+                    Some(field) => {
+                        let bit = field.bit.as_ref().unwrap();
+                        let size = field.size.as_ref().unwrap();
+
+                        quote::quote! {
+                            used |= ((#one << #size) - 1) << #bit;
+                        }
+                    },
+                    None => quote::quote! { #(#cfgs)* {
+                        let flags = #ty::iter();
+
+                        let mut i = 0;
+                        while i < flags.len() {
+                            used |= #one << (flags[i] as u8);
+
+                            i += 1;
+                        }
+                    }}
+                }
+            });
+
+            let name = syn::Ident::new("_EXHAUSTIVE_BITFIELD_HAS_UNUSED_BITS", span);
+            let fn_name = syn::Ident::new(&name.to_string().to_ascii_lowercase(), span);
+
+            // Only bits without a field can be unused.
+            let bits = self.bits_without_field().into_iter().map(
+                |bit| (bit, format!("Bit {} is not used by a field, flags or reserved bits", bit))
+            ).collect();
+
+            let assertion = generate_bit_assertion(
+                &name, "Not every bit is used by a field, flags or reserved bits", bits, quote::quote! { Self::#fn_name() }, span
+            );
+
+            assertions.push(quote::quote_spanned! { span =>
+                const fn #fn_name() -> u8 {
+                    let mut used: #primitive_type = 0;
+                    #(#used)*
+
+                    let mut bit = 0;
+                    while bit < #primitive_type::BITS {
+                        if used & (#one << bit) == 0 {
+                            return bit as u8;
+                        }
+
+                        bit += 1;
+                    }
+
+                    u8::MAX
+                }
+
+                #assertion
+            });
+        }
+
+        let is_generic = !self.generics.params.is_empty();
+
+        // Ensure `sizeof<T> == sizeof<Option<T>>` for `NonZero` bitfield types. The markers of
+        // generic bit fields are zero-sized and do not change the layout.
+        if self.attr.is_non_zero && !is_generic {
+            assertions.push(generate_assertion(
+                &syn::Ident::new("_OPTION_OF_NON_ZERO_BITFIELD_HAS_A_DIFFERENT_SIZE", self.attr.base_type.span()),
+                "Option of non-zero bitfield has a different size",
+                quote::quote! { ::core::mem::size_of::<#ident>() == ::core::mem::size_of::<::core::option::Option<#ident>>() },
+                self.attr.base_type.span()
+            ));
+        }
+
+        if assertions.is_empty() && conditional.is_empty() { return proc_macro2::TokenStream::new(); }
+
+        let unconditional = (!assertions.is_empty()).then(|| quote::quote! {
+            impl #ident {
+                #(#assertions)*
+            }
+        });
+
+        let conditional = conditional.into_iter().map(|(cfgs, assertion)| quote::quote! {
+            #(#cfgs)*
+            impl #ident {
+                #assertion
+            }
+        });
+
+        // Array lengths can not depend on a generic `Self`, so the assertions of generic bit fields
+        // are implemented for a non-generic type with the same name.
+        match is_generic {
+            false => quote::quote! {
+                #unconditional
+                #(#conditional)*
+            },
+            true => quote::quote! {
+                const _: () = {
+                    struct #ident;
+
+                    #unconditional
+                    #(#conditional)*
+                };
+            }
+        }
+    }
+
+    /// Generates a debug/display sequence for fields.
+    fn generate_print_field(
+        entry: &super::Entry, getter: &syn::Ident, print: proc_macro2::TokenStream, span: proc_macro2::Span
+    ) -> proc_macro2::TokenStream {
+        if entry.is_fallible() {
+            quote::quote_spanned! { span =>
+                let value = self.#getter();
+                if let ::core::result::Result::Ok(value) = value {
+                    #print
+                } else {
+                    #print
+                }
+            }
+        } else {
+            quote::quote_spanned! { span =>
+                let value = self.#getter();
+                #print
+            }
+        }
+    }
+
+    /// Generates the `::core::fmt::Debug` implementation, if `#[derive(Debug)]` is specified.
+    /// Expects all flags to expose a `fn iter() -> &'static [Self]` and a
+    /// `fn _bitfield_flag_name(&self) -> &'static str`, as generated by the `Flags` derive, all fields
+    /// to implement `::core::fmt::Debug`, and all flags to implement `::core::marker::Copy` and
+    /// `::core::clone::Clone`. Everything is written
+    /// directly into the formatter, so the `alloc` crate is not needed.
+    ///
+    /// Since field getters return a result, an `Ok` value will be unwrapped before it is printed,
+    /// to hide the `Ok(<value>)` around the `<value>`. Write-only entries are skipped.
+    fn generate_debug(&self) -> proc_macro2::TokenStream {
+        if self.debug.is_none() { return proc_macro2::TokenStream::new(); }
+
+        let ident = &self.ident;
+
+        let fields = match &self.data {
+            super::Data::Named(entries) => {
+                let mut fields = vec!();
+                let mut accessors = self.accessors().into_iter();
+
+                for entry in entries {
+                    let unraw = entry.ident.unraw();
+                    let span = entry.ident.span();
+
+                    // Reserved bits have no getter and are only displayed if they are not zero.
+                    if entry.entry.reserved.is_some() {
+                        let field = entry.entry.field.as_ref().unwrap();
+                        let bit = field.bit.as_ref().unwrap();
+                        let size = field.size.as_ref().unwrap();
+
+                        fields.push(quote::quote_spanned! { span =>
+                            let value = self._field(#bit, #size);
+                            if value != 0 {
+                                s.field(::core::stringify!(#unraw), &::core::format_args!("{:#x}", value));
+                            }
+                        });
+
+                        continue;
+                    }
+
+                    let accessors = accessors.next().unwrap();
+                    let getter = &accessors.getter;
+
+                    // Write-only entries have no getter.
+                    if !entry.entry.is_readable() { continue; }
+
+                    let cfgs = entry.entry.cfgs();
+
+                    fields.push(if entry.entry.field.is_some() {
+                        // Display fields as a normal struct field.
+                        super::BitField::generate_print_field(&entry.entry, getter, quote::quote_spanned! { span =>
+                            s.field(::core::stringify!(#unraw), &value);
+                        }, span)
+                    } else {
+                        // Display flags as sub structure with a `bool` field for each flag.
+                        let self_ident = &self.ident;
+                        let ty = &entry.entry.ty;
+                        let ty_name = &entry.entry.ty.segments.last().unwrap().ident;
+
+                        let (_, self_ty_generics, _) = self.generics.split_for_impl();
+                        let (generics, lifetime) = self.generics_with_lifetime();
+                        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+                        quote::quote_spanned! { span => {
+                            struct BitFieldDebugImplementor #generics (&#lifetime #self_ident #self_ty_generics) #where_clause;
+
+                            impl #impl_generics ::core::fmt::Debug for BitFieldDebugImplementor #ty_generics #where_clause {
+                                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                    let mut s = f.debug_struct(::core::stringify!(#ty_name));
+
+                                    for flag in <#ty>::iter() {
+                                        s.field(flag._bitfield_flag_name(), &self.0 . #getter(*flag));
+                                    }
+
+                                    s.finish()
+                                }
+                            }
+
+                            s.field(::core::stringify!(#unraw), &BitFieldDebugImplementor(&self));
+                        }}
+                    });
+
+                    // Conditional entries are only printed in the configurations they exist in.
+                    if !cfgs.is_empty() {
+                        let field = fields.pop();
+                        fields.push(quote::quote_spanned! { span => #(#cfgs)* { #field } });
+                    }
+                }
+
+                fields
+            },
+
+            super::Data::Tuple(entry) => {
+                let ty = &entry.ty;
+                let span = entry.ty.span();
+                let getter = &self.accessors().remove(0).getter;
+
+                vec!(if !entry.is_readable() {
+                    // Write-only entries have no getter.
+                    proc_macro2::TokenStream::new()
+                } else if entry.field.is_some()
+                {
+                    // Display fields as a normal struct field.
+                    let ident = &entry.stored_type().segments.last().unwrap().ident;
+
+                    super::BitField::generate_print_field(entry, getter, quote::quote_spanned! { span =>
+                        s.field(::core::stringify!(#ident), &value);
+                    }, entry.ty.span())
+                } else {
+                    // Display each flag as a `bool` field.
+                    quote::quote_spanned! { span =>
+                        for flag in <#ty>::iter() {
+                            s.field(flag._bitfield_flag_name(), &self.#getter(*flag));
+                        }
+                    }
+                })
+            }
+        };
+
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        quote::quote! {
+            impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    let mut s = f.debug_struct(::core::stringify!(#ident));
+
+                    #(#fields)*
+
+                    s.finish()
+                }
+            }
+        }
+    }
+
+    /// If `#[derive(Display)]` is specified this generates the `::core::fmt::Display` implementation,
+    /// for tuple bit fields, named bit fields that only host flags, or named bit fields with a
+    /// `#[display("...")]` template, otherwise an empty `TokenStream` is generated. Expects all flags
+    /// to expose a `fn iter() -> &'static [Self]` and implement `::core::marker::Copy`,
+    /// `::core::clone::Clone`, and all flags and fields to implement `::core::fmt::Debug`.
+    fn generate_display(&self) -> proc_macro2::TokenStream {
+        /// Generates the implementation for a single entry (named or tuple struct).
+        fn generate_display_for_entry(
+            ident: &syn::Ident, generics: &syn::Generics, getter: &syn::Ident, entry: &super::Entry,
+            separator: &syn::LitStr, empty: &syn::LitStr, span: proc_macro2::Span
+        ) -> proc_macro2::TokenStream {
+            let ty = &entry.ty;
+
+            let implementation = if entry.field.is_some()
+            {
+                // Display the field value.
+                super::BitField::generate_print_field(entry, getter, quote::quote! {
+                    ::core::write!(f, "{:?}", value)
+                }, span)
+            } else {
+                // Display all set flags joined with the separator, or the empty marker if no flag
+                // is set at all.
+                quote::quote_spanned! { span =>
+                    let mut is_empty = true;
+
+                    for flag in <#ty>::iter() {
+                        if self.#getter(*flag) {
+                            if !is_empty { f.write_str(#separator)?; }
+                            ::core::write!(f, "{:?}", flag)?;
+                            is_empty = false;
+                        }
+                    }
+
+                    if is_empty { f.write_str(#empty) } else { ::core::fmt::Result::Ok(()) }
+                }
+            };
+
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            quote::quote_spanned! { span =>
+                impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        #implementation
+                    }
+                }
+            }
+        }
+
+        /// Generates the implementation for a `#[display("...")]` template.
+        fn generate_display_for_template(
+            ident: &syn::Ident, generics: &syn::Generics, entries: &[&super::EntryNamed], accessors: &[super::Accessors],
+            template: &super::DisplayTemplate, separator: &syn::LitStr, empty: &syn::LitStr
+        ) -> proc_macro2::TokenStream {
+            let span = template.lit.span();
+
+            let segments = template.segments.iter().map(|segment| match segment {
+                super::DisplaySegment::Text(text) => quote::quote_spanned! { span =>
+                    f.write_str(#text)?;
+                },
+
+                super::DisplaySegment::Entry(name) => {
+                    // Should have been checked in `parse::validate_display`.
+                    let (entry, accessors) = entries.iter().zip(accessors).find(
+                        |(e, _)| e.ident.unraw() == name
+                    ).expect("unknown entry in `Display` template");
+                    let getter = &accessors.getter;
+                    let ty = &entry.entry.ty;
+
+                    if entry.entry.field.is_some() {
+                        super::BitField::generate_print_field(&entry.entry, getter, quote::quote! {
+                            ::core::write!(f, "{:?}", value)?;
+                        }, span)
+                    } else {
+                        quote::quote_spanned! { span =>
+                            let mut is_empty = true;
+
+                            for flag in <#ty>::iter() {
+                                if self.#getter(*flag) {
+                                    if !is_empty { f.write_str(#separator)?; }
+                                    ::core::write!(f, "{:?}", flag)?;
+                                    is_empty = false;
+                                }
+                            }
+
+                            if is_empty { f.write_str(#empty)?; }
+                        }
+                    }
+                }
+            });
+
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            quote::quote_spanned! { span =>
+                impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        #({ #segments })*
+
+                        ::core::fmt::Result::Ok(())
+                    }
+                }
+            }
+        }
+
+        if self.display.is_none() { return proc_macro2::TokenStream::new(); }
+
+        let ident = &self.ident;
+        let options = self.display_options.as_ref();
+
+        let separator = options.and_then(|o| o.separator.clone()).unwrap_or_else(
+            || syn::LitStr::new(" | ", proc_macro2::Span::call_site())
+        );
+        let empty = options.and_then(|o| o.empty.clone()).unwrap_or_else(
+            || syn::LitStr::new("-", proc_macro2::Span::call_site())
+        );
+
+        let accessors = self.accessors();
+
+        match &self.data {
+            super::Data::Named(_) => {
+                let entries = self.data.named();
+
+                if let Some(template) = options.and_then(|o| o.template.as_ref()) {
+                    generate_display_for_template(ident, &self.generics, &entries, &accessors, template, &separator, &empty)
+                } else if entries.len() == 0 {
+                    // Do not generate `Display` for bit fields with no fields or flags at all.
+                    // Should have been checked in `parse::validate_display`.
+                    panic!("can not generate `Display` for empty bit fields");
+                } else if entries.len() == 1 {
+                    let first = entries.first().unwrap();
+                    generate_display_for_entry(
+                        ident, &self.generics, &accessors[0].getter, &first.entry, &separator, &empty, first.ident.span()
+                    )
+                } else {
+                    // Do not generate `Display` for bit fields with non-flags.
+                    // Should have been checked in `parse::validate_display`.
+                    for entry in &entries {
+                        if entry.entry.field.is_some() {
+                            panic!("can not generate `Display` for bit fields with non-flag fields");
+                        }
+                    }
+
+                    let iterators = entries.iter().zip(&accessors).map(|(c, accessors)| {
+                        let ty = &c.entry.ty;
+                        let getter = &accessors.getter;
+                        let span = c.ident.span();
+
+                        let format_data = if entries.len() > 1 {
+                            quote::quote! { "{}::{:?}", ::core::any::type_name::<#ty>(), flag }
+                        } else {
+                            quote::quote! { "{:?}", flag }
+                        };
+
+                        quote::quote_spanned! { span =>
+                            for flag in <#ty>::iter() {
+                                if self.#getter(*flag) {
+                                    if !is_empty { f.write_str(#separator)?; }
+                                    ::core::write!(f, #format_data)?;
+                                    is_empty = false;
+                                }
+                            }
+                        }
+                    });
+
+                    let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+                    quote::quote! {
+                        impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+                            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                                let mut is_empty = true;
+
+                                #(#iterators)*
+
+                                if is_empty { f.write_str(#empty) } else { ::core::fmt::Result::Ok(()) }
+                            }
+                        }
+                    }
+                }
+            },
+
+            super::Data::Tuple(entry) => {
+                generate_display_for_entry(ident, &self.generics, &accessors[0].getter, entry, &separator, &empty, entry.ty.span())
+            }
+        }
+    }
+
+    /// Generates the `::core::str::FromStr` implementation and its error type, if
+    /// `#[derive(Display)]` is specified. The implementation accepts the exact format of the
+    /// generated `::core::fmt::Display` implementation. Flags are additionally accepted without the
+    /// whitespace around the separator, and numeric literals set the corresponding raw bits. Integer
+    /// values in templates are read up to their last digit, as the following text may start with a
+    /// sign or digit. Expects the same trait implementations and methods as `generate_display`.
+    fn generate_from_str(&self) -> proc_macro2::TokenStream {
+        if self.display.is_none() { return proc_macro2::TokenStream::new(); }
+
+        let vis = &self.vis;
+        let ident = &self.ident;
+        let base_type = &self.attr.base_type;
+        let primitive_type = &self.attr.primitive_type;
+        let error = quote::format_ident!("{}ParseError", ident.unraw());
+
+        let options = self.display_options.as_ref();
+
+        let separator = options.and_then(|o| o.separator.clone()).unwrap_or_else(
+            || syn::LitStr::new(" | ", proc_macro2::Span::call_site())
+        );
+        let empty = options.and_then(|o| o.empty.clone()).unwrap_or_else(
+            || syn::LitStr::new("-", proc_macro2::Span::call_site())
+        );
+
+        // Parses the flags of all types in `types` from `token`. Type names must prefix the flags
+        // if `is_qualified` is `true`.
+        let parse_flags = |token: proc_macro2::TokenStream, types: &[&syn::Path], is_qualified: bool| {
+            let format_data = match is_qualified {
+                false => types.iter().map(|_| quote::quote! { "{:?}", flag }).collect::<Vec<_>>(),
+                true => types.iter().map(|ty| quote::quote! {
+                    "{}::{:?}", ::core::any::type_name::<#ty>(), flag
+                }).collect()
+            };
+
+            quote::quote! {
+                if #token.trim() != #empty.trim() {
+                    let separator = match #separator.trim() { "" => #separator, separator => separator };
+
+                    for token in #token.split(separator) {
+                        let token = token.trim();
+
+                        #(
+                            if let ::core::option::Option::Some(flag) = <#types>::iter().iter().find(
+                                |flag| matches(token, ::core::format_args!(#format_data))
+                            ) {
+                                result |= 1 << (*flag as #primitive_type);
+                                continue;
+                            }
+                        )*
+
+                        match number(token) {
+                            ::core::option::Option::Some(value) => result |= value,
+                            ::core::option::Option::None => return ::core::result::Result::Err(error(token))
+                        }
+                    }
+                }
+            }
+        };
+
+        // Parses the value of a field from `token`.
+        let parse_field = |token: proc_macro2::TokenStream, entry: &super::Entry| {
+            let field = entry.field.as_ref().unwrap();
+            let ty = &entry.ty;
+            let bit = field.bit.as_ref().unwrap().base10_parse::<u8>().unwrap();
+            let size = field.size.as_ref().unwrap().base10_parse::<u8>().unwrap();
+            let primitive_type_unsigned = crate::primitive::type_from_bits(size, false, field.size.span());
+
+            // Fields that do not use all bits of their type must be checked for the upper bound.
+            let check = |bits: u8| match size < bits {
+                false => quote::quote!(),
+                true => quote::quote!(if value >> #size == 0)
+            };
+
+            let value = match ty.get_ident() {
+                _ if entry.non_zero_bits().is_some() => {
+                    let inner = entry.optional_type().unwrap();
+                    let check = match size < entry.non_zero_bits().unwrap() {
+                        false => quote::quote!(),
+                        true => quote::quote!(if value.get() >> #size == 0)
+                    };
+
+                    // `None` is stored as `0`.
+                    quote::quote! {
+                        match token.strip_prefix("Some(").and_then(|token| token.strip_suffix(')')).map(
+                            |token| token.parse::<#inner>()
+                        ) {
+                            ::core::option::Option::Some(::core::result::Result::Ok(value)) #check => value.get() as #primitive_type,
+                            _ if token == "None" => 0,
+                            _ => return ::core::result::Result::Err(error(token))
+                        }
+                    }
+                },
+
+                Some(ty) if crate::primitive::is_bool(ty) => quote::quote! {
+                    match token {
+                        "false" => 0,
+                        "true" => 1,
+                        _ => return ::core::result::Result::Err(error(token))
+                    }
+                },
+
+                Some(ty) if crate::primitive::is_signed_primitive(ty) => quote::quote! {
+                    match token.parse::<#ty>() {
+                        ::core::result::Result::Ok(value) => value as #primitive_type_unsigned as #primitive_type,
+                        ::core::result::Result::Err(_) => return ::core::result::Result::Err(error(token))
+                    }
+                },
+
+                Some(ty) if crate::primitive::is_unsigned_primitive(ty) => {
+                    let check = check(crate::primitive::primitive_bits(ty).unwrap());
+
+                    quote::quote! {
+                        match token.parse::<#ty>() {
+                            ::core::result::Result::Ok(value) #check => value as #primitive_type,
+                            _ => return ::core::result::Result::Err(error(token))
+                        }
+                    }
+                },
+
+                _ => {
+                    // Fields that are not `complete` display unknown values as `Err(primitive)`.
+                    let fallback = match field.complete.is_some() {
+                        false => {
+                            let check = check(crate::primitive::field_primitive_size(size));
+
+                            quote::quote! {
+                                match token.strip_prefix("Err(").and_then(|token| token.strip_suffix(')')).map(
+                                    |token| token.parse::<#primitive_type_unsigned>()
+                                ) {
+                                    ::core::option::Option::Some(::core::result::Result::Ok(value)) #check => value as #primitive_type,
+                                    _ => return ::core::result::Result::Err(error(token))
+                                }
+                            }
+                        },
+                        true => quote::quote!(return ::core::result::Result::Err(error(token)))
+                    };
+
+                    // `None` is stored as `0`, and other values of `Option` fields are wrapped in `Some`.
+                    let (ty, format, none) = match entry.optional_type() {
+                        Some(ty) => (ty, quote::quote!("Some({:?})"), Some(quote::quote!(_ if token == "None" => 0,))),
+                        None => (ty, quote::quote!("{:?}"), None)
+                    };
+
+                    quote::quote! {
+                        match <#ty>::iter().iter().find(
+                            |variant| matches(token, ::core::format_args!(#format, variant))
+                        ) {
+                            ::core::option::Option::Some(variant) => *variant as #primitive_type_unsigned as #primitive_type,
+                            #none
+                            ::core::option::Option::None => #fallback
+                        }
+                    }
+                }
+            };
+
+            quote::quote! {
+                let token = #token.trim();
+                let value: #primitive_type = #value;
+                result |= value << #bit;
+            }
+        };
+
+        let parse_entry = |token: proc_macro2::TokenStream, entry: &super::Entry| match entry.field {
+            Some(_) => parse_field(token, entry),
+            None => parse_flags(token, &[&entry.ty], false)
+        };
+
+        let entries = self.data.named();
+        let implementation = match &self.data {
+            super::Data::Named(_) => match options.and_then(|o| o.template.as_ref()) {
+                Some(template) => {
+                    let mut segments = template.segments.iter().peekable();
+                    let mut parsers = vec!();
+
+                    while let Some(segment) = segments.next() {
+                        parsers.push(match segment {
+                            super::DisplaySegment::Text(text) => quote::quote! {
+                                rest = match rest.strip_prefix(#text) {
+                                    ::core::option::Option::Some(rest) => rest,
+                                    ::core::option::Option::None => return ::core::result::Result::Err(error(rest))
+                                };
+                            },
+
+                            super::DisplaySegment::Entry(name) => {
+                                // Should have been checked in `parse::validate_display`.
+                                let entry = entries.iter().find(
+                                    |e| e.ident.unraw() == name
+                                ).expect("unknown entry in `Display` template");
+
+                                // Integers are read up to their last digit, so the following text
+                                // may start with a sign or digit. Other values end at one of the
+                                // occurrences of the following text and are never empty. The text
+                                // may also appear within a value (e.g. the separator of flags), so
+                                // the longest value that can be parsed wins.
+                                let is_integer = entry.entry.field.is_some()
+                                    && entry.entry.ty.get_ident().is_some_and(|ty|
+                                        crate::primitive::is_signed_primitive(ty)
+                                            || crate::primitive::is_unsigned_primitive(ty)
+                                    );
+
+                                let token = match segments.peek() {
+                                    Some(super::DisplaySegment::Text(_)) if is_integer => quote::quote! {
+                                        let sign = match rest.as_bytes().first() {
+                                            ::core::option::Option::Some(b'+' | b'-') => 1,
+                                            _ => 0
+                                        };
+                                        let (token, remainder) = rest.split_at(
+                                            sign + rest.as_bytes()[sign..].iter().take_while(|byte| byte.is_ascii_digit()).count()
+                                        );
+                                        rest = remainder;
+                                    },
+                                    Some(super::DisplaySegment::Text(text)) => {
+                                        let parser = parse_entry(quote::quote!(token), &entry.entry);
+
+                                        parsers.push(quote::quote! {
+                                            let parse = |token: &str| -> ::core::result::Result<#primitive_type, #error> {
+                                                let mut result: #primitive_type = 0;
+                                                #parser
+                                                ::core::result::Result::Ok(result)
+                                            };
+
+                                            let mut parsed = ::core::option::Option::None;
+                                            let mut failed = ::core::option::Option::None;
+                                            let mut end = rest.chars().next().map_or(0, char::len_utf8);
+
+                                            while let ::core::option::Option::Some(index) = rest[end..].find(#text) {
+                                                end += index;
+
+                                                match parse(&rest[..end]) {
+                                                    ::core::result::Result::Ok(value) => parsed = ::core::option::Option::Some((value, end)),
+                                                    ::core::result::Result::Err(e) => failed = failed.or(::core::option::Option::Some(e))
+                                                }
+
+                                                end += rest[end..].chars().next().map_or(1, char::len_utf8);
+                                            }
+
+                                            match parsed {
+                                                ::core::option::Option::Some((value, end)) => {
+                                                    result |= value;
+                                                    rest = &rest[end..];
+                                                },
+                                                ::core::option::Option::None => return ::core::result::Result::Err(
+                                                    failed.unwrap_or_else(|| error(rest))
+                                                )
+                                            }
+                                        });
+
+                                        continue;
+                                    },
+                                    Some(super::DisplaySegment::Entry(_)) => panic!(
+                                        "adjacent entries in `Display` template"
+                                    ),
+                                    None => quote::quote! {
+                                        let token = ::core::mem::take(&mut rest);
+                                    }
+                                };
+                                let parser = parse_entry(quote::quote!(token), &entry.entry);
+
+                                quote::quote! {
+                                    #token
+                                    #parser
+                                }
+                            }
+                        });
+                    }
+
+                    quote::quote! {
+                        let mut rest = s;
+
+                        #({ #parsers })*
+
+                        if !rest.is_empty() {
+                            return ::core::result::Result::Err(error(rest));
+                        }
+                    }
+                },
+
+                None if entries.len() == 1 => parse_entry(quote::quote!(s), &entries[0].entry),
+
+                None => parse_flags(
+                    quote::quote!(s),
+                    &entries.iter().map(|e| &e.entry.ty).collect::<Vec<_>>(),
+                    true
+                )
+            },
+
+            super::Data::Tuple(entry) => parse_entry(quote::quote!(s), entry)
+        };
+
+        let new = self.generate_new(quote::quote!(Self), quote::quote!(result));
+        let constructor = match self.attr.is_non_zero {
+            false => quote::quote!(::core::result::Result::Ok(#new)),
+            true => quote::quote! {
+                match #base_type::new(result) {
+                    ::core::option::Option::Some(result) => ::core::result::Result::Ok(#new),
+                    ::core::option::Option::None => ::core::result::Result::Err(error(s))
+                }
+            }
+        };
+
+        let error_doc = format!(
+            " The error type returned when parsing [`{}`] with `core::str::FromStr` fails.", ident.unraw()
+        );
+
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        quote::quote! {
+            #[doc = #error_doc]
+            #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+            #vis struct #error {
+                position: usize,
+                token: [u8; 32],
+                length: u8
+            }
+
+            impl #error {
+                /// Returns the token that could not be parsed, truncated to 32 bytes.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn token(&self) -> &str {
+                    ::core::str::from_utf8(&self.token[..self.length as usize]).unwrap_or_default()
+                }
+
+                /// Returns the byte position of the token in the parsed string.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis const fn position(&self) -> usize {
+                    self.position
+                }
+            }
+
+            impl ::core::fmt::Display for #error {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::write!(f, "invalid token `{}` at position {}", self.token(), self.position)
+                }
+            }
+
+            impl #impl_generics ::core::str::FromStr for #ident #ty_generics #where_clause {
+                type Err = #error;
+
+                #[allow(unused_mut)]
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    /// Compares the output of `arguments` with a token, without allocating.
+                    struct Matcher<'a>(&'a str);
+
+                    impl<'a> ::core::fmt::Write for Matcher<'a> {
+                        fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                            match self.0.strip_prefix(s) {
+                                ::core::option::Option::Some(rest) => { self.0 = rest; ::core::fmt::Result::Ok(()) },
+                                ::core::option::Option::None => ::core::fmt::Result::Err(::core::fmt::Error)
+                            }
+                        }
+                    }
+
+                    #[allow(unused)]
+                    fn matches(token: &str, arguments: ::core::fmt::Arguments) -> bool {
+                        let mut matcher = Matcher(token);
+                        ::core::fmt::write(&mut matcher, arguments).is_ok() && matcher.0.is_empty()
+                    }
+
+                    /// Parses a decimal, or a `0x`, `0o` or `0b` prefixed numeric literal.
+                    #[allow(unused)]
+                    fn number(token: &str) -> ::core::option::Option<#primitive_type> {
+                        let (digits, radix) = if let ::core::option::Option::Some(digits) = token.strip_prefix("0x") {
+                            (digits, 16)
+                        } else if let ::core::option::Option::Some(digits) = token.strip_prefix("0o") {
+                            (digits, 8)
+                        } else if let ::core::option::Option::Some(digits) = token.strip_prefix("0b") {
+                            (digits, 2)
+                        } else {
+                            (token, 10)
+                        };
+
+                        #primitive_type::from_str_radix(digits, radix).ok()
+                    }
+
+                    // Keeps a copy of the token, truncated at a character boundary.
+                    let error = |token: &str| {
+                        let mut length = token.len().min(32);
+                    
+                        while !token.is_char_boundary(length) {
+                            length -= 1;
+                        }
+                    
+                        let mut bytes = [0; 32];
+                        bytes[..length].copy_from_slice(&token.as_bytes()[..length]);
+                    
+                        #error {
+                            position: token.as_ptr() as usize - s.as_ptr() as usize,
+                            token: bytes,
+                            length: length as u8
+                        }
+                    };
+
+                    let mut result: #primitive_type = 0;
+
+                    #implementation
+
+                    #constructor
+                }
+            }
+        }
+    }
+
+    /// Generates the `::core::fmt::{LowerHex, UpperHex, Binary, Octal}` implementations, if they
+    /// are specified in `#[derive(...)]`. All implementations forward to the primitive type, except
+    /// for the alternate form of `Binary` (`{:#b}`), which prints all bits of the primitive type and
+    /// separates them with `|` at the boundaries of fields and flags. Every flag is a group of its
+    /// own, and the unused bits between entries form one group. Expects all flags to expose a
+    /// `fn iter() -> &'static [Self]` and implement `::core::marker::Copy`.
+    fn generate_radix(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let primitive_type = &self.attr.primitive_type;
+
+        let destructor = match self.attr.is_non_zero {
+            false => quote::quote!(self.0),
+            true => quote::quote!(self.0.get())
+        };
+
+        let forward = |span: Option<proc_macro2::Span>, name: &str| span.map(|span| {
+            let name = syn::Ident::new(name, span);
+
+            quote::quote_spanned! { span =>
+                impl #impl_generics ::core::fmt::#name for #ident #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        ::core::fmt::#name::fmt(&#destructor, f)
+                    }
+                }
+            }
+        }).unwrap_or_default();
+
+        let lower_hex = forward(self.lower_hex, "LowerHex");
+        let upper_hex = forward(self.upper_hex, "UpperHex");
+        let octal = forward(self.octal, "Octal");
+
+        let binary = self.binary.map(|span| {
+            let boundaries = self.data.layout().into_iter().map(|entry| {
+                let cfgs = entry.cfgs();
+
+                if let Some(field) = &entry.field {
+                    let bit = field.bit.as_ref().unwrap().base10_parse::<u32>().unwrap();
+                    let end = bit + field.size.as_ref().unwrap().base10_parse::<u32>().unwrap();
+
+                    let boundaries = quote::quote_spanned! { span =>
+                        boundaries |= #primitive_type::checked_shl(1, #bit).unwrap_or(0);
+                        boundaries |= #primitive_type::checked_shl(1, #end).unwrap_or(0);
+                    };
+
+                    match cfgs.is_empty() {
+                        true => boundaries,
+                        false => quote::quote_spanned!(span => #(#cfgs)* { #boundaries })
+                    }
+                } else {
+                    let ty = &entry.ty;
+
+                    // Every flag is a group of its own.
+                    quote::quote_spanned! { span => #(#cfgs)* {
+                        for flag in <#ty>::iter() {
+                            let bit = *flag as u32;
+
+                            boundaries |= #primitive_type::checked_shl(1, bit).unwrap_or(0);
+                            boundaries |= #primitive_type::checked_shl(1, bit + 1).unwrap_or(0);
+                        }
+                    }}
+                }
+            });
+
+            quote::quote_spanned! { span =>
+                impl #impl_generics ::core::fmt::Binary for #ident #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let value = #destructor;
+
+                        if !f.alternate() {
+                            return ::core::fmt::Binary::fmt(&value, f);
+                        }
+
+                        // A set bit marks the first bit of an entry, or the first bit after it.
+                        let mut boundaries: #primitive_type = 0;
+                        #(#boundaries)*
+
+                        f.write_str("0b")?;
+
+                        let bits = (::core::mem::size_of::<#primitive_type>() * 8) as u32;
+                        let mut i = bits;
+                        while i > 0 {
+                            i -= 1;
+
+                            if i + 1 < bits && (boundaries >> (i + 1)) & 1 != 0 {
+                                f.write_str("|")?;
+                            }
+
+                            f.write_str(if (value >> i) & 1 != 0 { "1" } else { "0" })?;
+                        }
+
+                        ::core::fmt::Result::Ok(())
+                    }
+                }
+            }
+        }).unwrap_or_default();
+
+        quote::quote! {
+            #lower_hex
+            #upper_hex
+            #binary
+            #octal
+        }
+    }
+
+    /// Generates the read-modify-write shortcuts of all writable entries, which are named like the
+    /// in-place mutators without `_in_place`. They read the bit field with `read`, call the in-place
+    /// mutator and write `write_back` with `self.write(...)`. `doc_suffix` completes the
+    /// documentation, e.g. `"."`.
+    fn generate_shortcuts(
+        &self,
+        span: proc_macro2::Span,
+        receiver: proc_macro2::TokenStream,
+        read: proc_macro2::TokenStream,
+        write_back: proc_macro2::TokenStream,
+        doc_suffix: &str
+    ) -> Vec<proc_macro2::TokenStream> {
+        self.data.entries().into_iter().zip(self.accessors()).filter(
+            |(entry, _)| entry.is_writable()
+        ).flat_map(|(entry, accessors)| {
+            let attrs = &entry.attrs;
+            let vis = &entry.vis;
+            let ty = &entry.ty;
+            let super::Accessors { setter, inverter, .. } = &accessors;
+
+            // Generates a shortcut which calls the in-place `mutation` on the read bit field. If
+            // `rejected_type` is specified, `mutation` returns a `Result` and the bit field is only
+            // written back on success.
+            let shortcut = |
+                name: syn::Ident, doc: &str, parameters: proc_macro2::TokenStream,
+                mutation: proc_macro2::TokenStream, rejected_type: Option<proc_macro2::TokenStream>
+            | {
+                let doc = format!("{}{}", doc, doc_suffix);
+
+                match rejected_type {
+                    None => quote::quote_spanned! { span =>
+                        #(#attrs)*
+                        #[doc = #doc]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        #vis fn #name(#receiver #parameters) {
+                            let mut bitfield = #read;
+                            bitfield.#mutation;
+                            self.write(#write_back);
+                        }
+                    },
+
+                    Some(rejected_type) => quote::quote_spanned! { span =>
+                        #(#attrs)*
+                        #[doc = #doc]
+                        #[doc = ""]
+                        #[doc = " Returns the rejected value in `Err` and does not write the bit field, if `value` is bigger than the specified amount of bits the field can store."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        #vis fn #name(#receiver #parameters) -> ::core::result::Result<(), #rejected_type> {
+                            let mut bitfield = #read;
+                            let result = bitfield.#mutation;
+                            if result.is_ok() { self.write(#write_back); }
+                            result
+                        }
+                    }
+                }
+            };
 
-                        let name = syn::Ident::new(&format!("_FLAGS_IN_FIELD_{i}_OVERLAP_WITH_FLAGS_IN_FIELD_{inner_i}"), entry.ident.span());
+            let mut shortcuts = vec!();
+            let is_rw = entry.is_readable() && entry.is_writable();
+            let setter_in_place = super::Accessors::suffixed(setter, "in_place");
 
-                        let fn_name = syn::Ident::new(
-                            &name.to_string().to_ascii_lowercase(), name.span()
-                        );
+            if entry.field.is_some() {
+                let is_bool = entry.ty.get_ident().map(crate::primitive::is_bool).unwrap_or_default();
 
-                        let assertion = generate_assertion(
-                            &name,
-                            &format!("Flags in field \"{}\" overlap with flags in field \"{}\"", entry.ident.unraw(), inner.ident.unraw()),
-                            quote::quote! { !Self::#fn_name() },
-                            name.span()
-                        );
+                shortcuts.push(match entry.is_partial() {
+                    false => shortcut(
+                        setter.clone(), " Sets the field to the new value",
+                        quote::quote!(, value: #ty), quote::quote!(#setter_in_place(value)), None
+                    ),
+                    true => shortcut(
+                        quote::format_ident!("try_{}", setter), " Sets the field to the new value",
+                        quote::quote!(, value: #ty),
+                        { let mutator = quote::format_ident!("try_{}", setter_in_place); quote::quote!(#mutator(value)) },
+                        Some(quote::quote!(#ty))
+                    )
+                });
 
-                        quote::quote_spanned! { span =>
-                            const fn #fn_name() -> bool {
-                                let f1 = #entry_ty::iter();
-                                let f2 = #inner_ty::iter();
+                if is_bool && is_rw {
+                    let inverter_in_place = super::Accessors::suffixed(inverter, "in_place");
 
-                                let mut i1 = 0;
-                                while i1 < f1.len() {
-                                    let mut i2 = 0;
-                                    while i2 < f2.len() {
-                                        if (f1[i1] as u32) == (f2[i2] as u32) {
-                                            return true;
-                                        }
+                    shortcuts.push(shortcut(
+                        inverter.clone(), " Inverts the value of the field",
+                        quote::quote!(), quote::quote!(#inverter_in_place()), None
+                    ));
+                }
+            } else {
+                let insert = accessors.prefixed("insert");
+                let remove = accessors.prefixed("remove");
+
+                shortcuts.push(shortcut(
+                    setter.clone(), " Sets the specified `flag` to the new value",
+                    quote::quote!(, flag: #ty, value: bool), quote::quote!(#setter_in_place(flag, value)), None
+                ));
+                shortcuts.push(shortcut(
+                    insert.clone(), " Sets the specified `flag`",
+                    quote::quote!(, flag: #ty), quote::quote!(#insert(flag)), None
+                ));
+                shortcuts.push(shortcut(
+                    remove.clone(), " Clears the specified `flag`",
+                    quote::quote!(, flag: #ty), quote::quote!(#remove(flag)), None
+                ));
+
+                if is_rw {
+                    let toggle = accessors.prefixed("toggle");
+
+                    shortcuts.push(shortcut(
+                        toggle.clone(), " Inverts the value of the specified `flag`",
+                        quote::quote!(, flag: #ty), quote::quote!(#toggle(flag)), None
+                    ));
+                }
+            }
 
-                                        i2 += 1;
-                                    }
+            shortcuts
+        }).collect()
+    }
 
-                                    i1 += 1;
-                                }
+    /// Generates the `#NAME Register` type, if `register` is specified, which wraps a pointer to a
+    /// memory mapped register. The shortcuts for the setters do exactly one volatile read and one
+    /// volatile write, in which all write-1-to-clear flags are cleared, so no pending flag is
+    /// cleared by accident.
+    fn generate_register(&self) -> proc_macro2::TokenStream {
+        let span = match &self.attr.register {
+            Some(register) => register.span(),
+            None => return proc_macro2::TokenStream::new()
+        };
 
-                                false
-                            }
+        let vis = &self.vis;
+        let ident = &self.ident;
+        let register = syn::Ident::new(&format!("{}Register", ident.unraw()), ident.span());
+        let generics = &self.generics;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let turbofish = ty_generics.as_turbofish();
+        let marker = self.generate_marker().map(|marker| quote::quote!(, #marker));
+        let primitive_type = &self.attr.primitive_type;
+        let accessors = self.accessors();
+
+        let w1c_masks: Vec<_> = self.data.entries().into_iter().zip(&accessors).filter(
+            |(entry, _)| entry.access_mode() == super::AccessMode::WriteOneToClear
+        ).map(|(_, accessors)| {
+            let mask = super::Accessors::suffixed(&accessors.getter, "mask");
+            quote::quote!(#ident #turbofish::#mask())
+        }).collect();
+
+        let write_back = match w1c_masks.is_empty() {
+            true => quote::quote!(bitfield),
+            false => self.generate_new(ident, quote::quote!(bitfield.0 & !(0 #(| #w1c_masks)*)))
+        };
 
-                            #assertion
-                        }
-                    }
-                });
+        let clearers = self.data.entries().into_iter().zip(&accessors).filter(
+            |(entry, _)| entry.access_mode() == super::AccessMode::WriteOneToClear
+        ).map(|(entry, accessors)| {
+            let attrs = &entry.attrs;
+            let vis = &entry.vis;
+            let ty = &entry.ty;
+            let clearer = accessors.prefixed("clear");
 
-                assertions.push(quote::quote!(#(#flag_assertions)*));
+            quote::quote_spanned! { span =>
+                #(#attrs)*
+                /// Clears the specified write-1-to-clear `flag` with a single volatile
+                /// read-modify-write, without clearing other pending write-1-to-clear flags.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn #clearer(&self, flag: #ty) {
+                    self.write(self.read().#clearer(flag));
+                }
             }
-        }
+        });
 
-        // Ensure `sizeof<T> == sizeof<Option<T>>` for `NonZero` bitfield types.
-        if self.attr.is_non_zero {
-            assertions.push(generate_assertion(
-                &syn::Ident::new("_OPTION_OF_NON_ZERO_BITFIELD_HAS_A_DIFFERENT_SIZE", self.attr.base_type.span()),
-                "Option of non-zero bitfield has a different size",
-                quote::quote! { ::core::mem::size_of::<#ident>() == ::core::mem::size_of::<::core::option::Option<#ident>>() },
-                self.attr.base_type.span()
-            ));
-        }
+        let shortcuts = self.generate_shortcuts(
+            span, quote::quote!(&self), quote::quote!(self.read()), write_back,
+            " with a single volatile read-modify-write."
+        );
 
-        if assertions.len() == 0 { return proc_macro2::TokenStream::new(); }
+        let doc = format!(" A memory mapped register containing a [`{}`].", ident.unraw());
+        let new = self.generate_new(quote::quote!(Self), quote::quote!(address));
+        let read = self.generate_new(ident, quote::quote!(unsafe { ::core::ptr::read_volatile(self.0) }));
 
-        quote::quote! {
-            impl #ident {
-                #(#assertions)*
-            }
-        }
-    }
+        quote::quote_spanned! { span =>
+            #[doc = #doc]
+            #[repr(transparent)]
+            #vis struct #register #generics (*mut #primitive_type #marker) #where_clause;
 
-    /// Generates a debug/display sequence for fields.
-    fn generate_print_field(
-        entry: &super::Entry, getter: &syn::Ident, print: proc_macro2::TokenStream, span: proc_macro2::Span
-    ) -> proc_macro2::TokenStream {
-        entry.ty.get_ident().and_then(|ty| crate::primitive::is_primitive(ty).then(
-            || quote::quote_spanned! { span =>
-                let value = self.#getter();
-                #print
-            }
-        )).unwrap_or_else(|| {
-            if !entry.field.as_ref().map(|f| f.complete.is_some()).unwrap_or_default() {
-                quote::quote_spanned! { span =>
-                    let value = self.#getter();
-                    if let ::core::result::Result::Ok(value) = value {
-                        #print
-                    } else {
-                        #print
-                    }
+            impl #impl_generics #register #ty_generics #where_clause {
+                /// Creates a register which accesses the bit field at `address`.
+                ///
+                /// # Safety
+                ///
+                /// `address` must be non-null, properly aligned and valid for volatile reads and
+                /// writes as long as the register is used.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis const unsafe fn new(address: *mut #primitive_type) -> Self {
+                    #new
                 }
-            } else {
-                quote::quote_spanned! { span =>
-                    let value = self.#getter();
-                    #print
+
+                /// Returns the address of the register.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis const fn as_ptr(&self) -> *mut #primitive_type {
+                    self.0
+                }
+
+                /// Reads the bit field with a single volatile read.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn read(&self) -> #ident #ty_generics {
+                    #read
+                }
+
+                /// Writes the bit field with a single volatile write.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn write(&self, value: #ident #ty_generics) {
+                    unsafe { ::core::ptr::write_volatile(self.0, value.0) }
+                }
+
+                /// Reads the bit field, modifies it with `f` and writes the result back, with
+                /// exactly one volatile read and one volatile write. Write-1-to-clear flags are
+                /// written back as returned by `f`.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn modify<F: ::core::ops::FnOnce(#ident #ty_generics) -> #ident #ty_generics>(&self, f: F) {
+                    self.write(f(self.read()));
                 }
+
+                #(#clearers)*
+                #(#shortcuts)*
             }
-        })
+        }
     }
 
-    /// Generates the `::core::fmt::Debug` implementation, if `#[derive(Debug)]` is specified.
-    /// Expects all flags to expose a `fn iter() -> &'static [Self]`, all flags and fields to
-    /// implement `::core::fmt::Debug`, and all flags to implement `::core::marker::Copy` and
-    /// `::core::clone::Clone`.
-    ///
-    /// Since field getters return a result, an `Ok` value will be unwrapped before it is printed,
-    /// to hide the `Ok(<value>)` around the `<value>`.
-    fn generate_debug(&self) -> proc_macro2::TokenStream {
-        if self.debug.is_none() { return proc_macro2::TokenStream::new(); }
+    /// Generates the `Atomic#NAME` type if `atomic` was specified, which wraps the matching
+    /// `core::sync::atomic::Atomic*` type.
+    fn generate_atomic(&self) -> proc_macro2::TokenStream {
+        let span = match &self.attr.atomic {
+            Some(atomic) => atomic.span(),
+            None => return proc_macro2::TokenStream::new()
+        };
 
+        let vis = &self.vis;
         let ident = &self.ident;
+        let atomic = syn::Ident::new(&format!("Atomic{}", ident.unraw()), ident.span());
+        let base_type = &self.attr.base_type;
+        let primitive_type = &self.attr.primitive_type;
 
-        let fields = match &self.data {
-            super::Data::Named(entries) => {
-                let mut fields = vec!();
+        // `u8` becomes `AtomicU8` and `usize` becomes `AtomicUsize`.
+        let primitive_name = primitive_type.to_string();
+        let atomic_type = syn::Ident::new(
+            &format!("Atomic{}{}", primitive_name[..1].to_uppercase(), &primitive_name[1..]), span
+        );
 
-                for entry in entries {
-                    let ident = &entry.ident;
-                    let unraw = ident.unraw();
-                    let span = entry.ident.span();
+        // The atomic value is only ever set to values of valid bit fields, so it is never `0` for
+        // `NonZero` bit fields.
+        let from_value = match self.attr.is_non_zero {
+            false => self.generate_new(ident, quote::quote!(value)),
+            true => self.generate_new(ident, quote::quote!(unsafe { #base_type::new_unchecked(value) }))
+        };
 
-                    fields.push(if entry.entry.field.is_some() {
-                        // Display fields as a normal struct field.
-                        super::BitField::generate_print_field(&entry.entry, ident, quote::quote_spanned! { span =>
-                            s.field(::core::stringify!(#unraw), &value);
-                        }, span)
-                    } else {
-                        // Display flags as sub structure with a `bool` field for each flag.
-                        let self_ident = &self.ident;
-                        let ty = &entry.entry.ty;
-                        let ty_name = &entry.entry.ty.segments.last().unwrap().ident;
+        let generics = &self.generics;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let bitfield_type = quote::quote!(#ident #ty_generics);
+        let marker = self.generate_marker().map(|marker| quote::quote!(, #marker));
 
-                        quote::quote_spanned! { span => {
-                            struct BitFieldDebugImplementor<'a>(&'a #self_ident);
+        let into = |bitfield: &str| {
+            let bitfield = syn::Ident::new(bitfield, span);
 
-                            impl<'a> ::core::fmt::Debug for BitFieldDebugImplementor<'a> {
-                                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                                    let mut s = f.debug_struct(::core::stringify!(#ty_name));
+            match self.attr.is_non_zero {
+                false => quote::quote!(#bitfield.0),
+                true => quote::quote!(#bitfield.0.get())
+            }
+        };
 
-                                    for flag in <#ty>::iter() {
-                                        s.field(&alloc::format!("{:?}", flag), &self.0 . #ident(*flag));
-                                    }
+        let (into_value, into_current, into_new) = (into("value"), into("current"), into("new"));
 
-                                    s.finish()
-                                }
-                            }
+        let fetch_flags = self.data.entries().into_iter().zip(self.accessors()).filter(
+            |(entry, _)| entry.field.is_none() && entry.is_writable()
+        ).map(|(entry, accessors)| {
+            let attrs = &entry.attrs;
+            let vis = &entry.vis;
+            let ty = &entry.ty;
 
-                            s.field(::core::stringify!(#unraw), &BitFieldDebugImplementor(&self));
-                        }}
-                    });
+            let name = |prefix: &str| match &accessors.name {
+                Some(name) => syn::Ident::new(&format!("{}_{}", prefix, name.unraw()), name.span()),
+                None => syn::Ident::new(&format!("{}_flag", prefix), accessors.setter.span())
+            };
+
+            let fetch_set = name("fetch_set");
+            let fetch_set = quote::quote_spanned! { span =>
+                #(#attrs)*
+                /// Sets the specified `flag` with `fetch_or` and returns the previous bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn #fetch_set(&self, flag: #ty, order: ::core::sync::atomic::Ordering) -> #bitfield_type {
+                    let value = self.0.fetch_or(1 << (flag as #primitive_type), order);
+                    #from_value
                 }
+            };
 
-                fields
-            },
+            let fetch_clear = match self.attr.is_non_zero {
+                false => {
+                    let fetch_clear = name("fetch_clear");
 
-            super::Data::Tuple(entry) => {
-                let ty = &entry.ty;
-                let span = entry.ty.span();
+                    quote::quote_spanned! { span =>
+                        #(#attrs)*
+                        /// Clears the specified `flag` with `fetch_and` and returns the previous bit
+                        /// field.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        #vis fn #fetch_clear(&self, flag: #ty, order: ::core::sync::atomic::Ordering) -> #bitfield_type {
+                            let value = self.0.fetch_and(!(1 << (flag as #primitive_type)), order);
+                            #from_value
+                        }
+                    }
+                },
 
-                vec!(if entry.field.is_some()
-                {
-                    // Display fields as a normal struct field.
-                    let ident = &ty.segments.last().unwrap().ident;
-                    let getter = syn::Ident::new("get", ty.span());
+                true => {
+                    let fetch_clear = name("try_fetch_clear");
 
-                    super::BitField::generate_print_field(entry, &getter, quote::quote_spanned! { span =>
-                        s.field(::core::stringify!(#ident), &value);
-                    }, entry.ty.span())
-                } else {
-                    // Display each flag as a `bool` field.
                     quote::quote_spanned! { span =>
-                        for flag in <#ty>::iter() {
-                            s.field(&alloc::format!("{:?}", flag), &self.has(*flag));
+                        #(#attrs)*
+                        /// Clears the specified `flag` with `fetch_update` and returns the previous
+                        /// bit field in `Ok`, or in `Err` if clearing it would clear the last set bit.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        #vis fn #fetch_clear(
+                            &self,
+                            flag: #ty,
+                            set_order: ::core::sync::atomic::Ordering,
+                            fetch_order: ::core::sync::atomic::Ordering
+                        ) -> ::core::result::Result<#bitfield_type, #bitfield_type> {
+                            self.0.fetch_update(set_order, fetch_order, |value| {
+                                match value & !(1 << (flag as #primitive_type)) {
+                                    0 => None,
+                                    value => Some(value)
+                                }
+                            }).map(|value| #from_value).map_err(|value| #from_value)
                         }
                     }
-                })
+                }
+            };
+
+            quote::quote! {
+                #fetch_set
+                #fetch_clear
             }
-        };
+        });
 
-        quote::quote! {
-            impl ::core::fmt::Debug for #ident {
-                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                    let mut s = f.debug_struct(::core::stringify!(#ident));
+        let doc = format!(" A [`{}`] which can be safely shared between threads.", ident.unraw());
+        let new = self.generate_new(quote::quote!(Self), quote::quote!(::core::sync::atomic::#atomic_type::new(#into_value)));
 
-                    #(#fields)*
+        quote::quote_spanned! { span =>
+            #[doc = #doc]
+            #[repr(transparent)]
+            #vis struct #atomic #generics (::core::sync::atomic::#atomic_type #marker) #where_clause;
 
-                    s.finish()
+            impl #impl_generics #atomic #ty_generics #where_clause {
+                /// Creates a new atomic bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis const fn new(value: #bitfield_type) -> Self {
+                    #new
+                }
+
+                /// Consumes the atomic bit field and returns the contained bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn into_inner(self) -> #bitfield_type {
+                    let value = self.0.into_inner();
+                    #from_value
+                }
+
+                /// Loads the bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn load(&self, order: ::core::sync::atomic::Ordering) -> #bitfield_type {
+                    let value = self.0.load(order);
+                    #from_value
+                }
+
+                /// Stores the bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn store(&self, value: #bitfield_type, order: ::core::sync::atomic::Ordering) {
+                    self.0.store(#into_value, order);
+                }
+
+                /// Stores the bit field and returns the previous bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn swap(&self, value: #bitfield_type, order: ::core::sync::atomic::Ordering) -> #bitfield_type {
+                    let value = self.0.swap(#into_value, order);
+                    #from_value
+                }
+
+                /// Stores `new` if the current bit field is `current` and returns the previous bit
+                /// field in `Ok` on success, or in `Err` otherwise.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn compare_exchange(
+                    &self,
+                    current: #bitfield_type,
+                    new: #bitfield_type,
+                    success: ::core::sync::atomic::Ordering,
+                    failure: ::core::sync::atomic::Ordering
+                ) -> ::core::result::Result<#bitfield_type, #bitfield_type> {
+                    self.0.compare_exchange(#into_current, #into_new, success, failure).map(
+                        |value| #from_value
+                    ).map_err(|value| #from_value)
+                }
+
+                /// Stores the bit field returned by `f` until no other thread modified the bit field
+                /// in between, or `f` returns `None`. Returns the previous bit field in `Ok` on
+                /// success, or in `Err` otherwise.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn fetch_update<F: ::core::ops::FnMut(#bitfield_type) -> ::core::option::Option<#bitfield_type>>(
+                    &self,
+                    set_order: ::core::sync::atomic::Ordering,
+                    fetch_order: ::core::sync::atomic::Ordering,
+                    mut f: F
+                ) -> ::core::result::Result<#bitfield_type, #bitfield_type> {
+                    self.0.fetch_update(set_order, fetch_order, |value| {
+                        f(#from_value).map(|value| #into_value)
+                    }).map(|value| #from_value).map_err(|value| #from_value)
                 }
+
+                #(#fetch_flags)*
             }
         }
     }
 
-    /// If `#[derive(Display)]` is specified this generates the `::core::fmt::Display` implementation,
-    /// for tuple bit fields, or named bit fields that only host flags, otherwise an empty
-    /// `TokenStream` is generated. Expects all flags to expose a `fn iter() -> &'static [Self]` and
-    /// implement `::core::marker::Copy`, `::core::clone::Clone`, and all flags and fields to implement
-    /// `::core::fmt::Debug`.
-    fn generate_display(&self) -> proc_macro2::TokenStream {
-        /// Generates the implementation for a single entry (named or tuple struct).
-        fn generate_display_for_entry(
-            ident: &syn::Ident, getter: &syn::Ident, entry: &super::Entry, span: proc_macro2::Span
-        ) -> proc_macro2::TokenStream {
+    /// Generates the `#NAME View` and `#NAME ViewMut` types if `view` was specified, which decode
+    /// the bit field from the first bytes of a byte slice in the specified byte order.
+    fn generate_view(&self) -> proc_macro2::TokenStream {
+        let (span, byte_order) = match &self.attr.view {
+            Some(view) => (view.span, view.byte_order),
+            None => return proc_macro2::TokenStream::new()
+        };
+
+        let vis = &self.vis;
+        let ident = &self.ident;
+        let view = syn::Ident::new(&format!("{}View", ident.unraw()), ident.span());
+        let view_mut = syn::Ident::new(&format!("{}ViewMut", ident.unraw()), ident.span());
+        let primitive_type = &self.attr.primitive_type;
+        let size = quote::quote!(::core::mem::size_of::<#primitive_type>());
+
+        let (from_bytes, to_bytes, doc_byte_order) = match byte_order {
+            super::ByteOrder::LittleEndian => (
+                quote::quote!(#primitive_type::from_le_bytes(*self.0)), quote::quote!(value.0.to_le_bytes()),
+                "little endian"
+            ),
+            super::ByteOrder::BigEndian => (
+                quote::quote!(#primitive_type::from_be_bytes(*self.0)), quote::quote!(value.0.to_be_bytes()),
+                "big endian"
+            )
+        };
+
+        // The getters of all readable entries, which decode the bit field first.
+        let getters: Vec<_> = self.data.entries().into_iter().zip(self.accessors()).filter(
+            |(entry, _)| entry.is_readable()
+        ).map(|(entry, accessors)| {
+            let attrs = &entry.attrs;
+            let vis = &entry.vis;
             let ty = &entry.ty;
+            let getter = &accessors.getter;
+
+            let getter_type = match &entry.field {
+                Some(field) => Self::generate_field_getter_type(entry, field),
+
+                None => {
+                    let getter_all = super::Accessors::suffixed(getter, "all");
+                    let getter_any = super::Accessors::suffixed(getter, "any");
+
+                    return quote::quote_spanned! { span =>
+                        #(#attrs)*
+                        /// Returns `true` if the specified `flag` is set.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        #vis fn #getter(&self, flag: #ty) -> bool {
+                            self.read().#getter(flag)
+                        }
+
+                        #(#attrs)*
+                        /// Returns `true` if all flags are set.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        #vis fn #getter_all(&self) -> bool {
+                            self.read().#getter_all()
+                        }
+
+                        #(#attrs)*
+                        /// Returns `true` if any flag is set.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        #vis fn #getter_any(&self) -> bool {
+                            self.read().#getter_any()
+                        }
+                    };
+                }
+            };
+
+            quote::quote_spanned! { span =>
+                #(#attrs)*
+                /// Gets the value of the field.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn #getter(&self) -> #getter_type {
+                    self.read().#getter()
+                }
+            }
+        }).collect();
 
-            let implementation = if entry.field.is_some()
-            {
-                // Display the field value.
-                super::BitField::generate_print_field(entry, getter, quote::quote! {
-                    f.write_str(&alloc::format!("{:?}", value))
-                }, span)
-            } else {
-                // Display all set flags joined with `" | "`, or "-" if no flag is set at all.
-                quote::quote_spanned! { span =>
-                    let mut flags = alloc::vec::Vec::new();
+        let shortcuts = self.generate_shortcuts(
+            span, quote::quote!(&mut self), quote::quote!(self.read()), quote::quote!(bitfield), "."
+        );
 
-                    for flag in <#ty>::iter() {
-                        if self.#getter(*flag) {
-                            flags.push(alloc::format!("{:?}", flag));
-                        }
+        let doc = format!(
+            " A view of a [`{}`] over the first bytes of a byte slice, in {} byte order.",
+            ident.unraw(), doc_byte_order
+        );
+        let doc_mut = format!(
+            " A mutable view of a [`{}`] over the first bytes of a byte slice, in {} byte order.",
+            ident.unraw(), doc_byte_order
+        );
+
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let (view_generics, lifetime) = self.generics_with_lifetime();
+        let (view_impl_generics, view_ty_generics, view_where_clause) = view_generics.split_for_impl();
+        let marker = self.generate_marker().map(|marker| quote::quote!(, #marker));
+        let read = self.generate_new(ident, &from_bytes);
+
+        // `#[derive(Clone, Copy)]` would require the type parameters to implement them as well.
+        let (derive, clone, from_array) = match marker {
+            None => (quote::quote!(#[derive(Clone, Copy)]), None, quote::quote!(Self)),
+            Some(_) => (quote::quote!(), Some(quote::quote! {
+                impl #view_impl_generics ::core::clone::Clone for #view #view_ty_generics #view_where_clause {
+                    #[inline(always)]
+                    fn clone(&self) -> Self {
+                        *self
                     }
+                }
 
-                    let flags = flags.join(" | ");
+                impl #view_impl_generics ::core::marker::Copy for #view #view_ty_generics #view_where_clause {}
+            }), quote::quote!(|bytes| Self(bytes, ::core::marker::PhantomData)))
+        };
+
+        quote::quote_spanned! { span =>
+            #[doc = #doc]
+            #derive
+            #vis struct #view #view_generics (&#lifetime [u8; #size] #marker) #view_where_clause;
+
+            #clone
 
-                    f.write_str(if flags.len() > 0 { &flags } else { "-" })
+            impl #view_impl_generics #view #view_ty_generics #view_where_clause {
+                /// Creates a view over the first bytes of `bytes`, or returns `None` if `bytes` is too
+                /// short.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn new(bytes: &#lifetime [u8]) -> ::core::option::Option<Self> {
+                    match bytes.get(..#size) {
+                        Some(bytes) => ::core::convert::TryInto::try_into(bytes).ok().map(#from_array),
+                        None => None
+                    }
                 }
-            };
 
-            quote::quote_spanned! { span =>
-                impl ::core::fmt::Display for #ident {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        #implementation
+                /// Creates a view over the bytes of `bytes` starting at `offset`, or returns `None`
+                /// if `bytes` is too short.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn new_at(bytes: &#lifetime [u8], offset: usize) -> ::core::option::Option<Self> {
+                    match bytes.get(offset..) {
+                        Some(bytes) => Self::new(bytes),
+                        None => None
                     }
                 }
+
+                /// Decodes the bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn read(&self) -> #ident #ty_generics {
+                    #read
+                }
+
+                /// Decodes the bit field and drops the view.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn into_owned(self) -> #ident #ty_generics {
+                    self.read()
+                }
+
+                #(#getters)*
             }
-        }
 
-        if self.display.is_none() { return proc_macro2::TokenStream::new(); }
+            #[doc = #doc_mut]
+            #vis struct #view_mut #view_generics (&#lifetime mut [u8; #size] #marker) #view_where_clause;
 
-        let ident = &self.ident;
+            impl #view_impl_generics #view_mut #view_ty_generics #view_where_clause {
+                /// Creates a mutable view over the first bytes of `bytes`, or returns `None` if
+                /// `bytes` is too short.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn new(bytes: &#lifetime mut [u8]) -> ::core::option::Option<Self> {
+                    match bytes.get_mut(..#size) {
+                        Some(bytes) => ::core::convert::TryInto::try_into(bytes).ok().map(#from_array),
+                        None => None
+                    }
+                }
 
-        match &self.data {
-            super::Data::Named(entries) => {
-                if entries.len() == 0 {
-                    // Do not generate `Display` for bit fields with no fields or flags at all.
-                    // Should have been checked in `parse::validate_display`.
-                    panic!("can not generate `Display` for empty bit fields");
-                } else if entries.len() == 1 {
-                    let first = entries.first().unwrap();
-                    generate_display_for_entry(ident, &first.ident, &first.entry, first.ident.span())
-                } else {
-                    // Do not generate `Display` for bit fields with non-flags.
-                    // Should have been checked in `parse::validate_display`.
-                    for entry in entries {
-                        if entry.entry.field.is_some() {
-                            panic!("can not generate `Display` for bit fields with non-flag fields");
-                        }
+                /// Creates a mutable view over the bytes of `bytes` starting at `offset`, or returns
+                /// `None` if `bytes` is too short.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn new_at(bytes: &#lifetime mut [u8], offset: usize) -> ::core::option::Option<Self> {
+                    match bytes.get_mut(offset..) {
+                        Some(bytes) => Self::new(bytes),
+                        None => None
                     }
+                }
 
-                    let iterators = entries.iter().map(|c| {
-                        let ty = &c.entry.ty;
-                        let ident = &c.ident;
-                        let span = c.ident.span();
+                /// Decodes the bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn read(&self) -> #ident #ty_generics {
+                    #read
+                }
 
-                        let format_data = if entries.len() > 1 {
-                            quote::quote! { "{}::{:?}", ::core::any::type_name::<#ty>(), flag }
-                        } else {
-                            quote::quote! { "{:?}", flag }
-                        };
+                /// Decodes the bit field and drops the view.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn into_owned(self) -> #ident #ty_generics {
+                    self.read()
+                }
 
-                        quote::quote_spanned! { span =>
-                            for flag in <#ty>::iter() {
-                                if self.#ident(*flag) {
-                                    flags.push(alloc::format!(#format_data));
-                                }
-                            }
-                        }
-                    });
+                /// Encodes the bit field into the bytes.
+                #[allow(unused)]
+                #[inline(always)]
+                #vis fn write(&mut self, value: #ident #ty_generics) {
+                    *self.0 = #to_bytes;
+                }
 
-                    quote::quote! {
-                        impl ::core::fmt::Display for #ident {
-                            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                                let mut flags = alloc::vec::Vec::new();
+                #(#getters)*
+                #(#shortcuts)*
+            }
+        }
+    }
 
-                                #(#iterators)*
+    /// Generates the `C_HEADER` constant if `c_header` was specified.
+    fn generate_c_header(&self) -> proc_macro2::TokenStream {
+        let span = match &self.attr.c_header {
+            Some(c_header) => c_header.span(),
+            None => return proc_macro2::TokenStream::new()
+        };
 
-                                let flags = flags.join(" | ");
+        let vis = &self.vis;
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
-                                f.write_str(
-                                    if flags.len() > 0 { &flags } else { "-" }
-                                )
-                            }
-                        }
-                    }
-                }
-            },
+        let header = match crate::c_header::bitfield(self) {
+            Ok(header) => header,
+            Err(error) => return error.to_compile_error()
+        };
 
-            super::Data::Tuple(entry) => {
-                let getter = syn::Ident::new(
-                    if entry.field.is_some() { "get" } else { "has" },
-                    entry.ty.span()
-                );
-                generate_display_for_entry(ident, &getter, entry, entry.ty.span())
+        quote::quote_spanned! { span =>
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Describes the layout of the bit field in C, with `#define`s for the shift and
+                /// mask of each field and `static inline` accessors.
+                #[allow(unused)]
+                #vis const C_HEADER: &'static str = #header;
             }
         }
     }
@@ -1245,14 +3617,16 @@ impl super::BitField {
 
         let vis = &self.vis;
         let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let new = self.generate_new(quote::quote!(Self), quote::quote!(0));
 
         quote::quote! {
-            impl #ident {
+            impl #impl_generics #ident #ty_generics #where_clause {
                 /// Creates a new instance with all flags and fields cleared.
                 #[allow(unused)]
                 #[inline(always)]
                 #vis const fn new() -> Self {
-                    Self(0)
+                    #new
                 }
             }
         }
@@ -1263,14 +3637,62 @@ impl super::BitField {
         let attrs = &self.attrs;
         let vis = &self.vis;
         let ident = &self.ident;
+        let generics = &self.generics;
+        let where_clause = &self.generics.where_clause;
         let base_type = &self.attr.base_type;
+        let marker = self.generate_marker().map(|marker| quote::quote!(, #marker));
 
         quote::quote! {
             #[repr(transparent)]
             #(#attrs)*
-            #vis struct #ident(#base_type);
+            #vis struct #ident #generics (#base_type #marker) #where_clause;
+        }
+    }
+
+    /// Generates the zero-sized type which uses the lifetime and type parameters of a generic bit
+    /// field, or `None` if there are none. The type parameters are only returned by `fn() -> T`, so
+    /// they do not affect auto traits.
+    fn generate_marker(&self) -> Option<proc_macro2::TokenStream> {
+        let lifetimes: Vec<_> = self.generics.lifetimes().map(|param| &param.lifetime).collect();
+        let types: Vec<_> = self.generics.type_params().map(|param| &param.ident).collect();
+
+        (!lifetimes.is_empty() || !types.is_empty()).then(|| quote::quote! {
+            ::core::marker::PhantomData<(#(&#lifetimes (),)* #(fn() -> #types,)*)>
+        })
+    }
+
+    /// Generates the construction of a bit field or one of its generated types, e.g. `Self`, from
+    /// the stored `value`, including the marker of generic bit fields.
+    fn generate_new(&self, constructor: impl quote::ToTokens, value: impl quote::ToTokens) -> proc_macro2::TokenStream {
+        match self.generate_marker() {
+            Some(_) => quote::quote!(#constructor(#value, ::core::marker::PhantomData)),
+            None => quote::quote!(#constructor(#value))
         }
     }
+
+    /// Generates the construction of a `NonZero` bit field from `result`, which is `None` for `0`.
+    fn generate_new_non_zero(&self) -> proc_macro2::TokenStream {
+        let base_type = &self.attr.base_type;
+        let new = self.generate_new(quote::quote!(Self), quote::quote!(result));
+
+        quote::quote!(match #base_type::new(result) { Some(result) => Some(#new), None => None })
+    }
+
+    /// Returns the generics of the bit field with an additional lifetime in front, which is used by
+    /// types that borrow the bit field or bytes. The lifetime is `'a`, unless the bit field already
+    /// declares it.
+    fn generics_with_lifetime(&self) -> (syn::Generics, syn::Lifetime) {
+        let name = match self.generics.lifetimes().any(|param| param.lifetime.ident == "a") {
+            false => "'a",
+            true => "'bitfield"
+        };
+
+        let lifetime = syn::Lifetime::new(name, proc_macro2::Span::call_site());
+        let mut generics = self.generics.clone();
+        generics.params.insert(0, syn::GenericParam::Lifetime(syn::LifetimeDef::new(lifetime.clone())));
+
+        (generics, lifetime)
+    }
 }
 
 /// Generates the user code for the parsed bit field.
@@ -1280,20 +3702,42 @@ impl ::core::convert::Into<proc_macro2::TokenStream> for super::BitField {
         let implementation = self.generate_impl();
         let accessors_low = self.generate_accessors_low();
         let accessors = self.generate_accessors();
+        let accessors_in_place = self.generate_accessors_in_place();
         let accessors_ops = self.generate_accessors_ops();
+        let predicates = self.generate_predicates();
+        let keys = self.generate_keys();
+        let parts = self.generate_parts();
+        let builder = self.generate_builder();
         let assertions = self.generate_assertions();
         let debug = self.generate_debug();
         let display = self.generate_display();
+        let from_str = self.generate_from_str();
+        let radix = self.generate_radix();
+        let register = self.generate_register();
+        let atomic = self.generate_atomic();
+        let view = self.generate_view();
+        let c_header = self.generate_c_header();
 
         quote::quote! {
             #field
             #implementation
             #accessors_low
             #accessors
+            #accessors_in_place
             #accessors_ops
+            #predicates
+            #keys
+            #parts
+            #builder
             #assertions
             #debug
             #display
+            #from_str
+            #radix
+            #register
+            #atomic
+            #view
+            #c_header
         }
     }
 }
@@ -1308,12 +3752,15 @@ mod tests {
             let bitfield = parse_valid!($attribute, $item);
 
             if let super::super::Data::Tuple(entry) = &bitfield.data {
-                let getter = syn::Ident::new("test_get", entry.ty.span());
-                let setter = syn::Ident::new("test_set", entry.ty.span());
-                let inverter = syn::Ident::new("test_invert", entry.ty.span());
+                let accessors = super::super::Accessors {
+                    name: None,
+                    getter: syn::Ident::new("test_get", entry.ty.span()),
+                    setter: syn::Ident::new("test_set", entry.ty.span()),
+                    inverter: syn::Ident::new("test_invert", entry.ty.span())
+                };
 
                 assert_eq!(
-                    bitfield.generate_accessor(&entry, &getter, &setter, &inverter, entry.ty.span(), $is_only_entry).to_string(),
+                    bitfield.generate_accessor(&entry, &accessors, entry.ty.span(), $is_only_entry).to_string(),
                     $result.to_string()
                 );
             } else { panic!("expected tuple struct") }
@@ -1329,6 +3776,36 @@ mod tests {
         }};
     }
 
+    /// Generates the match arms of the assertion for the flags of entry `$index` overlapping with
+    /// another entry, which has one arm per bit the flags can overlap in, and the fallback message.
+    macro_rules! overlap_arms {
+        ($attribute:expr, $item:expr, $index:expr, $entry:expr, flags $other:expr) => {{
+            let bitfield = parse_valid!($attribute, $item);
+            let bits = 0..bitfield.attr.bits.unwrap();
+            overlap_arms!(bitfield, $index, $entry, $other, "flags in field", bits, |bit| (bit, 1))
+        }};
+        ($attribute:expr, $item:expr, $index:expr, $entry:expr, field $other:expr, $bit:expr, $size:expr) => {{
+            let bitfield = parse_valid!($attribute, $item);
+            overlap_arms!(bitfield, $index, $entry, $other, "field", $bit..$bit + $size, |_| ($bit, $size))
+        }};
+        ($bitfield:ident, $index:expr, $entry:expr, $other:expr, $kind:expr, $bits:expr, $range:expr) => {{
+            let message = format!("Flags in field \"{}\" overlap with {} \"{}\"", $entry, $kind, $other);
+            let entry = format!("\"{}\"", $entry);
+            let other = format!("\"{}\"", $other);
+
+            let arms = $bits.map(|bit: u8| {
+                let (other_bit, other_size) = $range(bit);
+                let explanation = $bitfield.explain_overlap($index, (&entry, bit, 1), (&other, other_bit, other_size));
+                let message = format!("{}\n{}", message, explanation);
+                let bit = proc_macro2::Literal::u8_unsuffixed(bit);
+
+                quote::quote! { #bit => panic!(#message), }
+            }).collect::<Vec<_>>();
+
+            (quote::quote! { #(#arms)* }, message)
+        }};
+    }
+
     // Test macros.
 
     #[test]
@@ -1578,22 +4055,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn test_get(&self) -> ::core::result::Result<A, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
+                const fn test_get(&self) -> ::core::result::Result<A, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match A::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-
-                    A::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 #[some_attribute1]
@@ -1615,22 +4082,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn test_get(&self) -> ::core::result::Result<A, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
+                const fn test_get(&self) -> ::core::result::Result<A, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match A::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-
-                    A::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 #[some_attribute1]
@@ -1727,22 +4184,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                pub fn test_get(&self) -> ::core::result::Result<A, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
+                pub const fn test_get(&self) -> ::core::result::Result<A, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match A::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-
-                    A::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -1900,22 +4347,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn test_get(&self) -> ::core::result::Result<B, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
+                const fn test_get(&self) -> ::core::result::Result<B, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match B::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
-                    }
-
-                    B::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -1933,22 +4370,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn test_get(&self) -> ::core::result::Result<B, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
+                const fn test_get(&self) -> ::core::result::Result<B, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match B::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-
-                    B::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -2106,22 +4533,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn test_get(&self) -> ::core::result::Result<A, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
+                const fn test_get(&self) -> ::core::result::Result<A, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match A::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-
-                    A::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -2139,22 +4556,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn test_get(&self) -> ::core::result::Result<A, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
+                const fn test_get(&self) -> ::core::result::Result<A, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match A::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-
-                    A::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -2173,22 +4580,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn test_get(&self) -> ::core::result::Result<A, u16> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i16) -> ::core::result::Result<Self, i16>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u16) -> ::core::result::Result<Self, u16>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i16, Error = i16> {
-                        fn _bitfield_extract_primitive(value: i16) -> ::core::result::Result<Self, i16> {
-                            <T as ::core::convert::TryFrom<i16>>::try_from(value)
-                        }
+                const fn test_get(&self) -> ::core::result::Result<A, u16> {
+                    let value = self._field(1u8, 9u8);
+                    match A::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u16)
                     }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u16, Error = u16> {
-                        fn _bitfield_extract_primitive(value: u16) -> ::core::result::Result<Self, u16> {
-                            <T as ::core::convert::TryFrom<u16>>::try_from(value)
-                        }
-                    }
-
-                    A::_bitfield_extract_primitive(self._field(1u8, 9u8) as _).map_err(|e| e as u16)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -2206,22 +4603,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn test_get(&self) -> ::core::result::Result<A, u16> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i16) -> ::core::result::Result<Self, i16>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u16) -> ::core::result::Result<Self, u16>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i16, Error = i16> {
-                        fn _bitfield_extract_primitive(value: i16) -> ::core::result::Result<Self, i16> {
-                            <T as ::core::convert::TryFrom<i16>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u16, Error = u16> {
-                        fn _bitfield_extract_primitive(value: u16) -> ::core::result::Result<Self, u16> {
-                            <T as ::core::convert::TryFrom<u16>>::try_from(value)
-                        }
+                const fn test_get(&self) -> ::core::result::Result<A, u16> {
+                    let value = self._field(1u8, 9u8);
+                    match A::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u16)
                     }
-
-                    A::_bitfield_extract_primitive(self._field(1u8, 9u8) as _).map_err(|e| e as u16)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -2368,22 +4755,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn test_get(&self) -> ::core::result::Result<A, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
+                const fn test_get(&self) -> ::core::result::Result<A, u8> {
+                    let value = self._field(0u8, 8u8);
+                    match A::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-
-                    A::_bitfield_extract_primitive(self._field(0u8, 8u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -2401,23 +4778,9 @@ mod tests {
                 /// Gets the value of the field.
                 #[allow(unused)]
                 #[inline(always)]
-                fn test_get(&self) -> A {
+                const fn test_get(&self) -> A {
                     unsafe {
-                        #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                        #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                        impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                            fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                                <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                            }
-                        }
-                        impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                            fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                                <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                            }
-                        }
-
-                        A::_bitfield_extract_primitive(self._field(0u8, 8u8) as _).unwrap_unchecked()
+                        A::from_repr(self._field(0u8, 8u8) as _).unwrap_unchecked()
                     }
                 }
 
@@ -2432,6 +4795,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn accessor_optional() {
+        assert_accessor!(
+            "32", "struct A(#[field(0, 2)] Option<A>);", true, quote::quote! {
+                /// Gets the value of the field.
+                #[doc = "Returns `None` if all bits of the field are cleared, and the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
+                #[allow(unused)]
+                #[inline(always)]
+                const fn test_get(&self) -> ::core::result::Result<Option<A>, u8> {
+                    let value = self._field(0u8, 2u8);
+                    match A::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(::core::option::Option::Some(variant)),
+                        ::core::option::Option::None if value == 0 => ::core::result::Result::Ok(::core::option::Option::None),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
+                    }
+                }
+
+                /// Creates a copy of the bit field with the new value.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn test_set(&self, value: Option<A>) -> Self {
+                    self._set_field(0u8, 2u8, match value {
+                        ::core::option::Option::Some(value) => value as u8,
+                        ::core::option::Option::None => 0
+                    } as _)
+                }
+            }
+        );
+
+        assert_accessor!(
+            "32", "struct A(#[field(0, 4)] Option<NonZeroU8>);", true, quote::quote! {
+                /// Gets the value of the field, `None` if all bits of the field are cleared.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn test_get(&self) -> Option<NonZeroU8> {
+                    NonZeroU8::new(self._field(0u8, 4u8) as _)
+                }
+
+                /// Creates a copy of the bit field with the new value, `None` clears all
+                /// bits of the field.
+                ///
+                /// Returns `None` if `value` is bigger than the specified amount of
+                /// bits the field can store.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn test_set(&self, value: Option<NonZeroU8>) -> ::core::option::Option<Self> {
+                    let raw = match value {
+                        ::core::option::Option::Some(value) => value.get(),
+                        ::core::option::Option::None => 0
+                    };
+                    if raw >= (1 as u8).wrapping_shl(4u8 as u32) {
+                        return None;
+                    }
+
+                    Some(self._set_field(0u8, 4u8, raw as _))
+                }
+            }
+        );
+    }
+
     #[test]
     fn accessor_signed() {
         assert_accessor!(
@@ -2908,22 +5333,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn get(&self) -> ::core::result::Result<B, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
+                const fn get(&self) -> ::core::result::Result<B, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match B::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
-                    }
-
-                    B::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -2941,22 +5356,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn get(&self) -> ::core::result::Result<B, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
+                const fn get(&self) -> ::core::result::Result<B, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match B::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-
-                    B::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -3118,22 +5523,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn b(&self) -> ::core::result::Result<B, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
+                const fn b(&self) -> ::core::result::Result<B, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match B::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-
-                    B::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -3151,22 +5546,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn b(&self) -> ::core::result::Result<B, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
+                const fn b(&self) -> ::core::result::Result<B, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match B::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-
-                    B::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -3256,22 +5641,12 @@ mod tests {
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
                 #[inline(always)]
-                fn c(&self) -> ::core::result::Result<C, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
+                const fn c(&self) -> ::core::result::Result<C, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match C::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
-                    }
-
-                    C::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -3362,23 +5737,13 @@ mod tests {
                 /// Gets the value of the field.
                 #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                 #[allow(unused)]
-                #[inline(always)]
-                fn c(&self) -> ::core::result::Result<C, u8> {
-                    #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                    #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                    impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                        fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                            <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                        }
-                    }
-                    impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                        fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                            <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                        }
+                #[inline(always)]
+                const fn c(&self) -> ::core::result::Result<C, u8> {
+                    let value = self._field(0u8, 1u8);
+                    match C::from_repr(value as _) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                     }
-
-                    C::_bitfield_extract_primitive(self._field(0u8, 1u8) as _).map_err(|e| e as u8)
                 }
 
                 /// Creates a copy of the bit field with the new value.
@@ -3598,7 +5963,7 @@ mod tests {
         });
         assert_compare!(generate_accessors_ops, "NonZero8", "struct A {#[field(0, 1)] b: B, #[field(1, 1)] c: C}", quote::quote! {});
 
-        assert_compare!(generate_accessors_ops, "8", "struct A {#[field(0, 1)] b: B, #[field(1, 1)] b: B, #[field(2, 1)] c: C}", quote::quote! {
+        assert_compare!(generate_accessors_ops, "8", "struct A {#[field(0, 1)] b: B, #[field(1, 1)] d: B, #[field(2, 1)] c: C}", quote::quote! {
             impl ::core::ops::Add<C> for A {
                 type Output = Self;
 
@@ -3614,7 +5979,7 @@ mod tests {
                 }
             }
         });
-        assert_compare!(generate_accessors_ops, "NonZero8", "struct A {#[field(0, 1)] b: B, #[field(1, 1)] b: B, #[field(2, 1)] c: C}", quote::quote! {});
+        assert_compare!(generate_accessors_ops, "NonZero8", "struct A {#[field(0, 1)] b: B, #[field(1, 1)] d: B, #[field(2, 1)] c: C}", quote::quote! {});
 
         assert_compare!(generate_accessors_ops, "8", "struct A {}", quote::quote! {});
 
@@ -3729,46 +6094,580 @@ mod tests {
                 }
             }
 
-            impl ::core::ops::AddAssign<C> for A {
-                #[inline(always)]
-                fn add_assign(&mut self, flag: C) {
-                    self.0 = self.set_c(flag, true).0;
+            impl ::core::ops::AddAssign<C> for A {
+                #[inline(always)]
+                fn add_assign(&mut self, flag: C) {
+                    self.0 = self.set_c(flag, true).0;
+                }
+            }
+
+            impl ::core::ops::BitXor<C> for A {
+                type Output = Self;
+
+                #[inline(always)]
+                fn bitxor(self, flag: C) -> Self::Output {
+                    self.invert_c(flag)
+                }
+            }
+
+            impl ::core::ops::BitXorAssign<C> for A {
+                #[inline(always)]
+                fn bitxor_assign(&mut self, flag: C) {
+                    self.0 = self.invert_c(flag).0;
+                }
+            }
+
+            impl ::core::ops::Sub<C> for A {
+                type Output = Self;
+
+                #[inline(always)]
+                fn sub(self, flag: C) -> Self::Output {
+                    self.set_c(flag, false)
+                }
+            }
+
+            impl ::core::ops::SubAssign<C> for A {
+                #[inline(always)]
+                fn sub_assign(&mut self, flag: C) {
+                    self.0 = self.set_c(flag, false).0;
+                }
+            }
+        });
+        assert_compare!(generate_accessors_ops, "NonZero8", "struct A { b: B, c: C }", quote::quote! {});
+    }
+
+    #[test]
+    fn predicates() {
+        assert_compare!(generate_predicates, "8", "struct A(#[field(size = 2)] B);", quote::quote!());
+
+        assert_compare!(
+            generate_predicates, "16",
+            "struct A<T> { #[field(size = 2, groups(x, r#y))] b: B, #[field(size = 2, complete, groups(x))] pub c: C, #[cfg(z)] #[field(size = 2, groups(x))] d: Option<D> }",
+            quote::quote! {
+                impl<T> A<T> {
+                    #[doc = " Returns `true` if the field is a variant of the group `x`."]
+                    #[allow(unused)]
+                    #[inline(always)]
+                    const fn b_is_x(&self) -> bool {
+                        match self.b() {
+                            ::core::result::Result::Ok(value) => value.is_x(),
+                            _ => false
+                        }
+                    }
+
+                    #[doc = " Returns `true` if the field is a variant of the group `y`."]
+                    #[allow(unused)]
+                    #[inline(always)]
+                    const fn b_is_y(&self) -> bool {
+                        match self.b() {
+                            ::core::result::Result::Ok(value) => value.is_y(),
+                            _ => false
+                        }
+                    }
+
+                    #[doc = " Returns `true` if the field is a variant of the group `x`."]
+                    #[allow(unused)]
+                    #[inline(always)]
+                    pub const fn c_is_x(&self) -> bool {
+                        self.c().is_x()
+                    }
+
+                    #[cfg(z)]
+                    #[doc = " Returns `true` if the field is a variant of the group `x`."]
+                    #[allow(unused)]
+                    #[inline(always)]
+                    const fn d_is_x(&self) -> bool {
+                        match self.d() {
+                            ::core::result::Result::Ok(::core::option::Option::Some(value)) => value.is_x(),
+                            _ => false
+                        }
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_predicates, "8", "struct A(#[field(size = 2, complete, groups(x))] Option<B>);",
+            quote::quote! {
+                impl A {
+                    #[doc = " Returns `true` if the field is a variant of the group `x`."]
+                    #[allow(unused)]
+                    #[inline(always)]
+                    const fn is_x(&self) -> bool {
+                        match self.get() {
+                            ::core::option::Option::Some(value) => value.is_x(),
+                            _ => false
+                        }
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn keys() {
+        assert_compare!(generate_keys, "8", "struct A { b: B }", quote::quote!());
+
+        assert_compare!(
+            generate_keys, "8, keys", "struct A { #[field(size = 2)] b: B, #[field(size = 3)] c: u8, #[field(access = ro)] d: bool }",
+            quote::quote! {
+                #[doc = " A readable field of `A`, which is used with `A::get`."]
+                trait AKey {
+                    /// The return type of the getter of the field.
+                    type Output;
+
+                    /// Gets the value of the field.
+                    fn get(bitfield: &A) -> Self::Output;
+                }
+
+                #[doc = " A writable field of `A`, which is used with `A::with`."]
+                trait AKeyMut {
+                    /// The type of the field.
+                    type Value;
+
+                    /// The return type of the setter of the field, `Option` if the value can exceed the
+                    /// field.
+                    type Modified;
+
+                    /// Creates a copy of the bit field with the new value.
+                    fn with(bitfield: &A, value: Self::Value) -> Self::Modified;
+                }
+
+                impl A {
+                    /// Gets the value of the field `K`.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    fn get<K: AKey>(&self) -> K::Output {
+                        K::get(self)
+                    }
+
+                    /// Creates a copy of the bit field with the new value of the field `K`.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                    fn with<K: AKeyMut>(&self, value: K::Value) -> K::Modified {
+                        K::with(self, value)
+                    }
+                }
+
+                #[doc = " The key of the field `b`, which wraps a value of the field."]
+                #[derive(Clone, Copy)]
+                struct AB(B);
+
+                impl AKey for AB {
+                    type Output = ::core::result::Result<B, u8>;
+
+                    #[inline(always)]
+                    fn get(bitfield: &A) -> Self::Output {
+                        bitfield.b()
+                    }
+                }
+
+                impl AKeyMut for AB {
+                    type Value = B;
+                    type Modified = A;
+
+                    #[inline(always)]
+                    fn with(bitfield: &A, value: Self::Value) -> Self::Modified {
+                        bitfield.set_b(value)
+                    }
+                }
+
+                impl ::core::ops::Add<AB> for A {
+                    type Output = Self;
+
+                    #[inline(always)]
+                    fn add(self, key: AB) -> Self::Output {
+                        self.set_b(key.0)
+                    }
+                }
+
+                impl ::core::ops::AddAssign<AB> for A {
+                    #[inline(always)]
+                    fn add_assign(&mut self, key: AB) {
+                        self.0 = self.set_b(key.0).0;
+                    }
+                }
+
+                #[doc = " The key of the field `c`."]
+                #[derive(Clone, Copy)]
+                struct AC;
+
+                impl AKey for AC {
+                    type Output = u8;
+
+                    #[inline(always)]
+                    fn get(bitfield: &A) -> Self::Output {
+                        bitfield.c()
+                    }
+                }
+
+                impl AKeyMut for AC {
+                    type Value = u8;
+                    type Modified = ::core::option::Option<A>;
+
+                    #[inline(always)]
+                    fn with(bitfield: &A, value: Self::Value) -> Self::Modified {
+                        bitfield.set_c(value)
+                    }
+                }
+
+                #[doc = " The key of the field `d`."]
+                #[derive(Clone, Copy)]
+                struct AD;
+
+                impl AKey for AD {
+                    type Output = bool;
+
+                    #[inline(always)]
+                    fn get(bitfield: &A) -> Self::Output {
+                        bitfield.d()
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_keys, "8, keys", "pub struct A<T> { #[cfg(x)] #[field(size = 2, complete, access = wo)] pub r#type_a: B }",
+            quote::quote! {
+                #[doc = " A readable field of `A`, which is used with `A::get`."]
+                pub trait AKey<T> {
+                    /// The return type of the getter of the field.
+                    type Output;
+
+                    /// Gets the value of the field.
+                    fn get(bitfield: &A<T>) -> Self::Output;
+                }
+
+                #[doc = " A writable field of `A`, which is used with `A::with`."]
+                pub trait AKeyMut<T> {
+                    /// The type of the field.
+                    type Value;
+
+                    /// The return type of the setter of the field, `Option` if the value can exceed the
+                    /// field.
+                    type Modified;
+
+                    /// Creates a copy of the bit field with the new value.
+                    fn with(bitfield: &A<T>, value: Self::Value) -> Self::Modified;
+                }
+
+                impl<T> A<T> {
+                    /// Gets the value of the field `K`.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    pub fn get<K: AKey<T> >(&self) -> K::Output {
+                        K::get(self)
+                    }
+
+                    /// Creates a copy of the bit field with the new value of the field `K`.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                    pub fn with<K: AKeyMut<T> >(&self, value: K::Value) -> K::Modified {
+                        K::with(self, value)
+                    }
+                }
+
+                #[cfg(x)]
+                #[doc = " The key of the field `type_a`, which wraps a value of the field."]
+                #[derive(Clone, Copy)]
+                pub struct ATypeA(pub B);
+
+                #[cfg(x)]
+                impl<T> AKeyMut<T> for ATypeA {
+                    type Value = B;
+                    type Modified = A<T>;
+
+                    #[inline(always)]
+                    fn with(bitfield: &A<T>, value: Self::Value) -> Self::Modified {
+                        bitfield.set_type_a(value)
+                    }
+                }
+
+                #[cfg(x)]
+                impl<T> ::core::ops::Add<ATypeA> for A<T> {
+                    type Output = Self;
+
+                    #[inline(always)]
+                    fn add(self, key: ATypeA) -> Self::Output {
+                        self.set_type_a(key.0)
+                    }
+                }
+
+                #[cfg(x)]
+                impl<T> ::core::ops::AddAssign<ATypeA> for A<T> {
+                    #[inline(always)]
+                    fn add_assign(&mut self, key: ATypeA) {
+                        self.0 = self.set_type_a(key.0).0;
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn parts() {
+        assert_compare!(generate_parts, "8", "struct A { b: B }", quote::quote!());
+
+        assert_compare!(
+            generate_parts, "16, parts",
+            "#[derive(Debug)] struct A { #[field(size = 2)] b: B, #[field(size = 3)] c: u8, #[field(access = ro)] d: bool, pub e: E }",
+            quote::quote! {
+                #[doc = " The readable entries of `A`, see `A::to_parts` and `A::from_parts`."]
+                #[derive(Debug)]
+                #[derive(Clone, Copy)]
+                struct AParts {
+                    b: ::core::result::Result<B, u8>,
+                    c: u8,
+                    d: bool,
+                    pub e: [bool; E::iter().len()]
+                }
+
+                impl A {
+                    /// Returns the values of all readable entries.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    const fn to_parts(&self) -> AParts {
+                        AParts {
+                            b: self.b(),
+                            c: self.c(),
+                            d: self.d(),
+                            e: {
+                                let mut flags = [false; E::iter().len()];
+                                let mut i = 0;
+                                while i < flags.len() {
+                                    flags[i] = self.e(E::iter()[i]);
+                                    i += 1;
+                                }
+
+                                flags
+                            }
+                        }
+                    }
+
+                    /// Creates a bit field from the values of all writable entries, the others stay `0`.
+                    ///
+                    /// Returns `None` if a value is bigger than the specified amount of bits its
+                    /// field can store.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    const fn from_parts(parts: AParts) -> ::core::option::Option<Self> {
+                        let bitfield = Self::new();
+                        let bitfield = match parts.b {
+                            ::core::result::Result::Ok(value) => bitfield.set_b(value),
+                            ::core::result::Result::Err(value) => {
+                                if value >= (1 as u8).wrapping_shl(2u8 as u32) {
+                                    return ::core::option::Option::None;
+                                }
+
+                                bitfield._set_field(0u8, 2u8, value as _)
+                            }
+                        };
+                        let bitfield = match bitfield.set_c(parts.c) {
+                            ::core::option::Option::Some(bitfield) => bitfield,
+                            ::core::option::Option::None => return ::core::option::Option::None
+                        };
+                        let bitfield = {
+                            let mut bitfield = bitfield;
+                            let mut i = 0;
+                            while i < E::iter().len() {
+                                bitfield = bitfield.set_e(E::iter()[i], parts.e[i]);
+                                i += 1;
+                            }
+
+                            bitfield
+                        };
+                        ::core::option::Option::Some(bitfield)
+                    }
+                }
+
+                impl ::core::convert::From<A> for AParts {
+                    #[inline(always)]
+                    fn from(bitfield: A) -> Self {
+                        bitfield.to_parts()
+                    }
+                }
+
+                impl ::core::convert::TryFrom<AParts> for A {
+                    type Error = AParts;
+
+                    #[inline(always)]
+                    fn try_from(parts: AParts) -> ::core::result::Result<Self, Self::Error> {
+                        match Self::from_parts(parts) {
+                            ::core::option::Option::Some(bitfield) => ::core::result::Result::Ok(bitfield),
+                            ::core::option::Option::None => ::core::result::Result::Err(parts)
+                        }
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_parts, "16, parts", "pub struct A<T> { #[cfg(x)] #[field(size = 8)] b: B, #[field(size = 8)] pub c: u8 }",
+            quote::quote! {
+                #[doc = " The readable entries of `A`, see `A::to_parts` and `A::from_parts`."]
+                #[derive(Clone, Copy)]
+                pub struct AParts {
+                    #[cfg(x)] b: ::core::result::Result<B, u8>,
+                    pub c: u8
+                }
+
+                impl<T> A<T> {
+                    /// Returns the values of all readable entries.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    pub const fn to_parts(&self) -> AParts {
+                        AParts {
+                            #[cfg(x)] b: self.b(),
+                            c: self.c()
+                        }
+                    }
+
+                    /// Creates a bit field from the values of all writable entries, the others stay `0`.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    pub const fn from_parts(parts: AParts) -> Self {
+                        let bitfield = Self::new();
+                        #[cfg(x)]
+                        let bitfield = match parts.b {
+                            ::core::result::Result::Ok(value) => bitfield.set_b(value),
+                            ::core::result::Result::Err(value) => {
+                                bitfield._set_field(0u8, 8u8, value as _)
+                            }
+                        };
+                        let bitfield = bitfield.set_c(parts.c);
+                        bitfield
+                    }
+                }
+
+                impl<T> ::core::convert::From<A<T> > for AParts {
+                    #[inline(always)]
+                    fn from(bitfield: A<T>) -> Self {
+                        bitfield.to_parts()
+                    }
+                }
+
+                impl<T> ::core::convert::From<AParts> for A<T> {
+                    #[inline(always)]
+                    fn from(parts: AParts) -> Self {
+                        Self::from_parts(parts)
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn builder() {
+        assert_compare!(generate_builder, "8", "struct A { #[field(size = 2)] b: B }", quote::quote!());
+
+        assert_compare!(
+            generate_builder, "16",
+            "struct A { #[field(size = 2, required)] b: B, #[field(size = 3)] c: u8, #[field(access = ro)] d: bool, pub e: E, #[field(size = 2, required)] pub f: F }",
+            quote::quote! {
+                #[doc = " Builds `A`, see `A::builder`. `build` is only available once `b` and `f` are set."]
+                #[must_use = "builders do nothing unless `build` is called"]
+                struct ABuilder<const B_IS_SET: bool, const F_IS_SET: bool>(A);
+
+                impl A {
+                    #[doc = " Creates a builder with all flags and fields cleared. `build` is only available once `b` and `f` are set."]
+                    #[allow(unused)]
+                    #[inline(always)]
+                    const fn builder() -> ABuilder<false, false> {
+                        ABuilder(Self::new())
+                    }
                 }
-            }
 
-            impl ::core::ops::BitXor<C> for A {
-                type Output = Self;
+                impl<const B_IS_SET: bool, const F_IS_SET: bool> ABuilder<B_IS_SET, F_IS_SET> {
+                    /// Sets the new value of the field.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    const fn set_b(self, value: B) -> ABuilder<true, F_IS_SET> {
+                        ABuilder(self.0.set_b(value))
+                    }
 
-                #[inline(always)]
-                fn bitxor(self, flag: C) -> Self::Output {
-                    self.invert_c(flag)
+                    /// Sets the new value of the field.
+                    ///
+                    /// Returns `None` if `value` is bigger than the specified amount of bits the
+                    /// field can store.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    const fn set_c(self, value: u8) -> ::core::option::Option<ABuilder<B_IS_SET, F_IS_SET> > {
+                        match self.0.set_c(value) {
+                            ::core::option::Option::Some(bitfield) => ::core::option::Option::Some(ABuilder(bitfield)),
+                            ::core::option::Option::None => ::core::option::Option::None
+                        }
+                    }
+
+                    /// Sets the new value for the specified flag.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    pub const fn set_e(self, flag: E, value: bool) -> ABuilder<B_IS_SET, F_IS_SET> {
+                        ABuilder(self.0.set_e(flag, value))
+                    }
+
+                    /// Sets the new value of the field.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    pub const fn set_f(self, value: F) -> ABuilder<B_IS_SET, true> {
+                        ABuilder(self.0.set_f(value))
+                    }
                 }
-            }
 
-            impl ::core::ops::BitXorAssign<C> for A {
-                #[inline(always)]
-                fn bitxor_assign(&mut self, flag: C) {
-                    self.0 = self.invert_c(flag).0;
+                impl ABuilder<true, true> {
+                    /// Returns the bit field with all required fields set.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    const fn build(self) -> A {
+                        self.0
+                    }
                 }
             }
+        );
 
-            impl ::core::ops::Sub<C> for A {
-                type Output = Self;
+        assert_compare!(
+            generate_builder, "8", "pub struct A<'a, T: Copy = u8>(#[cfg_attr(x, doc = \"B\")] #[field(size = 3, required)] pub u8);",
+            quote::quote! {
+                #[doc = " Builds `A`, see `A::builder`. `build` is only available once the field is set."]
+                #[must_use = "builders do nothing unless `build` is called"]
+                pub struct ABuilder<'a, T: Copy, const IS_SET: bool>(A<'a, T>);
 
-                #[inline(always)]
-                fn sub(self, flag: C) -> Self::Output {
-                    self.set_c(flag, false)
+                impl<'a, T: Copy> A<'a, T> {
+                    #[doc = " Creates a builder with all flags and fields cleared. `build` is only available once the field is set."]
+                    #[allow(unused)]
+                    #[inline(always)]
+                    pub const fn builder() -> ABuilder<'a, T, false> {
+                        ABuilder(Self::new())
+                    }
                 }
-            }
 
-            impl ::core::ops::SubAssign<C> for A {
-                #[inline(always)]
-                fn sub_assign(&mut self, flag: C) {
-                    self.0 = self.set_c(flag, false).0;
+                impl<'a, T: Copy, const IS_SET: bool> ABuilder<'a, T, IS_SET> {
+                    #[cfg_attr(x, doc = "B")]
+                    /// Sets the new value of the field.
+                    ///
+                    /// Returns `None` if `value` is bigger than the specified amount of bits the
+                    /// field can store.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    pub const fn set(self, value: u8) -> ::core::option::Option<ABuilder<'a, T, true> > {
+                        match self.0.set(value) {
+                            ::core::option::Option::Some(bitfield) => ::core::option::Option::Some(ABuilder(bitfield)),
+                            ::core::option::Option::None => ::core::option::Option::None
+                        }
+                    }
+                }
+
+                impl<'a, T: Copy> ABuilder<'a, T, true> {
+                    /// Returns the bit field with all required fields set.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    pub const fn build(self) -> A<'a, T> {
+                        self.0
+                    }
                 }
             }
-        });
-        assert_compare!(generate_accessors_ops, "NonZero8", "struct A { b: B, c: C }", quote::quote! {});
+        );
     }
 
     #[test]
@@ -4315,90 +7214,342 @@ mod tests {
                     result
                 }
 
-                /// Returns a modified variant with the field set to the specified value.
+                /// Returns a modified variant with the field set to the specified value.
+                #[inline(always)]
+                const fn _set_field(&self, position: u8, size: u8, value: u128) -> Self {
+                    let rest = size as u128 % (::core::mem::size_of::<u128>() * 8) as u128;
+                    let bit = (rest > 0) as u128;
+
+                    let limit = bit.wrapping_shl(rest as u32);
+                    let negative_mask = limit.wrapping_sub((size > 0) as _);
+                    let positioned_used_bits = negative_mask << position;
+                    let positioned_mask = !positioned_used_bits;
+                    let cleared = self.0 & positioned_mask;
+
+                    let shifted_value = value << position;
+
+                    let result = cleared | shifted_value;
+
+                    Self(result)
+                }
+            }
+        });
+        assert_compare!(generate_accessors_low, "NonZero128", "struct E(F);", quote::quote! {
+            impl E {
+                /// Returns a boolean value whether the specified flag is set.
+                #[inline(always)]
+                const fn _bit(&self, position: u8) -> bool {
+                    ((self.0.get() >> position) & 1) != 0
+                }
+
+                /// Returns a modified instance with the flag set to the specified value.
+                #[inline(always)]
+                const fn _set_bit(&self, position: u8, value: bool) -> ::core::option::Option<Self> {
+                    let cleared = self.0.get() & !(1 << position);
+                    let result = cleared | ((value as u128) << position);
+                    match ::core::num::NonZeroU128::new(result) {
+                        Some(result) => Some(Self(result)),
+                        None => None
+                    }
+                }
+
+                /// Returns a modified instance with the bit value inverted.
+                #[inline(always)]
+                const fn _invert_bit(&self, position: u8) -> ::core::option::Option<Self> {
+                    let result = self.0.get() ^ ((1 as u128) << position);
+                    match ::core::num::NonZeroU128::new(result) {
+                        Some(result) => Some(Self(result)),
+                        None => None
+                    }
+                }
+
+                /// Returns a field (subset of bits) from the internal value.
+                #[inline(always)]
+                const fn _field(&self, position: u8, size: u8) -> u128 {
+                    let shifted = self.0.get() >> position;
+
+                    let rest = size as u128 % (::core::mem::size_of::<u128>() * 8) as u128;
+                    let bit = (rest > 0) as u128;
+
+                    let limit = bit.wrapping_shl(rest as u32);
+                    let mask = limit.wrapping_sub((size > 0) as _);
+                    let result = shifted & mask;
+
+                    result
+                }
+
+                /// Returns a modified variant with the field set to the specified value.
+                #[inline(always)]
+                const fn _set_field(&self, position: u8, size: u8, value: u128) -> ::core::option::Option<Self> {
+                    let rest = size as u128 % (::core::mem::size_of::<u128>() * 8) as u128;
+                    let bit = (rest > 0) as u128;
+
+                    let limit = bit.wrapping_shl(rest as u32);
+                    let negative_mask = limit.wrapping_sub((size > 0) as _);
+                    let positioned_used_bits = negative_mask << position;
+                    let positioned_mask = !positioned_used_bits;
+                    let cleared = self.0.get() & positioned_mask;
+
+                    let shifted_value = value << position;
+
+                    let result = cleared | shifted_value;
+
+                    match ::core::num::NonZeroU128::new(result) {
+                        Some(result) => Some(Self(result)),
+                        None => None
+                    }
+                }
+            }
+        });
+
+        // Reserved bits with the `zero` policy are cleared whenever a new value is stored.
+        assert_compare!(
+            generate_accessors_low, "8",
+            "struct A { #[reserved(2, 2, policy = zero)] _r: _, #[reserved(6, 2, policy = zero)] _s: _ }",
+            quote::quote! {
+                impl A {
+                    /// Returns a boolean value whether the specified flag is set.
+                    #[inline(always)]
+                    const fn _bit(&self, position: u8) -> bool {
+                        ((self.0 >> position) & 1) != 0
+                    }
+
+                    /// Returns a modified instance with the flag set to the specified value.
+                    #[inline(always)]
+                    const fn _set_bit(&self, position: u8, value: bool) -> Self {
+                        let cleared = self.0 & !(1 << position);
+                        let result = cleared | ((value as u8) << position);
+                        Self((result & !0xccu8))
+                    }
+
+                    /// Returns a modified instance with the bit value inverted.
+                    #[inline(always)]
+                    const fn _invert_bit(&self, position: u8) -> Self {
+                        let result = self.0 ^ ((1 as u8) << position);
+                        Self((result & !0xccu8))
+                    }
+
+                    /// Returns a field (subset of bits) from the internal value.
+                    #[inline(always)]
+                    const fn _field(&self, position: u8, size: u8) -> u8 {
+                        let shifted = self.0 >> position;
+
+                        let rest = size as u8 % (::core::mem::size_of::<u8>() * 8) as u8;
+                        let bit = (rest > 0) as u8;
+
+                        let limit = bit.wrapping_shl(rest as u32);
+                        let mask = limit.wrapping_sub((size > 0) as _);
+                        let result = shifted & mask;
+
+                        result
+                    }
+
+                    /// Returns a modified variant with the field set to the specified value.
+                    #[inline(always)]
+                    const fn _set_field(&self, position: u8, size: u8, value: u8) -> Self {
+                        let rest = size as u8 % (::core::mem::size_of::<u8>() * 8) as u8;
+                        let bit = (rest > 0) as u8;
+
+                        let limit = bit.wrapping_shl(rest as u32);
+                        let negative_mask = limit.wrapping_sub((size > 0) as _);
+                        let positioned_used_bits = negative_mask << position;
+                        let positioned_mask = !positioned_used_bits;
+                        let cleared = self.0 & positioned_mask;
+
+                        let shifted_value = value << position;
+
+                        let result = cleared | shifted_value;
+
+                        Self((result & !0xccu8))
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn accessors_access() {
+        assert_compare!(generate_accessors, "8", "struct A { #[field(access = ro)] b: bool, #[field(access = wo)] c: bool }", quote::quote! {
+            impl A {
+                /// Gets the value of the field.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn b(&self) -> bool {
+                    self._bit(0u8)
+                }
+
+                /// Creates a copy of the bit field with the new value.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn set_c(&self, value: bool) -> Self {
+                    self._set_bit(1u8, value)
+                }
+            }
+        });
+
+        assert_compare!(generate_accessors, "8", "struct A(#[field(access = w1c)] B);", quote::quote! {
+            impl A {
+                /// Returns `true` if the specified `flag` is set.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn has(&self, flag: B) -> bool {
+                    self._bit(flag as _)
+                }
+
+                /// Returns a bit mask of all possible flags.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn has_mask() -> u8 {
+                    let mut mask = 0;
+                    let mut i = 0;
+                    while i < B::iter().len() {
+                        mask |= 1 << (B::iter()[i] as u8);
+                        i += 1;
+                    }
+                    mask
+                }
+
+                /// Returns `true` if all flags are set.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn has_all(&self) -> bool {
+                    (self.0 & Self::has_mask()) == Self::has_mask()
+                }
+
+                /// Returns `true` if any flag is set.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn has_any(&self) -> bool {
+                    (self.0 & Self::has_mask()) != 0
+                }
+
+                /// Creates a copy of the bit field to clear the specified write-1-to-clear `flag`,
+                /// in which it is the only set write-1-to-clear flag.
+                #[allow(unused)]
                 #[inline(always)]
-                const fn _set_field(&self, position: u8, size: u8, value: u128) -> Self {
-                    let rest = size as u128 % (::core::mem::size_of::<u128>() * 8) as u128;
-                    let bit = (rest > 0) as u128;
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn clear(&self, flag: B) -> Self {
+                    let result = (self.0 & !(0 | Self::has_mask())) | (1 << (flag as u8));
+                    Self(result)
+                }
+            }
+        });
 
-                    let limit = bit.wrapping_shl(rest as u32);
-                    let negative_mask = limit.wrapping_sub((size > 0) as _);
-                    let positioned_used_bits = negative_mask << position;
-                    let positioned_mask = !positioned_used_bits;
-                    let cleared = self.0 & positioned_mask;
+        assert_compare!(generate_accessors_in_place, "8", "struct A(#[field(access = w1c)] B);", quote::quote! {});
+        assert_compare!(generate_accessors_ops, "8", "struct A(#[field(access = ro)] B);", quote::quote! {});
 
-                    let shifted_value = value << position;
+        assert_compare!(generate_accessors_in_place, "8", "struct A(#[field(access = wo)] B);", quote::quote! {
+            impl A {
+                #[doc = " Sets the specified `flag` to the new value."]
+                #[allow(unused)]
+                #[inline(always)]
+                const fn set_in_place(&mut self, flag: B, value: bool) {
+                    *self = self.set(flag, value);
+                }
 
-                    let result = cleared | shifted_value;
+                /// Sets the specified `flag`.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn insert(&mut self, flag: B) {
+                    let result = self.0 | (1 << (flag as u8));
+                    *self = Self(result);
+                }
 
-                    Self(result)
+                #[doc = " Clears the specified `flag`."]
+                #[allow(unused)]
+                #[inline(always)]
+                const fn remove(&mut self, flag: B) {
+                    *self = self.set(flag, false);
                 }
             }
         });
-        assert_compare!(generate_accessors_low, "NonZero128", "struct E(F);", quote::quote! {
-            impl E {
-                /// Returns a boolean value whether the specified flag is set.
+    }
+
+    #[test]
+    fn accessors_in_place() {
+        assert_compare!(generate_accessors_in_place, "8", "struct A(#[field(0, 3)] u8);", quote::quote! {
+            impl A {
+                #[doc = " Sets the field to the new value."]
+                #[doc = ""]
+                #[doc = " Returns the rejected value in `Err` and leaves the bit field unmodified, if `value` is bigger than the specified amount of bits the field can store."]
+                #[allow(unused)]
                 #[inline(always)]
-                const fn _bit(&self, position: u8) -> bool {
-                    ((self.0.get() >> position) & 1) != 0
+                const fn try_set_in_place(&mut self, value: u8) -> ::core::result::Result<(), u8> {
+                    match self.set(value) {
+                        ::core::option::Option::Some(result) => {
+                            *self = result;
+                            ::core::result::Result::Ok(())
+                        },
+                        ::core::option::Option::None => ::core::result::Result::Err(value)
+                    }
                 }
+            }
+        });
 
-                /// Returns a modified instance with the flag set to the specified value.
+        assert_compare!(generate_accessors_in_place, "NonZero8", "struct A { #[field(bit = 2)] pub b: bool }", quote::quote! {
+            impl A {
+                #[doc = " Sets the field to the new value."]
+                #[doc = ""]
+                #[doc = " Returns the rejected value in `Err` and leaves the bit field unmodified, if all bits of the bit field would be cleared."]
+                #[allow(unused)]
                 #[inline(always)]
-                const fn _set_bit(&self, position: u8, value: bool) -> ::core::option::Option<Self> {
-                    let cleared = self.0.get() & !(1 << position);
-                    let result = cleared | ((value as u128) << position);
-                    match ::core::num::NonZeroU128::new(result) {
-                        Some(result) => Some(Self(result)),
-                        None => None
+                pub const fn try_set_b_in_place(&mut self, value: bool) -> ::core::result::Result<(), bool> {
+                    match self.set_b(value) {
+                        ::core::option::Option::Some(result) => {
+                            *self = result;
+                            ::core::result::Result::Ok(())
+                        },
+                        ::core::option::Option::None => ::core::result::Result::Err(value)
                     }
                 }
 
-                /// Returns a modified instance with the bit value inverted.
+                #[doc = " Inverts the value of the field."]
+                #[doc = ""]
+                #[doc = " Returns the rejected value in `Err` and leaves the bit field unmodified, if all bits of the bit field would be cleared."]
+                #[allow(unused)]
                 #[inline(always)]
-                const fn _invert_bit(&self, position: u8) -> ::core::option::Option<Self> {
-                    let result = self.0.get() ^ ((1 as u128) << position);
-                    match ::core::num::NonZeroU128::new(result) {
-                        Some(result) => Some(Self(result)),
-                        None => None
+                pub const fn try_invert_b_in_place(&mut self) -> ::core::result::Result<(), bool> {
+                    match self.invert_b() {
+                        ::core::option::Option::Some(result) => {
+                            *self = result;
+                            ::core::result::Result::Ok(())
+                        },
+                        ::core::option::Option::None => ::core::result::Result::Err(!self.b())
                     }
                 }
+            }
+        });
 
-                /// Returns a field (subset of bits) from the internal value.
+        assert_compare!(generate_accessors_in_place, "8", "struct A(B);", quote::quote! {
+            impl A {
+                #[doc = " Sets the specified `flag` to the new value."]
+                #[allow(unused)]
                 #[inline(always)]
-                const fn _field(&self, position: u8, size: u8) -> u128 {
-                    let shifted = self.0.get() >> position;
-
-                    let rest = size as u128 % (::core::mem::size_of::<u128>() * 8) as u128;
-                    let bit = (rest > 0) as u128;
-
-                    let limit = bit.wrapping_shl(rest as u32);
-                    let mask = limit.wrapping_sub((size > 0) as _);
-                    let result = shifted & mask;
-
-                    result
+                const fn set_in_place(&mut self, flag: B, value: bool) {
+                    *self = self.set(flag, value);
                 }
 
-                /// Returns a modified variant with the field set to the specified value.
+                /// Sets the specified `flag`.
+                #[allow(unused)]
                 #[inline(always)]
-                const fn _set_field(&self, position: u8, size: u8, value: u128) -> ::core::option::Option<Self> {
-                    let rest = size as u128 % (::core::mem::size_of::<u128>() * 8) as u128;
-                    let bit = (rest > 0) as u128;
-
-                    let limit = bit.wrapping_shl(rest as u32);
-                    let negative_mask = limit.wrapping_sub((size > 0) as _);
-                    let positioned_used_bits = negative_mask << position;
-                    let positioned_mask = !positioned_used_bits;
-                    let cleared = self.0.get() & positioned_mask;
-
-                    let shifted_value = value << position;
+                const fn insert(&mut self, flag: B) {
+                    let result = self.0 | (1 << (flag as u8));
+                    *self = Self(result);
+                }
 
-                    let result = cleared | shifted_value;
+                #[doc = " Clears the specified `flag`."]
+                #[allow(unused)]
+                #[inline(always)]
+                const fn remove(&mut self, flag: B) {
+                    *self = self.set(flag, false);
+                }
 
-                    match ::core::num::NonZeroU128::new(result) {
-                        Some(result) => Some(Self(result)),
-                        None => None
-                    }
+                #[doc = " Inverts the value of the specified `flag`."]
+                #[allow(unused)]
+                #[inline(always)]
+                const fn toggle(&mut self, flag: B) {
+                    *self = self.invert(flag);
                 }
             }
         });
@@ -4591,6 +7742,150 @@ mod tests {
         });
     }
 
+    #[test]
+    fn accessors_naming() {
+        assert_compare!(generate_accessors, "8, naming = \"with\"", "struct A { b: bool }", quote::quote! {
+            impl A {
+                /// Gets the value of the field.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn b(&self) -> bool {
+                    self._bit(0u8)
+                }
+
+                /// Creates a copy of the bit field with the new value.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn with_b(&self, value: bool) -> Self {
+                    self._set_bit(0u8, value)
+                }
+
+                /// Creates a copy of the bit field with the value of the field inverted.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn invert_b(&self) -> Self {
+                    self._invert_bit(0u8)
+                }
+            }
+        });
+
+        assert_compare!(generate_accessors, "8, naming = \"with\"", "struct A(bool);", quote::quote! {
+            impl A {
+                /// Gets the value of the field.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn get(&self) -> bool {
+                    self._bit(0u8)
+                }
+
+                /// Creates a copy of the bit field with the new value.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn with(&self, value: bool) -> Self {
+                    self._set_bit(0u8, value)
+                }
+
+                /// Creates a copy of the bit field with the value of the field inverted.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn invert(&self) -> Self {
+                    self._invert_bit(0u8)
+                }
+            }
+        });
+
+        assert_compare!(generate_accessors, "8, naming = \"get_set\"", "struct A { r#b: bool }", quote::quote! {
+            impl A {
+                /// Gets the value of the field.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn get_b(&self) -> bool {
+                    self._bit(0u8)
+                }
+
+                /// Creates a copy of the bit field with the new value.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn set_b(&self, value: bool) -> Self {
+                    self._set_bit(0u8, value)
+                }
+
+                /// Creates a copy of the bit field with the value of the field inverted.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn invert_b(&self) -> Self {
+                    self._invert_bit(0u8)
+                }
+            }
+        });
+
+        assert_compare!(generate_accessors, "8, naming = \"with\"", "struct A { #[field(get = is_b, invert = flip_b)] b: bool }", quote::quote! {
+            impl A {
+                /// Gets the value of the field.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn is_b(&self) -> bool {
+                    self._bit(0u8)
+                }
+
+                /// Creates a copy of the bit field with the new value.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn with_b(&self, value: bool) -> Self {
+                    self._set_bit(0u8, value)
+                }
+
+                /// Creates a copy of the bit field with the value of the field inverted.
+                #[allow(unused)]
+                #[inline(always)]
+                #[must_use = "leaves `self` unmodified and returns a modified variant"]
+                const fn flip_b(&self) -> Self {
+                    self._invert_bit(0u8)
+                }
+            }
+        });
+
+        assert_compare!(generate_accessors_in_place, "8", "struct A { #[field(get = has_b, set = with_b)] b: B }", quote::quote! {
+            impl A {
+                #[doc = " Sets the specified `flag` to the new value."]
+                #[allow(unused)]
+                #[inline(always)]
+                const fn with_b_in_place(&mut self, flag: B, value: bool) {
+                    *self = self.with_b(flag, value);
+                }
+
+                /// Sets the specified `flag`.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn insert_b(&mut self, flag: B) {
+                    let result = self.0 | (1 << (flag as u8));
+                    *self = Self(result);
+                }
+
+                #[doc = " Clears the specified `flag`."]
+                #[allow(unused)]
+                #[inline(always)]
+                const fn remove_b(&mut self, flag: B) {
+                    *self = self.with_b(flag, false);
+                }
+
+                #[doc = " Inverts the value of the specified `flag`."]
+                #[allow(unused)]
+                #[inline(always)]
+                const fn toggle_b(&mut self, flag: B) {
+                    *self = self.invert_b(flag);
+                }
+            }
+        });
+    }
+
     #[test]
     fn assertions() {
         let non_zero_check = quote::quote! {
@@ -4637,6 +7932,42 @@ mod tests {
             quote::quote!(impl A { #check_1 #non_zero_check })
         );
 
+        // Generic bit fields are checked by a non-generic type with the same name.
+        assert_compare!(generate_assertions,
+            "16", "struct A<T>(#[field(0, 9)] B) where T: C;",
+            quote::quote!(const _: () = { struct A; impl A { #check_1 } };)
+        );
+        assert_compare!(generate_assertions,
+            "NonZero16", "struct A<T>(#[field(0, 9)] B);",
+            quote::quote!(const _: () = { struct A; impl A { #check_1 } };)
+        );
+
+        // Conditional entries are only checked in the configurations in which they exist.
+        assert_compare!(generate_assertions,
+            "8", "struct A { #[field(0, 2)] b: u8, #[cfg(x)] #[field(1, 2)] c: u8 }",
+            quote::quote! {
+                impl A {
+                    const _TYPE_IN_FIELD_0_IS_SMALLER_THAN_THE_SPECIFIED_SIZE_OF_2_BITS: [();
+                        if ::core::mem::size_of::<u8>() * 8 >= 2 { 0 } else { panic!("Type is smaller than the specified size of 2 bits") }
+                    ] = [];
+                }
+
+                #[cfg(x)]
+                impl A {
+                    const _TYPE_IN_FIELD_1_IS_SMALLER_THAN_THE_SPECIFIED_SIZE_OF_2_BITS: [();
+                        if ::core::mem::size_of::<u8>() * 8 >= 2 { 0 } else { panic!("Type is smaller than the specified size of 2 bits") }
+                    ] = [];
+                }
+
+                #[cfg(x)]
+                impl A {
+                    const _FIELD_1_OVERLAPS_WITH_FIELD_0: [();
+                        if false { 0 } else { panic!("Field \"c\" overlaps with field \"b\"\n\"c\": bits 1-2, \"b\": bits 0-1, overlapping: bit 1\nlayout (bit 7 first): `.....#X=`, `#` is \"c\", `=` is \"b\", `X` is both, `-` are other fields, `.` are free bits\nhelp: the next free position is `#[field(2, 2)]`") }
+                    ] = [];
+                }
+            }
+        );
+
         assert_compare!(
             generate_assertions, "16", "struct A(#[field(4, 8)] B);", quote::quote! {
                 impl A {
@@ -4696,6 +8027,50 @@ mod tests {
             "16, allow_overlaps", "struct A(B);",
             quote::quote!(impl A { #check_2 })
         );
+        let unused = (0..16u8).map(|bit| {
+            let message = format!("Bit {} is not used by a field, flags or reserved bits", bit);
+            let bit = proc_macro2::Literal::u8_unsuffixed(bit);
+
+            quote::quote! { #bit => panic!(#message), }
+        });
+        assert_compare!(
+            generate_assertions, "16, exhaustive", "struct A(B);", quote::quote! {
+                impl A {
+                    #check_2
+
+                    const fn _exhaustive_bitfield_has_unused_bits() -> u8 {
+                        let mut used: u16 = 0;
+                        {
+                            let flags = B::iter();
+
+                            let mut i = 0;
+                            while i < flags.len() {
+                                used |= 1u16 << (flags[i] as u8);
+
+                                i += 1;
+                            }
+                        }
+
+                        let mut bit = 0;
+                        while bit < u16::BITS {
+                            if used & (1u16 << bit) == 0 {
+                                return bit as u8;
+                            }
+
+                            bit += 1;
+                        }
+
+                        u8::MAX
+                    }
+
+                    const _EXHAUSTIVE_BITFIELD_HAS_UNUSED_BITS: [(); match Self::_exhaustive_bitfield_has_unused_bits() {
+                        u8::MAX => 0,
+                        #(#unused)*
+                        _ => panic!("Not every bit is used by a field, flags or reserved bits")
+                    }] = [];
+                }
+            }
+        );
         let check_2_non_zero = quote::quote! {
             const _FLAGS_IN_FIELD_0_MUST_BE_REPR_U8: [();
                 if ::core::mem::size_of::<B>() == 1 { 0 } else { panic!("Flags must be #[repr(u8)]") }
@@ -4764,22 +8139,27 @@ mod tests {
                 impl A {
                     #check_3
 
-                    const fn _flags_in_field_1_overlap_with_field_0() -> bool {
+                    const fn _flags_in_field_1_overlap_with_field_0() -> u8 {
                         let flags = C::iter();
 
                         let mut i = 0;
                         while i < flags.len () {
                             let flag = flags[i] as u8;
-                            if flag >= 0u8 && flag < 0u8 + 2u8 { return true; }
+                            if flag >= 0u8 && flag < 0u8 + 2u8 { return flag; }
 
                             i += 1;
                         }
 
-                        false
+                        u8::MAX
                     }
 
                     const _FLAGS_IN_FIELD_1_OVERLAP_WITH_FIELD_0: [();
-                        if !Self::_flags_in_field_1_overlap_with_field_0() { 0 } else { panic!("Flags in field \"c\" overlap with field \"b\"") }
+                        match Self::_flags_in_field_1_overlap_with_field_0() {
+                            u8::MAX => 0,
+                            0 => panic!("Flags in field \"c\" overlap with field \"b\"\n\"c\": bit 0, \"b\": bits 0-1, overlapping: bit 0\nlayout (bit 7 first): `......=X`, `#` is \"c\", `=` is \"b\", `X` is both, `-` are other fields, `.` are free bits\nhelp: the next bit which is not used by a field is 2"),
+                            1 => panic!("Flags in field \"c\" overlap with field \"b\"\n\"c\": bit 1, \"b\": bits 0-1, overlapping: bit 1\nlayout (bit 7 first): `......X=`, `#` is \"c\", `=` is \"b\", `X` is both, `-` are other fields, `.` are free bits\nhelp: the next bit which is not used by a field is 2"),
+                            _ => panic!("Flags in field \"c\" overlap with field \"b\"")
+                        }
                     ] = [];
                 }
             }
@@ -4824,22 +8204,27 @@ mod tests {
                 impl A {
                     #check_3_non_zero
 
-                    const fn _flags_in_field_1_overlap_with_field_0() -> bool {
+                    const fn _flags_in_field_1_overlap_with_field_0() -> u8 {
                         let flags = C::iter();
 
                         let mut i = 0;
                         while i < flags.len () {
                             let flag = flags[i] as u8;
-                            if flag >= 0u8 && flag < 0u8 + 2u8 { return true; }
+                            if flag >= 0u8 && flag < 0u8 + 2u8 { return flag; }
 
                             i += 1;
                         }
 
-                        false
+                        u8::MAX
                     }
 
                     const _FLAGS_IN_FIELD_1_OVERLAP_WITH_FIELD_0: [();
-                        if !Self::_flags_in_field_1_overlap_with_field_0() { 0 } else { panic!("Flags in field \"c\" overlap with field \"b\"") }
+                        match Self::_flags_in_field_1_overlap_with_field_0() {
+                            u8::MAX => 0,
+                            0 => panic!("Flags in field \"c\" overlap with field \"b\"\n\"c\": bit 0, \"b\": bits 0-1, overlapping: bit 0\nlayout (bit 7 first): `......=X`, `#` is \"c\", `=` is \"b\", `X` is both, `-` are other fields, `.` are free bits\nhelp: the next bit which is not used by a field is 2"),
+                            1 => panic!("Flags in field \"c\" overlap with field \"b\"\n\"c\": bit 1, \"b\": bits 0-1, overlapping: bit 1\nlayout (bit 7 first): `......X=`, `#` is \"c\", `=` is \"b\", `X` is both, `-` are other fields, `.` are free bits\nhelp: the next bit which is not used by a field is 2"),
+                            _ => panic!("Flags in field \"c\" overlap with field \"b\"")
+                        }
                     ] = [];
 
                     #non_zero_check
@@ -4896,12 +8281,13 @@ mod tests {
                 } as usize { 0 } else { panic!("Flags exceed the bitfield size") }
             ] = [];
         };
+        let (b_c, b_c_message) = overlap_arms!("8", "struct A { b: B, c: C }", 0, "b", flags "c");
         assert_compare!(
             generate_assertions, "8", "struct A { b: B, c: C }", quote::quote! {
                 impl A {
                     #check_4
 
-                    const fn _flags_in_field_0_overlap_with_flags_in_field_1() -> bool {
+                    const fn _flags_in_field_0_overlap_with_flags_in_field_1() -> u8 {
                         let f1 = B::iter();
                         let f2 = C::iter();
 
@@ -4909,7 +8295,7 @@ mod tests {
                         while i1 < f1.len() {
                             let mut i2 = 0;
                             while i2 < f2.len() {
-                                if (f1[i1] as u32) == (f2[i2] as u32) { return true; }
+                                if (f1[i1] as u8) == (f2[i2] as u8) { return f1[i1] as u8; }
 
                                 i2 += 1;
                             }
@@ -4917,11 +8303,11 @@ mod tests {
                             i1 += 1;
                         }
 
-                        false
+                        u8::MAX
                     }
 
                     const _FLAGS_IN_FIELD_0_OVERLAP_WITH_FLAGS_IN_FIELD_1: [();
-                        if !Self::_flags_in_field_0_overlap_with_flags_in_field_1() { 0 } else { panic!("Flags in field \"b\" overlap with flags in field \"c\"") }
+                        match Self::_flags_in_field_0_overlap_with_flags_in_field_1() { u8::MAX => 0, #b_c _ => panic!(#b_c_message) }
                     ] = [];
                 }
             }
@@ -4978,12 +8364,13 @@ mod tests {
                 } as usize { 0 } else { panic!("Flags exceed the bitfield size") }
             ] = [];
         };
+        let (b_c, b_c_message) = overlap_arms!("NonZero8", "struct A { b: B, c: C }", 0, "b", flags "c");
         assert_compare!(
             generate_assertions, "NonZero8", "struct A { b: B, c: C }", quote::quote! {
                 impl A {
                     #check_4_non_zero
 
-                    const fn _flags_in_field_0_overlap_with_flags_in_field_1() -> bool {
+                    const fn _flags_in_field_0_overlap_with_flags_in_field_1() -> u8 {
                         let f1 = B::iter();
                         let f2 = C::iter();
 
@@ -4991,7 +8378,7 @@ mod tests {
                         while i1 < f1.len() {
                             let mut i2 = 0;
                             while i2 < f2.len() {
-                                if (f1[i1] as u32) == (f2[i2] as u32) { return true; }
+                                if (f1[i1] as u8) == (f2[i2] as u8) { return f1[i1] as u8; }
 
                                 i2 += 1;
                             }
@@ -4999,11 +8386,11 @@ mod tests {
                             i1 += 1;
                         }
 
-                        false
+                        u8::MAX
                     }
 
                     const _FLAGS_IN_FIELD_0_OVERLAP_WITH_FLAGS_IN_FIELD_1: [();
-                        if !Self::_flags_in_field_0_overlap_with_flags_in_field_1() { 0 } else { panic!("Flags in field \"b\" overlap with flags in field \"c\"") }
+                        match Self::_flags_in_field_0_overlap_with_flags_in_field_1() { u8::MAX => 0, #b_c _ => panic!(#b_c_message) }
                     ] = [];
 
                     #non_zero_check
@@ -5064,19 +8451,46 @@ mod tests {
                                     max = current;
                                 }
 
-                                i += 1;
-                            }
+                                i += 1;
+                            }
+
+                            max
+                        } as usize { 0 } else { panic!("Flags exceed the bitfield size") }
+                    ] = [];
+
+                    #non_zero_check
+                }
+            }
+        );
+        assert_compare!(
+            generate_assertions, "size", "struct A(#[field(4, 3)] B);", quote::quote! {
+                impl A {
+                    const _TYPE_IN_FIELD_0_IS_SMALLER_THAN_THE_SPECIFIED_SIZE_OF_3_BITS: [();
+                        if ::core::mem::size_of::<B>() * 8 >= 3 { 0 } else { panic!("Type is smaller than the specified size of 3 bits") }
+                    ] = [];
+
+                    const _TYPE_IN_FIELD_0_EXCEEDS_FIELD_SIZE_OF_3_BITS: [();
+                        if B::size() <= 3 { 0 } else { panic!("Size of the field type exceeds the specified field size of 3 bits") }
+                    ] = [];
 
-                            max
-                        } as usize { 0 } else { panic!("Flags exceed the bitfield size") }
+                    const _FIELD_0_EXCEEDS_THE_BITFIELD_SIZE: [();
+                        if ::core::mem::size_of::<usize>() * 8 >= 4 + 3 { 0 } else { panic!("Field exceeds the bitfield size") }
                     ] = [];
 
-                    #non_zero_check
+                    const _FIELD_0_HAS_THE_SIZE_OF_THE_WHOLE_BITFIELD: [();
+                        if ::core::mem::size_of::<usize>() * 8 != 3 { 0 } else { panic!("Field has the size of the whole bitfield") }
+                    ] = [];
                 }
             }
         );
+        let unused = (0..4u8).chain(7..64).map(|bit| {
+            let message = format!("Bit {} is not used by a field, flags or reserved bits", bit);
+            let bit = proc_macro2::Literal::u8_unsuffixed(bit);
+
+            quote::quote! { #bit => panic!(#message), }
+        });
         assert_compare!(
-            generate_assertions, "size", "struct A(#[field(4, 3)] B);", quote::quote! {
+            generate_assertions, "size, exhaustive", "struct A(#[field(4, 3)] B);", quote::quote! {
                 impl A {
                     const _TYPE_IN_FIELD_0_IS_SMALLER_THAN_THE_SPECIFIED_SIZE_OF_3_BITS: [();
                         if ::core::mem::size_of::<B>() * 8 >= 3 { 0 } else { panic!("Type is smaller than the specified size of 3 bits") }
@@ -5093,6 +8507,28 @@ mod tests {
                     const _FIELD_0_HAS_THE_SIZE_OF_THE_WHOLE_BITFIELD: [();
                         if ::core::mem::size_of::<usize>() * 8 != 3 { 0 } else { panic!("Field has the size of the whole bitfield") }
                     ] = [];
+
+                    const fn _exhaustive_bitfield_has_unused_bits() -> u8 {
+                        let mut used: usize = 0;
+                        used |= ((1usize << 3) - 1) << 4;
+
+                        let mut bit = 0;
+                        while bit < usize::BITS {
+                            if used & (1usize << bit) == 0 {
+                                return bit as u8;
+                            }
+
+                            bit += 1;
+                        }
+
+                        u8::MAX
+                    }
+
+                    const _EXHAUSTIVE_BITFIELD_HAS_UNUSED_BITS: [(); match Self::_exhaustive_bitfield_has_unused_bits() {
+                        u8::MAX => 0,
+                        #(#unused)*
+                        _ => panic!("Not every bit is used by a field, flags or reserved bits")
+                    }] = [];
                 }
             }
         );
@@ -5151,7 +8587,7 @@ mod tests {
                     let mut s = f.debug_struct(::core::stringify!(A));
 
                     for flag in <B>::iter() {
-                        s.field(&alloc::format!("{:?}", flag), &self.has(*flag));
+                        s.field(flag._bitfield_flag_name(), &self.has(*flag));
                     }
 
                     s.finish()
@@ -5261,7 +8697,7 @@ mod tests {
                                     let mut s = f.debug_struct(::core::stringify!(B));
 
                                     for flag in <super::B>::iter() {
-                                        s.field(&alloc::format!("{:?}", flag), &self.0.b(*flag));
+                                        s.field(flag._bitfield_flag_name(), &self.0.b(*flag));
                                     }
 
                                     s.finish()
@@ -5292,7 +8728,7 @@ mod tests {
                                     let mut s = f.debug_struct(::core::stringify!(B));
 
                                     for flag in <B>::iter() {
-                                        s.field(&alloc::format!("{:?}", flag), &self.0.b(*flag));
+                                        s.field(flag._bitfield_flag_name(), &self.0.b(*flag));
                                     }
 
                                     s.finish()
@@ -5318,7 +8754,7 @@ mod tests {
                                     let mut s = f.debug_struct(::core::stringify!(D));
 
                                     for flag in <D>::iter() {
-                                        s.field(&alloc::format!("{:?}", flag), &self.0.d(*flag));
+                                        s.field(flag._bitfield_flag_name(), &self.0.d(*flag));
                                     }
 
                                     s.finish()
@@ -5349,7 +8785,7 @@ mod tests {
                                     let mut s = f.debug_struct(::core::stringify!(B));
 
                                     for flag in <B>::iter() {
-                                        s.field(&alloc::format!("{:?}", flag), &self.0.b(*flag));
+                                        s.field(flag._bitfield_flag_name(), &self.0.b(*flag));
                                     }
 
                                     s.finish()
@@ -5367,7 +8803,7 @@ mod tests {
                                     let mut s = f.debug_struct(::core::stringify!(C));
 
                                     for flag in <C>::iter() {
-                                        s.field(&alloc::format!("{:?}", flag), &self.0.c(*flag));
+                                        s.field(flag._bitfield_flag_name(), &self.0.c(*flag));
                                     }
 
                                     s.finish()
@@ -5416,79 +8852,466 @@ mod tests {
         );
 
         assert_compare!(
-            generate_debug, "8", "#[derive(Debug)] struct A { #[field(2, 1)] b: bool }",
+            generate_debug, "8", "#[derive(Debug)] struct A { #[field(2, 1)] b: bool }",
+            quote::quote! {
+                impl ::core::fmt::Debug for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let mut s = f.debug_struct(::core::stringify!(A));
+
+                        let value = self.b();
+                        s.field(::core::stringify!(b), &value);
+
+                        s.finish()
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_debug, "8", "#[derive(Debug)] struct A { #[reserved(2)] _r: _, #[field(2, 1)] b: bool }",
+            quote::quote! {
+                impl ::core::fmt::Debug for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let mut s = f.debug_struct(::core::stringify!(A));
+
+                        let value = self._field(0, 2);
+                        if value != 0 {
+                            s.field(::core::stringify!(_r), &::core::format_args!("{:#x}", value));
+                        }
+
+                        let value = self.b();
+                        s.field(::core::stringify!(b), &value);
+
+                        s.finish()
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_debug, "32", "#[derive(Debug)] struct A { #[field(0, 16)] b: u16 }",
+            quote::quote! {
+                impl ::core::fmt::Debug for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let mut s = f.debug_struct(::core::stringify!(A));
+
+                        let value = self.b();
+                        s.field(::core::stringify!(b), &value);
+
+                        s.finish()
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_debug, "8", "#[derive(Debug)] struct A { r#b: B }",
+            quote::quote! {
+                impl ::core::fmt::Debug for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let mut s = f.debug_struct(::core::stringify!(A));
+
+                        {
+                            struct BitFieldDebugImplementor<'a>(&'a A);
+
+                            impl<'a> ::core::fmt::Debug for BitFieldDebugImplementor<'a> {
+                                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                    let mut s = f.debug_struct(::core::stringify!(B));
+
+                                    for flag in <B>::iter() {
+                                        s.field(flag._bitfield_flag_name(), &self.0.r#b(*flag));
+                                    }
+
+                                    s.finish()
+                                }
+                            }
+
+                            s.field(::core::stringify!(b), &BitFieldDebugImplementor(&self));
+                        }
+
+                        s.finish()
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_debug, "32", "#[derive(Debug)] struct A { #[field(0, 16)] r#b: u16 }",
+            quote::quote! {
+                impl ::core::fmt::Debug for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let mut s = f.debug_struct(::core::stringify!(A));
+
+                        let value = self.r#b();
+                        s.field(::core::stringify!(b), &value);
+
+                        s.finish()
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_compare!(generate_display, "8", "struct A(B);", quote::quote! {});
+
+        assert_compare!(generate_display, "8", "#[derive(Display)] struct A(B);", quote::quote! {
+            impl ::core::fmt::Display for A {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    let mut is_empty = true;
+
+                    for flag in <B>::iter() {
+                        if self.has(*flag) {
+                            if !is_empty { f.write_str(" | ")?; }
+                            ::core::write!(f, "{:?}", flag)?;
+                            is_empty = false;
+                        }
+                    }
+
+                    if is_empty { f.write_str("-") } else { ::core::fmt::Result::Ok(()) }
+                }
+            }
+        });
+
+        assert_compare!(
+            generate_display, "8", "#[derive(Display)] struct A(#[field(0, 1)] B);", quote::quote! {
+                impl ::core::fmt::Display for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let value = self.get();
+                        if let ::core::result::Result::Ok(value) = value {
+                            ::core::write!(f, "{:?}", value)
+                        } else {
+                            ::core::write!(f, "{:?}", value)
+                        }
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_display, "8", "#[derive(Display)] struct A(#[field(0, 1, complete)] B);",
+            quote::quote! {
+                impl ::core::fmt::Display for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let value = self.get();
+                        ::core::write!(f, "{:?}", value)
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_display, "8", "#[derive(Display)] struct A { #[field(0, 1)] b: B }",
+            quote::quote! {
+                impl ::core::fmt::Display for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let value = self.b();
+
+                        if let ::core::result::Result::Ok(value) = value {
+                            ::core::write!(f, "{:?}", value)
+                        } else {
+                            ::core::write!(f, "{:?}", value)
+                        }
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_display, "8", "#[derive(Display)] struct A { #[field(0, 1, complete)] b: B }",
+            quote::quote! {
+                impl ::core::fmt::Display for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let value = self.b();
+                        ::core::write!(f, "{:?}", value)
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_display, "8", "#[derive(Display)] struct A { b: super::B }", quote::quote! {
+                impl ::core::fmt::Display for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let mut is_empty = true;
+
+                        for flag in <super::B>::iter() {
+                            if self.b(*flag) {
+                                if !is_empty { f.write_str(" | ")?; }
+                                ::core::write!(f, "{:?}", flag)?;
+                                is_empty = false;
+                            }
+                        }
+
+                        if is_empty { f.write_str("-") } else { ::core::fmt::Result::Ok(()) }
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_display, "8", "#[derive(Display)] struct A { b: B, c: C }", quote::quote! {
+                impl ::core::fmt::Display for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let mut is_empty = true;
+
+                        for flag in <B>::iter() {
+                            if self.b(*flag) {
+                                if !is_empty { f.write_str(" | ")?; }
+                                ::core::write!(f, "{}::{:?}", ::core::any::type_name::<B>(), flag)?;
+                                is_empty = false;
+                            }
+                        }
+
+                        for flag in <C>::iter() {
+                            if self.c(*flag) {
+                                if !is_empty { f.write_str(" | ")?; }
+                                ::core::write!(f, "{}::{:?}", ::core::any::type_name::<C>(), flag)?;
+                                is_empty = false;
+                            }
+                        }
+
+                        if is_empty { f.write_str("-") } else { ::core::fmt::Result::Ok(()) }
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_display, "8", "#[derive(Display)] struct A(#[field(0, 1)] bool);",
+            quote::quote! {
+                impl ::core::fmt::Display for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let value = self.get();
+                        ::core::write!(f, "{:?}", value)
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_display, "32", "#[derive(Display)] struct A(#[field(0, 16)] u16);",
+            quote::quote! {
+                impl ::core::fmt::Display for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let value = self.get();
+                        ::core::write!(f, "{:?}", value)
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_display, "8", "#[derive(Display)] struct A { #[field(0, 1)] b: bool }",
+            quote::quote! {
+                impl ::core::fmt::Display for A {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        let value = self.b();
+                        ::core::write!(f, "{:?}", value)
+                    }
+                }
+            }
+        );
+
+        assert_compare!(
+            generate_display, "32", "#[derive(Display)] struct A { #[field(0, 16)] b: u16 }",
             quote::quote! {
-                impl ::core::fmt::Debug for A {
+                impl ::core::fmt::Display for A {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let mut s = f.debug_struct(::core::stringify!(A));
-
                         let value = self.b();
-                        s.field(::core::stringify!(b), &value);
-
-                        s.finish()
+                        ::core::write!(f, "{:?}", value)
                     }
                 }
             }
         );
+    }
 
+    #[test]
+    fn display_options() {
         assert_compare!(
-            generate_debug, "32", "#[derive(Debug)] struct A { #[field(0, 16)] b: u16 }",
+            generate_display, "8", "#[derive(Display)] #[display(separator = \", \", empty = \"none\")] struct A(B);",
             quote::quote! {
-                impl ::core::fmt::Debug for A {
+                impl ::core::fmt::Display for A {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let mut s = f.debug_struct(::core::stringify!(A));
+                        let mut is_empty = true;
 
-                        let value = self.b();
-                        s.field(::core::stringify!(b), &value);
+                        for flag in <B>::iter() {
+                            if self.has(*flag) {
+                                if !is_empty { f.write_str(", ")?; }
+                                ::core::write!(f, "{:?}", flag)?;
+                                is_empty = false;
+                            }
+                        }
 
-                        s.finish()
+                        if is_empty { f.write_str("none") } else { ::core::fmt::Result::Ok(()) }
                     }
                 }
             }
         );
 
         assert_compare!(
-            generate_debug, "8", "#[derive(Debug)] struct A { r#b: B }",
+            generate_display, "8", "#[derive(Display)] #[display(\"{b}/{c} [{d}]\", empty = \"\")] struct A { #[field(0, 1)] b: B, #[field(1, 1)] c: bool, d: D }",
             quote::quote! {
-                impl ::core::fmt::Debug for A {
+                impl ::core::fmt::Display for A {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let mut s = f.debug_struct(::core::stringify!(A));
-
                         {
-                            struct BitFieldDebugImplementor<'a>(&'a A);
-
-                            impl<'a> ::core::fmt::Debug for BitFieldDebugImplementor<'a> {
-                                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                                    let mut s = f.debug_struct(::core::stringify!(B));
+                            let value = self.b();
 
-                                    for flag in <B>::iter() {
-                                        s.field(&alloc::format!("{:?}", flag), &self.0.r#b(*flag));
-                                    }
+                            if let ::core::result::Result::Ok(value) = value {
+                                ::core::write!(f, "{:?}", value)?;
+                            } else {
+                                ::core::write!(f, "{:?}", value)?;
+                            }
+                        }
+                        { f.write_str("/")?; }
+                        {
+                            let value = self.c();
+                            ::core::write!(f, "{:?}", value)?;
+                        }
+                        { f.write_str(" [")?; }
+                        {
+                            let mut is_empty = true;
 
-                                    s.finish()
+                            for flag in <D>::iter() {
+                                if self.d(*flag) {
+                                    if !is_empty { f.write_str(" | ")?; }
+                                    ::core::write!(f, "{:?}", flag)?;
+                                    is_empty = false;
                                 }
                             }
 
-                            s.field(::core::stringify!(b), &BitFieldDebugImplementor(&self));
+                            if is_empty { f.write_str("")?; }
                         }
+                        { f.write_str("]")?; }
 
-                        s.finish()
+                        ::core::fmt::Result::Ok(())
                     }
                 }
             }
         );
+    }
+
+    #[test]
+    #[should_panic]
+    fn display_empty() {
+        assert_compare!(generate_display, "8", "#[derive(Display)] struct A {}", quote::quote! {});
+    }
+
+    #[test]
+    #[should_panic]
+    fn display_non_flags() {
+        assert_compare!(
+            generate_display, "8", "#[derive(Display)] struct A { b: B, #[field(0, 1)] c: C }",
+            quote::quote! {}
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_compare!(generate_from_str, "8", "struct A(B);", quote::quote! {});
 
         assert_compare!(
-            generate_debug, "32", "#[derive(Debug)] struct A { #[field(0, 16)] r#b: u16 }",
+            generate_from_str, "8", "#[derive(Display)] struct A(#[field(bit = 2)] bool);",
             quote::quote! {
-                impl ::core::fmt::Debug for A {
+                #[doc = " The error type returned when parsing [`A`] with `core::str::FromStr` fails."]
+                #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+                struct AParseError {
+                    position: usize,
+                    token: [u8; 32],
+                    length: u8
+                }
+
+                impl AParseError {
+                    /// Returns the token that could not be parsed, truncated to 32 bytes.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    fn token(&self) -> &str {
+                        ::core::str::from_utf8(&self.token[..self.length as usize]).unwrap_or_default()
+                    }
+
+                    /// Returns the byte position of the token in the parsed string.
+                    #[allow(unused)]
+                    #[inline(always)]
+                    const fn position(&self) -> usize {
+                        self.position
+                    }
+                }
+
+                impl ::core::fmt::Display for AParseError {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let mut s = f.debug_struct(::core::stringify!(A));
+                        ::core::write!(f, "invalid token `{}` at position {}", self.token(), self.position)
+                    }
+                }
 
-                        let value = self.r#b();
-                        s.field(::core::stringify!(b), &value);
+                impl ::core::str::FromStr for A {
+                    type Err = AParseError;
 
-                        s.finish()
+                    #[allow(unused_mut)]
+                    fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                        /// Compares the output of `arguments` with a token, without allocating.
+                        struct Matcher<'a>(&'a str);
+
+                        impl<'a> ::core::fmt::Write for Matcher<'a> {
+                            fn write_str(&mut self, s: &str) -> ::core::fmt::Result {
+                                match self.0.strip_prefix(s) {
+                                    ::core::option::Option::Some(rest) => { self.0 = rest; ::core::fmt::Result::Ok(()) },
+                                    ::core::option::Option::None => ::core::fmt::Result::Err(::core::fmt::Error)
+                                }
+                            }
+                        }
+
+                        #[allow(unused)]
+                        fn matches(token: &str, arguments: ::core::fmt::Arguments) -> bool {
+                            let mut matcher = Matcher(token);
+                            ::core::fmt::write(&mut matcher, arguments).is_ok() && matcher.0.is_empty()
+                        }
+
+                        /// Parses a decimal, or a `0x`, `0o` or `0b` prefixed numeric literal.
+                        #[allow(unused)]
+                        fn number(token: &str) -> ::core::option::Option<u8> {
+                            let (digits, radix) = if let ::core::option::Option::Some(digits) = token.strip_prefix("0x") {
+                                (digits, 16)
+                            } else if let ::core::option::Option::Some(digits) = token.strip_prefix("0o") {
+                                (digits, 8)
+                            } else if let ::core::option::Option::Some(digits) = token.strip_prefix("0b") {
+                                (digits, 2)
+                            } else {
+                                (token, 10)
+                            };
+
+                            u8::from_str_radix(digits, radix).ok()
+                        }
+
+                        // Keeps a copy of the token, truncated at a character boundary.
+                        let error = |token: &str| {
+                            let mut length = token.len().min(32);
+                        
+                            while !token.is_char_boundary(length) {
+                                length -= 1;
+                            }
+                        
+                            let mut bytes = [0; 32];
+                            bytes[..length].copy_from_slice(&token.as_bytes()[..length]);
+                        
+                            AParseError {
+                                position: token.as_ptr() as usize - s.as_ptr() as usize,
+                                token: bytes,
+                                length: length as u8
+                            }
+                        };
+
+                        let mut result: u8 = 0;
+
+                        let token = s.trim();
+                        let value: u8 = match token {
+                            "false" => 0,
+                            "true" => 1,
+                            _ => return ::core::result::Result::Err(error(token))
+                        };
+                        result |= value << 2u8;
+
+                        ::core::result::Result::Ok(Self(result))
                     }
                 }
             }
@@ -5496,201 +9319,502 @@ mod tests {
     }
 
     #[test]
-    fn display() {
-        assert_compare!(generate_display, "8", "struct A(B);", quote::quote! {});
+    fn radix() {
+        assert_compare!(generate_radix, "8", "struct A(B);", quote::quote! {});
 
-        assert_compare!(generate_display, "8", "#[derive(Display)] struct A(B);", quote::quote! {
-            impl ::core::fmt::Display for A {
+        assert_compare!(generate_radix, "8", "#[derive(LowerHex, UpperHex, Octal)] struct A(B);", quote::quote! {
+            impl ::core::fmt::LowerHex for A {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                    let mut flags = alloc::vec::Vec::new();
+                    ::core::fmt::LowerHex::fmt(&self.0, f)
+                }
+            }
 
-                    for flag in <B>::iter() {
-                        if self.has(*flag) {
-                            flags.push(alloc::format!("{:?}", flag));
+            impl ::core::fmt::UpperHex for A {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::UpperHex::fmt(&self.0, f)
+                }
+            }
+
+            impl ::core::fmt::Octal for A {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::Octal::fmt(&self.0, f)
+                }
+            }
+        });
+
+        assert_compare!(generate_radix, "NonZero8", "#[derive(LowerHex)] struct A(B);", quote::quote! {
+            impl ::core::fmt::LowerHex for A {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::LowerHex::fmt(&self.0.get(), f)
+                }
+            }
+        });
+
+        assert_compare!(generate_radix, "16", "#[derive(Binary)] struct A { #[field(1, 3)] b: B, c: C }", quote::quote! {
+            impl ::core::fmt::Binary for A {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    let value = self.0;
+
+                    if !f.alternate() {
+                        return ::core::fmt::Binary::fmt(&value, f);
+                    }
+
+                    let mut boundaries: u16 = 0;
+
+                    boundaries |= u16::checked_shl(1, 1u32).unwrap_or(0);
+                    boundaries |= u16::checked_shl(1, 4u32).unwrap_or(0);
+
+                    {
+                        for flag in <C>::iter() {
+                            let bit = *flag as u32;
+
+                            boundaries |= u16::checked_shl(1, bit).unwrap_or(0);
+                            boundaries |= u16::checked_shl(1, bit + 1).unwrap_or(0);
                         }
                     }
 
-                    let flags = flags.join(" | ");
+                    f.write_str("0b")?;
 
-                    f.write_str(
-                        if flags.len() > 0 { &flags } else { "-" }
-                    )
+                    let bits = (::core::mem::size_of::<u16>() * 8) as u32;
+                    let mut i = bits;
+                    while i > 0 {
+                        i -= 1;
+
+                        if i + 1 < bits && (boundaries >> (i + 1)) & 1 != 0 {
+                            f.write_str("|")?;
+                        }
+
+                        f.write_str(if (value >> i) & 1 != 0 { "1" } else { "0" })?;
+                    }
+
+                    ::core::fmt::Result::Ok(())
                 }
             }
         });
+    }
 
-        assert_compare!(
-            generate_display, "8", "#[derive(Display)] struct A(#[field(0, 1)] B);", quote::quote! {
-                impl ::core::fmt::Display for A {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let value = self.get();
-                        if let ::core::result::Result::Ok(value) = value {
-                            f.write_str(&alloc::format!("{:?}", value))
-                        } else {
-                            f.write_str(&alloc::format!("{:?}", value))
+    #[test]
+    fn atomic() {
+        assert_compare!(generate_atomic, "8", "struct A(B);", quote::quote!());
+
+        assert_compare!(generate_atomic, "NonZero8, atomic", "struct A(B);", quote::quote! {
+            #[doc = " A [`A`] which can be safely shared between threads."]
+            #[repr(transparent)]
+            struct AtomicA(::core::sync::atomic::AtomicU8);
+
+            impl AtomicA {
+                /// Creates a new atomic bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn new(value: A) -> Self {
+                    Self(::core::sync::atomic::AtomicU8::new(value.0.get()))
+                }
+
+                /// Consumes the atomic bit field and returns the contained bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn into_inner(self) -> A {
+                    let value = self.0.into_inner();
+                    A(unsafe { ::core::num::NonZeroU8::new_unchecked(value) })
+                }
+
+                /// Loads the bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn load(&self, order: ::core::sync::atomic::Ordering) -> A {
+                    let value = self.0.load(order);
+                    A(unsafe { ::core::num::NonZeroU8::new_unchecked(value) })
+                }
+
+                /// Stores the bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn store(&self, value: A, order: ::core::sync::atomic::Ordering) {
+                    self.0.store(value.0.get(), order);
+                }
+
+                /// Stores the bit field and returns the previous bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn swap(&self, value: A, order: ::core::sync::atomic::Ordering) -> A {
+                    let value = self.0.swap(value.0.get(), order);
+                    A(unsafe { ::core::num::NonZeroU8::new_unchecked(value) })
+                }
+
+                /// Stores `new` if the current bit field is `current` and returns the previous bit
+                /// field in `Ok` on success, or in `Err` otherwise.
+                #[allow(unused)]
+                #[inline(always)]
+                fn compare_exchange(
+                    &self,
+                    current: A,
+                    new: A,
+                    success: ::core::sync::atomic::Ordering,
+                    failure: ::core::sync::atomic::Ordering
+                ) -> ::core::result::Result<A, A> {
+                    self.0.compare_exchange(current.0.get(), new.0.get(), success, failure).map(
+                        |value| A(unsafe { ::core::num::NonZeroU8::new_unchecked(value) })
+                    ).map_err(|value| A(unsafe { ::core::num::NonZeroU8::new_unchecked(value) }))
+                }
+
+                /// Stores the bit field returned by `f` until no other thread modified the bit field
+                /// in between, or `f` returns `None`. Returns the previous bit field in `Ok` on
+                /// success, or in `Err` otherwise.
+                #[allow(unused)]
+                #[inline(always)]
+                fn fetch_update<F: ::core::ops::FnMut(A) -> ::core::option::Option<A>>(
+                    &self,
+                    set_order: ::core::sync::atomic::Ordering,
+                    fetch_order: ::core::sync::atomic::Ordering,
+                    mut f: F
+                ) -> ::core::result::Result<A, A> {
+                    self.0.fetch_update(set_order, fetch_order, |value| {
+                        f(A(unsafe { ::core::num::NonZeroU8::new_unchecked(value) })).map(|value| value.0.get())
+                    }).map(
+                        |value| A(unsafe { ::core::num::NonZeroU8::new_unchecked(value) })
+                    ).map_err(|value| A(unsafe { ::core::num::NonZeroU8::new_unchecked(value) }))
+                }
+
+                /// Sets the specified `flag` with `fetch_or` and returns the previous bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn fetch_set_flag(&self, flag: B, order: ::core::sync::atomic::Ordering) -> A {
+                    let value = self.0.fetch_or(1 << (flag as u8), order);
+                    A(unsafe { ::core::num::NonZeroU8::new_unchecked(value) })
+                }
+
+                /// Clears the specified `flag` with `fetch_update` and returns the previous
+                /// bit field in `Ok`, or in `Err` if clearing it would clear the last set bit.
+                #[allow(unused)]
+                #[inline(always)]
+                fn try_fetch_clear_flag(
+                    &self,
+                    flag: B,
+                    set_order: ::core::sync::atomic::Ordering,
+                    fetch_order: ::core::sync::atomic::Ordering
+                ) -> ::core::result::Result<A, A> {
+                    self.0.fetch_update(set_order, fetch_order, |value| {
+                        match value & !(1 << (flag as u8)) {
+                            0 => None,
+                            value => Some(value)
                         }
-                    }
+                    }).map(
+                        |value| A(unsafe { ::core::num::NonZeroU8::new_unchecked(value) })
+                    ).map_err(|value| A(unsafe { ::core::num::NonZeroU8::new_unchecked(value) }))
                 }
             }
-        );
+        });
 
-        assert_compare!(
-            generate_display, "8", "#[derive(Display)] struct A(#[field(0, 1, complete)] B);",
-            quote::quote! {
-                impl ::core::fmt::Display for A {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let value = self.get();
-                        f.write_str(&alloc::format!("{:?}", value))
-                    }
+        assert_compare!(generate_atomic, "size, atomic", "struct A { #[field(access = ro)] b: B, c: C }", quote::quote! {
+            #[doc = " A [`A`] which can be safely shared between threads."]
+            #[repr(transparent)]
+            struct AtomicA(::core::sync::atomic::AtomicUsize);
+
+            impl AtomicA {
+                /// Creates a new atomic bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn new(value: A) -> Self {
+                    Self(::core::sync::atomic::AtomicUsize::new(value.0))
+                }
+
+                /// Consumes the atomic bit field and returns the contained bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn into_inner(self) -> A {
+                    let value = self.0.into_inner();
+                    A(value)
+                }
+
+                /// Loads the bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn load(&self, order: ::core::sync::atomic::Ordering) -> A {
+                    let value = self.0.load(order);
+                    A(value)
+                }
+
+                /// Stores the bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn store(&self, value: A, order: ::core::sync::atomic::Ordering) {
+                    self.0.store(value.0, order);
+                }
+
+                /// Stores the bit field and returns the previous bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn swap(&self, value: A, order: ::core::sync::atomic::Ordering) -> A {
+                    let value = self.0.swap(value.0, order);
+                    A(value)
+                }
+
+                /// Stores `new` if the current bit field is `current` and returns the previous bit
+                /// field in `Ok` on success, or in `Err` otherwise.
+                #[allow(unused)]
+                #[inline(always)]
+                fn compare_exchange(
+                    &self,
+                    current: A,
+                    new: A,
+                    success: ::core::sync::atomic::Ordering,
+                    failure: ::core::sync::atomic::Ordering
+                ) -> ::core::result::Result<A, A> {
+                    self.0.compare_exchange(current.0, new.0, success, failure).map(
+                        |value| A(value)
+                    ).map_err(|value| A(value))
+                }
+
+                /// Stores the bit field returned by `f` until no other thread modified the bit field
+                /// in between, or `f` returns `None`. Returns the previous bit field in `Ok` on
+                /// success, or in `Err` otherwise.
+                #[allow(unused)]
+                #[inline(always)]
+                fn fetch_update<F: ::core::ops::FnMut(A) -> ::core::option::Option<A>>(
+                    &self,
+                    set_order: ::core::sync::atomic::Ordering,
+                    fetch_order: ::core::sync::atomic::Ordering,
+                    mut f: F
+                ) -> ::core::result::Result<A, A> {
+                    self.0.fetch_update(set_order, fetch_order, |value| {
+                        f(A(value)).map(|value| value.0)
+                    }).map(|value| A(value)).map_err(|value| A(value))
+                }
+
+                /// Sets the specified `flag` with `fetch_or` and returns the previous bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn fetch_set_c(&self, flag: C, order: ::core::sync::atomic::Ordering) -> A {
+                    let value = self.0.fetch_or(1 << (flag as usize), order);
+                    A(value)
+                }
+
+                /// Clears the specified `flag` with `fetch_and` and returns the previous bit
+                /// field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn fetch_clear_c(&self, flag: C, order: ::core::sync::atomic::Ordering) -> A {
+                    let value = self.0.fetch_and(!(1 << (flag as usize)), order);
+                    A(value)
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn register() {
+        assert_compare!(generate_register, "8", "struct A(bool);", quote::quote!());
+
+        assert_compare!(generate_register, "8, register", "struct A(bool);", quote::quote! {
+            #[doc = " A memory mapped register containing a [`A`]."]
+            #[repr(transparent)]
+            struct ARegister(*mut u8);
+
+            impl ARegister {
+                /// Creates a register which accesses the bit field at `address`.
+                ///
+                /// # Safety
+                ///
+                /// `address` must be non-null, properly aligned and valid for volatile reads and
+                /// writes as long as the register is used.
+                #[allow(unused)]
+                #[inline(always)]
+                const unsafe fn new(address: *mut u8) -> Self {
+                    Self(address)
+                }
+
+                /// Returns the address of the register.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn as_ptr(&self) -> *mut u8 {
+                    self.0
+                }
+
+                /// Reads the bit field with a single volatile read.
+                #[allow(unused)]
+                #[inline(always)]
+                fn read(&self) -> A {
+                    A(unsafe { ::core::ptr::read_volatile(self.0) })
+                }
+
+                /// Writes the bit field with a single volatile write.
+                #[allow(unused)]
+                #[inline(always)]
+                fn write(&self, value: A) {
+                    unsafe { ::core::ptr::write_volatile(self.0, value.0) }
+                }
+
+                /// Reads the bit field, modifies it with `f` and writes the result back, with
+                /// exactly one volatile read and one volatile write. Write-1-to-clear flags are
+                /// written back as returned by `f`.
+                #[allow(unused)]
+                #[inline(always)]
+                fn modify<F: ::core::ops::FnOnce(A) -> A>(&self, f: F) {
+                    self.write(f(self.read()));
+                }
+
+                #[doc = " Sets the field to the new value with a single volatile read-modify-write."]
+                #[allow(unused)]
+                #[inline(always)]
+                fn set(&self, value: bool) {
+                    let mut bitfield = self.read();
+                    bitfield.set_in_place(value);
+                    self.write(bitfield);
+                }
+
+                #[doc = " Inverts the value of the field with a single volatile read-modify-write."]
+                #[allow(unused)]
+                #[inline(always)]
+                fn invert(&self) {
+                    let mut bitfield = self.read();
+                    bitfield.invert_in_place();
+                    self.write(bitfield);
                 }
             }
-        );
+        });
+    }
 
-        assert_compare!(
-            generate_display, "8", "#[derive(Display)] struct A { #[field(0, 1)] b: B }",
-            quote::quote! {
-                impl ::core::fmt::Display for A {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let value = self.b();
+    #[test]
+    fn view() {
+        assert_compare!(generate_view, "8", "struct A(bool);", quote::quote!());
 
-                        if let ::core::result::Result::Ok(value) = value {
-                            f.write_str(&alloc::format!("{:?}", value))
-                        } else {
-                            f.write_str(&alloc::format!("{:?}", value))
-                        }
+        assert_compare!(generate_view, "16, view = \"be\"", "struct A(bool);", quote::quote! {
+            #[doc = " A view of a [`A`] over the first bytes of a byte slice, in big endian byte order."]
+            #[derive(Clone, Copy)]
+            struct AView<'a>(&'a [u8; ::core::mem::size_of::<u16>()]);
+
+            impl<'a> AView<'a> {
+                /// Creates a view over the first bytes of `bytes`, or returns `None` if `bytes` is too
+                /// short.
+                #[allow(unused)]
+                #[inline(always)]
+                fn new(bytes: &'a [u8]) -> ::core::option::Option<Self> {
+                    match bytes.get(..::core::mem::size_of::<u16>()) {
+                        Some(bytes) => ::core::convert::TryInto::try_into(bytes).ok().map(Self),
+                        None => None
                     }
                 }
-            }
-        );
 
-        assert_compare!(
-            generate_display, "8", "#[derive(Display)] struct A { #[field(0, 1, complete)] b: B }",
-            quote::quote! {
-                impl ::core::fmt::Display for A {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let value = self.b();
-                        f.write_str(&alloc::format!("{:?}", value))
+                /// Creates a view over the bytes of `bytes` starting at `offset`, or returns `None`
+                /// if `bytes` is too short.
+                #[allow(unused)]
+                #[inline(always)]
+                fn new_at(bytes: &'a [u8], offset: usize) -> ::core::option::Option<Self> {
+                    match bytes.get(offset..) {
+                        Some(bytes) => Self::new(bytes),
+                        None => None
                     }
                 }
-            }
-        );
-
-        assert_compare!(
-            generate_display, "8", "#[derive(Display)] struct A { b: super::B }", quote::quote! {
-                impl ::core::fmt::Display for A {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let mut flags = alloc::vec::Vec::new();
 
-                        for flag in <super::B>::iter() {
-                            if self.b(*flag) {
-                                flags.push(alloc::format!("{:?}", flag));
-                            }
-                        }
+                /// Decodes the bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn read(&self) -> A {
+                    A(u16::from_be_bytes(*self.0))
+                }
 
-                        let flags = flags.join(" | ");
+                /// Decodes the bit field and drops the view.
+                #[allow(unused)]
+                #[inline(always)]
+                fn into_owned(self) -> A {
+                    self.read()
+                }
 
-                        f.write_str(
-                            if flags.len() > 0 { &flags } else { "-" }
-                        )
-                    }
+                /// Gets the value of the field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn get(&self) -> bool {
+                    self.read().get()
                 }
             }
-        );
 
-        assert_compare!(
-            generate_display, "8", "#[derive(Display)] struct A { b: B, c: C }", quote::quote! {
-                impl ::core::fmt::Display for A {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let mut flags = alloc::vec::Vec::new();
+            #[doc = " A mutable view of a [`A`] over the first bytes of a byte slice, in big endian byte order."]
+            struct AViewMut<'a>(&'a mut [u8; ::core::mem::size_of::<u16>()]);
 
-                        for flag in <B>::iter() {
-                            if self.b(*flag) {
-                                flags.push(alloc::format!(
-                                    "{}::{:?}", ::core::any::type_name::<B>(), flag
-                                ));
-                            }
-                        }
+            impl<'a> AViewMut<'a> {
+                /// Creates a mutable view over the first bytes of `bytes`, or returns `None` if
+                /// `bytes` is too short.
+                #[allow(unused)]
+                #[inline(always)]
+                fn new(bytes: &'a mut [u8]) -> ::core::option::Option<Self> {
+                    match bytes.get_mut(..::core::mem::size_of::<u16>()) {
+                        Some(bytes) => ::core::convert::TryInto::try_into(bytes).ok().map(Self),
+                        None => None
+                    }
+                }
 
-                        for flag in <C>::iter() {
-                            if self.c(*flag) {
-                                flags.push(alloc::format!(
-                                    "{}::{:?}", ::core::any::type_name::<C>(), flag
-                                ));
-                            }
-                        }
+                /// Creates a mutable view over the bytes of `bytes` starting at `offset`, or returns
+                /// `None` if `bytes` is too short.
+                #[allow(unused)]
+                #[inline(always)]
+                fn new_at(bytes: &'a mut [u8], offset: usize) -> ::core::option::Option<Self> {
+                    match bytes.get_mut(offset..) {
+                        Some(bytes) => Self::new(bytes),
+                        None => None
+                    }
+                }
 
-                        let flags = flags.join(" | ");
+                /// Decodes the bit field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn read(&self) -> A {
+                    A(u16::from_be_bytes(*self.0))
+                }
 
-                        f.write_str(
-                            if flags.len() > 0 { &flags } else { "-" }
-                        )
-                    }
+                /// Decodes the bit field and drops the view.
+                #[allow(unused)]
+                #[inline(always)]
+                fn into_owned(self) -> A {
+                    self.read()
                 }
-            }
-        );
 
-        assert_compare!(
-            generate_display, "8", "#[derive(Display)] struct A(#[field(0, 1)] bool);",
-            quote::quote! {
-                impl ::core::fmt::Display for A {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let value = self.get();
-                        f.write_str(&alloc::format!("{:?}", value))
-                    }
+                /// Encodes the bit field into the bytes.
+                #[allow(unused)]
+                #[inline(always)]
+                fn write(&mut self, value: A) {
+                    *self.0 = value.0.to_be_bytes();
                 }
-            }
-        );
 
-        assert_compare!(
-            generate_display, "32", "#[derive(Display)] struct A(#[field(0, 16)] u16);",
-            quote::quote! {
-                impl ::core::fmt::Display for A {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let value = self.get();
-                        f.write_str(&alloc::format!("{:?}", value))
-                    }
+                /// Gets the value of the field.
+                #[allow(unused)]
+                #[inline(always)]
+                fn get(&self) -> bool {
+                    self.read().get()
                 }
-            }
-        );
 
-        assert_compare!(
-            generate_display, "8", "#[derive(Display)] struct A { #[field(0, 1)] b: bool }",
-            quote::quote! {
-                impl ::core::fmt::Display for A {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let value = self.b();
-                        f.write_str(&alloc::format!("{:?}", value))
-                    }
+                #[doc = " Sets the field to the new value."]
+                #[allow(unused)]
+                #[inline(always)]
+                fn set(&mut self, value: bool) {
+                    let mut bitfield = self.read();
+                    bitfield.set_in_place(value);
+                    self.write(bitfield);
                 }
-            }
-        );
 
-        assert_compare!(
-            generate_display, "32", "#[derive(Display)] struct A { #[field(0, 16)] b: u16 }",
-            quote::quote! {
-                impl ::core::fmt::Display for A {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                        let value = self.b();
-                        f.write_str(&alloc::format!("{:?}", value))
-                    }
+                #[doc = " Inverts the value of the field."]
+                #[allow(unused)]
+                #[inline(always)]
+                fn invert(&mut self) {
+                    let mut bitfield = self.read();
+                    bitfield.invert_in_place();
+                    self.write(bitfield);
                 }
             }
-        );
+        });
     }
 
     #[test]
-    #[should_panic]
-    fn display_empty() {
-        assert_compare!(generate_display, "8", "#[derive(Display)] struct A {}", quote::quote! {});
-    }
+    fn c_header() {
+        assert_compare!(generate_c_header, "8", "struct A(bool);", quote::quote!());
 
-    #[test]
-    #[should_panic]
-    fn display_non_flags() {
-        assert_compare!(
-            generate_display, "8", "#[derive(Display)] struct A { b: B, #[field(0, 1)] c: C }",
-            quote::quote! {}
-        );
+        assert_compare!(generate_c_header, "8, c_header", "pub struct A(bool);", quote::quote! {
+            impl A {
+                /// Describes the layout of the bit field in C, with `#define`s for the shift and
+                /// mask of each field and `static inline` accessors.
+                #[allow(unused)]
+                pub const C_HEADER: &'static str = "#include <stdbool.h>\n#include <stdint.h>\n\n/* A */\n#define A_SHIFT 0\n#define A_MASK 0x1u\nstatic inline bool a_get(uint8_t value) { return ((value & A_MASK) >> A_SHIFT) != 0; }\nstatic inline uint8_t a_set(uint8_t value, bool field) { return (uint8_t)((value & ~(uint8_t)A_MASK) | (((uint8_t)field << A_SHIFT) & A_MASK)); }\n";
+            }
+        });
     }
 
     #[test]
@@ -5706,6 +9830,17 @@ mod tests {
             }
         });
         assert_compare!(generate_impl, "NonZero8", "struct A(A);", quote::quote! {});
+
+        assert_compare!(generate_impl, "8", "struct A<'a, T: B, const N: usize>(A) where T: C;", quote::quote! {
+            impl<'a, T: B, const N: usize> A<'a, T, N> where T: C {
+                /// Creates a new instance with all flags and fields cleared.
+                #[allow(unused)]
+                #[inline(always)]
+                const fn new() -> Self {
+                    Self(0, ::core::marker::PhantomData)
+                }
+            }
+        });
     }
 
     #[test]
@@ -5768,8 +9903,30 @@ mod tests {
         });
     }
 
+    #[test]
+    fn struct_generics() {
+        assert_compare!(generate_struct, "8", "struct A<T>(A);", quote::quote! {
+            #[repr(transparent)]
+            struct A<T>(u8, ::core::marker::PhantomData<(fn() -> T,)>);
+        });
+
+        assert_compare!(generate_struct, "8", "struct A<'a, T: B, U>(A) where U: C;", quote::quote! {
+            #[repr(transparent)]
+            struct A<'a, T: B, U>(u8, ::core::marker::PhantomData<(&'a (), fn() -> T, fn() -> U,)>) where U: C;
+        });
+
+        // Const parameters do not need a marker.
+        assert_compare!(generate_struct, "8", "struct A<const N: usize>(A);", quote::quote! {
+            #[repr(transparent)]
+            struct A<const N: usize>(u8);
+        });
+    }
+
     #[test]
     fn everything() {
+        let (b_c, b_c_message) = overlap_arms!("16", "/** D1 */ #[derive(Debug)] pub(crate) struct A { /** D2 */ pub(crate) b: B, /** D3 */ #[field(7, 3)] pub c: C, /** D4 */ d: D }", 0, "b", field "c", 7, 3);
+        let (b_d, b_d_message) = overlap_arms!("16", "/** D1 */ #[derive(Debug)] pub(crate) struct A { /** D2 */ pub(crate) b: B, /** D3 */ #[field(7, 3)] pub c: C, /** D4 */ d: D }", 0, "b", flags "d");
+        let (d_c, d_c_message) = overlap_arms!("16", "/** D1 */ #[derive(Debug)] pub(crate) struct A { /** D2 */ pub(crate) b: B, /** D3 */ #[field(7, 3)] pub c: C, /** D4 */ d: D }", 2, "d", field "c", 7, 3);
         assert_eq!(
             Into::<proc_macro2::TokenStream>::into(parse_valid!(
                 "16", "/** D1 */ #[derive(Debug)] pub(crate) struct A { /** D2 */ pub(crate) r#b: B, /** D3 */ #[field(7, 3)] pub r#c: C, /** D4 */ d: D }"
@@ -5934,22 +10091,12 @@ mod tests {
                     #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                     #[allow(unused)]
                     #[inline(always)]
-                    pub fn r#c(&self) -> ::core::result::Result<C, u8> {
-                        #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                        #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                        impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                            fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                                <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                            }
-                        }
-                        impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                            fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                                <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                            }
+                    pub const fn r#c(&self) -> ::core::result::Result<C, u8> {
+                        let value = self._field(7u8, 3u8);
+                        match C::from_repr(value as _) {
+                            ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                            ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                         }
-
-                        C::_bitfield_extract_primitive(self._field(7u8, 3u8) as _).map_err(|e| e as u8)
                     }
 
                     #[doc = " D3 "]
@@ -6041,6 +10188,83 @@ mod tests {
                     }
                 }
 
+                    // accessors_in_place
+                    impl A {
+                        #[doc = " D2 "]
+                        #[doc = " Sets the specified `flag` to the new value."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        pub(crate) const fn set_b_in_place(&mut self, flag: B, value: bool) {
+                            *self = self.set_b(flag, value);
+                        }
+
+                        #[doc = " D2 "]
+                        /// Sets the specified `flag`.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        pub(crate) const fn insert_b(&mut self, flag: B) {
+                            let result = self.0 | (1 << (flag as u16));
+                            *self = Self(result);
+                        }
+
+                        #[doc = " D2 "]
+                        #[doc = " Clears the specified `flag`."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        pub(crate) const fn remove_b(&mut self, flag: B) {
+                            *self = self.set_b(flag, false);
+                        }
+
+                        #[doc = " D2 "]
+                        #[doc = " Inverts the value of the specified `flag`."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        pub(crate) const fn toggle_b(&mut self, flag: B) {
+                            *self = self.invert_b(flag);
+                        }
+
+                        #[doc = " D3 "]
+                        #[doc = " Sets the field to the new value."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        pub const fn set_c_in_place(&mut self, value: C) {
+                            *self = self.set_c(value);
+                        }
+
+                        #[doc = " D4 "]
+                        #[doc = " Sets the specified `flag` to the new value."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        const fn set_d_in_place(&mut self, flag: D, value: bool) {
+                            *self = self.set_d(flag, value);
+                        }
+
+                        #[doc = " D4 "]
+                        /// Sets the specified `flag`.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        const fn insert_d(&mut self, flag: D) {
+                            let result = self.0 | (1 << (flag as u16));
+                            *self = Self(result);
+                        }
+
+                        #[doc = " D4 "]
+                        #[doc = " Clears the specified `flag`."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        const fn remove_d(&mut self, flag: D) {
+                            *self = self.set_d(flag, false);
+                        }
+
+                        #[doc = " D4 "]
+                        #[doc = " Inverts the value of the specified `flag`."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        const fn toggle_d(&mut self, flag: D) {
+                            *self = self.invert_d(flag);
+                        }
+                    }
+
                 // accessors ops flags
                 impl ::core::ops::Add<B> for A {
                     type Output = Self;
@@ -6159,35 +10383,35 @@ mod tests {
                         } as usize { 0 } else { panic!("Flags exceed the bitfield size") }
                     ] = [];
 
-                    const fn _flags_in_field_0_overlap_with_field_1() -> bool {
+                    const fn _flags_in_field_0_overlap_with_field_1() -> u8 {
                         let flags = B::iter();
 
                         let mut i = 0;
                         while i < flags.len() {
                             let flag = flags[i] as u8;
                             if flag >= 7u8 && flag < 7u8 + 3u8 {
-                                return true;
+                                return flag;
                             }
 
                             i += 1;
                         }
 
-                        false
+                        u8::MAX
                     }
 
                     const _FLAGS_IN_FIELD_0_OVERLAP_WITH_FIELD_1: [();
-                        if !Self::_flags_in_field_0_overlap_with_field_1() { 0 } else { panic!("Flags in field \"b\" overlap with field \"c\"") }
+                        match Self::_flags_in_field_0_overlap_with_field_1() { u8::MAX => 0, #b_c _ => panic!(#b_c_message) }
                     ] = [];
 
-                    const fn _flags_in_field_0_overlap_with_flags_in_field_2() -> bool {
+                    const fn _flags_in_field_0_overlap_with_flags_in_field_2() -> u8 {
                         let f1 = B::iter();
                         let f2 = D::iter();
 
                         let mut i1 = 0;
                         while i1 < f1.len() {
                             let mut i2 = 0; while i2 < f2.len() {
-                                if (f1 [i1] as u32) == (f2 [i2] as u32) {
-                                    return true;
+                                if (f1 [i1] as u8) == (f2 [i2] as u8) {
+                                    return f1[i1] as u8;
                                 }
 
                                 i2 += 1;
@@ -6196,31 +10420,31 @@ mod tests {
                             i1 += 1;
                         }
 
-                        false
+                        u8::MAX
                     }
 
                     const _FLAGS_IN_FIELD_0_OVERLAP_WITH_FLAGS_IN_FIELD_2: [();
-                        if !Self::_flags_in_field_0_overlap_with_flags_in_field_2() { 0 } else { panic!("Flags in field \"b\" overlap with flags in field \"d\"") }
+                        match Self::_flags_in_field_0_overlap_with_flags_in_field_2() { u8::MAX => 0, #b_d _ => panic!(#b_d_message) }
                     ] = [];
 
-                    const fn _flags_in_field_2_overlap_with_field_1() -> bool {
+                    const fn _flags_in_field_2_overlap_with_field_1() -> u8 {
                         let flags = D::iter();
 
                         let mut i = 0;
                         while i < flags.len() {
                             let flag = flags[i] as u8;
                             if flag >= 7u8 && flag < 7u8 + 3u8 {
-                                return true;
+                                return flag;
                             }
 
                             i += 1;
                         }
 
-                        false
+                        u8::MAX
                     }
 
                     const _FLAGS_IN_FIELD_2_OVERLAP_WITH_FIELD_1: [();
-                        if !Self::_flags_in_field_2_overlap_with_field_1() { 0 } else { panic!("Flags in field \"d\" overlap with field \"c\"") }
+                        match Self::_flags_in_field_2_overlap_with_field_1() { u8::MAX => 0, #d_c _ => panic!(#d_c_message) }
                     ] = [];
                 }
 
@@ -6237,7 +10461,7 @@ mod tests {
                                     let mut s = f.debug_struct(::core::stringify!(B));
 
                                     for flag in <B>::iter() {
-                                        s.field(&alloc::format!("{:?}", flag), &self.0.r#b(*flag));
+                                        s.field(flag._bitfield_flag_name(), &self.0.r#b(*flag));
                                     }
 
                                     s.finish()
@@ -6262,7 +10486,7 @@ mod tests {
                                     let mut s = f.debug_struct(::core::stringify!(D));
 
                                     for flag in <D>::iter() {
-                                        s.field(&alloc::format!("{:?}", flag), &self.0.d(*flag));
+                                        s.field(flag._bitfield_flag_name(), &self.0.d(*flag));
                                     }
 
                                     s.finish()
@@ -6277,6 +10501,9 @@ mod tests {
                 }
             }.to_string()
         );
+        let (b_c, b_c_message) = overlap_arms!("NonZero16", "/** D1 */ #[derive(Debug)] pub(crate) struct A { /** D2 */ pub(crate) b: B, /** D3 */ #[field(7, 3)] pub c: C, /** D4 */ d: D }", 0, "b", field "c", 7, 3);
+        let (b_d, b_d_message) = overlap_arms!("NonZero16", "/** D1 */ #[derive(Debug)] pub(crate) struct A { /** D2 */ pub(crate) b: B, /** D3 */ #[field(7, 3)] pub c: C, /** D4 */ d: D }", 0, "b", flags "d");
+        let (d_c, d_c_message) = overlap_arms!("NonZero16", "/** D1 */ #[derive(Debug)] pub(crate) struct A { /** D2 */ pub(crate) b: B, /** D3 */ #[field(7, 3)] pub c: C, /** D4 */ d: D }", 2, "d", field "c", 7, 3);
         assert_eq!(
             Into::<proc_macro2::TokenStream>::into(parse_valid!(
                 "NonZero16", "/** D1 */ #[derive(Debug)] pub(crate) struct A { /** D2 */ pub(crate) b: B, /** D3 */ #[field(7, 3)] pub c: C, /** D4 */ d: D }"
@@ -6443,22 +10670,12 @@ mod tests {
                     #[doc = "Returns the primitive value encapsulated in the `Err` variant, if the value can not be converted to the expected type."]
                     #[allow(unused)]
                     #[inline(always)]
-                    pub fn c(&self) -> ::core::result::Result<C, u8> {
-                        #[allow(unused)] trait BitFieldExtractionSigned: Sized { fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8>; }
-                        #[allow(unused)] trait BitFieldExtractionUnsigned: Sized { fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8>; }
-
-                        impl<T> BitFieldExtractionSigned for T where T: ::core::convert::TryFrom<i8, Error = i8> {
-                            fn _bitfield_extract_primitive(value: i8) -> ::core::result::Result<Self, i8> {
-                                <T as ::core::convert::TryFrom<i8>>::try_from(value)
-                            }
-                        }
-                        impl <T> BitFieldExtractionUnsigned for T where T: ::core::convert::TryFrom<u8, Error = u8> {
-                            fn _bitfield_extract_primitive(value: u8) -> ::core::result::Result<Self, u8> {
-                                <T as ::core::convert::TryFrom<u8>>::try_from(value)
-                            }
+                    pub const fn c(&self) -> ::core::result::Result<C, u8> {
+                        let value = self._field(7u8, 3u8);
+                        match C::from_repr(value as _) {
+                            ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                            ::core::option::Option::None => ::core::result::Result::Err(value as u8)
                         }
-
-                        C::_bitfield_extract_primitive(self._field(7u8, 3u8) as _).map_err(|e| e as u8)
                     }
 
                     #[doc = " D3 "]
@@ -6553,6 +10770,139 @@ mod tests {
                     }
                 }
 
+                    // accessors_in_place
+                    impl A {
+                        #[doc = " D2 "]
+                        #[doc = " Sets the specified `flag` to the new value."]
+                        #[doc = ""]
+                        #[doc = " Returns the rejected value in `Err` and leaves the bit field unmodified, if all bits of the bit field would be cleared."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        pub(crate) const fn try_set_b_in_place(&mut self, flag: B, value: bool) -> ::core::result::Result<(), B> {
+                            match self.set_b(flag, value) {
+                                ::core::option::Option::Some(result) => {
+                                    *self = result;
+                                    ::core::result::Result::Ok(())
+                                },
+                                ::core::option::Option::None => ::core::result::Result::Err(flag)
+                            }
+                        }
+
+                        #[doc = " D2 "]
+                        /// Sets the specified `flag`.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        pub(crate) const fn insert_b(&mut self, flag: B) {
+                            let result = self.0.get() | (1 << (flag as u16));
+                            *self = Self(unsafe { ::core::num::NonZeroU16::new_unchecked(result) });
+                        }
+
+                        #[doc = " D2 "]
+                        #[doc = " Clears the specified `flag`."]
+                        #[doc = ""]
+                        #[doc = " Returns the rejected value in `Err` and leaves the bit field unmodified, if all bits of the bit field would be cleared."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        pub(crate) const fn try_remove_b(&mut self, flag: B) -> ::core::result::Result<(), B> {
+                            match self.set_b(flag, false) {
+                                ::core::option::Option::Some(result) => {
+                                    *self = result;
+                                    ::core::result::Result::Ok(())
+                                },
+                                ::core::option::Option::None => ::core::result::Result::Err(flag)
+                            }
+                        }
+
+                        #[doc = " D2 "]
+                        #[doc = " Inverts the value of the specified `flag`."]
+                        #[doc = ""]
+                        #[doc = " Returns the rejected value in `Err` and leaves the bit field unmodified, if all bits of the bit field would be cleared."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        pub(crate) const fn try_toggle_b(&mut self, flag: B) -> ::core::result::Result<(), B> {
+                            match self.invert_b(flag) {
+                                ::core::option::Option::Some(result) => {
+                                    *self = result;
+                                    ::core::result::Result::Ok(())
+                                },
+                                ::core::option::Option::None => ::core::result::Result::Err(flag)
+                            }
+                        }
+
+                        #[doc = " D3 "]
+                        #[doc = " Sets the field to the new value."]
+                        #[doc = ""]
+                        #[doc = " Returns the rejected value in `Err` and leaves the bit field unmodified, if all bits of the bit field would be cleared."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        pub const fn try_set_c_in_place(&mut self, value: C) -> ::core::result::Result<(), C> {
+                            match self.set_c(value) {
+                                ::core::option::Option::Some(result) => {
+                                    *self = result;
+                                    ::core::result::Result::Ok(())
+                                },
+                                ::core::option::Option::None => ::core::result::Result::Err(value)
+                            }
+                        }
+
+                        #[doc = " D4 "]
+                        #[doc = " Sets the specified `flag` to the new value."]
+                        #[doc = ""]
+                        #[doc = " Returns the rejected value in `Err` and leaves the bit field unmodified, if all bits of the bit field would be cleared."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        const fn try_set_d_in_place(&mut self, flag: D, value: bool) -> ::core::result::Result<(), D> {
+                            match self.set_d(flag, value) {
+                                ::core::option::Option::Some(result) => {
+                                    *self = result;
+                                    ::core::result::Result::Ok(())
+                                },
+                                ::core::option::Option::None => ::core::result::Result::Err(flag)
+                            }
+                        }
+
+                        #[doc = " D4 "]
+                        /// Sets the specified `flag`.
+                        #[allow(unused)]
+                        #[inline(always)]
+                        const fn insert_d(&mut self, flag: D) {
+                            let result = self.0.get() | (1 << (flag as u16));
+                            *self = Self(unsafe { ::core::num::NonZeroU16::new_unchecked(result) });
+                        }
+
+                        #[doc = " D4 "]
+                        #[doc = " Clears the specified `flag`."]
+                        #[doc = ""]
+                        #[doc = " Returns the rejected value in `Err` and leaves the bit field unmodified, if all bits of the bit field would be cleared."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        const fn try_remove_d(&mut self, flag: D) -> ::core::result::Result<(), D> {
+                            match self.set_d(flag, false) {
+                                ::core::option::Option::Some(result) => {
+                                    *self = result;
+                                    ::core::result::Result::Ok(())
+                                },
+                                ::core::option::Option::None => ::core::result::Result::Err(flag)
+                            }
+                        }
+
+                        #[doc = " D4 "]
+                        #[doc = " Inverts the value of the specified `flag`."]
+                        #[doc = ""]
+                        #[doc = " Returns the rejected value in `Err` and leaves the bit field unmodified, if all bits of the bit field would be cleared."]
+                        #[allow(unused)]
+                        #[inline(always)]
+                        const fn try_toggle_d(&mut self, flag: D) -> ::core::result::Result<(), D> {
+                            match self.invert_d(flag) {
+                                ::core::option::Option::Some(result) => {
+                                    *self = result;
+                                    ::core::result::Result::Ok(())
+                                },
+                                ::core::option::Option::None => ::core::result::Result::Err(flag)
+                            }
+                        }
+                    }
+
                 // assertions
                 impl A {
                     const _FLAGS_IN_FIELD_0_MUST_BE_REPR_U8: [();
@@ -6607,35 +10957,35 @@ mod tests {
                         } as usize { 0 } else { panic!("Flags exceed the bitfield size") }
                     ] = [];
 
-                    const fn _flags_in_field_0_overlap_with_field_1() -> bool {
+                    const fn _flags_in_field_0_overlap_with_field_1() -> u8 {
                         let flags = B::iter();
 
                         let mut i = 0;
                         while i < flags.len() {
                             let flag = flags[i] as u8;
                             if flag >= 7u8 && flag < 7u8 + 3u8 {
-                                return true;
+                                return flag;
                             }
 
                             i += 1;
                         }
 
-                        false
+                        u8::MAX
                     }
 
                     const _FLAGS_IN_FIELD_0_OVERLAP_WITH_FIELD_1: [();
-                        if !Self::_flags_in_field_0_overlap_with_field_1() { 0 } else { panic!("Flags in field \"b\" overlap with field \"c\"") }
+                        match Self::_flags_in_field_0_overlap_with_field_1() { u8::MAX => 0, #b_c _ => panic!(#b_c_message) }
                     ] = [];
 
-                    const fn _flags_in_field_0_overlap_with_flags_in_field_2() -> bool {
+                    const fn _flags_in_field_0_overlap_with_flags_in_field_2() -> u8 {
                         let f1 = B::iter();
                         let f2 = D::iter();
 
                         let mut i1 = 0;
                         while i1 < f1.len() {
                             let mut i2 = 0; while i2 < f2.len() {
-                                if (f1 [i1] as u32) == (f2 [i2] as u32) {
-                                    return true;
+                                if (f1 [i1] as u8) == (f2 [i2] as u8) {
+                                    return f1[i1] as u8;
                                 }
 
                                 i2 += 1;
@@ -6644,31 +10994,31 @@ mod tests {
                             i1 += 1;
                         }
 
-                        false
+                        u8::MAX
                     }
 
                     const _FLAGS_IN_FIELD_0_OVERLAP_WITH_FLAGS_IN_FIELD_2: [();
-                        if !Self::_flags_in_field_0_overlap_with_flags_in_field_2() { 0 } else { panic!("Flags in field \"b\" overlap with flags in field \"d\"") }
+                        match Self::_flags_in_field_0_overlap_with_flags_in_field_2() { u8::MAX => 0, #b_d _ => panic!(#b_d_message) }
                     ] = [];
 
-                    const fn _flags_in_field_2_overlap_with_field_1() -> bool {
+                    const fn _flags_in_field_2_overlap_with_field_1() -> u8 {
                         let flags = D::iter();
 
                         let mut i = 0;
                         while i < flags.len() {
                             let flag = flags[i] as u8;
                             if flag >= 7u8 && flag < 7u8 + 3u8 {
-                                return true;
+                                return flag;
                             }
 
                             i += 1;
                         }
 
-                        false
+                        u8::MAX
                     }
 
                     const _FLAGS_IN_FIELD_2_OVERLAP_WITH_FIELD_1: [();
-                        if !Self::_flags_in_field_2_overlap_with_field_1() { 0 } else { panic!("Flags in field \"d\" overlap with field \"c\"") }
+                        match Self::_flags_in_field_2_overlap_with_field_1() { u8::MAX => 0, #d_c _ => panic!(#d_c_message) }
                     ] = [];
 
                     const _OPTION_OF_NON_ZERO_BITFIELD_HAS_A_DIFFERENT_SIZE: [();
@@ -6689,7 +11039,7 @@ mod tests {
                                     let mut s = f.debug_struct(::core::stringify!(B));
 
                                     for flag in <B>::iter() {
-                                        s.field(&alloc::format!("{:?}", flag), &self.0.b(*flag));
+                                        s.field(flag._bitfield_flag_name(), &self.0.b(*flag));
                                     }
 
                                     s.finish()
@@ -6714,7 +11064,7 @@ mod tests {
                                     let mut s = f.debug_struct(::core::stringify!(D));
 
                                     for flag in <D>::iter() {
-                                        s.field(&alloc::format!("{:?}", flag), &self.0.d(*flag));
+                                        s.field(flag._bitfield_flag_name(), &self.0.d(*flag));
                                     }
 
                                     s.finish()
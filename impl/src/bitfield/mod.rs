@@ -1,5 +1,7 @@
 //! Contains all data types to represent bit fields.
 
+use syn::{ext::IdentExt, spanned::Spanned};
+
 #[macro_use]
 pub(super) mod parse;
 pub(super) mod generate;
@@ -11,7 +13,50 @@ pub struct Attribute {
     /// `None` for `isize` and `usize`.
     pub bits: Option<u8>,
     pub is_non_zero: bool,
-    pub allow_overlaps: Option<syn::Ident>
+    pub allow_overlaps: Option<syn::Ident>,
+    /// Requires every bit to be used by a field, flags or reserved bits.
+    pub exhaustive: Option<syn::Ident>,
+    pub naming: Naming,
+    /// Generates a `#NAME Register` type for volatile access to memory mapped registers.
+    pub register: Option<syn::Ident>,
+    /// Generates an `Atomic#NAME` type for lock-free access from multiple threads.
+    pub atomic: Option<syn::Ident>,
+    /// Generates `#NAME View` and `#NAME ViewMut` types over byte slices.
+    pub view: Option<View>,
+    /// Generates a `C_HEADER` constant, which describes the layout in C.
+    pub c_header: Option<syn::Ident>,
+    /// Generates a `#NAME #FIELD` key type for every field and the `#NAME Key` trait.
+    pub keys: Option<syn::Ident>,
+    /// Generates a `#NAME Parts` struct with a member for every entry.
+    pub parts: Option<syn::Ident>
+}
+
+/// Stores the `view = "..."` option of the proc-macro attribute header.
+pub struct View {
+    /// Span of the option name. Used for error reporting.
+    pub span: proc_macro2::Span,
+    pub byte_order: ByteOrder
+}
+
+/// The byte order in which views decode and encode their bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ByteOrder {
+    /// `"le"`
+    LittleEndian,
+    /// `"be"`
+    BigEndian
+}
+
+/// The scheme used to name the generated accessors, selected with `naming = "..."`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Naming {
+    /// `x`, `set_x` and `invert_x`, or `get`/`has`, `set` and `invert` for tuple structs.
+    #[default]
+    Set,
+    /// `x`, `with_x` and `invert_x`, or `get`/`has`, `with` and `invert` for tuple structs.
+    With,
+    /// `get_x`/`has_x`, `set_x` and `invert_x`, or the same as `Set` for tuple structs.
+    GetSet
 }
 
 /// Stores all information about a bit field, which is parsed from a struct with named fields, or a
@@ -20,39 +65,116 @@ pub struct BitField {
     pub attr: Attribute,
     pub debug: Option<proc_macro2::Span>,
     pub display: Option<proc_macro2::Span>,
+    pub lower_hex: Option<proc_macro2::Span>,
+    pub upper_hex: Option<proc_macro2::Span>,
+    pub binary: Option<proc_macro2::Span>,
+    pub octal: Option<proc_macro2::Span>,
+    pub display_options: Option<DisplayOptions>,
     pub attrs: Vec<syn::Attribute>,
     pub vis: syn::Visibility,
     pub ident: syn::Ident,
+    /// Lifetime and type parameters are only used as zero-sized markers.
+    pub generics: syn::Generics,
     pub data: Data
 }
 
+/// Stores the spans of the `core::fmt::*` traits that were filtered from `#[derive(...)]`.
+#[derive(Default)]
+pub struct Derive {
+    pub debug: Option<proc_macro2::Span>,
+    pub display: Option<proc_macro2::Span>,
+    pub lower_hex: Option<proc_macro2::Span>,
+    pub upper_hex: Option<proc_macro2::Span>,
+    pub binary: Option<proc_macro2::Span>,
+    pub octal: Option<proc_macro2::Span>
+}
+
+/// Stores the options of the `#[display(...)]` attribute, which customize the generated
+/// `core::fmt::Display` implementation.
+pub struct DisplayOptions {
+    /// Span of the attribute name.
+    pub span: proc_macro2::Span,
+    pub template: Option<DisplayTemplate>,
+    /// Joins set flags, `" | "` if `None`.
+    pub separator: Option<syn::LitStr>,
+    /// Is displayed if no flag is set, `"-"` if `None`.
+    pub empty: Option<syn::LitStr>
+}
+
+/// Stores a parsed template string like `"{button}/{icon} [{style}]"`.
+pub struct DisplayTemplate {
+    pub lit: syn::LitStr,
+    pub segments: Vec<DisplaySegment>
+}
+
+/// A part of a `DisplayTemplate`.
+#[derive(Debug, Eq, PartialEq)]
+pub enum DisplaySegment {
+    /// Text that is written as is, with `{{` and `}}` already unescaped.
+    Text(String),
+    /// The name of an entry whose value is written.
+    Entry(String)
+}
+
 /// Stores the parsed data from either from a struct with named fields, or a tuple struct.
 /// The tuple struct only supports one tuple entry and it should be used for simple bit fields.
 pub enum Data {
     Named(Vec<EntryNamed>),
-    Tuple(Entry)
+    Tuple(Box<Entry>)
 }
 
 impl Data {
-    /// Easy access to all entries, regardless of the struct type.
+    /// Easy access to all entries with accessors, regardless of the struct type. Reserved entries
+    /// are skipped, see `layout`.
     pub fn entries(&self) -> Vec<&Entry> {
+        match self {
+            Self::Named(entries) => entries.iter().filter(
+                |e| e.entry.reserved.is_none()
+            ).map(
+                |e| &e.entry
+            ).collect(),
+
+            Self::Tuple(entry) => vec!(&**entry)
+        }
+    }
+
+    /// Access to all entries in the order of their declaration, including reserved entries.
+    pub fn layout(&self) -> Vec<&Entry> {
         match self {
             Self::Named(entries) => entries.iter().map(
                 |e| &e.entry
             ).collect(),
 
-            Self::Tuple(entry) => vec!(entry)
+            Self::Tuple(entry) => vec!(&**entry)
+        }
+    }
+
+    /// All named entries with accessors, aka. without reserved entries. Empty for tuple structs.
+    pub fn named(&self) -> Vec<&EntryNamed> {
+        match self {
+            Self::Named(entries) => entries.iter().filter(|e| e.entry.reserved.is_none()).collect(),
+            Self::Tuple(_) => vec!()
         }
     }
 
-    /// Easy, mutable access to all entries, regardless of the struct type.
+    /// Returns `true` if the entry at `index` in `entries` is an alternative of an entry before it,
+    /// see `EntryNamed::are_alternatives`. Alternatives generate the same names and implementations.
+    pub fn is_repeated(&self, index: usize) -> bool {
+        let named = self.named();
+
+        named.get(index).map(
+            |entry| named[..index].iter().any(|other| EntryNamed::are_alternatives(other, entry))
+        ).unwrap_or_default()
+    }
+
+    /// Easy, mutable access to all entries, including reserved entries.
     pub fn entries_mut(&mut self) -> Vec<&mut Entry> {
         match self {
             Self::Named(entries) => entries.iter_mut().map(
                 |e| &mut e.entry
             ).collect(),
 
-            Self::Tuple(entry) => vec!(entry)
+            Self::Tuple(entry) => vec!(&mut **entry)
         }
     }
 }
@@ -63,7 +185,341 @@ pub struct Entry {
     pub attrs: Vec<syn::Attribute>,
     pub vis: syn::Visibility,
     pub ty: syn::Path,
-    pub field: Option<FieldDetails>
+    pub field: Option<FieldDetails>,
+    pub names: AccessorNames,
+    pub access: Option<Access>,
+    /// `Some` if the builder requires the entry to be set before the bit field can be built, from
+    /// `#[field(required)]`.
+    pub required: Option<syn::Ident>,
+    pub groups: Option<Groups>,
+    /// `Some` for reserved bits, which have no accessors. Their type is the smallest unsigned
+    /// primitive type which fits the bits.
+    pub reserved: Option<Reserved>,
+    /// `true` for the copies of an entry created for `#[cfg_attr(..., field(...))]`. They are gated
+    /// with `#[cfg(...)]` and do not move the position of the following implicit fields.
+    pub is_alternative: bool
+}
+
+/// Stores the variant groups of an enumeration field from `#[field(groups(...))]`, which get
+/// predicates forwarding to the `is_#GROUP` methods generated by `bitfield::Field`.
+pub struct Groups {
+    /// Span of `groups`. Used for error reporting.
+    pub span: proc_macro2::Span,
+    pub idents: Vec<syn::Ident>
+}
+
+/// Stores the options of the `#[reserved(...)]` attribute.
+pub struct Reserved {
+    /// Span of the attribute name. Used for error reporting.
+    pub span: proc_macro2::Span,
+    pub policy: ReservedPolicy
+}
+
+/// Selects what setters do with reserved bits, with `#[reserved(..., policy = ...)]`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ReservedPolicy {
+    /// `preserve`, the bits keep their value.
+    #[default]
+    Preserve,
+    /// `zero`, every setter clears the bits.
+    Zero
+}
+
+/// Stores the access mode of an entry, from `#[field(access = ...)]`.
+pub struct Access {
+    /// Span of the access mode. Used for error reporting.
+    pub span: proc_macro2::Span,
+    pub mode: AccessMode
+}
+
+/// Restricts which accessors are generated for an entry.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessMode {
+    /// `rw`, all accessors are generated.
+    ReadWrite,
+    /// `ro`, no setters or inverters are generated.
+    ReadOnly,
+    /// `wo`, no getters or inverters are generated, and the entry is skipped by `Debug`.
+    WriteOnly,
+    /// `w1c`, only for flags, which are read like `ReadOnly` flags and cleared with `clear_x`.
+    WriteOneToClear
+}
+
+/// Stores the accessor names of an entry that were overridden with
+/// `#[field(get = ..., set = ..., invert = ...)]`.
+#[derive(Default)]
+pub struct AccessorNames {
+    pub getter: Option<syn::Ident>,
+    pub setter: Option<syn::Ident>,
+    pub inverter: Option<syn::Ident>
+}
+
+/// Stores the final names of the accessors of an entry, after applying the naming scheme and the
+/// overrides of the entry.
+pub struct Accessors {
+    /// The entry name for named structs, `None` for tuple structs.
+    pub name: Option<syn::Ident>,
+    pub getter: syn::Ident,
+    pub setter: syn::Ident,
+    pub inverter: syn::Ident
+}
+
+impl Accessors {
+    /// Creates a name derived from an accessor, e.g. `x` and `"mask"` become `x_mask`.
+    pub fn suffixed(ident: &syn::Ident, suffix: &str) -> syn::Ident {
+        syn::Ident::new(&format!("{}_{}", ident.unraw(), suffix), ident.span())
+    }
+
+    /// Creates the name of the predicate of a variant group, e.g. `read` becomes `x_is_read`, or
+    /// `is_read` for tuple structs.
+    pub fn predicate(&self, group: &syn::Ident) -> syn::Ident {
+        match &self.name {
+            Some(name) => syn::Ident::new(&format!("{}_is_{}", name.unraw(), group.unraw()), group.span()),
+            None => syn::Ident::new(&format!("is_{}", group.unraw()), group.span())
+        }
+    }
+
+    /// Creates a name derived from the entry name, e.g. `"insert"` becomes `insert_x`, or `insert`
+    /// for tuple structs.
+    pub fn prefixed(&self, prefix: &str) -> syn::Ident {
+        match &self.name {
+            Some(name) => syn::Ident::new(&format!("{}_{}", prefix, name.unraw()), name.span()),
+            None => syn::Ident::new(prefix, self.setter.span())
+        }
+    }
+}
+
+impl BitField {
+    /// Returns the accessor names of all entries, in the same order as `Data::entries`.
+    pub fn accessors(&self) -> Vec<Accessors> {
+        let naming = self.attr.naming;
+
+        let accessors = |entry: &Entry, name: Option<&syn::Ident>| {
+            let (getter, setter, inverter) = match name {
+                Some(name) => {
+                    let unraw = name.unraw();
+                    let ident = |prefix: &str| syn::Ident::new(&format!("{}_{}", prefix, unraw), name.span());
+
+                    (
+                        match naming {
+                            Naming::GetSet => ident(if entry.field.is_some() { "get" } else { "has" }),
+                            _ => name.clone()
+                        },
+                        ident(if naming == Naming::With { "with" } else { "set" }),
+                        ident("invert")
+                    )
+                },
+
+                None => {
+                    let ident = |name: &str| syn::Ident::new(name, entry.ty.span());
+
+                    (
+                        ident(if entry.field.is_some() { "get" } else { "has" }),
+                        ident(if naming == Naming::With { "with" } else { "set" }),
+                        ident("invert")
+                    )
+                }
+            };
+
+            Accessors {
+                name: name.cloned(),
+                getter: entry.names.getter.clone().unwrap_or(getter),
+                setter: entry.names.setter.clone().unwrap_or(setter),
+                inverter: entry.names.inverter.clone().unwrap_or(inverter)
+            }
+        };
+
+        match &self.data {
+            Data::Named(_) => self.data.named().into_iter().map(
+                |e| accessors(&e.entry, Some(&e.ident))
+            ).collect(),
+
+            Data::Tuple(entry) => vec!(accessors(entry, None))
+        }
+    }
+
+    /// Returns the name of the key type of a field, e.g. `A` and `default_button` become
+    /// `ADefaultButton`.
+    pub fn key_type(&self, name: &syn::Ident) -> syn::Ident {
+        let name = name.unraw().to_string().split('_').map(|part| {
+            let mut chars = part.chars();
+            chars.next().map(|first| first.to_ascii_uppercase().to_string() + chars.as_str()).unwrap_or_default()
+        }).collect::<String>();
+
+        syn::Ident::new(&format!("{}{}", self.ident.unraw(), name), self.ident.span())
+    }
+
+    /// Returns the bits which are not used by a field or reserved bits, so only flags can use them.
+    /// For `usize` based bit fields, these are the bits of the widest supported `usize`. Conditional
+    /// entries are skipped, as they do not exist in every configuration.
+    pub fn bits_without_field(&self) -> Vec<u8> {
+        let fields = self.data.layout().into_iter().filter(
+            |entry| !entry.is_conditional()
+        ).filter_map(|entry| entry.field.as_ref()).map(|field| (
+            field.bit.as_ref().unwrap().base10_parse::<u16>().unwrap(),
+            field.size.as_ref().unwrap().base10_parse::<u16>().unwrap()
+        )).collect::<Vec<_>>();
+
+        (0..self.attr.bits.unwrap_or(64)).filter(
+            |bit| !fields.iter().any(|(start, size)| *bit as u16 >= *start && (*bit as u16) < start + size)
+        ).collect()
+    }
+
+    /// Explains an overlap of the entry at `index` in the `layout` with another entry in additional
+    /// lines: the bits of both entries, the overlapping bits, a layout of all fields and the next
+    /// free position for the entry. `entry` and `other` are the quoted names and the bit ranges of
+    /// both entries, for flags only the overlapping bit is known. The layout and the position are
+    /// omitted for `usize` based bit fields, as their size depends on the target.
+    pub fn explain_overlap(&self, index: usize, entry: (&str, u8, u8), other: (&str, u8, u8)) -> String {
+        fn range(bit: u16, size: u16) -> String {
+            if size == 1 { format!("bit {}", bit) } else { format!("bits {}-{}", bit, bit + size - 1) }
+        }
+
+        let (entry_name, entry_bit, entry_size) = (entry.0, entry.1 as u16, entry.2 as u16);
+        let (other_name, other_bit, other_size) = (other.0, other.1 as u16, other.2 as u16);
+        let start = entry_bit.max(other_bit);
+        let end = (entry_bit + entry_size).min(other_bit + other_size);
+
+        let mut lines = vec!(format!(
+            "{}: {}, {}: {}, overlapping: {}",
+            entry_name, range(entry_bit, entry_size), other_name, range(other_bit, other_size), range(start, end - start)
+        ));
+
+        let Some(bits) = self.attr.bits else {
+            return lines.join("\n");
+        };
+
+        // The bits of all other fields and reserved bits, the bits of other flags are not known.
+        // Conditional entries may not exist in the configuration of the overlap.
+        let entries = self.data.layout();
+        let used = entries.iter().enumerate()
+            .filter(|(i, entry)| *i != index && !entry.is_conditional())
+            .filter_map(|(_, entry)| entry.field.as_ref())
+            .map(|field| (
+                field.bit.as_ref().unwrap().base10_parse::<u16>().unwrap(),
+                field.size.as_ref().unwrap().base10_parse::<u16>().unwrap()
+            ))
+            .chain([(other_bit, other_size)])
+            .collect::<Vec<_>>();
+        let is_used = |bit: u16| used.iter().any(|(start, size)| bit >= *start && bit < start + size);
+
+        let map = (0..bits as u16).rev().map(|bit| {
+            let in_entry = bit >= entry_bit && bit < entry_bit + entry_size;
+            let in_other = bit >= other_bit && bit < other_bit + other_size;
+            match (in_entry, in_other) {
+                (true, true) => 'X',
+                (true, false) => '#',
+                (false, true) => '=',
+                _ if is_used(bit) => '-',
+                _ => '.'
+            }
+        }).collect::<String>();
+
+        lines.push(format!(
+            "layout (bit {} first): `{}`, `#` is {}, `=` is {}, `X` is both, `-` are other fields, `.` are free bits",
+            bits - 1, map, entry_name, other_name
+        ));
+
+        let free = (0..=(bits as u16).saturating_sub(entry_size)).find(
+            |bit| (*bit..bit + entry_size).all(|bit| !is_used(bit))
+        );
+        let attribute = if entries[index].reserved.is_some() { "reserved" } else { "field" };
+        lines.push(match (free, entries[index].field.is_some()) {
+            (Some(bit), true) => format!("help: the next free position is `#[{}({}, {})]`", attribute, bit, entry_size),
+            (Some(bit), false) => format!("help: the next bit which is not used by a field is {}", bit),
+            (None, _) => format!("help: there are no {} consecutive free bits", entry_size)
+        });
+
+        lines.join("\n")
+    }
+}
+
+impl Entry {
+    /// Returns the access mode of the entry, `AccessMode::ReadWrite` if none was specified.
+    pub fn access_mode(&self) -> AccessMode {
+        self.access.as_ref().map(|access| access.mode).unwrap_or(AccessMode::ReadWrite)
+    }
+
+    /// Returns `true` if getters are generated for the entry.
+    pub fn is_readable(&self) -> bool {
+        self.access_mode() != AccessMode::WriteOnly
+    }
+
+    /// Returns `true` if setters are generated for the entry.
+    pub fn is_writable(&self) -> bool {
+        matches!(self.access_mode(), AccessMode::ReadWrite | AccessMode::WriteOnly)
+    }
+
+    /// Returns the `#[cfg(...)]` attributes of the entry.
+    pub fn cfgs(&self) -> Vec<&syn::Attribute> {
+        self.attrs.iter().filter(|attr| attr.path.is_ident("cfg")).collect()
+    }
+
+    /// Returns `true` if the entry only exists in some configurations.
+    pub fn is_conditional(&self) -> bool {
+        self.attrs.iter().any(|attr| attr.path.is_ident("cfg"))
+    }
+
+    /// Returns `true` for fields with an unsigned primitive type, or an `Option` of an unsigned
+    /// `NonZero` type, which do not use all bits of the primitive type. Setting such fields can fail.
+    pub fn is_partial(&self) -> bool {
+        let bits = match (&self.field, self.ty.get_ident()) {
+            (Some(_), Some(ty)) if crate::primitive::is_unsigned_primitive(ty) => crate::primitive::primitive_bits(ty),
+            (Some(_), _) => self.non_zero_bits(),
+            _ => None
+        };
+
+        match (&self.field, bits) {
+            (Some(field), Some(bits)) => Some(bits) != field.size.as_ref().and_then(|s| s.base10_parse().ok()),
+            _ => false
+        }
+    }
+
+    /// Returns the inner type `T` of fields with an `Option<T>` type, which store `None` as `0`.
+    pub fn optional_type(&self) -> Option<&syn::Path> {
+        let segments: Vec<_> = self.ty.segments.iter().map(|segment| segment.ident.to_string()).collect();
+        let is_option = match segments.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+            ["Option"] => self.ty.leading_colon.is_none(),
+            ["core" | "std", "option", "Option"] => true,
+            _ => false
+        };
+
+        match &self.ty.segments.last()?.arguments {
+            syn::PathArguments::AngleBracketed(arguments) if is_option && arguments.args.len() == 1 => {
+                match arguments.args.first() {
+                    Some(syn::GenericArgument::Type(syn::Type::Path(ty))) if ty.qself.is_none() => Some(&ty.path),
+                    _ => None
+                }
+            },
+            _ => None
+        }
+    }
+
+    /// Returns the type which is stored in the bits of the entry, which is the inner type of
+    /// `Option<T>` fields.
+    pub fn stored_type(&self) -> &syn::Path {
+        self.optional_type().unwrap_or(&self.ty)
+    }
+
+    /// Returns the amount of bits of the inner type of `Option<NonZeroUN>` fields.
+    pub fn non_zero_bits(&self) -> Option<u8> {
+        self.optional_type()
+            .and_then(|ty| ty.segments.last())
+            .filter(|segment| segment.arguments.is_empty())
+            .and_then(|segment| crate::primitive::non_zero_bits(&segment.ident))
+    }
+
+    /// Returns `true` for fields whose getter returns a `Result`, which are fields of enumerations
+    /// that are not `complete`.
+    pub fn is_fallible(&self) -> bool {
+        match &self.field {
+            Some(field) => {
+                let is_primitive = self.ty.get_ident().map(crate::primitive::is_primitive).unwrap_or_default();
+                !is_primitive && self.non_zero_bits().is_none() && field.complete.is_none()
+            },
+            None => false
+        }
+    }
 }
 
 /// Stores a bit field entry from a struct with named fields.
@@ -72,6 +528,14 @@ pub struct EntryNamed {
     pub entry: Entry
 }
 
+impl EntryNamed {
+    /// Returns `true` if both entries are conditional and have the same name, so they are
+    /// alternatives of each other which never exist at the same time.
+    pub fn are_alternatives(left: &Self, right: &Self) -> bool {
+        left.ident.unraw() == right.ident.unraw() && left.entry.is_conditional() && right.entry.is_conditional()
+    }
+}
+
 /// Stores details about the boundaries of a field.
 pub struct FieldDetails {
     /// Span of `bit, size`. Used for out of bounds error reporting.
@@ -1,6 +1,6 @@
 //! Contains code to parse bit fields.
 
-use syn::spanned::Spanned;
+use syn::{ext::IdentExt, spanned::Spanned};
 
 impl syn::parse::Parse for super::Attribute {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
@@ -46,31 +46,144 @@ impl syn::parse::Parse for super::Attribute {
             span, "expected one of: `8`, `16`, `32`, `64`, `128`, `size`, `NonZero8`, `NonZero16`, `NonZero32`, `NonZero64`, `NonZero128`, `NonZeroSize`"
         ))?;
 
-        // Read the optional `allow_overlaps` identifier.
+        // Read the optional `allow_overlaps`, `atomic`, `c_header`, `exhaustive`, `keys`, `parts`
+        // and `register` identifiers and the `naming = "..."` and `view = "..."` options.
         let mut allow_overlaps = None;
-        if !input.is_empty() {
+        let mut atomic = None;
+        let mut c_header = None;
+        let mut exhaustive = None;
+        let mut keys = None;
+        let mut naming = None;
+        let mut parts = None;
+        let mut register = None;
+        let mut view = None;
+        while !input.is_empty() {
             input.parse::<syn::Token![,]>()?;
             let ident: syn::Ident = input.parse()?;
             if ident == "allow_overlaps" {
+                if allow_overlaps.is_some() {
+                    return Err(syn::Error::new(ident.span(), "duplicate"));
+                }
+
                 allow_overlaps = Some(ident);
+            } else if ident == "atomic" {
+                if atomic.is_some() {
+                    return Err(syn::Error::new(ident.span(), "duplicate"));
+                }
+
+                // `core::sync::atomic::AtomicU128` is not stable.
+                if bits == Some(128) {
+                    return Err(syn::Error::new(ident.span(), "not supported for 128 bit bit fields"));
+                }
+
+                atomic = Some(ident);
+            } else if ident == "c_header" {
+                if c_header.is_some() {
+                    return Err(syn::Error::new(ident.span(), "duplicate"));
+                }
+
+                // C has no standard 128 bit integer types.
+                if bits == Some(128) {
+                    return Err(syn::Error::new(ident.span(), "not supported for 128 bit bit fields"));
+                }
+
+                c_header = Some(ident);
+            } else if ident == "exhaustive" {
+                if exhaustive.is_some() {
+                    return Err(syn::Error::new(ident.span(), "duplicate"));
+                }
+
+                exhaustive = Some(ident);
+            } else if ident == "keys" {
+                if keys.is_some() {
+                    return Err(syn::Error::new(ident.span(), "duplicate"));
+                }
+
+                // The setters of `NonZero` bit fields return `Option<Self>`.
+                if is_non_zero {
+                    return Err(syn::Error::new(ident.span(), "not supported for `NonZero` bit fields"));
+                }
+
+                keys = Some(ident);
+            } else if ident == "naming" {
+                if naming.is_some() {
+                    return Err(syn::Error::new(ident.span(), "duplicate"));
+                }
+
+                input.parse::<syn::Token![=]>()?;
+                let lit: syn::LitStr = input.parse()?;
+                naming = Some(match lit.value().as_ref() {
+                    "set" => super::Naming::Set,
+                    "with" => super::Naming::With,
+                    "get_set" => super::Naming::GetSet,
+                    _ => return Err(syn::Error::new(
+                        lit.span(), "expected one of: `\"set\"`, `\"with\"`, `\"get_set\"`"
+                    ))
+                });
+            } else if ident == "parts" {
+                if parts.is_some() {
+                    return Err(syn::Error::new(ident.span(), "duplicate"));
+                }
+
+                // `from_parts` starts with `new`, which is not generated for `NonZero` bit fields.
+                if is_non_zero {
+                    return Err(syn::Error::new(ident.span(), "not supported for `NonZero` bit fields"));
+                }
+
+                parts = Some(ident);
+            } else if ident == "register" {
+                if register.is_some() {
+                    return Err(syn::Error::new(ident.span(), "duplicate"));
+                }
+
+                // Memory mapped registers can always contain `0`.
+                if is_non_zero {
+                    return Err(syn::Error::new(ident.span(), "not supported for `NonZero` bit fields"));
+                }
+
+                register = Some(ident);
+            } else if ident == "view" {
+                if view.is_some() {
+                    return Err(syn::Error::new(ident.span(), "duplicate"));
+                }
+
+                // Byte slices can always contain `0`.
+                if is_non_zero {
+                    return Err(syn::Error::new(ident.span(), "not supported for `NonZero` bit fields"));
+                }
+
+                input.parse::<syn::Token![=]>()?;
+                let lit: syn::LitStr = input.parse()?;
+                view = Some(super::View {
+                    span: ident.span(),
+                    byte_order: match lit.value().as_ref() {
+                        "le" => super::ByteOrder::LittleEndian,
+                        "be" => super::ByteOrder::BigEndian,
+                        _ => return Err(syn::Error::new(lit.span(), "expected one of: `\"le\"`, `\"be\"`"))
+                    }
+                });
             } else {
                 return Err(syn::Error::new(
-                    ident.span(), "expected either `allow_overlaps` or nothing"
+                    ident.span(), "expected either `allow_overlaps`, `atomic`, `c_header`, `exhaustive`, `keys`, `naming`, `parts`, `register`, `view` or nothing"
                 ));
             }
         }
 
-        Ok(Self { base_type, primitive_type, bits, is_non_zero, allow_overlaps })
+        Ok(Self {
+            base_type, primitive_type, bits, is_non_zero, allow_overlaps, exhaustive,
+            naming: naming.unwrap_or_default(),
+            register, atomic, view, c_header, keys, parts
+        })
     }
 }
 
 impl super::BitField {
-    /// Filters `Debug` and `Display` from `#[derive(...)]` and returns whether they occurred.
+    /// Filters `Debug`, `Display`, `LowerHex`, `UpperHex`, `Binary` and `Octal` from
+    /// `#[derive(...)]` and returns whether they occurred.
     fn filter_derive(attrs: Vec<syn::Attribute>) -> syn::Result<
-        (Vec<syn::Attribute>, Option<proc_macro2::Span>, Option<proc_macro2::Span>)
+        (Vec<syn::Attribute>, super::Derive)
     > {
-        let mut debug = None;
-        let mut display = None;
+        let mut derive = super::Derive::default();
         let mut filtered_attrs = Vec::with_capacity(attrs.len());
 
         for mut attr in attrs {
@@ -89,15 +202,24 @@ impl super::BitField {
                 )),
             }.into_iter().filter(|meta| match meta {
                 syn::NestedMeta::Meta(meta) => {
-                    if meta.path().is_ident("Debug") {
-                        debug = Some(meta.path().span());
-                        false
+                    let target = if meta.path().is_ident("Debug") {
+                        &mut derive.debug
                     } else if meta.path().is_ident("Display") {
-                        display = Some(meta.path().span());
-                        false
+                        &mut derive.display
+                    } else if meta.path().is_ident("LowerHex") {
+                        &mut derive.lower_hex
+                    } else if meta.path().is_ident("UpperHex") {
+                        &mut derive.upper_hex
+                    } else if meta.path().is_ident("Binary") {
+                        &mut derive.binary
+                    } else if meta.path().is_ident("Octal") {
+                        &mut derive.octal
                     } else {
-                        true
-                    }
+                        return true;
+                    };
+
+                    *target = Some(meta.path().span());
+                    false
                 },
                 _ => true
             }).collect::<Vec<_>>();
@@ -110,10 +232,11 @@ impl super::BitField {
             }
         }
 
-        Ok((filtered_attrs, debug, display))
+        Ok((filtered_attrs, derive))
     }
 
-    fn overlaps(left: &super::FieldDetails, right: &super::FieldDetails) -> syn::Result<bool> {
+    /// Returns `true` if both fields share at least one bit.
+    pub fn overlaps(left: &super::FieldDetails, right: &super::FieldDetails) -> syn::Result<bool> {
         let left_bit = left.bit.as_ref().unwrap().base10_parse::<u8>()?;
         let left_size = left.size.as_ref().unwrap().base10_parse::<u8>()?;
 
@@ -141,6 +264,7 @@ impl super::BitField {
                 attrs: Vec<syn::Attribute>,
                 vis: syn::Visibility,
                 ident: syn::Ident,
+                generics: syn::Generics,
                 data: super::Data
             }
 
@@ -150,21 +274,40 @@ impl super::BitField {
                     let vis = input.parse()?;
                     input.parse::<syn::Token![struct]>()?;
                     let ident = input.parse()?;
+                    let mut generics: syn::Generics = input.parse()?;
+                    generics.where_clause = input.parse()?;
                     let data = input.parse()?;
 
-                    Ok(Self { attrs, vis, ident, data })
+                    // The `where` clause of a tuple struct follows the entry.
+                    if let super::Data::Tuple(_) = data {
+                        if generics.where_clause.is_none() {
+                            generics.where_clause = input.parse()?;
+                        }
+
+                        input.parse::<syn::Token![;]>()?;
+                    }
+
+                    Ok(Self { attrs, vis, ident, generics, data })
                 }
             }
 
-            let bit_field: BitField = syn::parse2(item)?;
+            let mut bit_field: BitField = syn::parse2(item)?;
 
-            let (attrs, debug, display) =
-                super::BitField::filter_derive(bit_field.attrs)?;
+            let display_options = super::DisplayOptions::parse(&mut bit_field.attrs)?;
+            let (attrs, derive) = super::BitField::filter_derive(bit_field.attrs)?;
 
             Ok(super::BitField {
-                attr, debug, display, attrs,
+                attr, attrs,
+                debug: derive.debug,
+                display: derive.display,
+                lower_hex: derive.lower_hex,
+                upper_hex: derive.upper_hex,
+                binary: derive.binary,
+                octal: derive.octal,
+                display_options,
                 vis: bit_field.vis,
                 ident: bit_field.ident,
+                generics: bit_field.generics,
                 data: bit_field.data
             })
         }
@@ -177,7 +320,30 @@ impl super::BitField {
             for entry in bitfield.data.entries_mut() {
                 let primitive_size = entry.ty.get_ident().map(
                     |ident| crate::primitive::primitive_bits(ident)
-                ).flatten();
+                ).flatten().or_else(|| entry.non_zero_bits());
+
+                // An `Option` of an enumeration can not be used as flags.
+                if entry.field.is_none() && primitive_size.is_none() && entry.optional_type().is_some() {
+                    return Err(syn::Error::new(entry.ty.span(),
+                       "expected an explicit `size` value in the `field` attribute"
+                    ));
+                }
+
+                // The position of an alternative can not be derived from the entries before it, as
+                // these can be alternatives as well.
+                if entry.is_alternative {
+                    match &entry.field {
+                        Some(field) if field.bit.is_none() => return Err(syn::Error::new(field.span,
+                            "expected an explicit `bit` value in the `field` attribute of `cfg_attr`"
+                        )),
+                        None if primitive_size.is_some() => return Err(syn::Error::new(entry.ty.span(),
+                            "expected an explicit `bit` value in the `field` attribute of `cfg_attr`"
+                        )),
+                        _ => ()
+                    }
+                }
+
+                let position = bit;
 
                 if let Some(field) = &mut entry.field {
                     // Handle optional `size`.
@@ -216,6 +382,11 @@ impl super::BitField {
                     });
                     bit += primitive_size;
                 }
+
+                // Alternatives do not move the position of the following implicit fields.
+                if entry.is_alternative {
+                    bit = position;
+                }
             }
 
             Ok(())
@@ -225,9 +396,69 @@ impl super::BitField {
         fn validate_bitfield(bitfield: &super::BitField) -> syn::Result<()> {
             /// Validates displayable content if `#[derive(Display)]` is defined.
             fn validate_display(bitfield: &super::BitField) -> syn::Result<()> {
-                if bitfield.display.is_none() { return Ok(()); }
+                if bitfield.display.is_none() {
+                    return match &bitfield.display_options {
+                        Some(options) => Err(syn::Error::new(
+                            options.span, "expected `#[derive(Display)]`"
+                        )),
+                        None => Ok(())
+                    };
+                }
+
+                if bitfield.data.entries().iter().any(|e| e.is_conditional()) {
+                    return Err(syn::Error::new(
+                        bitfield.display.unwrap(),
+                        "can not generate `Display` for bit fields with conditional entries"
+                    ));
+                }
+
+                if let Some(template) = bitfield.display_options.as_ref().and_then(|o| o.template.as_ref()) {
+                    let entries = match &bitfield.data {
+                        super::Data::Named(_) => bitfield.data.named(),
+                        super::Data::Tuple(_) => return Err(syn::Error::new(
+                            template.lit.span(), "templates are only supported for structs with named fields"
+                        ))
+                    };
+
+                    // Every placeholder must reference a readable entry.
+                    for segment in &template.segments {
+                        if let super::DisplaySegment::Entry(name) = segment {
+                            match entries.iter().find(|e| e.ident.unraw() == name) {
+                                Some(entry) if !entry.entry.is_readable() => return Err(syn::Error::new(
+                                    template.lit.span(), format!("can not display write-only entry `{}`", name)
+                                )),
+                                Some(_) => (),
+                                None => return Err(syn::Error::new(template.lit.span(), format!(
+                                    "unknown entry `{}`, expected one of: {}", name,
+                                    entries.iter().map(
+                                        |e| format!("`{}`", e.ident.unraw())
+                                    ).collect::<Vec<_>>().join(", ")
+                                )))
+                            }
+                        }
+                    }
 
-                if let super::Data::Named(entries) = &bitfield.data {
+                    // `FromStr` needs text between placeholders to know where a value ends.
+                    for pair in template.segments.windows(2) {
+                        if let [super::DisplaySegment::Entry(left), super::DisplaySegment::Entry(right)] = pair {
+                            return Err(syn::Error::new(template.lit.span(), format!(
+                                "entries `{}` and `{}` must be separated by text", left, right
+                            )));
+                        }
+                    }
+
+                    return Ok(());
+                }
+
+                if bitfield.data.entries().iter().any(|e| !e.is_readable()) {
+                    return Err(syn::Error::new(
+                        bitfield.display.unwrap(),
+                        "can not generate `Display` for bit fields with write-only entries"
+                    ));
+                }
+
+                if let super::Data::Named(_) = &bitfield.data {
+                    let entries = bitfield.data.named();
                     if entries.len() == 0 {
                         // Do not generate `Display` for bit fields with no fields or flags at all.
                         return Err(syn::Error::new(
@@ -240,7 +471,7 @@ impl super::BitField {
                             if entry.entry.field.is_some() {
                                 return Err(syn::Error::new(
                                     bitfield.display.unwrap(),
-                                    "can not generate `Display` for bit fields with non-flag fields"
+                                    "can not generate `Display` for bit fields with non-flag fields, specify a `#[display(\"...\")]` template instead"
                                 ));
                             }
                         }
@@ -328,6 +559,45 @@ impl super::BitField {
                             }
                         }
                     }
+
+                    // `Option` fields store `None` as `0`, so the inner type must not use it.
+                    if let Some(ty) = entry.optional_type() {
+                        if let Some(field_size) = entry.non_zero_bits() {
+                            if let Some(complete) = &field.complete {
+                                return Err(syn::Error::new(complete.span(), "unnecessary for primitive types"));
+                            }
+
+                            if field_size < size {
+                                return Err(syn::Error::new(ty.span(), format!(
+                                    "type is smaller than the specified size of {} bits", size
+                                )));
+                            }
+
+                            if let Some(bits) = bits {
+                                if field_size > bits {
+                                    return Err(syn::Error::new(ty.span(), format!(
+                                        "bigger than the size of the bit field, use `NonZeroU{}` instead",
+                                        bits
+                                    )));
+                                }
+                            }
+
+                            for s in &[8u8, 16, 32, 64, 128] {
+                                if field_size > *s && size <= *s {
+                                    return Err(syn::Error::new(ty.span(), format!(
+                                        "field only uses {} bits, use `NonZeroU{}` instead", size, *s
+                                    )));
+                                }
+                            }
+                        } else if
+                            ty.get_ident().map(crate::primitive::is_primitive).unwrap_or_default() ||
+                            ty.segments.last().map(|s| s.ident.to_string().starts_with("NonZero")).unwrap_or_default()
+                        {
+                            return Err(syn::Error::new(ty.span(),
+                                "expected an enumeration or an unsigned `NonZero` type, `None` is stored as `0`"
+                            ));
+                        }
+                    }
                 }
 
                 Ok(())
@@ -342,16 +612,24 @@ impl super::BitField {
 
                 let mut overlap = None;
                 let mut has_flags = false;
+                let mut has_conditional_overlap = false;
 
                 for (i, entry) in entries.iter().enumerate() {
                     if let Some(field) = &entry.entry.field {
-                        for inner in entries[i..].iter().filter(
-                            |e| e.entry.field.is_some()
-                        ).skip(1) {
+                        for (inner_i, inner) in entries.iter().enumerate().skip(i + 1).filter(
+                            |(_, e)| e.entry.field.is_some() && !super::EntryNamed::are_alternatives(entry, e)
+                        ) {
                             if super::BitField::overlaps(
                                 field, inner.entry.field.as_ref().unwrap()
                             )? {
-                                overlap = Some((entry, inner));
+                                // Overlaps with conditional entries are checked by constant
+                                // assertions for the configurations in which both exist.
+                                if entry.entry.is_conditional() || inner.entry.is_conditional() {
+                                    has_conditional_overlap = true;
+                                    continue;
+                                }
+
+                                overlap = Some((entry, inner, inner_i));
                                 break;
                             }
                         }
@@ -362,16 +640,35 @@ impl super::BitField {
                     if overlap.is_some() && has_flags { break; }
                 }
 
-                if let Some(overlap) = overlap {
+                if let Some((other, entry, index)) = overlap {
                     if bitfield.attr.allow_overlaps.is_none() {
-                        return Err(syn::Error::new(overlap.1.entry.field.as_ref().unwrap().span,
+                        let range = |entry: &super::EntryNamed| {
+                            let field = entry.entry.field.as_ref().unwrap();
+                            Ok::<_, syn::Error>((field.bit.as_ref().unwrap().base10_parse()?, field.size.as_ref().unwrap().base10_parse()?))
+                        };
+                        let (entry_name, other_name) = (format!("`{}`", entry.ident.unraw()), format!("`{}`", other.ident.unraw()));
+                        let ((entry_bit, entry_size), (other_bit, other_size)) = (range(entry)?, range(other)?);
+
+                        let kind = |entry: &super::EntryNamed| match entry.entry.reserved {
+                            Some(_) => "reserved bits",
+                            None => "field"
+                        };
+
+                        let mut error = syn::Error::new(entry.entry.field.as_ref().unwrap().span,
                             format!(
-                                "overlaps with field `{}`, please specify `allow_overlaps` if this is intended",
-                                overlap.0.ident
+                                "overlaps with {} `{}`, please specify `allow_overlaps` if this is intended\n{}",
+                                kind(other), other.ident, bitfield.explain_overlap(
+                                    index, (&entry_name, entry_bit, entry_size), (&other_name, other_bit, other_size)
+                                )
                             )
+                        );
+                        error.combine(syn::Error::new(
+                            other.entry.field.as_ref().unwrap().span,
+                            format!("{} `{}` is declared here", kind(other), other.ident.unraw())
                         ));
+                        return Err(error);
                     }
-                } else if !has_flags {
+                } else if !has_flags && !has_conditional_overlap {
                     if let Some(allow_overlaps) = &bitfield.attr.allow_overlaps {
                         return Err(syn::Error::new(allow_overlaps.span(), format!(
                             "unnecessary since no fields overlap"
@@ -382,12 +679,384 @@ impl super::BitField {
                 Ok(())
             }
 
+            /// Checks if every bit is used by a field, if `exhaustive` is specified. Bit fields with
+            /// flags, conditional entries or a target dependent size are checked by constant
+            /// assertions instead.
+            fn validate_exhaustive(bitfield: &super::BitField) -> syn::Result<()> {
+                let exhaustive = match &bitfield.attr.exhaustive {
+                    Some(exhaustive) => exhaustive,
+                    None => return Ok(())
+                };
+
+                if
+                    bitfield.attr.bits.is_none() ||
+                    bitfield.data.layout().iter().any(|e| e.field.is_none() || e.is_conditional())
+                {
+                    return Ok(());
+                }
+
+                // Group the unused bits into ranges.
+                let mut ranges: Vec<(u8, u8)> = vec!();
+                for bit in bitfield.bits_without_field() {
+                    match ranges.last_mut() {
+                        Some((_, end)) if *end + 1 == bit => *end = bit,
+                        _ => ranges.push((bit, bit))
+                    }
+                }
+
+                if ranges.is_empty() { return Ok(()); }
+
+                Err(syn::Error::new(exhaustive.span(), format!(
+                    "not every bit is used by a field, flags or reserved bits, unused bits: {}",
+                    ranges.iter().map(|(start, end)| match start == end {
+                        true => format!("{}", start),
+                        false => format!("{}-{}", start, end)
+                    }).collect::<Vec<_>>().join(", ")
+                )))
+            }
+
+            /// Checks if any generated accessor names collide with each other, or with the other
+            /// methods of the bit field, the register or the views.
+            fn validate_names(bitfield: &super::BitField) -> syn::Result<()> {
+                // Adds the name of `ident` to `names`, if it does not collide with another name.
+                fn insert(
+                    names: &mut Vec<(String, String)>, ident: &syn::Ident, kind: &str, entry: Option<&syn::Ident>
+                ) -> syn::Result<()> {
+                    let name = ident.unraw().to_string();
+                    let description = match entry {
+                        Some(entry) => format!("{} `{}` of entry `{}`", kind, name, entry.unraw()),
+                        None => format!("{} `{}`", kind, name)
+                    };
+
+                    if let Some((_, other)) = names.iter().find(|(n, _)| *n == name) {
+                        return Err(syn::Error::new(ident.span(), format!(
+                            "{} collides with {}, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+                            description, other
+                        )));
+                    }
+
+                    names.push((name, description));
+                    Ok(())
+                }
+
+                let is_non_zero = bitfield.attr.is_non_zero;
+                let entries = bitfield.data.entries();
+                let is_only_entry = entries.len() == 1;
+                let has_builder = entries.iter().any(|e| e.required.is_some());
+
+                // Start with the methods that are always generated.
+                let mut methods = vec!("_bit", "_set_bit", "_invert_bit", "_field", "_set_field");
+                if !is_non_zero { methods.push("new"); }
+                if bitfield.attr.keys.is_some() { methods.extend(["get", "with"]); }
+                if bitfield.attr.parts.is_some() { methods.extend(["to_parts", "from_parts"]); }
+                if has_builder { methods.push("builder"); }
+
+                let mut names: Vec<(String, String)> = methods.into_iter().map(
+                    |m| (m.to_string(), format!("method `{}`", m))
+                ).collect();
+
+                // The methods of the `#NAME Register` type, if any.
+                let mut register_names: Vec<(String, String)> = match bitfield.attr.register {
+                    Some(_) => ["new", "as_ptr", "read", "write", "modify"].into_iter().map(
+                        |m| (m.to_string(), format!("register method `{}`", m))
+                    ).collect(),
+                    None => vec!()
+                };
+
+                // The methods of the `#NAME View` and `#NAME ViewMut` types, if any.
+                let mut view_names: Vec<(String, String)> = match bitfield.attr.view {
+                    Some(_) => ["new", "new_at", "read", "into_owned", "write"].into_iter().map(
+                        |m| (m.to_string(), format!("view method `{}`", m))
+                    ).collect(),
+                    None => vec!()
+                };
+
+                // The methods of the `#NAME Builder` type, if any.
+                let mut builder_names: Vec<(String, String)> = match has_builder {
+                    true => vec!(("build".to_string(), "builder method `build`".to_string())),
+                    false => vec!()
+                };
+
+                for (i, (entry, accessors)) in entries.into_iter().zip(bitfield.accessors()).enumerate() {
+                    // Alternatives of an entry have the same names, but never exist at the same time.
+                    if bitfield.data.is_repeated(i) { continue; }
+
+                    let try_prefix = |ident: syn::Ident, is_fallible: bool| match is_fallible {
+                        false => ident,
+                        true => quote::format_ident!("try_{}", ident)
+                    };
+
+                    let setter_in_place = super::Accessors::suffixed(&accessors.setter, "in_place");
+                    let inverter_in_place = super::Accessors::suffixed(&accessors.inverter, "in_place");
+
+                    let is_readable = entry.is_readable();
+                    let is_writable = entry.is_writable();
+
+                    let mut generated = vec!();
+                    if is_writable { generated.push(accessors.setter.clone()); }
+
+                    // Getters are generated for the views as well.
+                    let mut getters = vec!();
+                    if is_readable { getters.push(accessors.getter.clone()); }
+
+                    // Shortcuts are named like the in-place mutators without `_in_place`.
+                    let mut shortcuts = vec!();
+                    let mut clearer = None;
+
+                    if entry.field.is_some() {
+                        let is_bool = entry.ty.get_ident().map(crate::primitive::is_bool).unwrap_or_default();
+
+                        if is_writable {
+                            generated.push(try_prefix(setter_in_place, entry.is_partial() || is_non_zero));
+                            shortcuts.push(try_prefix(accessors.setter.clone(), entry.is_partial()));
+                        }
+
+                        if is_bool && is_readable && is_writable {
+                            generated.push(accessors.inverter.clone());
+                            generated.push(try_prefix(inverter_in_place, is_non_zero));
+                            shortcuts.push(accessors.inverter.clone());
+                        }
+                    } else {
+                        generated.push(super::Accessors::suffixed(&accessors.getter, "mask"));
+
+                        if is_readable {
+                            getters.extend([
+                                super::Accessors::suffixed(&accessors.getter, "all"),
+                                super::Accessors::suffixed(&accessors.getter, "any")
+                            ]);
+                        }
+
+                        if is_writable {
+                            generated.extend([
+                                super::Accessors::suffixed(&accessors.setter, "all"),
+                                try_prefix(setter_in_place, is_non_zero),
+                                accessors.prefixed("insert"),
+                                try_prefix(accessors.prefixed("remove"), is_non_zero)
+                            ]);
+
+                            if !(is_non_zero && is_only_entry) {
+                                generated.push(super::Accessors::suffixed(&accessors.setter, "none"));
+                            }
+
+                            shortcuts.extend([
+                                accessors.setter.clone(),
+                                accessors.prefixed("insert"),
+                                accessors.prefixed("remove")
+                            ]);
+                        }
+
+                        if is_readable && is_writable {
+                            generated.extend([
+                                accessors.inverter.clone(),
+                                try_prefix(accessors.prefixed("toggle"), is_non_zero)
+                            ]);
+                            shortcuts.push(accessors.prefixed("toggle"));
+                        }
+
+                        if entry.access_mode() == super::AccessMode::WriteOneToClear {
+                            generated.push(accessors.prefixed("clear"));
+                            clearer = Some(accessors.prefixed("clear"));
+                        }
+                    }
+
+                    if let Some(groups) = &entry.groups {
+                        generated.extend(groups.idents.iter().map(|group| accessors.predicate(group)));
+                    }
+
+                    let entry = accessors.name.as_ref();
+
+                    for ident in getters.iter().chain(&generated) {
+                        insert(&mut names, ident, "accessor", entry)?;
+                    }
+
+                    if bitfield.attr.register.is_some() {
+                        for ident in shortcuts.iter().chain(&clearer) {
+                            insert(&mut register_names, ident, "register shortcut", entry)?;
+                        }
+                    }
+
+                    if !builder_names.is_empty() && is_writable {
+                        insert(&mut builder_names, &accessors.setter, "builder method", entry)?;
+                    }
+
+                    if bitfield.attr.view.is_some() {
+                        for ident in &getters {
+                            insert(&mut view_names, ident, "accessor", entry)?;
+                        }
+
+                        for ident in &shortcuts {
+                            insert(&mut view_names, ident, "view shortcut", entry)?;
+                        }
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// Checks if the access mode is supported by the entry.
+            fn validate_access(entry: &super::Entry) -> syn::Result<()> {
+                match &entry.access {
+                    Some(access) if access.mode == super::AccessMode::WriteOneToClear && entry.field.is_some() => Err(
+                        syn::Error::new(access.span, "only supported for flags")
+                    ),
+                    // The masks of all write-1-to-clear flags are combined in a single expression.
+                    Some(access) if access.mode == super::AccessMode::WriteOneToClear && entry.is_conditional() => Err(
+                        syn::Error::new(access.span, "not supported for conditional entries")
+                    ),
+                    _ => Ok(())
+                }
+            }
+
+            /// Checks if the entry is a readable enumeration field, whose variant groups can be
+            /// forwarded.
+            fn validate_groups(entry: &super::Entry) -> syn::Result<()> {
+                let span = match &entry.groups {
+                    Some(groups) => groups.span,
+                    None => return Ok(())
+                };
+
+                let is_primitive = entry.ty.get_ident().map(crate::primitive::is_primitive).unwrap_or_default();
+
+                if entry.field.is_none() || is_primitive || entry.non_zero_bits().is_some() {
+                    Err(syn::Error::new(span, "only supported for enumeration fields"))
+                } else if !entry.is_readable() {
+                    Err(syn::Error::new(span, "only supported for readable fields"))
+                } else {
+                    Ok(())
+                }
+            }
+
+            /// Checks if the policy of reserved bits is supported by the bit field.
+            fn validate_reserved(is_non_zero: bool, entry: &super::Entry) -> syn::Result<()> {
+                match &entry.reserved {
+                    // Clearing the reserved bits could result in `0`.
+                    Some(reserved) if reserved.policy == super::ReservedPolicy::Zero && is_non_zero => Err(
+                        syn::Error::new(reserved.span, "`policy = zero` is not supported for `NonZero` bit fields")
+                    ),
+                    _ => Ok(())
+                }
+            }
+
+            /// Checks if the entry types do not depend on the type parameters, which are only
+            /// zero-sized markers.
+            fn validate_generics(bitfield: &super::BitField) -> syn::Result<()> {
+                /// Finds a type parameter, which is not preceded by a path separator.
+                fn find_param(tokens: proc_macro2::TokenStream, params: &[&syn::Ident]) -> Option<proc_macro2::Span> {
+                    let mut is_separated = false;
+
+                    for token in tokens {
+                        match &token {
+                            proc_macro2::TokenTree::Ident(ident) if !is_separated && params.contains(&ident) => {
+                                return Some(ident.span());
+                            },
+                            proc_macro2::TokenTree::Group(group) => if let Some(span) = find_param(group.stream(), params) {
+                                return Some(span);
+                            },
+                            _ => ()
+                        }
+
+                        is_separated = matches!(&token, proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ':');
+                    }
+
+                    None
+                }
+
+                let params: Vec<_> = bitfield.generics.type_params().map(|param| &param.ident).collect();
+
+                for entry in bitfield.data.layout() {
+                    if let Some(span) = find_param(quote::ToTokens::to_token_stream(&entry.ty), &params) {
+                        return Err(syn::Error::new(span, "type parameters can only be used as markers, not in entry types"));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// Checks if the C header can describe the layout, which must not depend on the
+            /// configuration.
+            fn validate_c_header(bitfield: &super::BitField) -> syn::Result<()> {
+                match &bitfield.attr.c_header {
+                    Some(c_header) if bitfield.data.entries().iter().any(|e| e.is_conditional()) => Err(
+                        syn::Error::new(c_header.span(), "not supported for bit fields with conditional entries")
+                    ),
+                    _ => Ok(())
+                }
+            }
+
+            /// Checks if every entry has a name for its key type and its member in `#NAME Parts`.
+            fn validate_named_options(bitfield: &super::BitField) -> syn::Result<()> {
+                let option = bitfield.attr.keys.as_ref().or(bitfield.attr.parts.as_ref());
+
+                match (option, &bitfield.data) {
+                    (Some(option), super::Data::Tuple(_)) => Err(
+                        syn::Error::new(option.span(), "only supported for structs with named fields")
+                    ),
+                    _ => Ok(())
+                }
+            }
+
+            /// Checks if every required entry is a field the builder can set, in every configuration
+            /// the bit field exists in.
+            fn validate_required(bitfield: &super::BitField) -> syn::Result<()> {
+                for entry in bitfield.data.entries() {
+                    let required = match &entry.required {
+                        Some(required) => required,
+                        None => continue
+                    };
+
+                    // The builder starts with `new`, which `NonZero` bit fields do not have.
+                    if bitfield.attr.is_non_zero {
+                        return Err(syn::Error::new(required.span(), "not supported for `NonZero` bit fields"));
+                    }
+
+                    if entry.field.is_none() {
+                        return Err(syn::Error::new(required.span(), "only supported for fields"));
+                    }
+
+                    if !entry.is_writable() {
+                        return Err(syn::Error::new(required.span(), "only supported for writable fields"));
+                    }
+                }
+
+                // A conditional entry can only be set in every configuration through its alternatives.
+                let named = bitfield.data.named();
+                for (i, entry) in named.iter().enumerate() {
+                    let required = match &entry.entry.required {
+                        Some(required) if entry.entry.is_conditional() => required,
+                        _ => continue
+                    };
+
+                    let mut alternatives = named.iter().enumerate().filter(
+                        |(j, other)| i != *j && super::EntryNamed::are_alternatives(entry, other)
+                    ).peekable();
+
+                    if alternatives.peek().is_none() {
+                        return Err(syn::Error::new(required.span(), "not supported for conditional entries without alternatives"));
+                    }
+
+                    if alternatives.any(|(_, other)| other.entry.required.is_none()) {
+                        return Err(syn::Error::new(required.span(), "not supported unless all alternatives are `required`"));
+                    }
+                }
+
+                Ok(())
+            }
+
             // Validate all fields separately.
-            for entry in bitfield.data.entries() {
+            for entry in bitfield.data.layout() {
                 validate_field(bitfield.attr.bits, entry)?;
+                validate_access(entry)?;
+                validate_groups(entry)?;
+                validate_reserved(bitfield.attr.is_non_zero, entry)?;
             }
 
+            validate_generics(bitfield)?;
+            validate_c_header(bitfield)?;
+            validate_named_options(bitfield)?;
+            validate_required(bitfield)?;
             validate_overlaps(bitfield)?;
+            validate_exhaustive(bitfield)?;
+            validate_names(bitfield)?;
             validate_display(bitfield)?;
 
             Ok(())
@@ -403,6 +1072,114 @@ impl super::BitField {
     }
 }
 
+impl syn::parse::Parse for super::DisplayOptions {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let buffer; syn::parenthesized!(buffer in input);
+
+        let template = match buffer.peek(syn::LitStr) {
+            true => Some(super::DisplayTemplate::parse(buffer.parse()?)?),
+            false => None
+        };
+
+        let mut separator = None;
+        let mut empty = None;
+
+        // Parse `separator = LitStr` and `empty = LitStr` in any order.
+        while !buffer.is_empty() {
+            if template.is_some() || separator.is_some() || empty.is_some() {
+                buffer.parse::<syn::Token![,]>()?;
+            }
+
+            let ident = buffer.parse::<syn::Ident>()?;
+            let target = if ident == "separator" {
+                &mut separator
+            } else if ident == "empty" {
+                &mut empty
+            } else {
+                return Err(syn::Error::new(ident.span(), "expected `separator` or `empty`"));
+            };
+
+            if target.is_some() {
+                return Err(syn::Error::new(ident.span(), "duplicate"));
+            }
+
+            buffer.parse::<syn::Token![=]>()?;
+            *target = Some(buffer.parse::<syn::LitStr>()?);
+        }
+
+        Ok(Self { span, template, separator, empty })
+    }
+}
+
+impl super::DisplayOptions {
+    // Parse and remove the optional `display` attribute from `attrs`.
+    fn parse(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Option<Self>> {
+        if let Some(index) = attrs.iter().position(|attr| attr.path.is_ident("display")) {
+            let attr = attrs.remove(index);
+            let mut options: Self = syn::parse2(attr.tokens)?;
+            options.span = attr.path.span();
+            Ok(Some(options))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl super::DisplayTemplate {
+    /// Splits the template into text and `{entry}` placeholders.
+    fn parse(lit: syn::LitStr) -> syn::Result<Self> {
+        let value = lit.value();
+        let mut segments = vec!();
+        let mut text = String::new();
+        let mut chars = value.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => { chars.next(); text.push('{'); },
+                '}' if chars.peek() == Some(&'}') => { chars.next(); text.push('}'); },
+
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) if c == '_' || c.is_alphanumeric() => name.push(c),
+                            Some(_) => return Err(syn::Error::new(
+                                lit.span(), "expected an entry name in `{...}`"
+                            )),
+                            None => return Err(syn::Error::new(
+                                lit.span(), "unclosed `{` in template, use `{{` to display `{`"
+                            ))
+                        }
+                    }
+
+                    if name.is_empty() {
+                        return Err(syn::Error::new(lit.span(), "expected an entry name in `{...}`"));
+                    }
+
+                    if !text.is_empty() {
+                        segments.push(super::DisplaySegment::Text(core::mem::take(&mut text)));
+                    }
+                    segments.push(super::DisplaySegment::Entry(name));
+                },
+
+                '}' => return Err(syn::Error::new(
+                    lit.span(), "unmatched `}` in template, use `}}` to display `}`"
+                )),
+
+                c => text.push(c)
+            }
+        }
+
+        if !text.is_empty() {
+            segments.push(super::DisplaySegment::Text(text));
+        }
+
+        Ok(Self { lit, segments })
+    }
+}
+
 impl syn::parse::Parse for super::Data {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let lookahead = input.lookahead1();
@@ -411,9 +1188,9 @@ impl syn::parse::Parse for super::Data {
 
             let braces_content; syn::braced!(braces_content in input);
             let entries: syn::punctuated::Punctuated<_, syn::Token![,]> =
-                braces_content.parse_terminated(super::EntryNamed::parse)?;
+                braces_content.parse_terminated(super::EntryNamed::parse_alternatives)?;
 
-            Ok(super::Data::Named(entries.into_pairs().map(
+            Ok(super::Data::Named(entries.into_pairs().flat_map(
                 |p| p.into_value()
             ).collect()))
         } else if lookahead.peek(syn::token::Paren) {
@@ -421,7 +1198,6 @@ impl syn::parse::Parse for super::Data {
 
             let parens_content; syn::parenthesized!(parens_content in input);
             let entry = parens_content.parse()?;
-            input.parse::<syn::Token![;]>()?;
 
             Ok(super::Data::Tuple(entry))
         } else {
@@ -435,7 +1211,7 @@ impl syn::parse::Parse for super::Entry {
         let mut attrs = input.call(syn::Attribute::parse_outer)?;
         let vis = input.parse()?;
         let ty = input.parse::<syn::Path>()?;
-        let field = super::FieldDetails::parse(&mut attrs)?;
+        let FieldAttribute { details: field, names, access, required, groups } = super::FieldDetails::parse(&mut attrs)?;
 
         if let Some(complete) = field.as_ref().and_then(|f| f.complete.as_ref()) {
             if ty.get_ident().map(|i| crate::primitive::is_primitive(i)).unwrap_or_default() {
@@ -443,26 +1219,234 @@ impl syn::parse::Parse for super::Entry {
             }
         }
 
-        Ok(Self { attrs, vis, ty, field })
+        if let Some(attr) = attrs.iter().find(|attr| attr.path.is_ident("reserved")) {
+            return Err(syn::Error::new(attr.path.span(), "only supported for structs with named fields"));
+        }
+
+        // A tuple struct without its only entry would not be a bit field anymore.
+        for attr in &attrs {
+            if attr.path.is_ident("cfg") || CfgAttr::parse(attr)?.map(|c| c.field.is_some()).unwrap_or_default() {
+                return Err(syn::Error::new(attr.path.span(), "only supported for structs with named fields"));
+            }
+        }
+
+        Ok(Self { attrs, vis, ty, field, names, access, required, groups, reserved: None, is_alternative: false })
     }
 }
 
-impl syn::parse::Parse for super::EntryNamed {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+impl super::EntryNamed {
+    /// Parses an entry and the alternatives of it, one for each `#[cfg_attr(predicate, field(...))]`.
+    /// Every alternative uses its own `field` attribute and is gated with `#[cfg(predicate)]`, the
+    /// entry itself is gated with `#[cfg(not(any(predicates)))]`.
+    fn parse_alternatives(input: syn::parse::ParseStream) -> syn::Result<Vec<Self>> {
         let mut attrs = input.call(syn::Attribute::parse_outer)?;
-        let vis = input.parse()?;
-        let ident = input.parse()?;
+
+        // Remove the `field` attributes from the `cfg_attr` attributes.
+        let mut alternatives = vec!();
+        for attr in std::mem::take(&mut attrs) {
+            match CfgAttr::parse(&attr)? {
+                Some(CfgAttr { predicate, field: Some(field), attrs: others }) => {
+                    if !others.is_empty() {
+                        attrs.push(syn::parse_quote!(#[cfg_attr(#predicate, #(#others),*)]));
+                    }
+
+                    alternatives.push((predicate, field));
+                },
+                _ => attrs.push(attr)
+            }
+        }
+
+        // The last entry advances the input.
+        let mut entries = vec!();
+        for (predicate, field) in &alternatives {
+            let mut attrs: Vec<_> = attrs.iter().filter(|attr| !attr.path.is_ident("field")).cloned().collect();
+            attrs.push(syn::parse_quote!(#[cfg(#predicate)]));
+            attrs.push(field.clone());
+
+            let mut entry = Self::parse_with_attrs(attrs, &input.fork())?;
+            entry.entry.is_alternative = true;
+            entries.push(entry);
+        }
+
+        if !alternatives.is_empty() {
+            let predicates = alternatives.iter().map(|(predicate, _)| predicate);
+            attrs.push(syn::parse_quote!(#[cfg(not(any(#(#predicates),*)))]));
+        }
+
+        entries.insert(0, Self::parse_with_attrs(attrs, input)?);
+        Ok(entries)
+    }
+
+    /// Parses an entry with the already parsed outer attributes `attrs`.
+    fn parse_with_attrs(mut attrs: Vec<syn::Attribute>, input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let vis: syn::Visibility = input.parse()?;
+        let ident: syn::Ident = input.parse()?;
+
+        if let Some((reserved, field)) = super::Reserved::parse(&mut attrs)? {
+            // Reserved bits have no accessors, so they have neither a visibility nor a type. The
+            // placeholder `_` is required to keep the struct valid Rust syntax.
+            if !matches!(vis, syn::Visibility::Inherited) {
+                return Err(syn::Error::new(vis.span(), "unnecessary for reserved bits"));
+            }
+
+            input.parse::<syn::Token![:]>()?;
+            if !input.peek(syn::Token![_]) {
+                return Err(input.error("expected `_`, reserved bits have no type"));
+            }
+            input.parse::<syn::Token![_]>()?;
+
+            if let Some(attr) = attrs.iter().find(|attr| attr.path.is_ident("field") || attr.path.is_ident("cfg")) {
+                return Err(syn::Error::new(attr.path.span(), "not supported for reserved bits"));
+            }
+
+            let size = field.size.as_ref().unwrap().base10_parse()?;
+            let ty = crate::primitive::type_from_bits(size, false, ident.span()).into();
+
+            return Ok(Self { ident, entry: super::Entry {
+                attrs, vis, ty, field: Some(field), names: super::AccessorNames::default(), access: None,
+                required: None, groups: None, reserved: Some(reserved), is_alternative: false
+            }});
+        }
+
         input.parse::<syn::Token![:]>()?;
         let ty = input.parse()?;
-        let field = super::FieldDetails::parse(&mut attrs)?;
+        let FieldAttribute { details: field, names, access, required, groups } = super::FieldDetails::parse(&mut attrs)?;
+
+        Ok(Self { ident, entry: super::Entry {
+            attrs, vis, ty, field, names, access, required, groups, reserved: None, is_alternative: false
+        }})
+    }
+}
 
-        Ok(Self { ident, entry: super::Entry { attrs, vis, ty, field } })
+fn validate_bit(bit: &syn::LitInt) -> syn::Result<()> {
+    if !bit.base10_parse::<u8>().is_err() {
+        Ok(())
+    } else {
+        Err(syn::Error::new(bit.span(), "expected a number between 0-255"))
     }
 }
 
-impl syn::parse::Parse for super::FieldDetails {
+fn validate_size(size: &syn::LitInt) -> syn::Result<()> {
+    if !size.base10_parse::<core::num::NonZeroU8>().is_err() {
+        Ok(())
+    } else {
+        Err(syn::Error::new(size.span(), "expected a number between 1-255"))
+    }
+}
+
+/// Helper structure to parse the `field` attribute, which can contain field details, accessor
+/// names, the access mode, `required` and the variant groups in any combination.
+struct FieldAttribute {
+    details: Option<super::FieldDetails>,
+    names: super::AccessorNames,
+    access: Option<super::Access>,
+    required: Option<syn::Ident>,
+    groups: Option<super::Groups>
+}
+
+impl syn::parse::Parse for FieldAttribute {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        fn parse_extra(buffer: &syn::parse::ParseBuffer) -> syn::Result<Option<syn::Ident>> {
+        /// Parses `= ident` for the accessor name or access mode option `option`.
+        fn parse_option(
+            buffer: &syn::parse::ParseBuffer,
+            option: syn::Ident,
+            names: &mut super::AccessorNames,
+            access: &mut Option<super::Access>
+        ) -> syn::Result<()> {
+            if option == "access" {
+                buffer.parse::<syn::Token![=]>()?;
+                let value: syn::Ident = buffer.parse()?;
+
+                if access.is_some() {
+                    return Err(syn::Error::new(option.span(), "duplicate"));
+                }
+
+                let mode = if value == "rw" {
+                    super::AccessMode::ReadWrite
+                } else if value == "ro" {
+                    super::AccessMode::ReadOnly
+                } else if value == "wo" {
+                    super::AccessMode::WriteOnly
+                } else if value == "w1c" {
+                    super::AccessMode::WriteOneToClear
+                } else {
+                    return Err(syn::Error::new(value.span(), "expected one of: `ro`, `wo`, `w1c`, `rw`"));
+                };
+
+                *access = Some(super::Access { span: value.span(), mode });
+                return Ok(());
+            }
+
+            let name = if option == "get" {
+                &mut names.getter
+            } else if option == "set" {
+                &mut names.setter
+            } else if option == "invert" {
+                &mut names.inverter
+            } else {
+                return Err(syn::Error::new(
+                    option.span(), "expected one of: `get`, `set`, `invert`, `access`"
+                ));
+            };
+
+            buffer.parse::<syn::Token![=]>()?;
+            let value = buffer.parse()?;
+
+            if name.is_some() {
+                return Err(syn::Error::new(option.span(), "duplicate"));
+            }
+
+            *name = Some(value);
+            Ok(())
+        }
+
+        /// Sets `flag` to `ident`, if it was not set before.
+        fn parse_flag(flag: &mut Option<syn::Ident>, ident: syn::Ident) -> syn::Result<()> {
+            if flag.is_some() {
+                return Err(syn::Error::new(ident.span(), "duplicate"));
+            }
+
+            *flag = Some(ident);
+            Ok(())
+        }
+
+        /// Parses `(ident, ...)` for the `groups` option `option`.
+        fn parse_groups(
+            buffer: &syn::parse::ParseBuffer,
+            option: syn::Ident,
+            groups: &mut Option<super::Groups>
+        ) -> syn::Result<()> {
+            let content; syn::parenthesized!(content in buffer);
+            let idents = content.parse_terminated::<syn::Ident, syn::Token![,]>(syn::parse::Parse::parse)?;
+
+            if groups.is_some() {
+                return Err(syn::Error::new(option.span(), "duplicate"));
+            }
+
+            if idents.is_empty() {
+                return Err(syn::Error::new(option.span(), "expected at least one group"));
+            }
+
+            let mut unique: Vec<syn::Ident> = vec!();
+            for ident in idents {
+                if unique.iter().any(|other| other.unraw() == ident.unraw()) {
+                    return Err(syn::Error::new(ident.span(), "duplicate"));
+                }
+
+                unique.push(ident);
+            }
+
+            *groups = Some(super::Groups { span: option.span(), idents: unique });
+            Ok(())
+        }
+
+        fn parse_extra(
+            buffer: &syn::parse::ParseBuffer,
+            names: &mut super::AccessorNames,
+            access: &mut Option<super::Access>,
+            required: &mut Option<syn::Ident>,
+            groups: &mut Option<super::Groups>
+        ) -> syn::Result<Option<syn::Ident>> {
             let mut complete = None;
 
             loop {
@@ -470,14 +1454,16 @@ impl syn::parse::Parse for super::FieldDetails {
                     buffer.parse::<syn::Token![,]>()?;
                     let ident = buffer.parse::<syn::Ident>()?;
 
-                    if ident == "complete" {
-                        if complete.is_none() {
-                            complete = Some(ident);
-                        } else {
-                            return Err(syn::Error::new(ident.span(), "duplicate"));
-                        }
+                    if buffer.peek(syn::Token![=]) {
+                        parse_option(buffer, ident, names, access)?;
+                    } else if ident == "groups" && buffer.peek(syn::token::Paren) {
+                        parse_groups(buffer, ident, groups)?;
+                    } else if ident == "complete" {
+                        parse_flag(&mut complete, ident)?;
+                    } else if ident == "required" {
+                        parse_flag(required, ident)?;
                     } else {
-                        return Err(syn::Error::new(ident.span(), "did you mean `complete`?"));
+                        return Err(syn::Error::new(ident.span(), "expected one of: `complete`, `required`, `groups(...)`"));
                     }
                 } else {
                     break;
@@ -487,44 +1473,54 @@ impl syn::parse::Parse for super::FieldDetails {
             Ok(complete)
         }
 
-        fn validate_bit(bit: &syn::LitInt) -> syn::Result<()> {
-            if !bit.base10_parse::<u8>().is_err() {
-                Ok(())
-            } else {
-                Err(syn::Error::new(bit.span(), "expected a number between 0-255"))
-            }
-        }
-
-        fn validate_size(size: &syn::LitInt) -> syn::Result<()> {
-            if !size.base10_parse::<core::num::NonZeroU8>().is_err() {
-                Ok(())
-            } else {
-                Err(syn::Error::new(size.span(), "expected a number between 1-255"))
-            }
-        }
-
         let span = input.span();
         let buffer; syn::parenthesized!(buffer in input);
+        let mut names = super::AccessorNames::default();
+        let mut access = None;
+        let mut required = None;
+        let mut groups = None;
 
-        // Parse `bit = LitInt, complete?` or `size = LitInt, complete?`.
+        // Parse `bit = LitInt, complete?`, `size = LitInt, complete?` or only accessor names, the
+        // access mode and `required`.
         if let Ok(ident) = buffer.parse::<syn::Ident>() {
+            if ident == "get" || ident == "set" || ident == "invert" || ident == "access" || ident == "required" {
+                match ident == "required" {
+                    true => required = Some(ident),
+                    false => parse_option(&buffer, ident, &mut names, &mut access)?
+                }
+
+                if let Some(complete) = parse_extra(&buffer, &mut names, &mut access, &mut required, &mut groups)? {
+                    return Err(syn::Error::new(complete.span(), "unnecessary without `bit` or `size`"));
+                }
+
+                if !buffer.is_empty() {
+                    return Err(buffer.error("unexpected token"));
+                }
+
+                return Ok(Self { details: None, names, access, required, groups });
+            }
+
             buffer.parse::<syn::Token![=]>()?;
             let value: syn::LitInt = buffer.parse()?;
-            let complete = parse_extra(&buffer)?;
+            let complete = parse_extra(&buffer, &mut names, &mut access, &mut required, &mut groups)?;
 
             if !buffer.is_empty() {
                 return Err(buffer.error("unexpected token"));
             }
 
-            return if ident == "bit" {
+            let details = if ident == "bit" {
                 validate_bit(&value)?;
-                Ok(Self { span: value.span(), bit: Some(value), size: None, complete })
+                super::FieldDetails { span: value.span(), bit: Some(value), size: None, complete }
             } else if ident == "size" {
                 validate_size(&value)?;
-                Ok(Self { span: value.span(), bit: None, size: Some(value), complete })
+                super::FieldDetails { span: value.span(), bit: None, size: Some(value), complete }
             } else {
-                Err(syn::Error::new(ident.span(), "expected `bit` or `size`"))
+                return Err(syn::Error::new(
+                    ident.span(), "expected one of: `bit`, `size`, `get`, `set`, `invert`, `access`, `required`"
+                ));
             };
+
+            return Ok(Self { details: Some(details), names, access, required, groups });
         }
 
         // Parse `(bit: LitInt, size: LitInt)`.
@@ -536,29 +1532,179 @@ impl syn::parse::Parse for super::FieldDetails {
         let size: syn::LitInt = buffer.parse()?;
         validate_size(&size)?;
 
-        let complete = parse_extra(&buffer)?;
+        let complete = parse_extra(&buffer, &mut names, &mut access, &mut required, &mut groups)?;
 
         if !buffer.is_empty() {
             return Err(buffer.error("unexpected token"));
         }
 
-        Ok(Self { span, bit: Some(bit), size: Some(size), complete })
+        Ok(Self {
+            details: Some(super::FieldDetails { span, bit: Some(bit), size: Some(size), complete }),
+            names, access, required, groups
+        })
+    }
+}
+
+/// Helper structure to split a `#[cfg_attr(predicate, attributes...)]` attribute into the predicate,
+/// the `field` attribute and the other attributes.
+struct CfgAttr {
+    predicate: proc_macro2::TokenStream,
+    field: Option<syn::Attribute>,
+    attrs: Vec<proc_macro2::TokenStream>
+}
+
+impl CfgAttr {
+    /// Parses `attr`, returns `None` if it is no `cfg_attr` attribute.
+    fn parse(attr: &syn::Attribute) -> syn::Result<Option<Self>> {
+        if !attr.path.is_ident("cfg_attr") {
+            return Ok(None);
+        }
+
+        // Split the content at every top-level comma.
+        let mut parts = syn::parse::Parser::parse2(|input: syn::parse::ParseStream| {
+            let buffer; syn::parenthesized!(buffer in input);
+            let mut parts = vec!(proc_macro2::TokenStream::new());
+
+            while !buffer.is_empty() {
+                match buffer.parse()? {
+                    proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ',' => parts.push(proc_macro2::TokenStream::new()),
+                    token => parts.last_mut().unwrap().extend([token])
+                }
+            }
+
+            Ok(parts)
+        }, attr.tokens.clone())?;
+
+        if parts.len() > 2 && parts.last().unwrap().is_empty() {
+            parts.pop();
+        }
+
+        if parts.len() < 2 || parts.iter().any(|part| part.is_empty()) {
+            return Err(syn::Error::new(attr.path.span(), "expected `cfg_attr(predicate, attributes...)`"));
+        }
+
+        let predicate = parts.remove(0);
+        let mut field = None;
+        let mut attrs = vec!();
+
+        for part in parts {
+            let inner = syn::parse::Parser::parse2(
+                syn::Attribute::parse_outer, quote::quote!(#[#part])
+            )?.remove(0);
+
+            if !inner.path.is_ident("field") {
+                attrs.push(part);
+            } else if field.is_some() {
+                return Err(syn::Error::new(inner.path.span(), "duplicate"));
+            } else {
+                field = Some(inner);
+            }
+        }
+
+        Ok(Some(Self { predicate, field, attrs }))
     }
 }
 
 impl super::FieldDetails {
-    // Parse and remove the optional `field` attribute from `attrs`.
-    fn parse(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Option<Self>> {
+    // Parse and remove the optional `field` attribute from `attrs`, returning the field details,
+    // the accessor names, the access mode and `required` of the entry.
+    fn parse(attrs: &mut Vec<syn::Attribute>) -> syn::Result<FieldAttribute> {
         if let Some(index) = attrs.iter().enumerate().find(
             |(_, attr)| attr.path.is_ident("field")
         ).map(|result| result.0) {
-            Ok(Some(syn::parse2(attrs.remove(index).tokens)?))
+            syn::parse2(attrs.remove(index).tokens)
         } else {
-            Ok(None)
+            Ok(FieldAttribute { details: None, names: super::AccessorNames::default(), access: None, required: None, groups: None })
         }
     }
 }
 
+impl super::Reserved {
+    /// Parses and removes the optional `reserved` attribute from `attrs`, returning the options and
+    /// the boundaries of the reserved bits.
+    fn parse(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Option<(Self, super::FieldDetails)>> {
+        let index = match attrs.iter().position(|attr| attr.path.is_ident("reserved")) {
+            Some(index) => index,
+            None => return Ok(None)
+        };
+
+        let attr = attrs.remove(index);
+        let span = attr.path.span();
+
+        let parser = |input: syn::parse::ParseStream| {
+            let details_span = input.span();
+            let buffer; syn::parenthesized!(buffer in input);
+
+            let mut bit: Option<syn::LitInt> = None;
+            let mut size: Option<syn::LitInt> = None;
+            let mut policy = None;
+
+            // Parse `(size: LitInt)` or `(bit: LitInt, size: LitInt)`.
+            if buffer.peek(syn::LitInt) {
+                let value = buffer.parse()?;
+
+                if buffer.peek(syn::Token![,]) && buffer.peek2(syn::LitInt) {
+                    buffer.parse::<syn::Token![,]>()?;
+                    validate_bit(&value)?;
+                    bit = Some(value);
+                    size = Some(buffer.parse()?);
+                } else {
+                    size = Some(value);
+                }
+
+                validate_size(size.as_ref().unwrap())?;
+            }
+
+            // Parse `bit = LitInt`, `size = LitInt` and `policy = ...` in any order.
+            while !buffer.is_empty() {
+                if bit.is_some() || size.is_some() || policy.is_some() {
+                    buffer.parse::<syn::Token![,]>()?;
+                }
+
+                let ident: syn::Ident = buffer.parse()?;
+                buffer.parse::<syn::Token![=]>()?;
+
+                if ident == "bit" || ident == "size" {
+                    let target = if ident == "bit" { &mut bit } else { &mut size };
+                    if target.is_some() {
+                        return Err(syn::Error::new(ident.span(), "duplicate"));
+                    }
+
+                    let value = buffer.parse()?;
+                    if ident == "bit" { validate_bit(&value)?; } else { validate_size(&value)?; }
+                    *target = Some(value);
+                } else if ident == "policy" {
+                    if policy.is_some() {
+                        return Err(syn::Error::new(ident.span(), "duplicate"));
+                    }
+
+                    let value: syn::Ident = buffer.parse()?;
+                    policy = Some(if value == "preserve" {
+                        super::ReservedPolicy::Preserve
+                    } else if value == "zero" {
+                        super::ReservedPolicy::Zero
+                    } else {
+                        return Err(syn::Error::new(value.span(), "expected one of: `preserve`, `zero`"));
+                    });
+                } else {
+                    return Err(syn::Error::new(ident.span(), "expected one of: `bit`, `size`, `policy`"));
+                }
+            }
+
+            if size.is_none() {
+                return Err(syn::Error::new(details_span, "expected a `size`"));
+            }
+
+            Ok((
+                Self { span, policy: policy.unwrap_or_default() },
+                super::FieldDetails { span: details_span, bit, size, complete: None }
+            ))
+        };
+
+        syn::parse::Parser::parse2(parser, attr.tokens).map(Some)
+    }
+}
+
 #[cfg(test)]
 #[macro_use]
 pub(super) mod tests {
@@ -574,11 +1720,11 @@ pub(super) mod tests {
 
             assert_eq!(result.0.len(), $len);
             assert_eq!(
-                result.1.map(|s| (s.start().line, s.start().column, s.end().line, s.end().column)),
+                result.1.debug.map(|s| (s.start().line, s.start().column, s.end().line, s.end().column)),
                 $debug
             );
             assert_eq!(
-                result.2.map(|s| (s.start().line, s.start().column, s.end().line, s.end().column)),
+                result.1.display.map(|s| (s.start().line, s.start().column, s.end().line, s.end().column)),
                 $display
             );
 
@@ -658,7 +1804,7 @@ pub(super) mod tests {
 
         parse_invalid!(
             "8, Ident", "",
-            "expected either `allow_overlaps` or nothing",
+            "expected either `allow_overlaps`, `atomic`, `c_header`, `exhaustive`, `keys`, `naming`, `parts`, `register`, `view` or nothing",
             (1, 3), (1, 8)
         );
 
@@ -683,6 +1829,30 @@ pub(super) mod tests {
         ).attr.allow_overlaps.unwrap().span(), (1, 3), (1, 17));
     }
 
+    #[test]
+    fn attribute_atomic() {
+        assert!(parse_valid!("8", "struct A(A);").attr.atomic.is_none());
+
+        compare_span!(parse_valid!(
+            "8, atomic", "struct A(A);"
+        ).attr.atomic.unwrap().span(), (1, 3), (1, 9));
+
+        assert!(parse_valid!("NonZeroSize, atomic", "struct A(A);").attr.atomic.is_some());
+        assert!(parse_valid!("64, register, atomic", "struct A(A);").attr.atomic.is_some());
+
+        parse_invalid!(
+            "NonZero128, atomic", "",
+            "not supported for 128 bit bit fields",
+            (1, 12), (1, 18)
+        );
+
+        parse_invalid!(
+            "8, atomic, atomic", "",
+            "duplicate",
+            (1, 11), (1, 17)
+        );
+    }
+
     #[test]
     fn attribute_bits_size() {
         parse_invalid!(
@@ -788,6 +1958,206 @@ pub(super) mod tests {
         assert_eq!(attr.primitive_type, "usize");
     }
 
+    #[test]
+    fn attribute_c_header() {
+        assert!(parse_valid!("8", "struct A(A);").attr.c_header.is_none());
+
+        compare_span!(parse_valid!(
+            "NonZero64, c_header", "struct A(A);"
+        ).attr.c_header.unwrap().span(), (1, 11), (1, 19));
+
+        parse_invalid!(
+            "128, c_header", "",
+            "not supported for 128 bit bit fields",
+            (1, 5), (1, 13)
+        );
+
+        parse_invalid!(
+            "8, c_header, c_header", "",
+            "duplicate",
+            (1, 13), (1, 21)
+        );
+
+        parse_invalid!(
+            "8, c_header", "struct A { #[cfg(x)] b: bool }",
+            "not supported for bit fields with conditional entries",
+            (1, 3), (1, 11)
+        );
+    }
+
+    #[test]
+    fn attribute_exhaustive() {
+        assert!(parse_valid!("8", "struct A(A);").attr.exhaustive.is_none());
+
+        compare_span!(parse_valid!(
+            "size, exhaustive", "struct A(A);"
+        ).attr.exhaustive.unwrap().span(), (1, 6), (1, 16));
+
+        parse_invalid!(
+            "8, exhaustive, exhaustive", "",
+            "duplicate",
+            (1, 15), (1, 25)
+        );
+    }
+
+    #[test]
+    fn attribute_keys() {
+        assert!(parse_valid!("8", "struct A(A);").attr.keys.is_none());
+
+        compare_span!(parse_valid!(
+            "8, keys", "struct A { b: B }"
+        ).attr.keys.unwrap().span(), (1, 3), (1, 7));
+
+        parse_invalid!(
+            "NonZero8, keys", "",
+            "not supported for `NonZero` bit fields",
+            (1, 10), (1, 14)
+        );
+
+        parse_invalid!(
+            "8, keys, keys", "",
+            "duplicate",
+            (1, 9), (1, 13)
+        );
+
+        parse_invalid!(
+            "8, keys", "struct A(B);",
+            "only supported for structs with named fields",
+            (1, 3), (1, 7)
+        );
+
+        parse_invalid!(
+            "8, keys", "struct A { get: bool }",
+            "accessor `get` of entry `get` collides with method `get`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 11), (1, 14)
+        );
+    }
+
+    #[test]
+    fn attribute_naming() {
+        assert_eq!(parse_valid!("8", "struct A(A);").attr.naming, Naming::Set);
+        assert_eq!(parse_valid!("8, naming = \"set\"", "struct A(A);").attr.naming, Naming::Set);
+        assert_eq!(parse_valid!("8, naming = \"with\"", "struct A(A);").attr.naming, Naming::With);
+        assert_eq!(parse_valid!("8, naming = \"get_set\"", "struct A(A);").attr.naming, Naming::GetSet);
+
+        assert_eq!(parse_valid!(
+            "8, allow_overlaps, naming = \"with\"", "struct A { #[field(0, 2)] b: B, c: C }"
+        ).attr.naming, Naming::With);
+
+        parse_invalid!(
+            "8, naming = \"other\"", "",
+            "expected one of: `\"set\"`, `\"with\"`, `\"get_set\"`",
+            (1, 12), (1, 19)
+        );
+
+        parse_invalid!(
+            "8, naming = with", "",
+            "expected string literal",
+            (1, 12), (1, 16)
+        );
+
+        parse_invalid!(
+            "8, naming = \"with\", naming = \"set\"", "",
+            "duplicate",
+            (1, 20), (1, 26)
+        );
+
+        parse_invalid!(
+            "8, allow_overlaps, allow_overlaps", "",
+            "duplicate",
+            (1, 19), (1, 33)
+        );
+    }
+
+    #[test]
+    fn attribute_parts() {
+        assert!(parse_valid!("8", "struct A(A);").attr.parts.is_none());
+
+        compare_span!(parse_valid!(
+            "8, keys, parts", "struct A { b: B }"
+        ).attr.parts.unwrap().span(), (1, 9), (1, 14));
+
+        parse_invalid!(
+            "NonZero8, parts", "",
+            "not supported for `NonZero` bit fields",
+            (1, 10), (1, 15)
+        );
+
+        parse_invalid!(
+            "8, parts, parts", "",
+            "duplicate",
+            (1, 10), (1, 15)
+        );
+
+        parse_invalid!(
+            "8, parts", "struct A(B);",
+            "only supported for structs with named fields",
+            (1, 3), (1, 8)
+        );
+
+        parse_invalid!(
+            "8, parts", "struct A { to_parts: bool }",
+            "accessor `to_parts` of entry `to_parts` collides with method `to_parts`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 11), (1, 19)
+        );
+    }
+
+    #[test]
+    fn attribute_register() {
+        assert!(parse_valid!("8", "struct A(A);").attr.register.is_none());
+
+        compare_span!(parse_valid!(
+            "8, register", "struct A(A);"
+        ).attr.register.unwrap().span(), (1, 3), (1, 11));
+
+        assert!(parse_valid!(
+            "8, naming = \"with\", register", "struct A(A);"
+        ).attr.register.is_some());
+
+        parse_invalid!(
+            "NonZero8, register", "",
+            "not supported for `NonZero` bit fields",
+            (1, 10), (1, 18)
+        );
+
+        parse_invalid!(
+            "8, register, register", "",
+            "duplicate",
+            (1, 13), (1, 21)
+        );
+    }
+
+    #[test]
+    fn attribute_view() {
+        assert!(parse_valid!("8", "struct A(A);").attr.view.is_none());
+
+        let view = parse_valid!("8, view = \"le\"", "struct A(A);").attr.view.unwrap();
+        assert_eq!(view.byte_order, ByteOrder::LittleEndian);
+        compare_span!(view.span, (1, 3), (1, 7));
+
+        assert_eq!(parse_valid!(
+            "size, register, view = \"be\"", "struct A(A);"
+        ).attr.view.unwrap().byte_order, ByteOrder::BigEndian);
+
+        parse_invalid!(
+            "8, view = \"ne\"", "",
+            "expected one of: `\"le\"`, `\"be\"`",
+            (1, 10), (1, 14)
+        );
+
+        parse_invalid!(
+            "NonZero8, view = \"le\"", "",
+            "not supported for `NonZero` bit fields",
+            (1, 10), (1, 14)
+        );
+
+        parse_invalid!(
+            "8, view = \"le\", view = \"be\"", "",
+            "duplicate",
+            (1, 16), (1, 20)
+        );
+    }
+
     #[test]
     fn bitfield_attrs() {
         let attrs = parse_valid!(
@@ -817,11 +2187,110 @@ pub(super) mod tests {
         );
         parse_invalid!(
             "8", "#[derive(Display)] struct A { b: B, #[field(0, 2)] c: C }",
-            "can not generate `Display` for bit fields with non-flag fields",
+            "can not generate `Display` for bit fields with non-flag fields, specify a `#[display(\"...\")]` template instead",
+            (1, 9), (1, 16)
+        );
+        parse_invalid!(
+            "8", "#[derive(Display)] struct A { #[cfg(x)] b: B }",
+            "can not generate `Display` for bit fields with conditional entries",
             (1, 9), (1, 16)
         );
     }
 
+    #[test]
+    fn bitfield_display_options() {
+        assert!(parse_valid!("8", "#[derive(Display)] struct A(A);").display_options.is_none());
+
+        parse_invalid!(
+            "8", "#[display(separator = \", \")] struct A(A);",
+            "expected `#[derive(Display)]`",
+            (1, 2), (1, 9)
+        );
+        parse_invalid!(
+            "8", "#[derive(Display)] #[display(x = \"\")] struct A(A);",
+            "expected `separator` or `empty`",
+            (1, 29), (1, 30)
+        );
+        parse_invalid!(
+            "8", "#[derive(Display)] #[display(empty = \"\", empty = \"\")] struct A(A);",
+            "duplicate",
+            (1, 41), (1, 46)
+        );
+        parse_invalid!(
+            "8", "#[derive(Display)] #[display(\"{b}\")] struct A(B);",
+            "templates are only supported for structs with named fields",
+            (1, 29), (1, 34)
+        );
+        parse_invalid!(
+            "8", "#[derive(Display)] #[display(\"{b} {d}\")] struct A { b: B, #[field(0, 1)] c: C }",
+            "unknown entry `d`, expected one of: `b`, `c`",
+            (1, 29), (1, 38)
+        );
+        parse_invalid!(
+            "8", "#[derive(Display)] #[display(\"{b}{c}\")] struct A { b: B, #[field(0, 1)] c: C }",
+            "entries `b` and `c` must be separated by text",
+            (1, 29), (1, 37)
+        );
+        parse_invalid!(
+            "8", "#[derive(Display)] #[display(\"{b\")] struct A { b: B }",
+            "unclosed `{` in template, use `{{` to display `{`",
+            (1, 29), (1, 33)
+        );
+        parse_invalid!(
+            "8", "#[derive(Display)] #[display(\"b}\")] struct A { b: B }",
+            "unmatched `}` in template, use `}}` to display `}`",
+            (1, 29), (1, 33)
+        );
+        parse_invalid!(
+            "8", "#[derive(Display)] #[display(\"{b:?}\")] struct A { b: B }",
+            "expected an entry name in `{...}`",
+            (1, 29), (1, 36)
+        );
+        parse_invalid!(
+            "8", "#[derive(Display)] #[display(\"{}\")] struct A { b: B }",
+            "expected an entry name in `{...}`",
+            (1, 29), (1, 33)
+        );
+
+        let options = parse_valid!(
+            "8", "#[derive(Display)] #[display(\"{{{b}}}/{c} \", empty = \"none\", separator = \", \")] struct A { b: B, #[field(0, 1)] r#c: C }"
+        ).display_options.unwrap();
+        assert_eq!(options.template.unwrap().segments, vec!(
+            DisplaySegment::Text("{".to_string()),
+            DisplaySegment::Entry("b".to_string()),
+            DisplaySegment::Text("}/".to_string()),
+            DisplaySegment::Entry("c".to_string()),
+            DisplaySegment::Text(" ".to_string())
+        ));
+        assert_eq!(options.separator.unwrap().value(), ", ");
+        assert_eq!(options.empty.unwrap().value(), "none");
+
+        let options = parse_valid!(
+            "8", "#[derive(Display)] #[display(separator = \",\")] struct A { b: B, c: C }"
+        ).display_options.unwrap();
+        assert!(options.template.is_none());
+        assert_eq!(options.separator.unwrap().value(), ",");
+        assert!(options.empty.is_none());
+    }
+
+    #[test]
+    fn bitfield_radix() {
+        let bitfield = parse_valid!("8", "struct A(A);");
+        assert!(bitfield.lower_hex.is_none());
+        assert!(bitfield.upper_hex.is_none());
+        assert!(bitfield.binary.is_none());
+        assert!(bitfield.octal.is_none());
+
+        let bitfield = parse_valid!(
+            "8", "#[derive(Binary, Debug, LowerHex, Octal, UpperHex)] struct A(A);"
+        );
+        compare_span!(bitfield.binary.unwrap(), (1, 9), (1, 15));
+        compare_span!(bitfield.lower_hex.unwrap(), (1, 24), (1, 32));
+        compare_span!(bitfield.octal.unwrap(), (1, 34), (1, 39));
+        compare_span!(bitfield.upper_hex.unwrap(), (1, 41), (1, 49));
+        assert!(bitfield.attrs.is_empty());
+    }
+
     #[test]
     fn bitfield_vis() {
         assert!(match &parse_valid!("8", "struct A(A);").vis {
@@ -841,6 +2310,41 @@ pub(super) mod tests {
         assert_eq!(parse_valid!("8", "struct B(A);").ident.to_string(), "B");
     }
 
+    #[test]
+    fn bitfield_generics() {
+        let generics = |item: &str| {
+            let bitfield = parse_valid!("8", item);
+            let generics = &bitfield.generics;
+            (generics.params.to_token_stream().to_string(), generics.where_clause.to_token_stream().to_string())
+        };
+
+        assert_eq!(generics("struct A(B);"), ("".to_string(), "".to_string()));
+        assert_eq!(generics("struct A<T>(B);"), ("T".to_string(), "".to_string()));
+        assert_eq!(generics("struct A<'a, T: C, const N: usize>(B);"), ("'a , T : C , const N : usize".to_string(), "".to_string()));
+        assert_eq!(generics("struct A<T>(B) where T: C;"), ("T".to_string(), "where T : C".to_string()));
+        assert_eq!(generics("struct A<T> where T: C { b: B }"), ("T".to_string(), "where T : C".to_string()));
+
+        parse_invalid!(
+            "8", "struct A<T>(B) where T: C",
+            "expected `;`",
+            (1, 0), (1, 0)
+        );
+
+        parse_invalid!(
+            "8", "struct A<T> { #[field(0, 2)] b: T }",
+            "type parameters can only be used as markers, not in entry types",
+            (1, 32), (1, 33)
+        );
+
+        parse_invalid!(
+            "8", "struct A<T>(super::B<T>);",
+            "type parameters can only be used as markers, not in entry types",
+            (1, 21), (1, 22)
+        );
+
+        parse_valid!("8", "struct A<T> { t: super::T }");
+    }
+
     #[test]
     fn complete_fields() {
         macro_rules! assert_field {
@@ -930,6 +2434,14 @@ pub(super) mod tests {
         assert_field!(data, 0, 0, 1);
         assert_field!(data, 1, 1, 8);
         assert_field!(data, 2, 9, 1);
+
+        // Reserved bits take the next position, but are no accessible entries.
+        let data = parse_valid!(
+            "16", "struct A { b: bool, #[reserved(3)] _c: _, d: u8 }"
+        ).data;
+        assert_field!(data, 0, 0, 1);
+        assert_field!(data, 1, 4, 8);
+        assert_eq!(data.layout().len(), 3);
     }
 
     #[test]
@@ -974,6 +2486,82 @@ pub(super) mod tests {
         assert_eq!(attrs.first().unwrap().path.get_ident().unwrap().to_string(), "some_attribute1");
     }
 
+
+    #[test]
+    fn entry_cfg() {
+        let cfgs = |entry: &EntryNamed| entry.entry.cfgs().into_iter().map(
+            |attr| quote::ToTokens::to_token_stream(attr).to_string()
+        ).collect::<Vec<_>>();
+        let bit = |entry: &EntryNamed| entry.entry.field.as_ref().unwrap().bit.as_ref().unwrap().to_string();
+
+        // Plain `#[cfg]` attributes stay with the entry.
+        let entries = match parse_valid!("16", "struct A { #[cfg(test)] b: u8 }").data {
+            Data::Named(entries) => entries,
+            _ => unreachable!()
+        };
+        assert_eq!(entries.len(), 1);
+        assert_eq!(cfgs(&entries[0]), vec!("# [cfg (test)]"));
+        assert!(!entries[0].entry.is_alternative);
+
+        // Every `field` in a `cfg_attr` creates an alternative, which does not move the next field.
+        let entries = match parse_valid!(
+            "16", "struct A { #[cfg_attr(x, field(8, 4), allow(unused))] #[cfg_attr(y, field(12, 4))] #[field(size = 4)] b: u8, #[field(size = 4)] c: u8 }"
+        ).data {
+            Data::Named(entries) => entries,
+            _ => unreachable!()
+        };
+        assert_eq!(entries.len(), 4);
+        assert_eq!(entries.iter().map(|e| e.ident.to_string()).collect::<Vec<_>>(), vec!("b", "b", "b", "c"));
+        assert_eq!(entries.iter().map(bit).collect::<Vec<_>>(), vec!("0", "8", "12", "4"));
+        assert_eq!(entries.iter().map(|e| e.entry.is_alternative).collect::<Vec<_>>(), vec!(false, true, true, false));
+        assert_eq!(cfgs(&entries[0]), vec!("# [cfg (not (any (x , y)))]"));
+        assert_eq!(cfgs(&entries[1]), vec!("# [cfg (x)]"));
+        assert_eq!(cfgs(&entries[2]), vec!("# [cfg (y)]"));
+        assert!(cfgs(&entries[3]).is_empty());
+        assert_eq!(
+            quote::ToTokens::to_token_stream(&entries[1].entry.attrs[0]).to_string(),
+            "# [cfg_attr (x , allow (unused))]"
+        );
+        assert_eq!(entries[2].entry.attrs.len(), 2);
+
+        parse_invalid!(
+            "8", "struct A { #[cfg_attr(x, field(size = 4))] b: u8 }",
+            "expected an explicit `bit` value in the `field` attribute of `cfg_attr`",
+            (1, 38), (1, 39)
+        );
+        parse_invalid!(
+            "8", "struct A { #[cfg_attr(x, field(get = c))] b: u8 }",
+            "expected an explicit `bit` value in the `field` attribute of `cfg_attr`",
+            (1, 45), (1, 47)
+        );
+        parse_invalid!(
+            "8", "struct A { #[cfg_attr(x, field(0, 4), field(4, 4))] b: u8 }",
+            "duplicate",
+            (1, 38), (1, 43)
+        );
+        parse_invalid!(
+            "8", "struct A { #[cfg_attr(x)] b: u8 }",
+            "expected `cfg_attr(predicate, attributes...)`",
+            (1, 13), (1, 21)
+        );
+        parse_invalid!(
+            "8", "struct A(#[cfg(x)] B);",
+            "only supported for structs with named fields",
+            (1, 11), (1, 14)
+        );
+        parse_invalid!(
+            "8", "struct A(#[cfg_attr(x, field(0, 4))] B);",
+            "only supported for structs with named fields",
+            (1, 11), (1, 19)
+        );
+        parse_valid!("8", "struct A(#[cfg_attr(x, allow(unused))] B);");
+        parse_invalid!(
+            "8", "struct A { #[cfg(x)] #[reserved(4)] _r: _ }",
+            "not supported for reserved bits",
+            (1, 13), (1, 16)
+        );
+    }
+
     #[test]
     fn entry_vis() {
         assert!(match parse_valid!("8", "struct A(A);").data {
@@ -1056,6 +2644,111 @@ pub(super) mod tests {
         assert_eq!(entries.first().unwrap().ident, "b");
     }
 
+    #[test]
+    fn entry_optional() {
+        let optional_type = |data: Data| data.entries().first().unwrap().optional_type().map(
+            |ty| quote::ToTokens::to_token_stream(ty).to_string()
+        );
+
+        assert_eq!(optional_type(parse_valid!("8", "struct A(#[field(size = 2)] Option<B>);").data).as_deref(), Some("B"));
+        assert_eq!(optional_type(parse_valid!("8", "struct A(#[field(size = 2)] core::option::Option<B>);").data).as_deref(), Some("B"));
+        assert_eq!(optional_type(parse_valid!("8", "struct A(#[field(size = 2)] ::std::option::Option<B>);").data).as_deref(), Some("B"));
+        assert_eq!(optional_type(parse_valid!("8", "struct A(#[field(size = 2)] other::Option<B>);").data), None);
+        assert_eq!(optional_type(parse_valid!("8", "struct A(#[field(size = 2)] ::Option<B>);").data), None);
+        assert_eq!(optional_type(parse_valid!("8", "struct A(#[field(size = 2)] B);").data), None);
+
+        // `NonZero` types use the size of their primitive type by default.
+        let bitfield = parse_valid!("16", "struct A { a: bool, b: Option<core::num::NonZeroU8> }");
+        let field = bitfield.data.entries()[1].field.as_ref().unwrap();
+        assert_eq!(field.bit.as_ref().unwrap().base10_parse::<u8>().unwrap(), 1);
+        assert_eq!(field.size.as_ref().unwrap().base10_parse::<u8>().unwrap(), 8);
+        assert!(!bitfield.data.entries()[1].is_partial());
+        assert!(!bitfield.data.entries()[1].is_fallible());
+
+        let bitfield = parse_valid!("16", "struct A { #[field(size = 4)] b: Option<NonZeroU8>, #[field(size = 4)] c: Option<C> }");
+        assert!(bitfield.data.entries()[0].is_partial());
+        assert!(!bitfield.data.entries()[0].is_fallible());
+        assert!(!bitfield.data.entries()[1].is_partial());
+        assert!(bitfield.data.entries()[1].is_fallible());
+
+        parse_invalid!(
+            "8", "struct A { b: Option<B> }",
+            "expected an explicit `size` value in the `field` attribute",
+            (1, 14), (1, 23)
+        );
+    }
+
+    #[test]
+    fn entry_reserved() {
+        macro_rules! assert_reserved {
+            ($item:expr, $bit:expr, $size:expr, $policy:expr) => {
+                let data = parse_valid!("16", $item).data;
+                let entry = data.layout().into_iter().find(|entry| entry.reserved.is_some()).unwrap();
+                let field = entry.field.as_ref().unwrap();
+                assert_eq!(field.bit.as_ref().unwrap().base10_parse::<u8>().unwrap(), $bit);
+                assert_eq!(field.size.as_ref().unwrap().base10_parse::<u8>().unwrap(), $size);
+                assert_eq!(entry.reserved.as_ref().unwrap().policy, $policy);
+                assert!(entry.access.is_none());
+            };
+        }
+
+        assert_reserved!("struct A { #[reserved(4)] _r: _ }", 0, 4, ReservedPolicy::Preserve);
+        assert_reserved!("struct A { #[reserved(2, 4)] _r: _ }", 2, 4, ReservedPolicy::Preserve);
+        assert_reserved!("struct A { #[reserved(bit = 3, size = 5)] _r: _ }", 3, 5, ReservedPolicy::Preserve);
+        assert_reserved!("struct A { #[reserved(8, policy = preserve)] _r: _ }", 0, 8, ReservedPolicy::Preserve);
+        assert_reserved!("struct A { #[reserved(size = 2, policy = zero)] _r: _ }", 0, 2, ReservedPolicy::Zero);
+        assert_reserved!("struct A { b: u8, #[reserved(2)] _r: _ }", 8, 2, ReservedPolicy::Preserve);
+        assert_eq!(parse_valid!("16", "struct A { b: u8, #[reserved(2)] _r: _ }").data.entries().len(), 1);
+
+        parse_invalid!(
+            "16", "struct A(#[reserved(2)] u8);",
+            "only supported for structs with named fields",
+            (1, 11), (1, 19)
+        );
+
+        parse_invalid!(
+            "16", "struct A { #[reserved(2)] pub _r: _ }",
+            "unnecessary for reserved bits",
+            (1, 26), (1, 29)
+        );
+
+        parse_invalid!(
+            "16", "struct A { #[reserved(2)] _r: u8 }",
+            "expected `_`, reserved bits have no type",
+            (1, 30), (1, 32)
+        );
+
+        parse_invalid!(
+            "16", "struct A { #[reserved(2)] #[field(0, 2)] _r: _ }",
+            "not supported for reserved bits",
+            (1, 28), (1, 33)
+        );
+
+        parse_invalid!(
+            "16", "struct A { #[reserved(bit = 2)] _r: _ }",
+            "expected a `size`",
+            (1, 21), (1, 22)
+        );
+
+        parse_invalid!(
+            "16", "struct A { #[reserved(2, policy = keep)] _r: _ }",
+            "expected one of: `preserve`, `zero`",
+            (1, 34), (1, 38)
+        );
+
+        parse_invalid!(
+            "16", "struct A { #[reserved(2, access = ro)] _r: _ }",
+            "expected one of: `bit`, `size`, `policy`",
+            (1, 25), (1, 31)
+        );
+
+        parse_invalid!(
+            "16", "struct A { #[reserved(2, policy = zero, policy = zero)] _r: _ }",
+            "duplicate",
+            (1, 40), (1, 46)
+        );
+    }
+
     #[test]
     fn field_details_span() {
         match parse_valid!("8", "struct A(#[field(0, 1)] A);").data {
@@ -1073,6 +2766,71 @@ pub(super) mod tests {
         };
     }
 
+    #[test]
+    fn field_details_access() {
+        assert!(parse_valid!("8", "struct A(B);").data.entries()[0].access.is_none());
+
+        for (access, mode) in [
+            ("rw", AccessMode::ReadWrite),
+            ("ro", AccessMode::ReadOnly),
+            ("wo", AccessMode::WriteOnly),
+            ("w1c", AccessMode::WriteOneToClear)
+        ] {
+            let item = format!("struct A(#[field(access = {})] B);", access);
+            assert_eq!(parse_valid!("8", item).data.entries()[0].access_mode(), mode);
+        }
+
+        let bitfield = parse_valid!("8", "struct A(#[field(1, 2, access = ro, get = kind)] B);");
+        let entry = bitfield.data.entries()[0];
+        assert_eq!(entry.access_mode(), AccessMode::ReadOnly);
+        assert!(entry.field.is_some());
+        compare_span!(entry.access.as_ref().unwrap().span, (1, 32), (1, 34));
+
+        parse_invalid!(
+            "8", "struct A(#[field(access = rx)] B);",
+            "expected one of: `ro`, `wo`, `w1c`, `rw`",
+            (1, 26), (1, 28)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(access = ro, access = wo)] B);",
+            "duplicate",
+            (1, 30), (1, 36)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(size = 2, access = w1c)] B);",
+            "only supported for flags",
+            (1, 36), (1, 39)
+        );
+
+        parse_invalid!(
+            "8", "struct A { #[field(access = w1c)] b: bool }",
+            "only supported for flags",
+            (1, 28), (1, 31)
+        );
+
+        parse_invalid!(
+            "8", "struct A { #[cfg(x)] #[field(access = w1c)] b: B }",
+            "not supported for conditional entries",
+            (1, 38), (1, 41)
+        );
+
+        parse_invalid!(
+            "8", "#[derive(Display)] struct A(#[field(access = wo)] B);",
+            "can not generate `Display` for bit fields with write-only entries",
+            (1, 9), (1, 16)
+        );
+
+        parse_invalid!(
+            "16", "#[derive(Display)] #[display(\"{b}/{c}\")] struct A { b: B, #[field(access = wo)] c: C }",
+            "can not display write-only entry `c`",
+            (1, 29), (1, 38)
+        );
+
+        parse_valid!("16", "#[derive(Display)] #[display(\"{b}\")] struct A { b: B, #[field(access = wo)] c: C }");
+    }
+
     #[test]
     fn field_details_bit() {
         parse_invalid!(
@@ -1212,6 +2970,132 @@ pub(super) mod tests {
         );
     }
 
+    #[test]
+    fn field_details_required() {
+        assert!(parse_valid!("8", "struct A(bool);").data.entries()[0].required.is_none());
+
+        compare_span!(parse_valid!(
+            "8", "struct A(#[field(required)] bool);"
+        ).data.entries()[0].required.as_ref().unwrap().span(), (1, 17), (1, 25));
+
+        let bitfield = parse_valid!("8", "struct A(#[field(size = 2, required, get = kind)] B);");
+        assert!(bitfield.data.entries()[0].required.is_some());
+
+        let bitfield = parse_valid!("8", "struct A(#[field(1, 2, complete, required)] B);");
+        assert!(bitfield.data.entries()[0].required.is_some());
+
+        let bitfield = parse_valid!("8", "struct A { #[cfg_attr(x, field(4, 1, required))] #[field(0, 1, required)] b: bool }");
+        assert!(bitfield.data.entries().iter().all(|e| e.required.is_some()));
+
+        parse_invalid!(
+            "8", "struct A(#[field(required, required)] bool);",
+            "duplicate",
+            (1, 27), (1, 35)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(required)] B);",
+            "only supported for fields",
+            (1, 17), (1, 25)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(access = ro, required)] bool);",
+            "only supported for writable fields",
+            (1, 30), (1, 38)
+        );
+
+        parse_invalid!(
+            "NonZero8", "struct A { #[field(required)] b: bool }",
+            "not supported for `NonZero` bit fields",
+            (1, 19), (1, 27)
+        );
+
+        parse_invalid!(
+            "8", "struct A { #[cfg(x)] #[field(required)] b: bool }",
+            "not supported for conditional entries without alternatives",
+            (1, 29), (1, 37)
+        );
+
+        parse_invalid!(
+            "8", "struct A { #[cfg_attr(x, field(4, 1))] #[field(0, 1, required)] b: bool }",
+            "not supported unless all alternatives are `required`",
+            (1, 53), (1, 61)
+        );
+
+        parse_invalid!(
+            "8", "struct A { #[field(required)] b: bool, builder: bool }",
+            "accessor `builder` of entry `builder` collides with method `builder`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 39), (1, 46)
+        );
+
+        parse_invalid!(
+            "8", "struct A { #[field(required)] b: bool, #[field(set = build)] c: bool }",
+            "builder method `build` of entry `c` collides with builder method `build`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 53), (1, 58)
+        );
+    }
+
+    #[test]
+    fn field_details_groups() {
+        assert!(parse_valid!("8", "struct A(#[field(size = 2)] B);").data.entries()[0].groups.is_none());
+
+        let bitfield = parse_valid!("8", "struct A(#[field(size = 2, groups(x, r#y), required)] B);");
+        let groups = bitfield.data.entries()[0].groups.as_ref().unwrap();
+        assert_eq!(groups.idents.iter().map(|g| g.to_string()).collect::<Vec<_>>(), vec!("x", "r#y"));
+        compare_span!(groups.span, (1, 27), (1, 33));
+
+        parse_valid!("8", "struct A { #[field(size = 2, complete, groups(x))] b: Option<B> }");
+
+        parse_invalid!(
+            "8", "struct A(#[field(size = 2, groups())] B);",
+            "expected at least one group",
+            (1, 27), (1, 33)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(size = 2, groups(x), groups(y))] B);",
+            "duplicate",
+            (1, 38), (1, 44)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(size = 2, groups(x, r#x))] B);",
+            "duplicate",
+            (1, 37), (1, 40)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(size = 2, groups)] B);",
+            "expected one of: `complete`, `required`, `groups(...)`",
+            (1, 27), (1, 33)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(get = b, groups(x))] B);",
+            "only supported for enumeration fields",
+            (1, 26), (1, 32)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(size = 2, groups(x))] u8);",
+            "only supported for enumeration fields",
+            (1, 27), (1, 33)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(size = 2, access = wo, groups(x))] B);",
+            "only supported for readable fields",
+            (1, 40), (1, 46)
+        );
+
+        parse_invalid!(
+            "8", "struct A { #[field(size = 2, groups(x))] b: B, b_is_x: bool }",
+            "accessor `b_is_x` of entry `b_is_x` collides with accessor `b_is_x` of entry `b`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 47), (1, 53)
+        );
+    }
+
     #[test]
     fn field_details_extra_tokens() {
         parse_invalid!(
@@ -1228,7 +3112,7 @@ pub(super) mod tests {
 
         parse_invalid!(
             "8", "struct A(#[field(bit = 1, complete, X)] B);",
-            "did you mean `complete`?",
+            "expected one of: `complete`, `required`, `groups(...)`",
             (1, 36), (1, 37)
         );
 
@@ -1245,11 +3129,67 @@ pub(super) mod tests {
         );
     }
 
+    #[test]
+    fn field_details_names() {
+        let bitfield = parse_valid!("8", "struct A { #[field(get = is_b, set = with_b, invert = flip_b)] b: bool }");
+        let entry = bitfield.data.entries()[0];
+        assert_eq!(entry.field.as_ref().unwrap().bit.as_ref().unwrap().base10_parse::<u8>().unwrap(), 0);
+        assert_eq!(entry.names.getter.as_ref().unwrap(), "is_b");
+        assert_eq!(entry.names.setter.as_ref().unwrap(), "with_b");
+        assert_eq!(entry.names.inverter.as_ref().unwrap(), "flip_b");
+
+        let bitfield = parse_valid!("8", "struct A(#[field(set = with)] B);");
+        let entry = bitfield.data.entries()[0];
+        assert!(entry.field.is_none());
+        assert!(entry.names.getter.is_none());
+        assert_eq!(entry.names.setter.as_ref().unwrap(), "with");
+
+        let bitfield = parse_valid!("8", "struct A(#[field(1, 2, complete, get = kind)] B);");
+        let entry = bitfield.data.entries()[0];
+        assert!(entry.field.as_ref().unwrap().complete.is_some());
+        assert_eq!(entry.names.getter.as_ref().unwrap(), "kind");
+
+        let bitfield = parse_valid!("8", "struct A(#[field(size = 2, set = with_kind, complete)] B);");
+        let entry = bitfield.data.entries()[0];
+        assert!(entry.field.as_ref().unwrap().complete.is_some());
+        assert_eq!(entry.names.setter.as_ref().unwrap(), "with_kind");
+
+        parse_invalid!(
+            "8", "struct A(#[field(get = a, get = b)] B);",
+            "duplicate",
+            (1, 26), (1, 29)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(get = a, complete)] B);",
+            "unnecessary without `bit` or `size`",
+            (1, 26), (1, 34)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(1, 2, other = a)] B);",
+            "expected one of: `get`, `set`, `invert`, `access`",
+            (1, 23), (1, 28)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(get = 1)] B);",
+            "expected identifier",
+            (1, 23), (1, 24)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(get = a b)] B);",
+            "unexpected token",
+            (1, 25), (1, 26)
+        );
+    }
+
     #[test]
     fn field_details_short() {
         parse_invalid!(
             "8", "struct A(#[field(x = 1)] B);",
-            "expected `bit` or `size`",
+            "expected one of: `bit`, `size`, `get`, `set`, `invert`, `access`, `required`",
             (1, 17), (1, 18)
         );
     }
@@ -1279,6 +3219,11 @@ pub(super) mod tests {
             Some((1, 9, 1, 14)), Some((1, 23, 1, 30)),
             quote::quote! { #[derive(Other)] }
         );
+        filter_derive!(
+            "#[derive(LowerHex, Other, UpperHex, Binary, Octal)]", 1,
+            None, None,
+            quote::quote! { #[derive(Other)] }
+        );
         filter_derive!(
             "#[derive(Other)] /** */", 2,
             None, None,
@@ -1308,12 +3253,14 @@ pub(super) mod tests {
 
     #[test]
     fn overlaps() {
-        let zero_one = syn::parse_str("(0, 1)").unwrap();
-        let zero_two = syn::parse_str("(0, 2)").unwrap();
-        let zero_three = syn::parse_str("(0, 3)").unwrap();
-        let one_one = syn::parse_str("(1, 1)").unwrap();
-        let one_two = syn::parse_str("(1, 2)").unwrap();
-        let two_one = syn::parse_str("(2, 1)").unwrap();
+        let details = |s| syn::parse_str::<super::FieldAttribute>(s).unwrap().details.unwrap();
+
+        let zero_one = details("(0, 1)");
+        let zero_two = details("(0, 2)");
+        let zero_three = details("(0, 3)");
+        let one_one = details("(1, 1)");
+        let one_two = details("(1, 2)");
+        let two_one = details("(2, 1)");
 
         // l-l r-r
         assert!(!BitField::overlaps(&zero_one, &two_one).unwrap());
@@ -1684,6 +3631,34 @@ pub(super) mod tests {
         parse_valid!("NonZero128", "struct A(#[field(1, 127)] u128);");
     }
 
+    #[test]
+    fn validate_exhaustive() {
+        parse_valid!("8, exhaustive", "struct A { #[field(0, 2)] b: B, #[field(2, 6)] c: C }");
+        parse_valid!("16, exhaustive", "struct A { b: u8, c: i8 }");
+
+        // Flags, conditional entries and `usize` based bit fields are checked by constant assertions.
+        parse_valid!("8, exhaustive", "struct A { #[field(0, 2)] b: B, c: C }");
+        parse_valid!("16, exhaustive", "struct A { #[cfg(x)] b: u8 }");
+        parse_valid!("8, exhaustive", "struct A(B);");
+        parse_valid!("size, exhaustive", "struct A { #[field(0, 2)] b: B }");
+
+        parse_invalid!(
+            "8, exhaustive", "struct A { #[field(1, 2)] b: B }",
+            "not every bit is used by a field, flags or reserved bits, unused bits: 0, 3-7",
+            (1, 3), (1, 13)
+        );
+        parse_invalid!(
+            "16, exhaustive", "struct A { #[field(0, 4)] b: B, #[field(8, 4)] c: C, #[field(13, 1)] d: D }",
+            "not every bit is used by a field, flags or reserved bits, unused bits: 4-7, 12, 14-15",
+            (1, 4), (1, 14)
+        );
+        parse_invalid!(
+            "8, exhaustive", "struct A {}",
+            "not every bit is used by a field, flags or reserved bits, unused bits: 0-7",
+            (1, 3), (1, 13)
+        );
+    }
+
     #[test]
     fn validate_field_size() {
         parse_invalid!(
@@ -1823,14 +3798,166 @@ pub(super) mod tests {
         parse_valid!("NonZero128", "struct A(#[field(1, 127)] u128);");
     }
 
+    #[test]
+    fn validate_names() {
+        parse_invalid!(
+            "16", "struct A { b: B, b_mask: u8 }",
+            "accessor `b_mask` of entry `b_mask` collides with accessor `b_mask` of entry `b`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 17), (1, 23)
+        );
+
+        parse_valid!("16", "struct A { #[field(get = b_flag)] b: B, b_mask: u8 }");
+
+        parse_invalid!(
+            "8", "struct A { #[field(set = set_c)] b: bool, c: bool }",
+            "accessor `set_c` of entry `c` collides with accessor `set_c` of entry `b`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 42), (1, 43)
+        );
+
+        parse_valid!("8", "struct A { b: bool, with_b: bool }");
+
+        parse_invalid!(
+            "8, naming = \"with\"", "struct A { b: bool, with_b: bool }",
+            "accessor `with_b` of entry `with_b` collides with accessor `with_b` of entry `b`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 20), (1, 26)
+        );
+
+        parse_invalid!(
+            "8", "struct A { new: bool }",
+            "accessor `new` of entry `new` collides with method `new`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 11), (1, 14)
+        );
+
+        parse_valid!("NonZero8", "struct A { new: bool }");
+
+        parse_valid!("8", "struct A { #[field(access = ro)] b: bool, set_b: bool }");
+        parse_valid!("8", "struct A { #[field(access = wo)] b: B, b_any: bool }");
+
+        parse_invalid!(
+            "8", "struct A { #[field(access = w1c)] b: B, clear_b: bool }",
+            "accessor `clear_b` of entry `clear_b` collides with accessor `clear_b` of entry `b`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 40), (1, 47)
+        );
+
+        parse_invalid!(
+            "8", "struct A(#[field(get = set)] bool);",
+            "accessor `set` collides with accessor `set`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 29), (1, 33)
+        );
+
+        parse_valid!("8", "struct A { b: bool, read: bool }");
+
+        // Alternatives of an entry never exist at the same time.
+        parse_valid!("8", "struct A { #[cfg_attr(x, field(4, 1))] b: bool }");
+        parse_valid!("8", "struct A { #[cfg(x)] b: bool, #[cfg(not(x))] #[field(4, 1)] b: bool }");
+        parse_invalid!(
+            "8", "struct A { b: bool, #[cfg(x)] #[field(4, 1)] b: bool }",
+            "accessor `b` of entry `b` collides with accessor `b` of entry `b`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 45), (1, 46)
+        );
+
+        parse_invalid!(
+            "8, register", "struct A { #[field(set = write)] b: bool }",
+            "register shortcut `write` of entry `b` collides with register method `write`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 25), (1, 30)
+        );
+
+        parse_valid!("8, register", "struct A { #[field(access = ro, set = write)] b: bool }");
+        parse_valid!("8", "struct A(#[field(invert = modify)] bool);");
+
+        parse_invalid!(
+            "8, view = \"le\"", "struct A { into_owned: bool }",
+            "accessor `into_owned` of entry `into_owned` collides with view method `into_owned`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 11), (1, 21)
+        );
+
+        parse_invalid!(
+            "8, view = \"le\"", "struct A(#[field(set = read)] B);",
+            "view shortcut `read` collides with view method `read`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 23), (1, 27)
+        );
+
+        parse_valid!("8, register", "struct A { into_owned: bool }");
+
+        parse_invalid!(
+            "8, register", "struct A(#[field(invert = modify)] bool);",
+            "register shortcut `modify` collides with register method `modify`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            (1, 26), (1, 32)
+        );
+    }
+
+    #[test]
+    fn validate_optional() {
+        parse_valid!("8", "struct A(#[field(size = 2, complete)] Option<B>);");
+        parse_valid!("16", "struct A(#[field(size = 9)] Option<NonZeroU16>);");
+
+        parse_invalid!(
+            "8", "struct A(#[field(size = 2)] Option<u8>);",
+            "expected an enumeration or an unsigned `NonZero` type, `None` is stored as `0`",
+            (1, 35), (1, 37)
+        );
+        parse_invalid!(
+            "8", "struct A(#[field(size = 2)] Option<NonZeroI8>);",
+            "expected an enumeration or an unsigned `NonZero` type, `None` is stored as `0`",
+            (1, 35), (1, 44)
+        );
+        parse_invalid!(
+            "8", "struct A(#[field(size = 2, complete)] Option<NonZeroU8>);",
+            "unnecessary for primitive types",
+            (1, 27), (1, 35)
+        );
+        parse_invalid!(
+            "16", "struct A(#[field(size = 9)] Option<NonZeroU8>);",
+            "type is smaller than the specified size of 9 bits",
+            (1, 35), (1, 44)
+        );
+        parse_invalid!(
+            "16", "struct A(#[field(size = 4)] Option<NonZeroU16>);",
+            "field only uses 4 bits, use `NonZeroU8` instead",
+            (1, 35), (1, 45)
+        );
+        parse_invalid!(
+            "8", "struct A { #[field(size = 4)] b: Option<NonZeroU16> }",
+            "bigger than the size of the bit field, use `NonZeroU8` instead",
+            (1, 40), (1, 50)
+        );
+    }
+
     #[test]
     fn validate_overlaps() {
         parse_invalid!(
             "8", "struct A { #[field(0, 2)] b: B, #[field(1, 2)] c: C }",
-            "overlaps with field `b`, please specify `allow_overlaps` if this is intended",
+            "overlaps with field `b`, please specify `allow_overlaps` if this is intended\n\
+            `c`: bits 1-2, `b`: bits 0-1, overlapping: bit 1\n\
+            layout (bit 7 first): `.....#X=`, `#` is `c`, `=` is `b`, `X` is both, `-` are other fields, `.` are free bits\n\
+            help: the next free position is `#[field(2, 2)]`",
             (1, 39), (1, 40)
         );
 
+        // The second error points at the other field.
+        let error = BitField::parse(
+            "8".parse().unwrap(), "struct A { #[field(0, 2)] b: B, #[field(1, 2)] c: C }".parse().unwrap()
+        ).map(|_| ()).unwrap_err();
+        let note = error.into_iter().nth(1).unwrap();
+        assert_eq!(note.to_string(), "field `b` is declared here");
+        compare_span!(note.span(), (1, 18), (1, 19));
+
+        parse_invalid!(
+            "16", "struct A { #[field(0, 4)] b: u8, #[field(4, 4)] c: u8, #[field(8, 6)] d: u8, #[field(2, 4)] r#e: u8 }",
+            "overlaps with field `c`, please specify `allow_overlaps` if this is intended\n\
+            `e`: bits 2-5, `c`: bits 4-7, overlapping: bits 4-5\n\
+            layout (bit 15 first): `..------==XX##--`, `#` is `e`, `=` is `c`, `X` is both, `-` are other fields, `.` are free bits\n\
+            help: there are no 4 consecutive free bits",
+            (1, 84), (1, 85)
+        );
+
+        parse_invalid!(
+            "size", "struct A { #[field(0, 2)] b: u8, #[field(1, 2)] c: u8 }",
+            "overlaps with field `b`, please specify `allow_overlaps` if this is intended\n\
+            `c`: bits 1-2, `b`: bits 0-1, overlapping: bit 1",
+            (1, 40), (1, 41)
+        );
+
         parse_invalid!(
             "8, allow_overlaps", "struct A { #[field(0, 1)] b: B, #[field(1, 2)] c: C }",
             "unnecessary since no fields overlap",
@@ -1838,6 +3965,37 @@ pub(super) mod tests {
         );
 
         parse_valid!("8, allow_overlaps", "struct A { #[field(0, 2)] b: B, #[field(1, 2)] c: C }");
+
+        // Conditional entries are checked by constant assertions, alternatives never overlap.
+        parse_valid!("8", "struct A { #[field(0, 2)] b: B, #[cfg(x)] #[field(1, 2)] c: C }");
+        parse_valid!("8, allow_overlaps", "struct A { #[field(0, 2)] b: B, #[cfg(x)] #[field(1, 2)] c: C }");
+        parse_valid!("8", "struct A { #[cfg_attr(x, field(1, 2))] #[field(0, 2)] b: B }");
+        parse_invalid!(
+            "8, allow_overlaps", "struct A { #[cfg_attr(x, field(1, 2))] #[field(0, 2)] b: B }",
+            "unnecessary since no fields overlap",
+            (1, 3), (1, 17)
+        );
+
+        parse_invalid!(
+            "8", "struct A { #[reserved(0, 4)] _r: _, #[field(2, 4)] b: u8 }",
+            "overlaps with reserved bits `_r`, please specify `allow_overlaps` if this is intended\n\
+            `b`: bits 2-5, `_r`: bits 0-3, overlapping: bits 2-3\n\
+            layout (bit 7 first): `..##XX==`, `#` is `b`, `=` is `_r`, `X` is both, `-` are other fields, `.` are free bits\n\
+            help: the next free position is `#[field(4, 4)]`",
+            (1, 43), (1, 44)
+        );
+    }
+
+    #[test]
+    fn validate_reserved() {
+        parse_invalid!(
+            "NonZero8", "struct A { #[reserved(4, policy = zero)] _r: _, b: bool }",
+            "`policy = zero` is not supported for `NonZero` bit fields",
+            (1, 13), (1, 21)
+        );
+
+        parse_valid!("NonZero8", "struct A { #[reserved(4)] _r: _, b: bool }");
+        parse_valid!("8, exhaustive", "struct A { #[reserved(4)] _r: _, #[field(size = 4)] b: u8 }");
     }
 
     #[test]
@@ -0,0 +1,368 @@
+//! Contains code to describe bit fields and their `Field` and `Flags` enumerations in C headers.
+
+use syn::ext::IdentExt;
+
+/// Converts a Rust identifier like `AccessFile` or `read_only` into `access_file` or `read_only`.
+pub(crate) fn snake_case(ident: &syn::Ident) -> String {
+    let name = ident.unraw().to_string();
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::with_capacity(name.len() + 4);
+
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let is_next_lowercase = chars.get(i + 1).map(|c| c.is_lowercase()).unwrap_or_default();
+
+            // Split `AccessFile` into `Access_File` and `HTTPServer` into `HTTP_Server`.
+            if previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && is_next_lowercase) {
+                result.push('_');
+            }
+        }
+
+        result.extend(c.to_lowercase());
+    }
+
+    result
+}
+
+/// Returns the C type for a primitive Rust type.
+fn c_type(primitive_type: &syn::Ident) -> syn::Result<&'static str> {
+    Ok(match primitive_type.to_string().as_ref() {
+        "bool" => "bool",
+        "u8" => "uint8_t",
+        "u16" => "uint16_t",
+        "u32" => "uint32_t",
+        "u64" => "uint64_t",
+        "usize" => "uintptr_t",
+        "i8" => "int8_t",
+        "i16" => "int16_t",
+        "i32" => "int32_t",
+        "i64" => "int64_t",
+        "isize" => "intptr_t",
+        _ => return Err(syn::Error::new(primitive_type.span(), "not supported in C headers"))
+    })
+}
+
+/// The includes every header needs.
+const INCLUDES: &str = "#include <stdbool.h>\n#include <stdint.h>\n";
+
+/// Generates the `#define`s and `static inline` accessors of a bit field, without any includes.
+///
+/// Fields get `NAME_FIELD_SHIFT` and `NAME_FIELD_MASK` macros, and `name_get_field` and
+/// `name_set_field` functions, which take and return the primitive value of the bit field. Enum
+/// fields are read and written as their unsigned primitive value. Flags get `name_get_flags` and
+/// `name_set_flags` functions, which take the position of the flag, i.e. the value of the variant.
+/// Reserved bits only get the `SHIFT` and `MASK` macros.
+pub(crate) fn bitfield_definitions(bitfield: &crate::bitfield::BitField) -> syn::Result<String> {
+    let prefix = snake_case(&bitfield.ident);
+    let macro_prefix = prefix.to_uppercase();
+    let value_type = c_type(&bitfield.attr.primitive_type)?;
+    let mut result = format!("/* {} */\n", bitfield.ident.unraw());
+
+    for (entry, accessors) in bitfield.data.entries().into_iter().zip(bitfield.accessors()) {
+        let (name, macro_name) = match &accessors.name {
+            Some(name) => (format!("_{}", snake_case(name)), format!("{}_{}", macro_prefix, snake_case(name).to_uppercase())),
+            None => (String::new(), macro_prefix.clone())
+        };
+
+        let is_readable = entry.is_readable();
+        let is_writable = entry.is_writable();
+
+        let field = match &entry.field {
+            Some(field) => field,
+
+            None => {
+                if is_readable {
+                    result += &format!(
+                        "static inline bool {prefix}_get{name}({value_type} value, unsigned flag) {{ return (value >> flag) & 1u; }}\n"
+                    );
+                }
+
+                if is_writable {
+                    result += &format!(
+                        "static inline {value_type} {prefix}_set{name}({value_type} value, unsigned flag, bool set) {{ return set ? ({value_type})(value | (({value_type})1u << flag)) : ({value_type})(value & ~(({value_type})1u << flag)); }}\n"
+                    );
+                }
+
+                continue;
+            }
+        };
+
+        let bit = field.bit.as_ref().unwrap().base10_parse::<u8>().unwrap();
+        let size = field.size.as_ref().unwrap().base10_parse::<u8>().unwrap();
+
+        // Enumerations are read and written as their unsigned primitive value.
+        let field_type = match entry.ty.get_ident().filter(|ty| crate::primitive::is_primitive(ty)) {
+            Some(ty) => c_type(ty)?,
+            None => c_type(&crate::primitive::type_from_bits(size, false, field.size.as_ref().unwrap().span()))?
+        };
+
+        let mask = match size {
+            128 => u128::MAX,
+            size => (1u128 << size) - 1
+        } << bit;
+
+        // Masks that do not fit into an `unsigned int` need a bigger literal.
+        let suffix = if mask > u32::MAX as u128 { "ull" } else { "u" };
+
+        result += &format!("#define {macro_name}_SHIFT {bit}\n");
+        result += &format!("#define {macro_name}_MASK 0x{mask:x}{suffix}\n");
+
+        if is_readable {
+            let extracted = format!("((value & {macro_name}_MASK) >> {macro_name}_SHIFT)");
+            let extracted = match field_type {
+                "bool" => format!("{extracted} != 0"),
+                _ => format!("({field_type}){extracted}")
+            };
+
+            result += &format!(
+                "static inline {field_type} {prefix}_get{name}({value_type} value) {{ return {extracted}; }}\n"
+            );
+        }
+
+        if is_writable {
+            result += &format!(
+                "static inline {value_type} {prefix}_set{name}({value_type} value, {field_type} field) {{ return ({value_type})((value & ~({value_type}){macro_name}_MASK) | ((({value_type})field << {macro_name}_SHIFT) & {macro_name}_MASK)); }}\n"
+            );
+        }
+    }
+
+    // Reserved bits have no accessors, only their position is described.
+    if let crate::bitfield::Data::Named(entries) = &bitfield.data {
+        for entry in entries.iter().filter(|entry| entry.entry.reserved.is_some()) {
+            let field = entry.entry.field.as_ref().unwrap();
+            let bit = field.bit.as_ref().unwrap().base10_parse::<u8>().unwrap();
+            let size = field.size.as_ref().unwrap().base10_parse::<u8>().unwrap();
+            let mask = ((1u128 << size) - 1) << bit;
+            let suffix = if mask > u32::MAX as u128 { "ull" } else { "u" };
+            let macro_name = format!("{}_{}", macro_prefix, snake_case(&entry.ident).trim_start_matches('_').to_uppercase());
+
+            result += &format!("#define {macro_name}_SHIFT {bit}\n");
+            result += &format!("#define {macro_name}_MASK 0x{mask:x}{suffix}\n");
+        }
+    }
+
+    Ok(result)
+}
+
+/// Generates the C header for a single bit field, as stored in its `C_HEADER` constant.
+pub(crate) fn bitfield(bitfield: &crate::bitfield::BitField) -> syn::Result<String> {
+    Ok(format!("{}\n{}", INCLUDES, bitfield_definitions(bitfield)?))
+}
+
+/// Generates a C `enum` for an enumeration that derives `Field` or `Flags`. Variants without a
+/// discriminant count up from the previous one, like in Rust.
+fn enumeration(item: &syn::ItemEnum) -> syn::Result<String> {
+    let prefix = snake_case(&item.ident);
+    let macro_prefix = prefix.to_uppercase();
+    let mut result = format!("enum {} {{\n", prefix);
+    let mut value = 0i128;
+
+    for (i, variant) in item.variants.iter().enumerate() {
+        if let Some((_, discriminant)) = &variant.discriminant {
+            value = parse_discriminant(discriminant)?;
+        } else if i > 0 {
+            value += 1;
+        }
+
+        let separator = if i + 1 < item.variants.len() { "," } else { "" };
+        result += &format!(
+            "    {}_{} = {}{}\n", macro_prefix, snake_case(&variant.ident).to_uppercase(), value, separator
+        );
+    }
+
+    result += "};\n";
+    Ok(result)
+}
+
+/// Parses an integer literal discriminant, which can be negative.
+fn parse_discriminant(expression: &syn::Expr) -> syn::Result<i128> {
+    match expression {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) => int.base10_parse(),
+
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => {
+            parse_discriminant(expr).map(|value| -value)
+        },
+
+        syn::Expr::Group(syn::ExprGroup { expr, .. }) | syn::Expr::Paren(syn::ExprParen { expr, .. }) => {
+            parse_discriminant(expr)
+        },
+
+        expression => Err(syn::Error::new_spanned(expression, "expected integer literal"))
+    }
+}
+
+/// Returns the arguments of `#[bitfield(...)]` or `#[bitfield::bitfield(...)]`, if `attrs` contains
+/// it, and the other attributes.
+fn split_bitfield_attribute(attrs: &[syn::Attribute]) -> syn::Result<(Option<proc_macro2::TokenStream>, Vec<syn::Attribute>)> {
+    let mut arguments = None;
+    let mut other = Vec::with_capacity(attrs.len());
+
+    for attr in attrs {
+        match attr.path.segments.last() {
+            Some(segment) if segment.ident == "bitfield" => arguments = Some(attr.parse_args()?),
+            _ => other.push(attr.clone())
+        }
+    }
+
+    Ok((arguments, other))
+}
+
+/// Returns `true` if the `#[derive(...)]` attributes contain `Field` or `Flags`.
+fn derives_field_or_flags(attrs: &[syn::Attribute]) -> syn::Result<bool> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("derive")) {
+        let paths = attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated
+        )?;
+
+        if paths.iter().any(|path| path.segments.last().map(
+            |segment| segment.ident == "Field" || segment.ident == "Flags"
+        ).unwrap_or_default()) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Collects the enumerations and bit fields of `items` and nested inline modules.
+fn collect(items: &[syn::Item], enums: &mut Vec<String>, bitfields: &mut Vec<String>) -> syn::Result<()> {
+    for item in items {
+        match item {
+            syn::Item::Enum(item) if derives_field_or_flags(&item.attrs)? => enums.push(enumeration(item)?),
+
+            syn::Item::Struct(item) => {
+                let (arguments, attrs) = split_bitfield_attribute(&item.attrs)?;
+
+                if let Some(arguments) = arguments {
+                    let item = syn::ItemStruct { attrs, ..item.clone() };
+                    let bitfield = crate::bitfield::BitField::parse(arguments, quote::ToTokens::into_token_stream(item))?;
+                    bitfields.push(bitfield_definitions(&bitfield)?);
+                }
+            },
+
+            syn::Item::Mod(syn::ItemMod { content: Some((_, items)), .. }) => collect(items, enums, bitfields)?,
+
+            _ => ()
+        }
+    }
+
+    Ok(())
+}
+
+/// Generates a C header from Rust source code, f. e. in a `build.rs` script. It contains a C `enum`
+/// for every enumeration which derives `Field` or `Flags`, and the `#define`s and `static inline`
+/// accessors of every bit field, see the `C_HEADER` constant of the `bitfield` macro.
+///
+/// Example:
+///
+/// ```ignore
+/// let source = std::fs::read_to_string("src/registers.rs").unwrap();
+/// let header = bitfield_impl::c_header::from_source(&source).unwrap();
+/// std::fs::write(std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("registers.h"), header).unwrap();
+/// ```
+pub fn from_source(source: &str) -> syn::Result<String> {
+    let file = syn::parse_file(source)?;
+
+    let mut enums = vec!();
+    let mut bitfields = vec!();
+    collect(&file.items, &mut enums, &mut bitfields)?;
+
+    let mut result = format!("/* Generated by the `bitfield` crate. */\n#pragma once\n\n{}", INCLUDES);
+    for definition in enums.into_iter().chain(bitfields) {
+        result += "\n";
+        result += &definition;
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn snake_case() {
+        const NAMES: &[(&str, &str)] = &[
+            ("A", "a"), ("AccessFile", "access_file"), ("read_only", "read_only"),
+            ("HTTPServer", "http_server"), ("Flag00001000", "flag00001000"), ("Io2Port", "io2_port"),
+            ("r#type", "type")
+        ];
+
+        for (name, result) in NAMES {
+            assert_eq!(super::snake_case(&syn::parse_str::<syn::Ident>(name).unwrap()), *result);
+        }
+    }
+
+    #[test]
+    fn from_source() {
+        let header = super::from_source(r#"
+            #[bitfield::bitfield(16)]
+            struct Status {
+                #[field(size = 2)] field: Field,
+                #[field(bit = 4, access = ro)] integer: i8,
+                #[field(bit = 12)] ready: bool,
+                #[field(access = wo)] flags: Flags
+            }
+
+            mod nested {
+                #[derive(Clone, Copy, bitfield::Field)]
+                #[repr(u8)]
+                enum Field { F1 = 1, F2, ReadWrite = 3 }
+
+                #[derive(Clone, Copy, Flags)]
+                #[repr(u8)]
+                enum Flags { F0, F13 = 13, F14 }
+
+                #[bitfield(8)]
+                struct Tuple(#[field(size = 3)] u8);
+            }
+
+            enum Ignored { A }
+        "#).unwrap();
+
+        assert_eq!(header, concat!(
+            "/* Generated by the `bitfield` crate. */\n",
+            "#pragma once\n",
+            "\n",
+            "#include <stdbool.h>\n",
+            "#include <stdint.h>\n",
+            "\n",
+            "enum field {\n",
+            "    FIELD_F1 = 1,\n",
+            "    FIELD_F2 = 2,\n",
+            "    FIELD_READ_WRITE = 3\n",
+            "};\n",
+            "\n",
+            "enum flags {\n",
+            "    FLAGS_F0 = 0,\n",
+            "    FLAGS_F13 = 13,\n",
+            "    FLAGS_F14 = 14\n",
+            "};\n",
+            "\n",
+            "/* Status */\n",
+            "#define STATUS_FIELD_SHIFT 0\n",
+            "#define STATUS_FIELD_MASK 0x3u\n",
+            "static inline uint8_t status_get_field(uint16_t value) { return (uint8_t)((value & STATUS_FIELD_MASK) >> STATUS_FIELD_SHIFT); }\n",
+            "static inline uint16_t status_set_field(uint16_t value, uint8_t field) { return (uint16_t)((value & ~(uint16_t)STATUS_FIELD_MASK) | (((uint16_t)field << STATUS_FIELD_SHIFT) & STATUS_FIELD_MASK)); }\n",
+            "#define STATUS_INTEGER_SHIFT 4\n",
+            "#define STATUS_INTEGER_MASK 0xff0u\n",
+            "static inline int8_t status_get_integer(uint16_t value) { return (int8_t)((value & STATUS_INTEGER_MASK) >> STATUS_INTEGER_SHIFT); }\n",
+            "#define STATUS_READY_SHIFT 12\n",
+            "#define STATUS_READY_MASK 0x1000u\n",
+            "static inline bool status_get_ready(uint16_t value) { return ((value & STATUS_READY_MASK) >> STATUS_READY_SHIFT) != 0; }\n",
+            "static inline uint16_t status_set_ready(uint16_t value, bool field) { return (uint16_t)((value & ~(uint16_t)STATUS_READY_MASK) | (((uint16_t)field << STATUS_READY_SHIFT) & STATUS_READY_MASK)); }\n",
+            "static inline uint16_t status_set_flags(uint16_t value, unsigned flag, bool set) { return set ? (uint16_t)(value | ((uint16_t)1u << flag)) : (uint16_t)(value & ~((uint16_t)1u << flag)); }\n",
+            "\n",
+            "/* Tuple */\n",
+            "#define TUPLE_SHIFT 0\n",
+            "#define TUPLE_MASK 0x7u\n",
+            "static inline uint8_t tuple_get(uint8_t value) { return (uint8_t)((value & TUPLE_MASK) >> TUPLE_SHIFT); }\n",
+            "static inline uint8_t tuple_set(uint8_t value, uint8_t field) { return (uint8_t)((value & ~(uint8_t)TUPLE_MASK) | (((uint8_t)field << TUPLE_SHIFT) & TUPLE_MASK)); }\n"
+        ));
+
+        let error = super::from_source("#[derive(Field)] #[repr(u8)] enum A { B = 1 + 1 }").unwrap_err();
+        assert_eq!(error.to_string(), "expected integer literal");
+
+        let error = super::from_source("#[bitfield(128)] struct A(#[field(size = 4)] u8);").unwrap_err();
+        assert_eq!(error.to_string(), "not supported in C headers");
+    }
+}
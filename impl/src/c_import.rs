@@ -0,0 +1,1272 @@
+//! Contains code to import bit field definitions from C headers.
+
+use std::collections::HashMap;
+
+/// A construct of a C header or an SVD file which could not be imported.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    /// The line of the construct, starting at `1`.
+    pub line: usize,
+    pub message: String
+}
+
+impl core::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// The result of `from_header` and `svd::from_svd`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Import {
+    /// Rust source code which contains the imported types.
+    pub source: String,
+    /// A diagnostic for every construct which could not be imported.
+    pub diagnostics: Vec<Diagnostic>
+}
+
+/// A C token and the line it starts in.
+#[derive(Clone, Debug)]
+struct Token {
+    text: String,
+    line: usize
+}
+
+impl Token {
+    fn is(&self, text: &str) -> bool {
+        self.text == text
+    }
+
+    fn is_ident(&self) -> bool {
+        self.text.starts_with(|c: char| c.is_alphabetic() || c == '_')
+    }
+}
+
+/// A top level construct of a C header.
+enum Event {
+    /// A preprocessor directive, with its line, raw text and tokens.
+    Directive(usize, String, Vec<Token>),
+    /// An empty or comment-only line outside of declarations.
+    Separator,
+    /// All tokens of a declaration, up to the terminating `;` or the closing `}` of a function body.
+    Declaration(Vec<Token>)
+}
+
+/// A C type, as far as it is needed to import bit fields.
+#[derive(Clone, Debug)]
+enum CType {
+    Bool,
+    Integer {
+        rust: &'static str,
+        /// `None` if the size depends on the target.
+        bits: Option<u8>,
+        /// `None` if the signedness depends on the target.
+        signed: Option<bool>
+    },
+    Float(&'static str),
+    Void,
+    /// An imported struct with its Rust name.
+    Struct(String),
+    /// A type which is not known, with its C name.
+    Unknown(String)
+}
+
+impl CType {
+    /// Returns the size in bits and the signedness, if the type can be used in a `bitfield`.
+    fn primitive(&self) -> Option<(u8, bool)> {
+        match self {
+            Self::Bool => Some((8, false)),
+            Self::Integer { bits: Some(bits), signed: Some(signed), .. } => Some((*bits, *signed)),
+            _ => None
+        }
+    }
+
+    /// Returns the Rust type, which is only possible for known types.
+    fn rust(&self) -> Result<String, String> {
+        match self {
+            Self::Bool => Ok("bool".to_string()),
+            Self::Integer { rust, .. } | Self::Float(rust) => Ok(rust.to_string()),
+            Self::Struct(name) => Ok(name.clone()),
+            Self::Void => Err("`void` members are not supported".to_string()),
+            Self::Unknown(name) => Err(format!("unknown type `{}`", name))
+        }
+    }
+}
+
+/// The declarator of a struct member, f. e. `*name[4]` or `name : 3`.
+struct Declarator {
+    name: Option<String>,
+    pointers: usize,
+    /// `true` if the type specifier contains `const`, which only matters for pointers.
+    is_const: bool,
+    arrays: Vec<u128>,
+    bits: Option<u128>,
+    line: usize
+}
+
+/// A `#define #PREFIX_MASK` and `#define #PREFIX_SHIFT` pair.
+struct Mask {
+    prefix: String,
+    /// The value and the line.
+    mask: Option<(u128, usize)>,
+    /// The value and the line.
+    shift: Option<(u128, usize)>
+}
+
+/// Replaces comments with spaces, but keeps the line breaks.
+fn strip_comments(header: &str) -> String {
+    let mut result = String::with_capacity(header.len());
+    let mut chars = header.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                while chars.peek().map(|c| *c != '\n').unwrap_or_default() {
+                    chars.next();
+                }
+            },
+
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        result.push('\n');
+                    } else if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+
+                result.push(' ');
+            },
+
+            '"' | '\'' => {
+                result.push(c);
+
+                while let Some(d) = chars.next() {
+                    result.push(d);
+                    if d == '\\' {
+                        if let Some(e) = chars.next() {
+                            result.push(e);
+                        }
+                    } else if d == c || d == '\n' {
+                        break;
+                    }
+                }
+            },
+
+            _ => result.push(c)
+        }
+    }
+
+    result
+}
+
+/// Splits a line into identifiers, numbers, string and character literals, `<<`, `>>` and single
+/// punctuation characters.
+fn tokenize(text: &str, line: usize, tokens: &mut Vec<Token>) {
+    let chars: Vec<char> = text.chars().collect();
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c.is_alphanumeric() || c == '_' {
+            while i < chars.len() && (
+                chars[i].is_alphanumeric() || chars[i] == '_' || (chars[i] == '.' && c.is_ascii_digit())
+            ) {
+                i += 1;
+            }
+        } else if c == '"' || c == '\'' {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i = (i + 1).min(chars.len());
+        } else if (c == '<' || c == '>') && chars.get(i + 1) == Some(&c) {
+            i += 2;
+        } else {
+            i += 1;
+        }
+
+        tokens.push(Token { text: chars[start..i].iter().collect(), line });
+    }
+}
+
+/// Moves every complete declaration from `pending` into `events`.
+fn split_declarations(pending: &mut Vec<Token>, events: &mut Vec<Event>, externs: &mut usize) {
+    loop {
+        // `extern "C" { ... }` blocks are transparent.
+        if pending.len() >= 3 && pending[0].is("extern") && pending[1].text.starts_with('"') && pending[2].is("{") {
+            pending.drain(..3);
+            *externs += 1;
+            continue;
+        }
+
+        if *externs > 0 && pending.first().map(|token| token.is("}")).unwrap_or_default() {
+            pending.remove(0);
+            *externs -= 1;
+            continue;
+        }
+
+        // Functions end with their body, all other declarations with a `;`.
+        let needs_semicolon = pending.first().map(
+            |token| ["struct", "union", "enum", "typedef"].contains(&token.text.as_str())
+        ).unwrap_or_default();
+
+        let mut depth = 0isize;
+        let mut end = None;
+        for (i, token) in pending.iter().enumerate() {
+            match token.text.as_str() {
+                "{" => depth += 1,
+                "}" => {
+                    depth -= 1;
+                    if depth < 0 || (depth == 0 && !needs_semicolon) {
+                        end = Some(i);
+                        break;
+                    }
+                },
+                ";" if depth == 0 => {
+                    end = Some(i);
+                    break;
+                },
+                _ => ()
+            }
+        }
+
+        match end {
+            Some(end) => events.push(Event::Declaration(pending.drain(..=end).collect())),
+            None => return
+        }
+    }
+}
+
+/// Splits a header into directives, separators and declarations.
+fn events(header: &str) -> Vec<Event> {
+    let cleaned = strip_comments(header);
+    let lines: Vec<&str> = cleaned.lines().collect();
+
+    let mut events = vec!();
+    let mut pending = vec!();
+    let mut externs = 0;
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = i + 1;
+        let text = lines[i].trim();
+        i += 1;
+
+        if let Some(directive) = text.strip_prefix('#') {
+            let mut directive = directive.trim().to_string();
+            while directive.ends_with('\\') && i < lines.len() {
+                directive.pop();
+                directive.push(' ');
+                directive += lines[i].trim();
+                i += 1;
+            }
+
+            let mut tokens = vec!();
+            tokenize(&directive, line, &mut tokens);
+            events.push(Event::Directive(line, directive, tokens));
+        } else if text.is_empty() {
+            if pending.is_empty() {
+                events.push(Event::Separator);
+            }
+        } else {
+            tokenize(text, line, &mut pending);
+            split_declarations(&mut pending, &mut events, &mut externs);
+        }
+    }
+
+    if !pending.is_empty() {
+        events.push(Event::Declaration(pending));
+    }
+
+    events
+}
+
+/// Parses a C integer literal like `0x1Fu`, `010` or `42ULL`.
+fn parse_integer(text: &str) -> Option<u128> {
+    let text = text.trim_end_matches(['u', 'U', 'l', 'L']);
+
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        u128::from_str_radix(hex, 16).ok()
+    } else if let Some(binary) = text.strip_prefix("0b").or_else(|| text.strip_prefix("0B")) {
+        u128::from_str_radix(binary, 2).ok()
+    } else if text.len() > 1 && text.starts_with('0') {
+        u128::from_str_radix(&text[1..], 8).ok()
+    } else {
+        text.parse().ok()
+    }
+}
+
+/// Converts `STATUS`, `gpio_regs` or `GIOChannel` into `Status`, `GpioRegs` or `GIOChannel`.
+pub(crate) fn camel_case(name: &str, lowercase: bool) -> String {
+    name.split('_').filter(|part| !part.is_empty()).map(|part| {
+        let part = if lowercase { part.to_lowercase() } else { part.to_string() };
+        let mut chars = part.chars();
+        chars.next().map(|c| c.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+    }).collect()
+}
+
+/// Converts a C identifier into a Rust identifier, escaping keywords.
+pub(crate) fn rust_ident(name: &str) -> String {
+    if syn::parse_str::<syn::Ident>(name).is_ok() {
+        name.to_string()
+    } else if syn::parse_str::<syn::Ident>(&format!("r#{}", name)).is_ok() {
+        format!("r#{}", name)
+    } else {
+        format!("{}_", name)
+    }
+}
+
+/// Returns the smallest primitive size in bits which can store `bits` bits.
+pub(crate) fn primitive_bits(bits: u128) -> Option<u8> {
+    [8u8, 16, 32, 64, 128].into_iter().find(|size| *size as u128 >= bits)
+}
+
+/// Validates a generated item with the parser of the corresponding macro.
+pub(crate) fn validate<T>(
+    parse: impl FnOnce(proc_macro2::TokenStream, proc_macro2::TokenStream) -> syn::Result<T>,
+    attribute: &str, item: &str
+) -> Result<(), String> {
+    let attribute = attribute.parse().map_err(|error: proc_macro2::LexError| error.to_string())?;
+    let item = item.parse().map_err(|error: proc_macro2::LexError| error.to_string())?;
+    // Diagnostics are printed as single lines, f. e. with `cargo:warning=`.
+    parse(attribute, item).map(|_| ()).map_err(|error| error.to_string().lines().collect::<Vec<_>>().join("; "))
+}
+
+/// Stores the state while importing a header.
+#[derive(Default)]
+struct Importer {
+    /// The values of all object-like macros which are integer constant expressions.
+    defines: HashMap<String, u128>,
+    /// Integer typedefs and imported structs, structs are stored as `struct #TAG`.
+    types: HashMap<String, CType>,
+    /// The Rust names of all generated types.
+    names: Vec<String>,
+    /// The consecutive `_MASK` and `_SHIFT` macros which were not imported yet.
+    masks: Vec<Mask>,
+    items: Vec<String>,
+    diagnostics: Vec<Diagnostic>
+}
+
+impl Importer {
+    fn diagnostic(&mut self, line: usize, message: String) {
+        self.diagnostics.push(Diagnostic { line, message });
+    }
+
+    /// Evaluates an integer constant expression with `|`, `<<`, `>>`, parentheses, casts, integer
+    /// literals and macros.
+    fn evaluate(&self, tokens: &[Token]) -> Option<u128> {
+        fn or(importer: &Importer, tokens: &[Token], i: &mut usize) -> Option<u128> {
+            let mut value = shift(importer, tokens, i)?;
+            while tokens.get(*i).map(|token| token.is("|")).unwrap_or_default() {
+                *i += 1;
+                value |= shift(importer, tokens, i)?;
+            }
+            Some(value)
+        }
+
+        fn shift(importer: &Importer, tokens: &[Token], i: &mut usize) -> Option<u128> {
+            let mut value = primary(importer, tokens, i)?;
+            while let Some(operator) = tokens.get(*i).filter(|token| token.is("<<") || token.is(">>")) {
+                *i += 1;
+                let amount = u32::try_from(primary(importer, tokens, i)?).ok().filter(|amount| *amount < 128)?;
+                value = if operator.is("<<") { value << amount } else { value >> amount };
+            }
+            Some(value)
+        }
+
+        fn primary(importer: &Importer, tokens: &[Token], i: &mut usize) -> Option<u128> {
+            let token = tokens.get(*i)?;
+            *i += 1;
+
+            if token.is("(") {
+                // Skip casts like `(uint32_t)`.
+                let end = (*i..tokens.len()).find(|j| tokens[*j].is(")"))?;
+                if end > *i && tokens[*i..end].iter().all(
+                    |token| token.is_ident() && !importer.defines.contains_key(&token.text)
+                ) {
+                    *i = end + 1;
+                    return primary(importer, tokens, i);
+                }
+
+                let value = or(importer, tokens, i)?;
+                return tokens.get(*i).filter(|token| token.is(")")).map(|_| {
+                    *i += 1;
+                    value
+                });
+            }
+
+            if token.text.starts_with(|c: char| c.is_ascii_digit()) {
+                parse_integer(&token.text)
+            } else {
+                importer.defines.get(&token.text).copied()
+            }
+        }
+
+        let mut i = 0;
+        or(self, tokens, &mut i).filter(|_| i == tokens.len())
+    }
+
+    /// Handles a preprocessor directive. `next` is the following event, to detect include guards.
+    fn directive(&mut self, line: usize, text: &str, tokens: &[Token], next: Option<&Event>) {
+        let Some(directive) = tokens.first() else { return };
+
+        match directive.text.as_str() {
+            "define" => self.define(line, text, tokens),
+
+            "undef" => if let Some(name) = tokens.get(1) {
+                self.defines.remove(&name.text);
+            },
+
+            "if" | "ifdef" | "ifndef" | "elif" => {
+                let is_guard = directive.is("ifndef") && matches!(
+                    next, Some(Event::Directive(_, _, next)) if next.len() >= 2 && next[0].is("define") &&
+                        tokens.get(1).map(|name| name.text == next[1].text).unwrap_or_default()
+                );
+
+                if !is_guard && !tokens.iter().any(|token| token.is("__cplusplus")) {
+                    self.diagnostic(line, format!(
+                        "`#{}` is not evaluated, all conditional branches are imported", directive.text
+                    ));
+                }
+            },
+
+            _ => ()
+        }
+    }
+
+    /// Stores the value of a `#define` and collects `_MASK` and `_SHIFT` macros.
+    fn define(&mut self, line: usize, text: &str, tokens: &[Token]) {
+        let Some(name) = tokens.get(1).filter(|token| token.is_ident()) else {
+            return self.diagnostic(line, "expected a macro name".to_string());
+        };
+        let name = name.text.clone();
+
+        let (prefix, is_mask) = match name.strip_suffix("_MASK") {
+            Some(prefix) => (prefix, true),
+            None => match name.strip_suffix("_SHIFT") {
+                Some(prefix) => (prefix, false),
+                None => (name.as_str(), false)
+            }
+        };
+        let is_pair = prefix.len() < name.len() && !prefix.is_empty();
+
+        // Function-like macros have a `(` directly after the name.
+        let is_function = text["define".len()..].trim_start()[name.len()..].starts_with('(');
+        if is_function {
+            if is_pair {
+                self.diagnostic(line, format!("`{}` is a function-like macro", name));
+            }
+            return;
+        }
+
+        let value = self.evaluate(&tokens[2..]);
+        if let Some(value) = value {
+            self.defines.insert(name.clone(), value);
+        }
+
+        if !is_pair {
+            return;
+        }
+
+        let Some(value) = value else {
+            return self.diagnostic(line, format!("`{}` is not an integer constant expression", name));
+        };
+
+        let index = match self.masks.iter().position(|mask| mask.prefix == prefix) {
+            Some(index) => index,
+            None => {
+                self.masks.push(Mask { prefix: prefix.to_string(), mask: None, shift: None });
+                self.masks.len() - 1
+            }
+        };
+
+        let target = if is_mask { &mut self.masks[index].mask } else { &mut self.masks[index].shift };
+        if target.is_some() {
+            return self.diagnostic(line, format!("`{}` is defined twice", name));
+        }
+        *target = Some((value, line));
+    }
+
+    /// Registers a generated type name, or returns an error if it is already taken.
+    fn register(&mut self, name: &str) -> Result<(), String> {
+        if self.names.iter().any(|other| other == name) {
+            return Err(format!("`{}` is already defined", name));
+        }
+
+        self.names.push(name.to_string());
+        Ok(())
+    }
+
+    /// Imports the collected `_MASK` and `_SHIFT` macros as one bit field.
+    fn flush_masks(&mut self) {
+        let masks = core::mem::take(&mut self.masks);
+        let Some(first_line) = masks.iter().flat_map(|mask| [mask.mask, mask.shift]).flatten().map(|(_, line)| line).min() else {
+            return;
+        };
+
+        // Resolve the position and size of every mask.
+        let mut entries = vec!();
+        for mask in &masks {
+            let prefix = &mask.prefix;
+
+            let Some((value, line)) = mask.mask else {
+                let line = mask.shift.unwrap().1;
+                self.diagnostic(line, format!("`{}_SHIFT` has no matching `{}_MASK`", prefix, prefix));
+                continue;
+            };
+
+            if value == 0 {
+                self.diagnostic(line, format!("`{}_MASK` is `0`", prefix));
+                continue;
+            }
+
+            let start = value.trailing_zeros() as u128;
+            let size = (value >> start).trailing_ones() as u128;
+            if (value >> start) >> size != 0 {
+                self.diagnostic(line, format!("`{}_MASK` is not contiguous", prefix));
+                continue;
+            }
+
+            // Masks can be defined shifted or unshifted.
+            let bit = match mask.shift {
+                None => start,
+                Some((shift, _)) if shift == start => start,
+                Some((shift, _)) if start == 0 && shift + size <= 128 => shift,
+                Some((shift, line)) => {
+                    self.diagnostic(line, format!(
+                        "`{}_SHIFT` is {}, but `{}_MASK` starts at bit {}", prefix, shift, prefix, start
+                    ));
+                    continue;
+                }
+            };
+
+            entries.push((prefix.as_str(), bit, size));
+        }
+
+        if entries.is_empty() {
+            return;
+        }
+        entries.sort_by_key(|(_, bit, _)| *bit);
+
+        // The bit field is named after the common prefix of all masks, up to the last `_`.
+        let common = entries.iter().skip(1).fold(entries[0].0, |common, (prefix, _, _)| {
+            let length = common.chars().zip(prefix.chars()).take_while(|(a, b)| a == b).count();
+            &common[..length]
+        });
+        let Some(cut) = common.rfind('_').filter(|cut| *cut > 0) else {
+            let names = entries.iter().map(|(prefix, _, _)| format!("`{}_MASK`", prefix)).collect::<Vec<_>>();
+            return self.diagnostic(first_line, format!(
+                "{} have no common prefix which ends with `_`, which is needed to name the bit field",
+                names.join(", ")
+            ));
+        };
+
+        let prefix = &common[..cut];
+        let name = camel_case(prefix, true);
+        let bits = entries.iter().map(|(_, bit, size)| bit + size).max().and_then(primitive_bits);
+        let Some(bits) = bits else {
+            return self.diagnostic(first_line, format!("`{}_*_MASK` exceeds 128 bits", prefix));
+        };
+
+        // Entry names must not start with a digit.
+        let entry_name = |entry: &str| {
+            let entry = &entry[cut + 1..];
+            if entry.starts_with(|c: char| c.is_ascii_digit()) { format!("{}_{}", prefix, entry) } else { entry.to_string() }
+        };
+
+        let doc = format!("/// Imported from the `{}_*_MASK` macros (line {}).\n", prefix, first_line);
+        let attribute = format!("{}", bits);
+
+        let result = if entries.iter().all(|(_, _, size)| *size == 1) {
+            let flag = format!("{}Flag", name);
+            let variants = entries.iter().map(
+                |(entry, bit, _)| format!("    {} = {}", camel_case(&entry_name(entry), true), bit)
+            ).collect::<Vec<_>>();
+
+            let item = format!("pub struct {}(pub {});", name, flag);
+            let flags = format!(
+                "#[derive(Clone, Copy, Debug, bitfield::Flags)]\n#[repr(u8)]\npub enum {} {{\n{}\n}}\n",
+                flag, variants.join(",\n")
+            );
+
+            validate(crate::bitfield::BitField::parse, &attribute, &item)
+                .and_then(|_| validate(|_, item| crate::flags::Flags::parse(item), "", &flags))
+                .and_then(|_| self.register(&name))
+                .and_then(|_| self.register(&flag))
+                .map(|_| format!("{}#[bitfield::bitfield({})]\n{}\n\n{}", doc, attribute, item, flags))
+        } else {
+            let fields = entries.iter().map(|(entry, bit, size)| {
+                let ident = rust_ident(&entry_name(entry).to_lowercase());
+                if *size == 1 {
+                    format!("    #[field(bit = {})] pub {}: bool", bit, ident)
+                } else {
+                    format!("    #[field({}, {})] pub {}: u{}", bit, size, ident, primitive_bits(*size).unwrap())
+                }
+            }).collect::<Vec<_>>();
+
+            let item = format!("pub struct {} {{\n{}\n}}\n", name, fields.join(",\n"));
+
+            validate(crate::bitfield::BitField::parse, &attribute, &item)
+                .and_then(|_| self.register(&name))
+                .map(|_| format!("{}#[bitfield::bitfield({})]\n{}", doc, attribute, item))
+        };
+
+        match result {
+            Ok(item) => self.items.push(item),
+            Err(error) => self.diagnostic(first_line, format!("`{}_*_MASK` is not imported: {}", prefix, error))
+        }
+    }
+
+    /// Parses a type specifier like `const unsigned long`, `uint8_t` or `struct node`.
+    fn type_specifier(&self, tokens: &[Token], i: &mut usize) -> Result<CType, String> {
+        const INTEGER: &[&str] = &["signed", "unsigned", "char", "short", "int", "long"];
+
+        let skip_qualifiers = |i: &mut usize| while tokens.get(*i).map(
+            |token| token.is("const") || token.is("volatile")
+        ).unwrap_or_default() {
+            *i += 1;
+        };
+
+        skip_qualifiers(i);
+        let token = tokens.get(*i).ok_or("expected a type")?;
+
+        let ty = if INTEGER.contains(&token.text.as_str()) {
+            let mut words = vec!();
+            while let Some(token) = tokens.get(*i).filter(
+                |token| INTEGER.contains(&token.text.as_str()) || token.is("const") || token.is("volatile")
+            ) {
+                words.push(token.text.as_str());
+                *i += 1;
+            }
+
+            if tokens.get(*i).map(|token| token.is("double")).unwrap_or_default() {
+                return Err("`long double` is not supported".to_string());
+            }
+
+            let count = |word: &str| words.iter().filter(|w| **w == word).count();
+            let unsigned = count("unsigned") > 0;
+            let signed = count("signed") > 0;
+
+            match (count("char") > 0, count("short") > 0, count("long")) {
+                (true, _, _) if unsigned => CType::Integer { rust: "core::ffi::c_uchar", bits: Some(8), signed: Some(false) },
+                (true, _, _) if signed => CType::Integer { rust: "core::ffi::c_schar", bits: Some(8), signed: Some(true) },
+                (true, _, _) => CType::Integer { rust: "core::ffi::c_char", bits: Some(8), signed: None },
+                (_, true, _) if unsigned => CType::Integer { rust: "core::ffi::c_ushort", bits: Some(16), signed: Some(false) },
+                (_, true, _) => CType::Integer { rust: "core::ffi::c_short", bits: Some(16), signed: Some(true) },
+                (_, _, 2) if unsigned => CType::Integer { rust: "core::ffi::c_ulonglong", bits: Some(64), signed: Some(false) },
+                (_, _, 2) => CType::Integer { rust: "core::ffi::c_longlong", bits: Some(64), signed: Some(true) },
+                (_, _, 1) if unsigned => CType::Integer { rust: "core::ffi::c_ulong", bits: None, signed: Some(false) },
+                (_, _, 1) => CType::Integer { rust: "core::ffi::c_long", bits: None, signed: Some(true) },
+                _ if unsigned => CType::Integer { rust: "core::ffi::c_uint", bits: Some(32), signed: Some(false) },
+                _ => CType::Integer { rust: "core::ffi::c_int", bits: Some(32), signed: Some(true) }
+            }
+        } else {
+            *i += 1;
+
+            match token.text.as_str() {
+                "_Bool" | "bool" => CType::Bool,
+                "uint8_t" => CType::Integer { rust: "u8", bits: Some(8), signed: Some(false) },
+                "uint16_t" => CType::Integer { rust: "u16", bits: Some(16), signed: Some(false) },
+                "uint32_t" => CType::Integer { rust: "u32", bits: Some(32), signed: Some(false) },
+                "uint64_t" => CType::Integer { rust: "u64", bits: Some(64), signed: Some(false) },
+                "int8_t" => CType::Integer { rust: "i8", bits: Some(8), signed: Some(true) },
+                "int16_t" => CType::Integer { rust: "i16", bits: Some(16), signed: Some(true) },
+                "int32_t" => CType::Integer { rust: "i32", bits: Some(32), signed: Some(true) },
+                "int64_t" => CType::Integer { rust: "i64", bits: Some(64), signed: Some(true) },
+                "size_t" | "uintptr_t" => CType::Integer { rust: "usize", bits: None, signed: Some(false) },
+                "ssize_t" | "intptr_t" | "ptrdiff_t" => CType::Integer { rust: "isize", bits: None, signed: Some(true) },
+                "float" => CType::Float("f32"),
+                "double" => CType::Float("f64"),
+                "void" => CType::Void,
+
+                "struct" | "union" | "enum" => {
+                    let tag = tokens.get(*i).filter(|token| token.is_ident()).ok_or("expected a tag name")?;
+                    *i += 1;
+
+                    if tokens.get(*i).map(|token| token.is("{")).unwrap_or_default() {
+                        return Err("nested structs, unions and enums are not supported".to_string());
+                    }
+
+                    let name = format!("{} {}", token.text, tag.text);
+                    self.types.get(&name).cloned().unwrap_or(CType::Unknown(name))
+                },
+
+                name if token.is_ident() => self.types.get(name).cloned().unwrap_or_else(|| CType::Unknown(name.to_string())),
+                _ => return Err(format!("expected a type, found `{}`", token.text))
+            }
+        };
+
+        skip_qualifiers(i);
+        Ok(ty)
+    }
+
+    /// Parses a declarator like `*name[4]`, `name : 3` or `: 0`.
+    fn declarator(&self, tokens: &[Token], line: usize) -> Result<Declarator, String> {
+        let mut declarator = Declarator { name: None, pointers: 0, is_const: false, arrays: vec!(), bits: None, line };
+
+        let mut i = 0;
+        while let Some(token) = tokens.get(i) {
+            match token.text.as_str() {
+                "*" if declarator.name.is_none() => declarator.pointers += 1,
+                "const" | "volatile" if declarator.name.is_none() => (),
+                "(" => return Err("function pointers are not supported".to_string()),
+                "[" => {
+                    let end = (i..tokens.len()).find(|j| tokens[*j].is("]")).ok_or("expected `]`")?;
+                    let length = self.evaluate(&tokens[i + 1..end]).ok_or("array length is not an integer constant expression")?;
+                    declarator.arrays.push(length);
+                    i = end;
+                },
+                ":" => {
+                    let bits = self.evaluate(&tokens[i + 1..]).ok_or("bit field width is not an integer constant expression")?;
+                    declarator.bits = Some(bits);
+                    break;
+                },
+                _ if token.is_ident() && declarator.name.is_none() => declarator.name = Some(token.text.clone()),
+                _ => return Err(format!("unexpected `{}`", token.text))
+            }
+
+            i += 1;
+        }
+
+        if declarator.bits.is_some() && (declarator.pointers > 0 || !declarator.arrays.is_empty()) {
+            return Err("bit fields must not be pointers or arrays".to_string());
+        }
+
+        if declarator.bits.is_none() && declarator.name.is_none() {
+            return Err("expected a member name".to_string());
+        }
+
+        Ok(declarator)
+    }
+
+    /// Parses the members of a struct body, or returns the line and message of the first error.
+    fn members(&self, tokens: &[Token]) -> Result<Vec<(CType, Declarator)>, (usize, String)> {
+        let mut members = vec!();
+
+        for member in tokens.split(|token| token.is(";")).filter(|member| !member.is_empty()) {
+            let line = member[0].line;
+            let error = |error: String| (line, error);
+
+            let mut i = 0;
+            let ty = self.type_specifier(member, &mut i).map_err(error)?;
+            let is_const = member[..i].iter().any(|token| token.is("const"));
+
+            for declarator in member[i..].split(|token| token.is(",")) {
+                let declarator = Declarator { is_const, ..self.declarator(declarator, line).map_err(error)? };
+
+                if declarator.bits.is_some() && !matches!(ty, CType::Bool | CType::Integer { .. }) {
+                    return Err(error("bit fields must have an integer type or `_Bool`".to_string()));
+                }
+
+                members.push((ty.clone(), declarator));
+            }
+        }
+
+        Ok(members)
+    }
+
+    /// Returns the Rust type of a member, with pointers and arrays.
+    fn member_type(ty: &CType, declarator: &Declarator) -> Result<String, String> {
+        let mut rust = if declarator.pointers > 0 {
+            // Pointers to unknown types are opaque.
+            let pointee = match ty {
+                CType::Void | CType::Unknown(_) => "core::ffi::c_void".to_string(),
+                ty => ty.rust()?
+            };
+
+            let innermost = if declarator.is_const { "const" } else { "mut" };
+            (1..declarator.pointers).fold(format!("*{} {}", innermost, pointee), |pointee, _| format!("*mut {}", pointee))
+        } else {
+            ty.rust()?
+        };
+
+        for length in declarator.arrays.iter().rev() {
+            rust = format!("[{}; {}]", rust, length);
+        }
+
+        Ok(rust)
+    }
+
+    /// Generates a `#[bitfield::bitfield]` struct if all members are bit fields which fit into one
+    /// storage unit of the same type.
+    fn bitfield(&self, name: &str, members: &[(CType, Declarator)]) -> Result<String, String> {
+        let mut size = None;
+        for (ty, declarator) in members {
+            if declarator.bits.is_none() {
+                return Err("not all members are bit fields".to_string());
+            }
+
+            let (bits, _) = ty.primitive().ok_or("member types depend on the target")?;
+            if size.replace(bits).map(|size| size != bits).unwrap_or_default() {
+                return Err("member types have different sizes".to_string());
+            }
+        }
+        let size = size.ok_or("no members")? as u128;
+
+        let mut bit = 0u128;
+        let mut fields = vec!();
+        for (ty, declarator) in members {
+            let bits = declarator.bits.unwrap();
+            if bits == 0 || bit / size != (bit + bits - 1) / size {
+                return Err("members use more than one storage unit".to_string());
+            }
+
+            if let Some(name) = &declarator.name {
+                let ident = rust_ident(name);
+                let field = match ty {
+                    CType::Bool => format!("#[field(bit = {})] pub {}: bool", bit, ident),
+                    CType::Integer { signed: Some(false), .. } if bits == 1 => format!("#[field(bit = {})] pub {}: bool", bit, ident),
+                    CType::Integer { signed: Some(signed), .. } => format!(
+                        "#[field({}, {})] pub {}: {}{}",
+                        bit, bits, ident, if *signed { "i" } else { "u" }, primitive_bits(bits).unwrap()
+                    ),
+                    _ => unreachable!()
+                };
+                fields.push(format!("    {}", field));
+            }
+
+            bit += bits;
+        }
+
+        let attribute = format!("{}", size);
+        let item = format!("pub struct {} {{\n{}\n}}\n", name, fields.join(",\n"));
+        validate(crate::bitfield::BitField::parse, &attribute, &item)?;
+
+        Ok(format!("#[bitfield::bitfield({})]\n{}", attribute, item))
+    }
+
+    /// Generates a `#[bitfield::c_struct]` struct, or a plain `#[repr(C)]` struct if no member is a
+    /// bit field.
+    fn c_struct(&self, name: &str, members: &[(CType, Declarator)]) -> Result<String, (usize, String)> {
+        let mut reserved = 0;
+        let mut fields = vec!();
+
+        for (ty, declarator) in members {
+            let rust = Self::member_type(ty, declarator).map_err(|error| (declarator.line, error))?;
+            let ident = match &declarator.name {
+                Some(name) => rust_ident(name),
+                None => {
+                    reserved += 1;
+                    format!("_reserved_{}", reserved - 1)
+                }
+            };
+
+            fields.push(match declarator.bits {
+                Some(bits) => format!("    #[bits({})] pub {}: {}", bits, ident, rust),
+                None => format!("    pub {}: {}", ident, rust)
+            });
+        }
+
+        let item = format!("pub struct {} {{\n{}\n}}\n", name, fields.join(",\n"));
+
+        let line = members.first().map(|(_, declarator)| declarator.line).unwrap_or_default();
+        if members.iter().any(|(_, declarator)| declarator.bits.is_some()) {
+            validate(crate::c_struct::CStruct::parse, "", &item).map_err(|error| (line, error))?;
+            Ok(format!("#[bitfield::c_struct]\n{}", item))
+        } else {
+            validate(|_, item| syn::parse2::<syn::ItemStruct>(item), "", &item).map_err(|error| (line, error))?;
+            Ok(format!("#[derive(Clone, Copy)]\n#[repr(C)]\n{}", item))
+        }
+    }
+
+    /// Imports a `struct` or `typedef` declaration.
+    fn declaration(&mut self, tokens: &[Token]) {
+        let line = tokens[0].line;
+        let is_typedef = tokens[0].is("typedef");
+        let mut i = is_typedef as usize;
+
+        let kind = match tokens.get(i) {
+            Some(token) if token.is("struct") => "struct",
+            Some(token) if token.is("union") || token.is("enum") => {
+                if tokens.iter().any(|token| token.is("{")) {
+                    self.diagnostic(line, format!("`{}` definitions are not imported", token.text));
+                }
+                return;
+            },
+            _ if is_typedef => return self.typedef(tokens),
+            // Functions and variables are ignored.
+            _ => return
+        };
+        i += 1;
+
+        let tag = tokens.get(i).filter(|token| token.is_ident()).map(|token| token.text.clone());
+        if tag.is_some() {
+            i += 1;
+        }
+
+        // Forward declarations and variables of struct types are ignored.
+        if !tokens.get(i).map(|token| token.is("{")).unwrap_or_default() {
+            return;
+        }
+
+        let end = tokens.iter().rposition(|token| token.is("}")).unwrap();
+        let body = &tokens[i + 1..end];
+        let typedef = tokens[end + 1..].first().filter(|token| is_typedef && token.is_ident()).map(|token| token.text.clone());
+
+        let c_name = match (&typedef, &tag) {
+            (Some(typedef), _) => typedef.clone(),
+            (None, Some(tag)) => format!("{} {}", kind, tag),
+            (None, None) => return self.diagnostic(line, "anonymous structs are not imported".to_string())
+        };
+        // `channel_t` becomes `Channel`.
+        let name = match &typedef {
+            Some(typedef) => camel_case(typedef.strip_suffix("_t").unwrap_or(typedef), false),
+            None => camel_case(tag.as_ref().unwrap(), false)
+        };
+
+        let result = self.members(body).and_then(|members| {
+            let item = self.bitfield(&name, &members).or_else(|_| self.c_struct(&name, &members))?;
+            self.register(&name).map_err(|error| (line, error))?;
+            Ok(item)
+        });
+
+        match result {
+            Ok(item) => {
+                self.items.push(format!("/// Imported from `{}` (line {}).\n{}", c_name, line, item));
+
+                let ty = CType::Struct(name);
+                if let Some(tag) = tag {
+                    self.types.insert(format!("{} {}", kind, tag), ty.clone());
+                }
+                if let Some(typedef) = typedef {
+                    self.types.insert(typedef, ty);
+                }
+            },
+
+            Err((line, error)) => self.diagnostic(line, format!("`{}` is not imported: {}", c_name, error))
+        }
+    }
+
+    /// Stores `typedef`s of integer types, so that they can be used for bit fields.
+    fn typedef(&mut self, tokens: &[Token]) {
+        let mut i = 1;
+        let Ok(ty) = self.type_specifier(tokens, &mut i) else { return };
+
+        if let [name, semicolon] = &tokens[i..] {
+            if name.is_ident() && semicolon.is(";") {
+                self.types.insert(name.text.clone(), ty);
+            }
+        }
+    }
+}
+
+/// Imports the bit fields of a C header, f. e. in a `build.rs` script. The following constructs are
+/// imported, in the order in which they are declared:
+///
+/// - Consecutive `#define #PREFIX_#ENTRY_MASK` macros and optional `#define #PREFIX_#ENTRY_SHIFT`
+///   macros, which are not separated by empty or comment-only lines, become one
+///   `#[bitfield::bitfield]` struct named `#PREFIX`, with the smallest size which contains all
+///   masks. If all masks have one bit, it stores a `#[derive(bitfield::Flags)]` enumeration named
+///   `#PREFIX Flag`, otherwise it has one field per mask.
+/// - `struct`s whose members are bit fields of the same type, which fit into one storage unit,
+///   become `#[bitfield::bitfield]` structs. Other `struct`s with bit fields become
+///   `#[bitfield::c_struct]` structs, and `struct`s without bit fields become `#[repr(C)]` structs.
+///   They are named after the `typedef` without a `_t` suffix, or after the tag.
+///
+/// A diagnostic is returned for every construct which can not be imported, f. e. conditional
+/// compilation, `union`s, function pointers or members of unknown types. Functions, variables and
+/// other macros are ignored.
+///
+/// Example:
+///
+/// ```ignore
+/// let header = std::fs::read_to_string("include/registers.h").unwrap();
+/// let import = bitfield_impl::c_import::from_header(&header);
+/// for diagnostic in &import.diagnostics {
+///     println!("cargo:warning=include/registers.h: {}", diagnostic);
+/// }
+/// std::fs::write(std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("registers.rs"), import.source).unwrap();
+/// ```
+pub fn from_header(header: &str) -> Import {
+    let mut importer = Importer::default();
+    let events = events(header);
+
+    for (i, event) in events.iter().enumerate() {
+        match event {
+            Event::Directive(line, text, tokens) => importer.directive(*line, text, tokens, events.get(i + 1)),
+            Event::Separator => importer.flush_masks(),
+            Event::Declaration(tokens) => {
+                let depth = tokens.iter().fold(0, |depth, token| depth + token.is("{") as isize - token.is("}") as isize);
+                if depth != 0 || !tokens.last().map(|token| token.is(";") || token.is("}")).unwrap_or_default() {
+                    importer.diagnostic(tokens[0].line, "unterminated declaration".to_string());
+                    continue;
+                }
+
+                if ["struct", "union", "enum", "typedef"].contains(&tokens[0].text.as_str()) {
+                    importer.flush_masks();
+                }
+                importer.declaration(tokens);
+            }
+        }
+    }
+    importer.flush_masks();
+
+    let mut source = "// Generated by the `bitfield` crate.\n".to_string();
+    for item in importer.items {
+        source += "\n";
+        source += &item;
+    }
+
+    Import { source, diagnostics: importer.diagnostics }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Returns the diagnostics as strings.
+    fn diagnostics(import: &super::Import) -> Vec<String> {
+        import.diagnostics.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn strip_comments() {
+        assert_eq!(
+            super::strip_comments("a /* b\nc */ d // e\n\"/* f */\" '/'"),
+            "a \n  d \n\"/* f */\" '/'"
+        );
+    }
+
+    #[test]
+    fn evaluate() {
+        let mut importer = super::Importer::default();
+        importer.defines.insert("SHIFT".to_string(), 4);
+
+        for (expression, result) in [
+            ("0x1Fu", Some(0x1F)), ("010", Some(8)), ("42ULL", Some(42)), ("0b101", Some(5)),
+            ("(1u << 3)", Some(8)), ("(uint32_t)0xF << SHIFT", Some(0xF0)), ("1 | 2 | 0x80", Some(0x83)),
+            ("0x100 >> SHIFT", Some(0x10)), ("UNKNOWN", None), ("1 + 2", None), ("(1", None), ("", None)
+        ] {
+            let mut tokens = vec!();
+            super::tokenize(expression, 1, &mut tokens);
+            assert_eq!(importer.evaluate(&tokens), result, "{}", expression);
+        }
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(super::camel_case("STATUS", true), "Status");
+        assert_eq!(super::camel_case("gpio_regs", false), "GpioRegs");
+        assert_eq!(super::camel_case("GIOChannel", false), "GIOChannel");
+        assert_eq!(super::rust_ident("type"), "r#type");
+        assert_eq!(super::rust_ident("self"), "self_");
+    }
+
+    #[test]
+    fn masks() {
+        let import = super::from_header("\
+#define STATUS_LENGTH_SHIFT 0
+#define STATUS_LENGTH_MASK (0xFu << STATUS_LENGTH_SHIFT)
+#define STATUS_READY_MASK 0x8000u
+#define STATUS_MODE_SHIFT 12
+#define STATUS_MODE_MASK 0x3
+
+/* Interrupts */
+#define IRQ_TX_MASK 0x01
+#define IRQ_RX_MASK 0x02
+#define IRQ_OVERRUN_MASK 0x80
+");
+
+        assert_eq!(import.source, "\
+// Generated by the `bitfield` crate.
+
+/// Imported from the `STATUS_*_MASK` macros (line 1).
+#[bitfield::bitfield(16)]
+pub struct Status {
+    #[field(0, 4)] pub length: u8,
+    #[field(12, 2)] pub mode: u8,
+    #[field(bit = 15)] pub ready: bool
+}
+
+/// Imported from the `IRQ_*_MASK` macros (line 8).
+#[bitfield::bitfield(8)]
+pub struct Irq(pub IrqFlag);
+
+#[derive(Clone, Copy, Debug, bitfield::Flags)]
+#[repr(u8)]
+pub enum IrqFlag {
+    Tx = 0,
+    Rx = 1,
+    Overrun = 7
+}
+");
+        assert!(import.diagnostics.is_empty());
+
+        let import = super::from_header("\
+#define A_SHIFT 3
+#define B_MASK 0x5
+#define C_SHIFT 2
+#define C_MASK 0x10
+#define D_MASK 0
+#define E_MASK BITS(1)
+#define F_MASK(x) ((x) & 1)
+
+#define G_0_MASK 0x1
+#define G_1_MASK 0x2
+
+#define X_MASK 0x1
+#define Y_MASK 0x2
+
+#define OVERLAP_A_MASK 0x3
+#define OVERLAP_B_MASK 0x6
+");
+
+        assert_eq!(diagnostics(&import), [
+            "line 6: `E_MASK` is not an integer constant expression",
+            "line 7: `F_MASK` is a function-like macro",
+            "line 1: `A_SHIFT` has no matching `A_MASK`",
+            "line 2: `B_MASK` is not contiguous",
+            "line 3: `C_SHIFT` is 2, but `C_MASK` starts at bit 4",
+            "line 5: `D_MASK` is `0`",
+            "line 12: `X_MASK`, `Y_MASK` have no common prefix which ends with `_`, which is needed to name the bit field",
+            "line 15: `OVERLAP_*_MASK` is not imported: overlaps with field `a`, please specify `allow_overlaps` if this is intended; \
+             `b`: bits 1-2, `a`: bits 0-1, overlapping: bit 1; \
+             layout (bit 7 first): `.....#X=`, `#` is `b`, `=` is `a`, `X` is both, `-` are other fields, `.` are free bits; \
+             help: the next free position is `#[field(2, 2)]`"
+        ]);
+        assert!(import.source.contains("pub struct G(pub GFlag);"));
+        assert!(import.source.contains("    G0 = 0,\n    G1 = 1"));
+    }
+
+    #[test]
+    fn structs() {
+        let import = super::from_header("\
+typedef uint32_t reg_t;
+
+struct control {
+    reg_t enable : 1;
+    reg_t mode : 3, : 4;
+    volatile reg_t prescaler : 8;
+    int32_t offset : 16;
+};
+
+typedef struct channel {
+    int id;
+    char name[6];
+    const char *label[2];
+    unsigned int use_buffer : 1;
+    long long priority : 3;
+    struct control control;
+    struct opaque *next;
+} channel_t;
+
+struct point { double x, y; };
+
+struct broken { void (*callback)(int); };
+struct unknown { handle_t handle; }; struct length { int values[2 * 3]; };
+struct wide { uint64_t a : 40; uint64_t b : 30; };
+union value { int i; float f; };
+struct forward;
+int function(int a) { return a; }
+");
+
+        assert_eq!(import.source, "\
+// Generated by the `bitfield` crate.
+
+/// Imported from `struct control` (line 3).
+#[bitfield::bitfield(32)]
+pub struct Control {
+    #[field(bit = 0)] pub enable: bool,
+    #[field(1, 3)] pub mode: u8,
+    #[field(8, 8)] pub prescaler: u8,
+    #[field(16, 16)] pub offset: i16
+}
+
+/// Imported from `channel_t` (line 10).
+#[bitfield::c_struct]
+pub struct Channel {
+    pub id: core::ffi::c_int,
+    pub name: [core::ffi::c_char; 6],
+    pub label: [*const core::ffi::c_char; 2],
+    #[bits(1)] pub use_buffer: core::ffi::c_uint,
+    #[bits(3)] pub priority: core::ffi::c_longlong,
+    pub control: Control,
+    pub next: *mut core::ffi::c_void
+}
+
+/// Imported from `struct point` (line 20).
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64
+}
+
+/// Imported from `struct wide` (line 24).
+#[bitfield::c_struct]
+pub struct Wide {
+    #[bits(40)] pub a: u64,
+    #[bits(30)] pub b: u64
+}
+");
+        assert_eq!(diagnostics(&import), [
+            "line 22: `struct broken` is not imported: function pointers are not supported",
+            "line 23: `struct unknown` is not imported: unknown type `handle_t`",
+            "line 23: `struct length` is not imported: array length is not an integer constant expression",
+            "line 25: `union` definitions are not imported"
+        ]);
+
+        let import = super::from_header("struct a { int x : 3;");
+        assert_eq!(import.source, "// Generated by the `bitfield` crate.\n");
+        assert_eq!(diagnostics(&import), ["line 1: unterminated declaration"]);
+    }
+
+    #[test]
+    fn preprocessor() {
+        let import = super::from_header("\
+#ifndef HEADER_H
+#define HEADER_H
+
+#ifdef __cplusplus
+extern \"C\" {
+#endif
+
+#define WIDTH 3
+
+#if defined(FEATURE)
+struct a { unsigned b : WIDTH; };
+#endif
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
+");
+
+        assert_eq!(diagnostics(&import), ["line 10: `#if` is not evaluated, all conditional branches are imported"]);
+        assert!(import.source.contains("pub struct A {\n    #[field(0, 3)] pub b: u8\n}"));
+    }
+
+    #[test]
+    fn c_header() {
+        // Import the header which is exported by the `c_header` option.
+        let header = crate::c_header::from_source("
+            #[bitfield::bitfield(16)]
+            struct Status {
+                #[field(size = 4)] length: u8,
+                #[field(bit = 15, access = ro)] ready: bool
+            }
+        ").unwrap();
+
+        let import = super::from_header(&header);
+        assert!(import.diagnostics.is_empty());
+        assert!(import.source.contains("\
+#[bitfield::bitfield(16)]
+pub struct Status {
+    #[field(0, 4)] pub length: u8,
+    #[field(bit = 15)] pub ready: bool
+}
+"));
+    }
+}
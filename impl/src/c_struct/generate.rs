@@ -0,0 +1,473 @@
+//! Contains code to generate structs with C-style bit field members.
+
+/// A maximal sequence of consecutive bit field members, which is stored in one byte array.
+struct Run<'a> {
+    /// The index of the storage member, e.g. `1` for `_bitfield_1`.
+    index: usize,
+    /// The index of the padding and size in bytes in the layout constant, followed by the bit
+    /// positions of the members.
+    layout_index: usize,
+    members: Vec<&'a super::Member>
+}
+
+/// A member of the struct, as it is laid out.
+enum Item<'a> {
+    Member(&'a super::Member),
+    Run(Run<'a>)
+}
+
+impl super::CStruct {
+    /// Groups consecutive bit field members into runs.
+    fn items(&self) -> Vec<Item<'_>> {
+        let mut items: Vec<Item> = Vec::with_capacity(self.members.len());
+        let mut runs = 0;
+        let mut layout_index = 0;
+
+        for member in &self.members {
+            if member.bits.is_none() {
+                items.push(Item::Member(member));
+                continue;
+            }
+
+            if let Some(Item::Run(run)) = items.last_mut() {
+                run.members.push(member);
+            } else {
+                items.push(Item::Run(Run { index: runs, layout_index, members: vec![member] }));
+                runs += 1;
+
+                // Reserve the padding and size in front of the bit positions.
+                layout_index += 2;
+            }
+
+            layout_index += 1;
+        }
+
+        items
+    }
+
+    /// Returns the identifier of the constant which stores the computed layout.
+    fn layout_ident(&self) -> syn::Ident {
+        quote::format_ident!(
+            "_{}_BITFIELD_LAYOUT", crate::c_header::snake_case(&self.ident).to_uppercase(),
+            span = self.ident.span()
+        )
+    }
+
+    /// Returns `true` if padding in front of the runs can be necessary for the selected ABI.
+    fn has_padding(&self) -> bool {
+        self.abi != Some(super::Abi::SystemV)
+    }
+
+    /// Returns the distinct declared types of all bit field members, which affect the alignment of
+    /// the struct.
+    fn alignment_types(&self) -> Vec<&syn::Type> {
+        let mut types: Vec<&syn::Type> = Vec::new();
+
+        for member in self.members.iter().filter(|member| member.bits.unwrap_or_default() > 0) {
+            let ty = &member.ty;
+            let name = quote::quote!(#ty).to_string();
+            if !types.iter().any(|ty| quote::quote!(#ty).to_string() == name) {
+                types.push(&member.ty);
+            }
+        }
+
+        types
+    }
+
+    /// Generates the `#[repr(C)]` struct, in which every run is replaced by a byte array.
+    fn generate_struct(&self) -> proc_macro2::TokenStream {
+        let attrs = &self.attrs;
+        let vis = &self.vis;
+        let ident = &self.ident;
+        let layout = self.layout_ident();
+
+        let members = self.items().into_iter().map(|item| match item {
+            Item::Member(member) => {
+                let super::Member { attrs, vis, ident, ty, .. } = member;
+
+                quote::quote! { #(#attrs)* #vis #ident: #ty, }
+            },
+
+            Item::Run(run) => {
+                let padding_index = run.layout_index;
+                let size_index = run.layout_index + 1;
+                let storage = quote::format_ident!("_bitfield_{}", run.index);
+                let padding = self.has_padding().then(|| {
+                    let padding = quote::format_ident!("_bitfield_padding_{}", run.index);
+                    quote::quote! { #padding: [u8; #layout[#padding_index]], }
+                });
+
+                quote::quote! {
+                    #padding
+                    #storage: [u8; #layout[#size_index]],
+                }
+            }
+        });
+
+        let alignments = self.alignment_types().into_iter().enumerate().map(|(i, ty)| {
+            let alignment = quote::format_ident!("_bitfield_align_{}", i);
+            quote::quote! { #alignment: [#ty; 0], }
+        });
+
+        quote::quote! {
+            #(#attrs)*
+            #[repr(C)]
+            #vis struct #ident {
+                #(#members)*
+                #(#alignments)*
+            }
+        }
+    }
+
+    /// Generates the constant which computes the padding and size of every run and the bit
+    /// positions of its members for the selected ABI.
+    fn generate_layout(&self) -> proc_macro2::TokenStream {
+        let layout = self.layout_ident();
+        let msvc = match self.abi {
+            Some(super::Abi::SystemV) => quote::quote!(false),
+            Some(super::Abi::Msvc) => quote::quote!(true),
+            // MSVC and MinGW both use the Microsoft layout on Windows.
+            None => quote::quote!(::core::cfg!(windows))
+        };
+
+        let items = self.items();
+        let length = items.iter().map(|item| match item {
+            Item::Member(_) => 0,
+            Item::Run(run) => run.members.len() + 2
+        }).sum::<usize>();
+
+        let steps = items.iter().map(|item| match item {
+            Item::Member(member) => {
+                let ty = &member.ty;
+
+                quote::quote! {
+                    offset = round_up(offset, ::core::mem::align_of::<#ty>()) + ::core::mem::size_of::<#ty>();
+                }
+            },
+
+            Item::Run(run) => {
+                let index = run.layout_index;
+                let members = run.members.iter().map(|member| {
+                    let ty = &member.ty;
+                    let bits = member.bits.unwrap() as usize;
+
+                    quote::quote! {
+                        (::core::mem::size_of::<#ty>() * 8, ::core::mem::align_of::<#ty>(), #bits)
+                    }
+                });
+
+                quote::quote! {
+                    offset = allocate(msvc, offset, &[#(#members),*], &mut layout, #index);
+                }
+            }
+        });
+
+        quote::quote! {
+            #[doc(hidden)]
+            const #layout: [usize; #length] = {
+                /// Rounds `value` up to the next multiple of `align`.
+                const fn round_up(value: usize, align: usize) -> usize {
+                    value.div_ceil(align) * align
+                }
+
+                /// Allocates the `(unit bits, alignment, bits)` members of a run, which starts at
+                /// byte `offset`, and returns the end of the run in bytes.
+                ///
+                /// Stores the padding and the size of the run in bytes at `index`, followed by the
+                /// bit positions of the members relative to the start of the run.
+                const fn allocate(
+                    msvc: bool, offset: usize, members: &[(usize, usize, usize)],
+                    layout: &mut [usize], index: usize
+                ) -> usize {
+                    let mut start = offset;
+                    let mut end = offset;
+                    let mut bit = offset * 8;
+                    // The size of the current storage unit in bits, or `0` if none is open (MSVC).
+                    let mut unit = 0;
+                    let mut opened = false;
+
+                    let mut i = 0;
+                    while i < members.len() {
+                        let (size, align, bits) = members[i];
+
+                        if msvc {
+                            if bits == 0 {
+                                // Zero sized members close the current storage unit.
+                                unit = 0;
+                            } else {
+                                // Open a new storage unit if the declared type size changes or if
+                                // the member does not fit into the current one anymore.
+                                if unit != size || bit + bits > end * 8 {
+                                    let begin = round_up(end, align);
+                                    if !opened {
+                                        start = begin;
+                                        opened = true;
+                                    }
+
+                                    bit = begin * 8;
+                                    end = begin + size / 8;
+                                    unit = size;
+                                }
+
+                                layout[index + 2 + i] = bit - start * 8;
+                                bit += bits;
+                            }
+                        } else {
+                            // Members must not cross an alignment boundary of their declared type,
+                            // zero sized members skip to the next one.
+                            if bits == 0 || bit / size != (bit + bits - 1) / size {
+                                bit = round_up(bit, size);
+                            }
+
+                            if bits > 0 {
+                                layout[index + 2 + i] = bit - start * 8;
+                                bit += bits;
+                            }
+
+                            end = bit.div_ceil(8);
+                        }
+
+                        i += 1;
+                    }
+
+                    layout[index] = start - offset;
+                    layout[index + 1] = end - start;
+                    end
+                }
+
+                let msvc = #msvc;
+                let mut layout = [0; #length];
+                let mut offset = 0;
+                #(#steps)*
+                let _ = offset;
+                layout
+            };
+        }
+    }
+
+    /// Generates the constructor and the accessors of all bit field members.
+    fn generate_impl(&self) -> proc_macro2::TokenStream {
+        let vis = &self.vis;
+        let ident = &self.ident;
+        let layout = self.layout_ident();
+        let items = self.items();
+
+        let parameters = self.members.iter().filter(|member| member.bits.is_none()).map(|member| {
+            let super::Member { ident, ty, .. } = member;
+            quote::quote! { #ident: #ty }
+        });
+
+        let initializers = items.iter().map(|item| match item {
+            Item::Member(member) => {
+                let ident = &member.ident;
+                quote::quote! { #ident, }
+            },
+
+            Item::Run(run) => {
+                let padding_index = run.layout_index;
+                let size_index = run.layout_index + 1;
+                let storage = quote::format_ident!("_bitfield_{}", run.index);
+                let padding = self.has_padding().then(|| {
+                    let padding = quote::format_ident!("_bitfield_padding_{}", run.index);
+                    quote::quote! { #padding: [0; #layout[#padding_index]], }
+                });
+
+                quote::quote! {
+                    #padding
+                    #storage: [0; #layout[#size_index]],
+                }
+            }
+        });
+
+        let alignments = (0..self.alignment_types().len()).map(|i| {
+            let alignment = quote::format_ident!("_bitfield_align_{}", i);
+            quote::quote! { #alignment: [], }
+        });
+
+        let accessors = items.iter().filter_map(|item| match item {
+            Item::Run(run) => Some(run),
+            _ => None
+        }).flat_map(|run| run.members.iter().enumerate().map(move |(i, member)| (run, i, member)))
+            .filter(|(_, _, member)| member.bits.unwrap() > 0)
+            .map(|(run, i, member)| {
+                let super::Member { attrs, vis, ident, ty, .. } = member;
+                let bits = member.bits.unwrap();
+                let size = bits as usize;
+                let index = run.layout_index + 2 + i;
+                let storage = quote::format_ident!("_bitfield_{}", run.index);
+                let setter = quote::format_ident!("set_{}", ident);
+
+                let getter_doc = format!(" Returns the `{}` bit field member.", ident);
+                let setter_doc = format!(
+                    " Sets the `{}` bit field member, `value` is truncated to {} bit{} like in C.",
+                    ident, bits, if bits == 1 { "" } else { "s" }
+                );
+
+                let value = if member.is_bool() {
+                    quote::quote! { Self::_read_bits(&self.#storage, #layout[#index], #size) != 0 }
+                } else {
+                    quote::quote! {
+                        let value = Self::_read_bits(&self.#storage, #layout[#index], #size);
+
+                        // Sign extend the value for signed types.
+                        let shift = 64 - #size;
+                        (if !(0 as #ty) < 0 as #ty { ((value << shift) as i64 >> shift) as u64 } else { value }) as #ty
+                    }
+                };
+
+                quote::quote! {
+                    #(#attrs)*
+                    #[doc = #getter_doc]
+                    #[inline(always)]
+                    #vis const fn #ident(&self) -> #ty {
+                        #value
+                    }
+
+                    #(#attrs)*
+                    #[doc = #setter_doc]
+                    #[inline(always)]
+                    #vis const fn #setter(&mut self, value: #ty) {
+                        Self::_write_bits(&mut self.#storage, #layout[#index], #size, value as u64);
+                    }
+                }
+            });
+
+        quote::quote! {
+            impl #ident {
+                /// Creates a new instance with the specified members, all bit field members are `0`.
+                #[allow(clippy::too_many_arguments)]
+                #[inline(always)]
+                #vis const fn new(#(#parameters),*) -> Self {
+                    Self {
+                        #(#initializers)*
+                        #(#alignments)*
+                    }
+                }
+
+                /// Reads `size` bits, starting at `bit`, from `bytes`.
+                #[inline(always)]
+                const fn _read_bits(bytes: &[u8], bit: usize, size: usize) -> u64 {
+                    let mut value = 0;
+
+                    let mut i = 0;
+                    while i < size {
+                        let position = bit + i;
+                        value |= (((bytes[position / 8] >> (position % 8)) & 1) as u64) << i;
+                        i += 1;
+                    }
+
+                    value
+                }
+
+                /// Writes the lowest `size` bits of `value` into `bytes`, starting at `bit`.
+                #[inline(always)]
+                const fn _write_bits(bytes: &mut [u8], bit: usize, size: usize, value: u64) {
+                    let mut i = 0;
+                    while i < size {
+                        let position = bit + i;
+                        if (value >> i) & 1 != 0 {
+                            bytes[position / 8] |= 1 << (position % 8);
+                        } else {
+                            bytes[position / 8] &= !(1 << (position % 8));
+                        }
+                        i += 1;
+                    }
+                }
+
+                #(#accessors)*
+            }
+        }
+    }
+
+    /// Generates constant assertions which check that no bit field member is larger than its
+    /// declared type.
+    fn generate_assertions(&self) -> proc_macro2::TokenStream {
+        let assertions = self.members.iter().filter(|member| member.bits.is_some()).map(|member| {
+            let ty = &member.ty;
+            let bits = member.bits.unwrap() as usize;
+            let message = format!("Bit field member `{}` is larger than its declared type", member.ident);
+
+            quote::quote_spanned! { member.ident.span() =>
+                const _: () = ::core::assert!(#bits <= ::core::mem::size_of::<#ty>() * 8, #message);
+            }
+        });
+
+        quote::quote! { #(#assertions)* }
+    }
+}
+
+/// Generates the user code for the parsed struct.
+impl core::convert::From<super::CStruct> for proc_macro2::TokenStream {
+    fn from(c_struct: super::CStruct) -> Self {
+        let item = c_struct.generate_struct();
+        let layout = c_struct.generate_layout();
+        let implementation = c_struct.generate_impl();
+        let assertions = c_struct.generate_assertions();
+
+        quote::quote! {
+            #item
+            #layout
+            #implementation
+            #assertions
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    macro_rules! assert_compare {
+        ($generator:ident, $attribute:expr, $item:expr, $result:expr) => {{
+            let c_struct = CStruct::parse($attribute.parse().unwrap(), $item.parse().unwrap())
+                .unwrap().$generator().to_string();
+            let expected = $result.to_string();
+
+            assert_eq!(&c_struct, &expected);
+        }};
+    }
+
+    // Test macros.
+
+    #[test]
+    #[should_panic]
+    fn test_assert_compare() {
+        assert_compare!(generate_struct, "", "struct A { #[bits(1)] b: u32 }", quote::quote! {});
+    }
+
+    // Test generation.
+
+    #[test]
+    fn c_struct() {
+        assert_compare!(generate_struct, "abi = \"sysv\"", "pub struct AB { a: u8, #[bits(1)] b: u32, #[bits(0)] c: u16, #[bits(2)] d: u32, e: u8 }", quote::quote! {
+            #[repr(C)]
+            pub struct AB {
+                a: u8,
+                _bitfield_0: [u8; _AB_BITFIELD_LAYOUT[1usize]],
+                e: u8,
+                _bitfield_align_0: [u32; 0],
+            }
+        });
+
+        assert_compare!(generate_struct, "", "#[derive(Clone)] struct A { #[bits(1)] b: u32, c: u8, #[doc = \"D\"] #[bits(1)] d: bool }", quote::quote! {
+            #[derive(Clone)]
+            #[repr(C)]
+            struct A {
+                _bitfield_padding_0: [u8; _A_BITFIELD_LAYOUT[0usize]],
+                _bitfield_0: [u8; _A_BITFIELD_LAYOUT[1usize]],
+                c: u8,
+                _bitfield_padding_1: [u8; _A_BITFIELD_LAYOUT[3usize]],
+                _bitfield_1: [u8; _A_BITFIELD_LAYOUT[4usize]],
+                _bitfield_align_0: [u32; 0],
+                _bitfield_align_1: [bool; 0],
+            }
+        });
+    }
+
+    #[test]
+    fn assertions() {
+        assert_compare!(generate_assertions, "", "struct A { a: u8, #[bits(3)] b: c_uint }", quote::quote! {
+            const _: () = ::core::assert!(3usize <= ::core::mem::size_of::<c_uint>() * 8, "Bit field member `b` is larger than its declared type");
+        });
+    }
+}
@@ -0,0 +1,45 @@
+//! Contains all data types to represent `repr(C)` structs with C-style bit field members.
+
+pub(super) mod parse;
+pub(super) mod generate;
+
+/// The ABI which determines how bit field members are allocated, selected with `abi = "..."`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Abi {
+    /// `"sysv"`: Bit field members are packed into the next bits which do not cross an alignment
+    /// boundary of their declared type, even if those bits share a byte with the previous member.
+    SystemV,
+    /// `"msvc"`: Bit field members are packed into storage units of their declared type, a new unit
+    /// is started if the declared type size changes or if the member does not fit anymore.
+    Msvc
+}
+
+/// Stores all information about a struct with C-style bit field members.
+pub struct CStruct {
+    /// `None` if the ABI should be selected based on the target.
+    pub abi: Option<Abi>,
+    pub attrs: Vec<syn::Attribute>,
+    pub vis: syn::Visibility,
+    pub ident: syn::Ident,
+    pub members: Vec<Member>
+}
+
+/// Stores all information about a member of the struct.
+pub struct Member {
+    pub attrs: Vec<syn::Attribute>,
+    pub vis: syn::Visibility,
+    pub ident: syn::Ident,
+    pub ty: syn::Type,
+    /// The amount of bits for members with a `#[bits(...)]` attribute.
+    pub bits: Option<u8>
+}
+
+impl Member {
+    /// Returns `true` if the declared type of the member is `bool`.
+    fn is_bool(&self) -> bool {
+        match &self.ty {
+            syn::Type::Path(path) => path.qself.is_none() && path.path.is_ident("bool"),
+            _ => false
+        }
+    }
+}
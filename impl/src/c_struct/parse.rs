@@ -0,0 +1,202 @@
+//! Contains code to parse structs with C-style bit field members.
+
+use syn::spanned::Spanned;
+
+impl syn::parse::Parse for super::Abi {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        if ident != "abi" {
+            return Err(syn::Error::new(ident.span(), "expected either `abi` or nothing"));
+        }
+
+        input.parse::<syn::Token![=]>()?;
+        let lit: syn::LitStr = input.parse()?;
+        let abi = match lit.value().as_ref() {
+            "sysv" => Self::SystemV,
+            "msvc" => Self::Msvc,
+            _ => return Err(syn::Error::new(lit.span(), "expected one of: `\"sysv\"`, `\"msvc\"`"))
+        };
+
+        if !input.is_empty() {
+            return Err(input.error("unexpected token"));
+        }
+
+        Ok(abi)
+    }
+}
+
+impl super::Member {
+    /// Parses a named field of the struct and removes its `#[bits(...)]` attribute.
+    fn parse(field: syn::Field) -> syn::Result<Self> {
+        let mut attrs = Vec::with_capacity(field.attrs.len());
+        let mut bits = None;
+
+        for attr in field.attrs {
+            if !attr.path.is_ident("bits") {
+                attrs.push(attr);
+                continue;
+            }
+
+            if bits.is_some() {
+                return Err(syn::Error::new(attr.path.span(), "duplicate"));
+            }
+
+            let lit: syn::LitInt = attr.parse_args()?;
+            let size = lit.base10_parse::<u8>()?;
+
+            // The bits are read and written via `u64`.
+            if size > 64 {
+                return Err(syn::Error::new(lit.span(), "bit fields larger than 64 bits are not supported"));
+            }
+
+            bits = Some((size, lit.span()));
+        }
+
+        let ident = field.ident.unwrap();
+        let member = Self { attrs, vis: field.vis, ident, ty: field.ty, bits: bits.map(|(size, _)| size) };
+
+        if let Some((size, span)) = bits {
+            // The size of aliases like `c_uint` is only known to the compiler, so only paths are
+            // accepted and checked with a constant assertion later on.
+            if !matches!(&member.ty, syn::Type::Path(path) if path.qself.is_none()) {
+                return Err(syn::Error::new(member.ty.span(), "expected integer type or `bool`"));
+            }
+
+            if member.is_bool() && size > 1 {
+                return Err(syn::Error::new(span, "`bool` bit fields can have at most 1 bit"));
+            }
+        }
+
+        Ok(member)
+    }
+}
+
+impl super::CStruct {
+    /// Parses the proc-macro attribute header and the struct.
+    pub fn parse(
+        attribute: proc_macro2::TokenStream,
+        item: proc_macro2::TokenStream
+    ) -> syn::Result<Self> {
+        let abi = if attribute.is_empty() {
+            None
+        } else {
+            Some(syn::parse2(attribute)?)
+        };
+
+        let item: syn::ItemStruct = syn::parse2(item)?;
+
+        if item.generics.lt_token.is_some() || item.generics.where_clause.is_some() {
+            return Err(syn::Error::new(item.generics.span(), "generics are not supported"));
+        }
+
+        if let Some(attr) = item.attrs.iter().find(|attr| attr.path.is_ident("repr")) {
+            return Err(syn::Error::new(attr.span(), "unnecessary, `#[repr(C)]` is generated"));
+        }
+
+        let fields = match item.fields {
+            syn::Fields::Named(fields) => fields.named,
+            _ => return Err(syn::Error::new(item.ident.span(), "expected struct with named fields"))
+        };
+
+        let members = fields.into_iter()
+            .map(super::Member::parse)
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        if members.iter().all(|member| member.bits.is_none()) {
+            return Err(syn::Error::new(
+                item.ident.span(), "expected at least one member with a `#[bits(...)]` attribute"
+            ));
+        }
+
+        Self::validate_names(&members)?;
+
+        Ok(Self { abi, attrs: item.attrs, vis: item.vis, ident: item.ident, members })
+    }
+
+    /// Checks that the generated constructor and accessors do not collide.
+    fn validate_names(members: &[super::Member]) -> syn::Result<()> {
+        let mut names = vec![("new".to_string(), "the constructor".to_string())];
+
+        for member in members.iter().filter(|member| member.bits.unwrap_or_default() > 0) {
+            let ident = syn::ext::IdentExt::unraw(&member.ident).to_string();
+
+            for (name, kind) in [(ident.clone(), "getter"), (format!("set_{}", ident), "setter")] {
+                if let Some((_, other)) = names.iter().find(|(other, _)| *other == name) {
+                    return Err(syn::Error::new(
+                        member.ident.span(), format!("{} `{}` collides with {}", kind, name, other)
+                    ));
+                }
+
+                names.push((name, format!("the {} of `{}`", kind, ident)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    macro_rules! parse_invalid {
+        ($attribute:expr, $item:expr, $message:expr, ($sl:expr, $sc:expr), ($el:expr, $ec:expr)) => {{
+            let error = CStruct::parse(
+                $attribute.parse().unwrap(), $item.parse().unwrap()
+            ).map(|_| ()).unwrap_err();
+            assert_eq!(error.to_string(), $message);
+            compare_span!(error.span(), ($sl, $sc), ($el, $ec));
+        }}
+    }
+
+    macro_rules! parse_valid {
+        ($attribute:expr, $item:expr) => {
+            CStruct::parse($attribute.parse().unwrap(), $item.parse().unwrap()).unwrap()
+        }
+    }
+
+    #[test]
+    fn abi() {
+        assert_eq!(parse_valid!("", "struct A { #[bits(1)] b: u32 }").abi, None);
+        assert_eq!(parse_valid!("abi = \"sysv\"", "struct A { #[bits(1)] b: u32 }").abi, Some(Abi::SystemV));
+        assert_eq!(parse_valid!("abi = \"msvc\"", "struct A { #[bits(1)] b: u32 }").abi, Some(Abi::Msvc));
+
+        parse_invalid!("a", "struct A { #[bits(1)] b: u32 }", "expected either `abi` or nothing", (1, 0), (1, 1));
+        parse_invalid!("abi", "struct A { #[bits(1)] b: u32 }", "expected `=`", (1, 0), (1, 0));
+        parse_invalid!("abi = \"gnu\"", "struct A { #[bits(1)] b: u32 }", "expected one of: `\"sysv\"`, `\"msvc\"`", (1, 6), (1, 11));
+        parse_invalid!("abi = \"sysv\", a", "struct A { #[bits(1)] b: u32 }", "unexpected token", (1, 12), (1, 13));
+    }
+
+    #[test]
+    fn members() {
+        let c_struct = parse_valid!("", "pub struct A { a: *const u8, #[bits(3)] pub b: c_uint, #[doc = \"C\"] #[bits(1)] c: bool }");
+        assert_eq!(c_struct.ident, "A");
+        assert_eq!(c_struct.members.len(), 3);
+        assert_eq!(c_struct.members[0].bits, None);
+        assert_eq!(c_struct.members[1].ident, "b");
+        assert_eq!(c_struct.members[1].bits, Some(3));
+        assert!(!c_struct.members[1].is_bool());
+        assert_eq!(c_struct.members[2].attrs.len(), 1);
+        assert_eq!(c_struct.members[2].bits, Some(1));
+        assert!(c_struct.members[2].is_bool());
+
+        parse_invalid!("", "struct A<T> { #[bits(1)] b: u32 }", "generics are not supported", (1, 8), (1, 11));
+        parse_invalid!("", "#[repr(C)] struct A { #[bits(1)] b: u32 }", "unnecessary, `#[repr(C)]` is generated", (1, 0), (1, 10));
+        parse_invalid!("", "struct A(u32);", "expected struct with named fields", (1, 7), (1, 8));
+        parse_invalid!("", "struct A { b: u32 }", "expected at least one member with a `#[bits(...)]` attribute", (1, 7), (1, 8));
+        parse_invalid!("", "struct A { #[bits(1)] #[bits(2)] b: u32 }", "duplicate", (1, 24), (1, 28));
+        parse_invalid!("", "struct A { #[bits] b: u32 }", "expected attribute arguments in parentheses: #[bits(...)]", (1, 11), (1, 18));
+        parse_invalid!("", "struct A { #[bits(65)] b: u64 }", "bit fields larger than 64 bits are not supported", (1, 18), (1, 20));
+        parse_invalid!("", "struct A { #[bits(1)] b: [u8; 1] }", "expected integer type or `bool`", (1, 25), (1, 32));
+        parse_invalid!("", "struct A { #[bits(2)] b: bool }", "`bool` bit fields can have at most 1 bit", (1, 18), (1, 19));
+    }
+
+    #[test]
+    fn validate_names() {
+        parse_valid!("", "struct A { new: u8, #[bits(0)] set_b: u32, #[bits(1)] b: u32 }");
+
+        parse_invalid!("", "struct A { #[bits(1)] new: u32 }", "getter `new` collides with the constructor", (1, 22), (1, 25));
+        parse_invalid!("", "struct A { #[bits(1)] b: u32, #[bits(1)] set_b: u32 }", "getter `set_b` collides with the setter of `b`", (1, 41), (1, 46));
+        parse_invalid!("", "struct A { #[bits(1)] set_b: u32, #[bits(1)] b: u32 }", "setter `set_b` collides with the getter of `set_b`", (1, 45), (1, 46));
+    }
+}
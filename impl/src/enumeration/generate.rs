@@ -1,29 +1,60 @@
 //! Contains code to generate bit field fields.
 
 impl super::Enumeration {
-    /// Generates a `core::convert::TryFrom<REPR, Error = REPR>` implementation.
-    pub fn generate_try_from(&self) -> proc_macro2::TokenStream {
+    /// Generates the `const fn from_repr(REPR) -> Option<Self>` and `const fn to_repr(self) -> REPR`
+    /// methods, which convert between variants and their discriminants in a `const` context.
+    pub fn generate_repr(&self) -> proc_macro2::TokenStream {
         let ident = &self.ident;
         let repr = &self.repr;
+        let vis = &self.vis;
         let span = repr.span();
         let variants = &self.variants;
 
-        // TODO: Add `const` when https://github.com/rust-lang/rust-project-goals/issues/106 is merged.
+        let from_repr = quote::quote_spanned!(span =>
+            /// Returns the variant with the discriminant `value`, or `None` if no variant uses it.
+            #[allow(non_upper_case_globals)]
+            #[inline(always)]
+            #vis const fn from_repr(value: #repr) -> ::core::option::Option<Self> {
+                #(const #variants: #repr = #ident::#variants as #repr;)*
+                match value {
+                    #(#variants)|* => ::core::option::Option::Some(unsafe {
+                        *(&value as *const #repr as *const Self)
+                    }),
+                    _ => ::core::option::Option::None
+                }
+            }
+        );
+
+        // Not spanned to the `repr` type, so an unused `to_repr` is not reported in user code.
+        quote::quote!(
+            #from_repr
+
+            /// Returns the discriminant of the variant.
+            #[inline(always)]
+            #vis const fn to_repr(self) -> #repr {
+                self as #repr
+            }
+        )
+    }
+
+    /// Generates a `core::convert::TryFrom<REPR, Error = REPR>` implementation, which expects the
+    /// methods of `generate_repr`.
+    pub fn generate_try_from(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let repr = &self.repr;
+        let span = repr.span();
+
         quote::quote_spanned!(span =>
             impl ::core::convert::TryFrom<#repr> for #ident {
                 type Error = #repr;
 
-                #[allow(non_upper_case_globals)]
                 #[inline(always)]
                 fn try_from(value: #repr) -> ::core::result::Result<
                     Self, <Self as ::core::convert::TryFrom<#repr>>::Error
                 > {
-                    #(const #variants: #repr = #ident::#variants as #repr;)*
-                    match value {
-                        #(#variants)|* => ::core::result::Result::Ok(unsafe {
-                            *(&value as *const #repr as *const Self)
-                        }),
-                        _ => ::core::result::Result::Err(value)
+                    match Self::from_repr(value) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value)
                     }
                 }
             }
@@ -31,6 +62,24 @@ impl super::Enumeration {
     }
 }
 
+/// Generates the user code for a `FromPrimitive` enumeration.
+impl core::convert::Into<proc_macro2::TokenStream> for super::Enumeration {
+    fn into(self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+
+        let repr = self.generate_repr();
+        let try_from = self.generate_try_from();
+
+        quote::quote! {
+            impl #ident {
+                #repr
+            }
+
+            #try_from
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     macro_rules! assert_compare {
@@ -52,24 +101,67 @@ mod tests {
 
     // Test generation.
 
+    #[test]
+    fn repr() {
+        assert_compare!(generate_repr, "#[repr(u8)] enum A { B }", quote::quote! {
+            /// Returns the variant with the discriminant `value`, or `None` if no variant uses it.
+            #[allow(non_upper_case_globals)]
+            #[inline(always)]
+            const fn from_repr(value: u8) -> ::core::option::Option<Self> {
+                const B: u8 = A::B as u8;
+
+                match value {
+                    B => ::core::option::Option::Some(unsafe {
+                        *(&value as *const u8 as *const Self)
+                    }),
+                    _ => ::core::option::Option::None
+                }
+            }
+
+            /// Returns the discriminant of the variant.
+            #[inline(always)]
+            const fn to_repr(self) -> u8 {
+                self as u8
+            }
+        });
+
+        assert_compare!(generate_repr, "#[repr(i16)] pub enum A { B = 3, C }", quote::quote! {
+            /// Returns the variant with the discriminant `value`, or `None` if no variant uses it.
+            #[allow(non_upper_case_globals)]
+            #[inline(always)]
+            pub const fn from_repr(value: i16) -> ::core::option::Option<Self> {
+                const B: i16 = A::B as i16;
+                const C: i16 = A::C as i16;
+
+                match value {
+                    B | C => ::core::option::Option::Some(unsafe {
+                        *(&value as *const i16 as *const Self)
+                    }),
+                    _ => ::core::option::Option::None
+                }
+            }
+
+            /// Returns the discriminant of the variant.
+            #[inline(always)]
+            pub const fn to_repr(self) -> i16 {
+                self as i16
+            }
+        });
+    }
+
     #[test]
     fn try_from() {
         assert_compare!(generate_try_from, "#[repr(u8)] enum A { B }", quote::quote! {
             impl ::core::convert::TryFrom<u8> for A {
                 type Error = u8;
 
-                #[allow(non_upper_case_globals)]
                 #[inline(always)]
                 fn try_from(value: u8) -> ::core::result::Result<
                     Self, <Self as ::core::convert::TryFrom<u8>>::Error
                 > {
-                    const B: u8 = A::B as u8;
-
-                    match value {
-                        B => ::core::result::Result::Ok(unsafe {
-                            *(&value as *const u8 as *const Self)
-                        }),
-                        _ => ::core::result::Result::Err(value)
+                    match Self::from_repr(value) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value)
                     }
                 }
             }
@@ -79,43 +171,16 @@ mod tests {
             impl ::core::convert::TryFrom<u16> for B {
                 type Error = u16;
 
-                #[allow(non_upper_case_globals)]
                 #[inline(always)]
                 fn try_from(value: u16) -> ::core::result::Result<
                     Self, <Self as ::core::convert::TryFrom<u16>>::Error
                 > {
-                    const A: u16 = B::A as u16;
-
-                    match value {
-                        A => ::core::result::Result::Ok(unsafe {
-                            *(&value as *const u16 as *const Self)
-                        }),
-                        _ => ::core::result::Result::Err(value)
-                    }
-                }
-            }
-        });
-
-        assert_compare!(generate_try_from, "#[repr(u8)] enum A { B = 3, C }", quote::quote! {
-            impl ::core::convert::TryFrom<u8> for A {
-                type Error = u8;
-
-                #[allow(non_upper_case_globals)]
-                #[inline(always)]
-                fn try_from(value: u8) -> ::core::result::Result<
-                    Self, <Self as ::core::convert::TryFrom<u8>>::Error
-                > {
-                    const B: u8 = A::B as u8;
-                    const C: u8 = A::C as u8;
-
-                    match value {
-                        B | C => ::core::result::Result::Ok(unsafe {
-                            *(&value as *const u8 as *const Self)
-                        }),
-                        _ => ::core::result::Result::Err(value)
+                    match Self::from_repr(value) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => ::core::result::Result::Err(value)
                     }
                 }
             }
         });
     }
-}
\ No newline at end of file
+}
@@ -1,5 +1,7 @@
 //! Contains code to generate bit field fields.
 
+use syn::ext::IdentExt;
+
 impl super::Field {
     /// Generates a `const fn iter() -> &'static [Self]` implementation.
     fn generate_iter(&self) -> proc_macro2::TokenStream {
@@ -54,6 +56,58 @@ impl super::Field {
             }
         )
     }
+
+    /// Generates a `const fn _bitfield_is_non_zero() -> bool` implementation, which is used by the
+    /// compile time check of `Option` fields in bit fields. It is hidden and prefixed to not collide
+    /// with methods of the user.
+    fn generate_is_non_zero(&self) -> proc_macro2::TokenStream {
+        let vis = &self.0.vis;
+        let repr = &self.0.repr;
+
+        quote::quote!(
+            /// Returns `true` if no variant uses the discriminant `0`, which allows using this type as
+            /// an `Option` field.
+            #[doc(hidden)]
+            #[inline(always)]
+            #vis const fn _bitfield_is_non_zero() -> bool {
+                let mut i = 0;
+                while i < Self::iter().len() {
+                    if Self::iter()[i] as #repr == 0 {
+                        return false;
+                    }
+                    i += 1;
+                }
+
+                true
+            }
+        )
+    }
+
+    /// Generates a `const fn is_#GROUP(self) -> bool` implementation for every group of variants.
+    fn generate_groups(&self) -> proc_macro2::TokenStream {
+        let vis = &self.0.vis;
+
+        let groups = self.1.iter().map(|group| {
+            let is_group = syn::Ident::new(&format!("is_{}", group.ident.unraw()), group.ident.span());
+            let variants = &group.variants;
+            let doc = format!(" Returns `true` if the variant belongs to the group `{}`.", group.ident.unraw());
+
+            // All variants can belong to a group.
+            quote::quote! {
+                #[doc = #doc]
+                #[allow(unreachable_patterns)]
+                #[inline(always)]
+                #vis const fn #is_group(self) -> bool {
+                    match self {
+                        #(Self::#variants)|* => true,
+                        _ => false
+                    }
+                }
+            }
+        });
+
+        quote::quote!(#(#groups)*)
+    }
 }
 
 /// Generates the user code for the parsed field of a bit field.
@@ -63,12 +117,18 @@ impl core::convert::Into<proc_macro2::TokenStream> for super::Field {
 
         let iter = self.generate_iter();
         let size = self.generate_size();
+        let is_non_zero = self.generate_is_non_zero();
+        let repr = self.0.generate_repr();
         let try_from = self.0.generate_try_from();
+        let groups = self.generate_groups();
 
         quote::quote! {
             impl #ident {
                 #iter
                 #size
+                #is_non_zero
+                #repr
+                #groups
             }
 
             #try_from
@@ -82,7 +142,7 @@ mod tests {
 
     macro_rules! assert_compare {
         ($generator:ident, $item:expr, $result:expr) => {{
-            let field = Field(parse_valid!($item)).$generator().to_string();
+            let field = Field::parse($item.parse().unwrap()).unwrap().$generator().to_string();
             let expected = $result.to_string();
 
             assert_eq!(&field, &expected);
@@ -188,11 +248,62 @@ mod tests {
         });
     }
 
+    #[test]
+    fn is_non_zero() {
+        assert_compare!(generate_is_non_zero, "#[repr(u8)] pub enum A { B = 1, C }", quote::quote! {
+            /// Returns `true` if no variant uses the discriminant `0`, which allows using this type as
+            /// an `Option` field.
+            #[doc(hidden)]
+            #[inline(always)]
+            pub const fn _bitfield_is_non_zero() -> bool {
+                let mut i = 0;
+                while i < Self::iter().len() {
+                    if Self::iter()[i] as u8 == 0 {
+                        return false;
+                    }
+                    i += 1;
+                }
+
+                true
+            }
+        });
+    }
+
+    #[test]
+    fn groups() {
+        assert_compare!(generate_groups, "#[repr(u8)] enum A { B }", quote::quote!());
+
+        assert_compare!(
+            generate_groups, "#[repr(u8)] pub enum A { #[group(x, r#y)] B, C, #[group(x)] D }",
+            quote::quote! {
+                #[doc = " Returns `true` if the variant belongs to the group `x`."]
+                #[allow(unreachable_patterns)]
+                #[inline(always)]
+                pub const fn is_x(self) -> bool {
+                    match self {
+                        Self::B | Self::D => true,
+                        _ => false
+                    }
+                }
+
+                #[doc = " Returns `true` if the variant belongs to the group `y`."]
+                #[allow(unreachable_patterns)]
+                #[inline(always)]
+                pub const fn is_y(self) -> bool {
+                    match self {
+                        Self::B => true,
+                        _ => false
+                    }
+                }
+            }
+        );
+    }
+
     #[test]
     fn everything() {
         assert_eq!(
             Into::<proc_macro2::TokenStream>::into(
-                Field(parse_valid!("#[repr(u8)] enum C { D }"))
+                Field::parse("#[repr(u8)] enum C { #[group(e)] D }".parse().unwrap()).unwrap()
             ).to_string(),
             quote::quote! {
                 impl C {
@@ -231,23 +342,64 @@ mod tests {
                             _ => max.ilog2() as u8 + 1
                         }
                     }
-                }
 
-                impl ::core::convert::TryFrom<u8> for C {
-                    type Error = u8;
+                    /// Returns `true` if no variant uses the discriminant `0`, which allows using this type as
+                    /// an `Option` field.
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    const fn _bitfield_is_non_zero() -> bool {
+                        let mut i = 0;
+                        while i < Self::iter().len() {
+                            if Self::iter()[i] as u8 == 0 {
+                                return false;
+                            }
+                            i += 1;
+                        }
 
+                        true
+                    }
+
+                    /// Returns the variant with the discriminant `value`, or `None` if no variant uses it.
                     #[allow(non_upper_case_globals)]
                     #[inline(always)]
-                    fn try_from(value: u8) -> ::core::result::Result<
-                        Self, <Self as ::core::convert::TryFrom<u8>>::Error
-                    > {
+                    const fn from_repr(value: u8) -> ::core::option::Option<Self> {
                         const D: u8 = C::D as u8;
 
                         match value {
-                            D => ::core::result::Result::Ok(unsafe {
+                            D => ::core::option::Option::Some(unsafe {
                                 *(&value as *const u8 as *const Self)
                             }),
-                            _ => ::core::result::Result::Err(value)
+                            _ => ::core::option::Option::None
+                        }
+                    }
+
+                    /// Returns the discriminant of the variant.
+                    #[inline(always)]
+                    const fn to_repr(self) -> u8 {
+                        self as u8
+                    }
+
+                    #[doc = " Returns `true` if the variant belongs to the group `e`."]
+                    #[allow(unreachable_patterns)]
+                    #[inline(always)]
+                    const fn is_e(self) -> bool {
+                        match self {
+                            Self::D => true,
+                            _ => false
+                        }
+                    }
+                }
+
+                impl ::core::convert::TryFrom<u8> for C {
+                    type Error = u8;
+
+                    #[inline(always)]
+                    fn try_from(value: u8) -> ::core::result::Result<
+                        Self, <Self as ::core::convert::TryFrom<u8>>::Error
+                    > {
+                        match Self::from_repr(value) {
+                            ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                            ::core::option::Option::None => ::core::result::Result::Err(value)
                         }
                     }
                 }
@@ -3,5 +3,11 @@
 pub(super) mod generate;
 pub(super) mod parse;
 
-/// Stores all information about a field of a bit field.
-pub struct Field(pub crate::enumeration::Enumeration);
\ No newline at end of file
+/// Stores all information about a field of a bit field, and the groups of its variants.
+pub struct Field(pub crate::enumeration::Enumeration, pub Vec<Group>);
+
+/// Stores a group of variants, from the `#[group(...)]` attributes of the variants.
+pub struct Group {
+    pub ident: syn::Ident,
+    pub variants: Vec<syn::Ident>
+}
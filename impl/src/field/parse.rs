@@ -1,7 +1,101 @@
 //! Contains code to parse bit field fields.
 
+use syn::{ext::IdentExt, spanned::Spanned};
+
 impl super::Field {
     pub fn parse(item: proc_macro2::TokenStream) -> syn::Result<Self> {
-        Ok(Self(crate::enumeration::Enumeration::parse(item)?))
+        let input: syn::DeriveInput = syn::parse2(item)?;
+        let groups = match &input.data {
+            syn::Data::Enum(e) => Self::parse_groups(e)?,
+            _ => vec!()
+        };
+
+        Ok(Self(crate::enumeration::Enumeration::parse_derived(input)?, groups))
+    }
+
+    /// Collects the groups from the `#[group(...)]` attributes of all variants, in the order in which
+    /// they are first used.
+    fn parse_groups(e: &syn::DataEnum) -> syn::Result<Vec<super::Group>> {
+        let mut groups: Vec<super::Group> = vec!();
+
+        for variant in &e.variants {
+            for attr in variant.attrs.iter().filter(|attr| attr.path.is_ident("group")) {
+                let idents = attr.parse_args_with(
+                    syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated
+                )?;
+
+                if idents.is_empty() {
+                    return Err(syn::Error::new(attr.span(), "expected at least one group"));
+                }
+
+                for ident in idents {
+                    match groups.iter_mut().find(|group| group.ident.unraw() == ident.unraw()) {
+                        Some(group) if group.variants.contains(&variant.ident) => return Err(
+                            syn::Error::new(ident.span(), "duplicate")
+                        ),
+                        Some(group) => group.variants.push(variant.ident.clone()),
+                        None => groups.push(super::Group { ident, variants: vec!(variant.ident.clone()) })
+                    }
+                }
+            }
+        }
+
+        Ok(groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    macro_rules! parse_invalid {
+        ($item:expr, $message:expr, ($sl:expr, $sc:expr), ($el:expr, $ec:expr)) => {{
+            let error = Field::parse($item.parse().unwrap()).map(|_| ()).unwrap_err();
+            assert_eq!(error.to_string(), $message);
+            compare_span!(error.span(), ($sl, $sc), ($el, $ec));
+        }}
+    }
+
+    macro_rules! parse_valid {
+        ($item:expr) => {
+            Field::parse($item.parse().unwrap()).unwrap()
+        }
+    }
+
+    // Test parsing.
+
+    #[test]
+    fn groups() {
+        assert!(parse_valid!("#[repr(u8)] enum A { B }").1.is_empty());
+
+        let groups = parse_valid!("#[repr(u8)] enum A { #[group(x, y)] B, C, #[group(y)] #[group(r#z)] D }").1;
+        assert_eq!(groups.iter().map(|g| g.ident.to_string()).collect::<Vec<_>>(), vec!("x", "y", "r#z"));
+        assert_eq!(groups[0].variants, vec!("B"));
+        assert_eq!(groups[1].variants, vec!("B", "D"));
+        assert_eq!(groups[2].variants, vec!("D"));
+
+        parse_invalid!(
+            "#[repr(u8)] enum A { #[group] B }",
+            "expected attribute arguments in parentheses: #[group(...)]",
+            (1, 21), (1, 29)
+        );
+
+        parse_invalid!(
+            "#[repr(u8)] enum A { #[group()] B }",
+            "expected at least one group",
+            (1, 21), (1, 31)
+        );
+
+        parse_invalid!(
+            "#[repr(u8)] enum A { #[group(x = 1)] B }",
+            "expected `,`",
+            (1, 31), (1, 32)
+        );
+
+        parse_invalid!(
+            "#[repr(u8)] enum A { #[group(x)] #[group(x)] B }",
+            "duplicate",
+            (1, 41), (1, 42)
+        );
     }
-}
\ No newline at end of file
+}
@@ -14,6 +14,26 @@ impl super::Flags {
             }
         )
     }
+
+    /// Generates a `const fn _bitfield_flag_name(&self) -> &'static str` implementation, which is used
+    /// by the generated `Debug` implementation of bit fields. It is hidden and prefixed to not collide
+    /// with methods of the user.
+    fn generate_name(&self) -> proc_macro2::TokenStream {
+        let variants = &self.0.variants;
+        let names = variants.iter().map(syn::ext::IdentExt::unraw);
+        let vis = &self.0.vis;
+
+        quote::quote!(
+            /// Returns the name of the enumeration variant.
+            #[doc(hidden)]
+            #[inline(always)]
+            #vis const fn _bitfield_flag_name(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => ::core::stringify!(#names)),*
+                }
+            }
+        )
+    }
 }
 
 /// Generates the user code for the parsed flags of a bit field.
@@ -22,10 +42,12 @@ impl core::convert::Into<proc_macro2::TokenStream> for super::Flags {
         let ident = &self.0.ident;
 
         let iter = self.generate_iter();
+        let name = self.generate_name();
 
         quote::quote! {
             impl #ident {
                 #iter
+                #name
             }
         }
     }
@@ -76,6 +98,32 @@ mod tests {
         });
     }
 
+    #[test]
+    fn name() {
+        assert_compare!(generate_name, "#[repr(u8)] enum A { B }", quote::quote! {
+            /// Returns the name of the enumeration variant.
+            #[doc(hidden)]
+            #[inline(always)]
+            const fn _bitfield_flag_name(&self) -> &'static str {
+                match self {
+                    Self::B => ::core::stringify!(B)
+                }
+            }
+        });
+
+        assert_compare!(generate_name, "#[repr(u8)] pub enum B { C, r#D = 6 }", quote::quote! {
+            /// Returns the name of the enumeration variant.
+            #[doc(hidden)]
+            #[inline(always)]
+            pub const fn _bitfield_flag_name(&self) -> &'static str {
+                match self {
+                    Self::C => ::core::stringify!(C),
+                    Self::r#D => ::core::stringify!(D)
+                }
+            }
+        });
+    }
+
     #[test]
     fn everything() {
         assert_eq!(
@@ -93,6 +141,17 @@ mod tests {
                             Self::F
                         ]
                     }
+
+                    /// Returns the name of the enumeration variant.
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    const fn _bitfield_flag_name(&self) -> &'static str {
+                        match self {
+                            Self::D => ::core::stringify!(D),
+                            Self::E => ::core::stringify!(E),
+                            Self::F => ::core::stringify!(F)
+                        }
+                    }
                 }
             }.to_string()
         );
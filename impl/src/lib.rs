@@ -5,8 +5,12 @@
 mod test;
 
 pub mod bitfield;
+pub mod c_header;
+pub mod c_import;
+pub mod c_struct;
 #[macro_use]
 pub mod enumeration;
 pub mod field;
 pub mod flags;
-mod primitive;
\ No newline at end of file
+mod primitive;
+pub mod svd;
\ No newline at end of file
@@ -30,6 +30,17 @@ pub(crate) fn primitive_bits(ident: &syn::Ident) -> Option<u8> {
     else { None }
 }
 
+/// Returns the amount of bits of an unsigned `NonZero` type, which is the last path segment of a
+/// field type like `::core::num::NonZeroU8`.
+pub(crate) fn non_zero_bits(ident: &syn::Ident) -> Option<u8> {
+    if ident == "NonZeroU8" { Some(8) }
+    else if ident == "NonZeroU16" { Some(16) }
+    else if ident == "NonZeroU32" { Some(32) }
+    else if ident == "NonZeroU64" { Some(64) }
+    else if ident == "NonZeroU128" { Some(128) }
+    else { None }
+}
+
 /// Returns the narrowest primitive type size to store `bits`.
 pub(crate) fn field_primitive_size(bits: u8) -> u8 {
     const SIZES: &[u8] = &[8, 16, 32, 64, 128];
@@ -0,0 +1,1313 @@
+//! Contains code to generate bit fields from CMSIS-SVD register descriptions.
+
+use std::collections::HashMap;
+
+use crate::c_import::{camel_case, primitive_bits, rust_ident, validate};
+pub use crate::c_import::{Diagnostic, Import};
+
+/// An XML element, as far as it is needed to read SVD files.
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+    /// The line of the start tag, starting at `1`.
+    line: usize
+}
+
+impl Element {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(other, _)| other == name).map(|(_, value)| value.as_str())
+    }
+
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Returns the trimmed text of the child element `name`.
+    fn text(&self, name: &str) -> Option<&str> {
+        self.child(name).map(|child| child.text.trim())
+    }
+
+    /// Returns the text of the child element `name` with all whitespace collapsed, for doc comments.
+    fn description(&self, name: &str) -> Option<String> {
+        self.text(name).map(|text| text.split_whitespace().collect::<Vec<_>>().join(" ")).filter(|text| !text.is_empty())
+    }
+}
+
+/// Reads an XML document and tracks the current line.
+struct Reader<'a> {
+    xml: &'a str,
+    position: usize,
+    line: usize
+}
+
+impl<'a> Reader<'a> {
+    fn rest(&self) -> &'a str {
+        &self.xml[self.position..]
+    }
+
+    fn error(&self, message: String) -> Diagnostic {
+        Diagnostic { line: self.line, message }
+    }
+
+    /// Consumes `length` bytes and returns them.
+    fn advance(&mut self, length: usize) -> &'a str {
+        let text = &self.xml[self.position..self.position + length];
+        self.line += text.matches('\n').count();
+        self.position += length;
+        text
+    }
+
+    /// Consumes everything up to and including `end` and returns the text before `end`.
+    fn until(&mut self, end: &str) -> Result<&'a str, Diagnostic> {
+        let length = self.rest().find(end).ok_or_else(|| self.error(format!("expected `{}`", end)))?;
+        let text = self.advance(length);
+        self.advance(end.len());
+        Ok(text)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let length = self.rest().len() - self.rest().trim_start().len();
+        self.advance(length);
+    }
+
+    /// Consumes an element or attribute name.
+    fn name(&mut self) -> Result<&'a str, Diagnostic> {
+        let length = self.rest().find(|c: char| c.is_whitespace() || ['=', '/', '>'].contains(&c)).unwrap_or(self.rest().len());
+        if length == 0 {
+            return Err(self.error("expected a name".to_string()));
+        }
+        Ok(self.advance(length))
+    }
+}
+
+/// Replaces the predefined entities and character references of XML.
+fn decode(text: &str, line: usize) -> Result<String, Diagnostic> {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let end = rest.find(';').ok_or_else(|| Diagnostic { line, message: "expected `;` after `&`".to_string() })?;
+        let entity = &rest[1..end];
+        let c = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => match entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                None => entity.strip_prefix('#').and_then(|decimal| decimal.parse().ok()).and_then(char::from_u32)
+            }
+        };
+
+        result.push(c.ok_or_else(|| Diagnostic { line, message: format!("unknown entity `&{};`", entity) })?);
+        rest = &rest[end + 1..];
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// Parses an XML document into its root element. Processing instructions, comments and document
+/// type declarations are skipped, namespaces are not resolved.
+fn parse_xml(xml: &str) -> Result<Element, Diagnostic> {
+    let mut reader = Reader { xml, position: 0, line: 1 };
+    let mut stack = vec!(Element::default());
+
+    while !reader.rest().is_empty() {
+        let rest = reader.rest();
+
+        if rest.starts_with("<!--") {
+            reader.advance(4);
+            reader.until("-->")?;
+        } else if rest.starts_with("<![CDATA[") {
+            reader.advance(9);
+            let text = reader.until("]]>")?;
+            stack.last_mut().unwrap().text.push_str(text);
+        } else if rest.starts_with("<?") {
+            reader.until("?>")?;
+        } else if rest.starts_with("<!") {
+            reader.until(">")?;
+        } else if rest.starts_with("</") {
+            reader.advance(2);
+            let name = reader.name()?;
+            reader.skip_whitespace();
+            if !reader.rest().starts_with('>') {
+                return Err(reader.error("expected `>`".to_string()));
+            }
+            reader.advance(1);
+
+            if stack.len() == 1 || stack.last().unwrap().name != name {
+                return Err(reader.error(format!("unexpected end tag `</{}>`", name)));
+            }
+            let element = stack.pop().unwrap();
+            stack.last_mut().unwrap().children.push(element);
+        } else if rest.starts_with('<') {
+            reader.advance(1);
+            let mut element = Element { name: reader.name()?.to_string(), line: reader.line, ..Element::default() };
+
+            loop {
+                reader.skip_whitespace();
+                if reader.rest().starts_with("/>") {
+                    reader.advance(2);
+                    stack.last_mut().unwrap().children.push(element);
+                    break;
+                } else if reader.rest().starts_with('>') {
+                    reader.advance(1);
+                    stack.push(element);
+                    break;
+                }
+
+                let name = reader.name()?.to_string();
+                reader.skip_whitespace();
+                if !reader.rest().starts_with('=') {
+                    return Err(reader.error(format!("expected `=` after attribute `{}`", name)));
+                }
+                reader.advance(1);
+                reader.skip_whitespace();
+
+                let quote = match reader.rest().chars().next() {
+                    Some(quote @ ('"' | '\'')) => quote,
+                    _ => return Err(reader.error(format!("expected a quoted value for attribute `{}`", name)))
+                };
+                reader.advance(1);
+                let line = reader.line;
+                let value = reader.until(&quote.to_string())?;
+                element.attributes.push((name, decode(value, line)?));
+            }
+        } else {
+            let line = reader.line;
+            let text = reader.advance(rest.find('<').unwrap_or(rest.len()));
+            stack.last_mut().unwrap().text.push_str(&decode(text, line)?);
+        }
+    }
+
+    if stack.len() > 1 {
+        let element = stack.last().unwrap();
+        return Err(Diagnostic { line: element.line, message: format!("`<{}>` is not closed", element.name) });
+    }
+
+    stack.pop().unwrap().children.into_iter().next().ok_or_else(|| reader.error("expected a root element".to_string()))
+}
+
+/// Parses a `scaledNonNegativeInteger`, f. e. `42`, `0x2A`, `#101010` or `4k`.
+fn parse_integer(text: &str) -> Option<u128> {
+    let text = text.trim();
+    let (text, scale) = match text.strip_suffix(['k', 'K']) {
+        Some(text) => (text, 1 << 10),
+        None => match text.strip_suffix(['m', 'M']) {
+            Some(text) => (text, 1 << 20),
+            None => match text.strip_suffix(['g', 'G']) {
+                Some(text) => (text, 1 << 30),
+                None => (text, 1)
+            }
+        }
+    };
+
+    let value = if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        u128::from_str_radix(hex, 16).ok()
+    } else if let Some(binary) = text.strip_prefix("0b").or_else(|| text.strip_prefix("0B")).or_else(|| text.strip_prefix('#')) {
+        u128::from_str_radix(binary, 2).ok()
+    } else {
+        text.parse().ok()
+    };
+
+    value?.checked_mul(scale)
+}
+
+/// Returns the indices of a `dim` element, either from `dimIndex` or counting from `0`.
+fn dim_indices(element: &Element, dim: u128) -> Result<Vec<String>, String> {
+    let indices: Vec<String> = match element.text("dimIndex") {
+        None => (0..dim).map(|index| index.to_string()).collect(),
+        Some(text) => match text.split_once('-') {
+            Some((start, end)) if !text.contains(',') => match (start.parse::<u128>(), end.parse::<u128>()) {
+                (Ok(start), Ok(end)) => (start..=end).map(|index| index.to_string()).collect(),
+                _ => match (start.as_bytes(), end.as_bytes()) {
+                    ([start], [end]) if start.is_ascii_uppercase() && end.is_ascii_uppercase() => {
+                        (*start..=*end).map(|index| (index as char).to_string()).collect()
+                    },
+                    _ => return Err(format!("`dimIndex` `{}` is not a valid range", text))
+                }
+            },
+            _ => text.split(',').map(|index| index.trim().to_string()).collect()
+        }
+    };
+
+    if indices.len() as u128 != dim {
+        return Err(format!("`dimIndex` has {} entries, but `dim` is {}", indices.len(), dim));
+    }
+
+    Ok(indices)
+}
+
+/// The access of a register or field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Access {
+    ReadOnly,
+    WriteOnly,
+    ReadWrite,
+    WriteOnce,
+    ReadWriteOnce
+}
+
+impl Access {
+    fn parse(text: &str) -> Option<Self> {
+        match text {
+            "read-only" => Some(Self::ReadOnly),
+            "write-only" => Some(Self::WriteOnly),
+            "read-write" => Some(Self::ReadWrite),
+            "writeOnce" => Some(Self::WriteOnce),
+            "read-writeOnce" => Some(Self::ReadWriteOnce),
+            _ => None
+        }
+    }
+
+    /// Returns the access mode of the `#[field]` attribute, `None` for `rw`.
+    fn mode(self) -> Option<&'static str> {
+        match self {
+            Self::ReadOnly => Some("ro"),
+            Self::WriteOnly | Self::WriteOnce => Some("wo"),
+            Self::ReadWrite | Self::ReadWriteOnce => None
+        }
+    }
+}
+
+/// The register properties, which are inherited from the device, peripherals and clusters.
+#[derive(Clone, Copy, Debug, Default)]
+struct Properties {
+    size: Option<u128>,
+    access: Option<Access>,
+    reset_value: Option<u128>,
+    reset_mask: Option<u128>
+}
+
+/// A field of a register, after resolving its position and type.
+struct Field {
+    name: String,
+    description: Option<String>,
+    bit: u128,
+    size: u128,
+    /// The Rust type, `None` for `bool` and unsigned integers.
+    ty: Option<String>,
+    complete: bool,
+    access: Option<Access>,
+    /// `true` for write-1-to-clear fields, which become flags.
+    one_to_clear: bool
+}
+
+/// Stores the state while importing a peripheral, which becomes a module.
+#[derive(Default)]
+struct Peripheral {
+    /// The Rust names of all generated types.
+    names: Vec<String>,
+    /// The generated enumerations by the name of their `enumeratedValues`, with the field size.
+    enums: HashMap<String, (String, u128)>,
+    items: Vec<String>
+}
+
+impl Peripheral {
+    /// Registers a generated type name, or returns an error if it is already taken.
+    fn register(&mut self, name: &str) -> Result<(), String> {
+        if self.names.iter().any(|other| other == name) {
+            return Err(format!("`{}` is already defined", name));
+        }
+
+        self.names.push(name.to_string());
+        Ok(())
+    }
+}
+
+/// Stores the state while importing an SVD file.
+#[derive(Default)]
+struct Importer {
+    /// The module names of all imported peripherals, by their SVD name.
+    peripherals: HashMap<String, String>,
+    modules: Vec<String>,
+    items: Vec<String>,
+    diagnostics: Vec<Diagnostic>
+}
+
+impl Importer {
+    fn diagnostic(&mut self, line: usize, message: String) {
+        self.diagnostics.push(Diagnostic { line, message });
+    }
+
+    /// Overrides the inherited properties with the ones of `element`.
+    fn properties(&mut self, element: &Element, inherited: Properties) -> Properties {
+        let mut properties = inherited;
+
+        for (name, target) in [("size", &mut properties.size), ("resetValue", &mut properties.reset_value), ("resetMask", &mut properties.reset_mask)] {
+            if let Some(child) = element.child(name) {
+                match parse_integer(&child.text) {
+                    Some(value) => *target = Some(value),
+                    None => self.diagnostic(child.line, format!("`{}` is not an integer", name))
+                }
+            }
+        }
+
+        if let Some(child) = element.child("access") {
+            match Access::parse(child.text.trim()) {
+                Some(access) => properties.access = Some(access),
+                None => self.diagnostic(child.line, format!("unknown access `{}`", child.text.trim()))
+            }
+        }
+
+        properties
+    }
+
+    /// Imports a peripheral as a module, or re-exports the module of the peripheral it is derived from.
+    fn peripheral(&mut self, element: &Element, device: Properties) {
+        let Some(name) = element.text("name") else {
+            return self.diagnostic(element.line, "peripheral has no `name`".to_string());
+        };
+
+        let Some(base_address) = element.text("baseAddress").and_then(parse_integer) else {
+            return self.diagnostic(element.line, format!("peripheral `{}` is not imported: expected a `baseAddress`", name));
+        };
+
+        let module = rust_ident(&name.to_lowercase());
+        if self.modules.contains(&module) {
+            return self.diagnostic(element.line, format!("peripheral `{}` is not imported: `{}` is already defined", name, module));
+        }
+
+        let mut doc = format!("/// Imported from the `{}` peripheral (line {}).\n", name, element.line);
+        if let Some(description) = element.description("description") {
+            doc += &format!("///\n/// {}\n", description);
+        }
+
+        let mut body = vec!();
+
+        if let Some(derived) = element.attribute("derivedFrom") {
+            let Some(derived) = self.peripherals.get(derived).cloned() else {
+                return self.diagnostic(element.line, format!(
+                    "peripheral `{}` is not imported: `{}` is not defined before", name, derived
+                ));
+            };
+
+            if element.child("registers").is_some() {
+                self.diagnostic(element.line, format!(
+                    "the registers of peripheral `{}` are ignored, it uses the registers of `{}`", name, derived
+                ));
+            }
+
+            body.push(format!("pub use super::{}::*;\n", derived));
+        } else {
+            let properties = self.properties(element, device);
+            let mut peripheral = Peripheral::default();
+
+            if let Some(registers) = element.child("registers") {
+                self.registers(&mut peripheral, name, registers, properties, "", 0);
+            }
+
+            body.append(&mut peripheral.items);
+        }
+
+        body.insert(0, format!(
+            "/// The base address of the peripheral.\npub const BASE_ADDRESS: usize = {:#x};\n", base_address
+        ));
+
+        let body = body.join("\n").lines()
+            .map(|line| if line.is_empty() { String::new() } else { format!("    {}", line) })
+            .collect::<Vec<_>>().join("\n");
+
+        self.peripherals.insert(name.to_string(), module.clone());
+        self.modules.push(module.clone());
+        self.items.push(format!("{}pub mod {} {{\n{}\n}}\n", doc, module, body));
+    }
+
+    /// Imports all registers of a `registers` or `cluster` element. Registers in clusters are
+    /// prefixed with the cluster name.
+    fn registers(
+        &mut self, peripheral: &mut Peripheral, path: &str, element: &Element, properties: Properties,
+        prefix: &str, offset: u128
+    ) {
+        for child in &element.children {
+            match child.name.as_str() {
+                "register" => self.register(peripheral, path, child, properties, prefix, offset),
+                "cluster" => {
+                    let Some(name) = child.text("name") else {
+                        self.diagnostic(child.line, "cluster has no `name`".to_string());
+                        continue;
+                    };
+
+                    if child.child("dim").is_some() || child.attribute("derivedFrom").is_some() {
+                        self.diagnostic(child.line, format!(
+                            "cluster `{}.{}` is not imported: arrays and derived clusters are not supported", path, name
+                        ));
+                        continue;
+                    }
+
+                    let Some(address_offset) = child.text("addressOffset").and_then(parse_integer) else {
+                        self.diagnostic(child.line, format!("cluster `{}.{}` is not imported: expected an `addressOffset`", path, name));
+                        continue;
+                    };
+
+                    let properties = self.properties(child, properties);
+                    let path = format!("{}.{}", path, name);
+                    let prefix = format!("{}{}_", prefix, name);
+                    self.registers(peripheral, &path, child, properties, &prefix, offset + address_offset);
+                },
+                _ => ()
+            }
+        }
+    }
+
+    /// Resolves the position of a field from `bitOffset` and `bitWidth`, `lsb` and `msb` or
+    /// `bitRange`.
+    fn position(element: &Element) -> Result<(u128, u128), String> {
+        let integer = |name: &str| element.text(name).map(|text| parse_integer(text).ok_or(format!("`{}` is not an integer", name)));
+
+        if let Some(bit) = integer("bitOffset") {
+            return Ok((bit?, integer("bitWidth").unwrap_or(Ok(1))?));
+        }
+
+        if let (Some(lsb), Some(msb)) = (integer("lsb"), integer("msb")) {
+            let (lsb, msb) = (lsb?, msb?);
+            return if msb >= lsb { Ok((lsb, msb - lsb + 1)) } else { Err("`msb` is smaller than `lsb`".to_string()) };
+        }
+
+        if let Some(range) = element.text("bitRange") {
+            let error = || format!("`bitRange` `{}` is not of the form `[msb:lsb]`", range);
+            let (msb, lsb) = range.strip_prefix('[').and_then(|range| range.strip_suffix(']'))
+                .and_then(|range| range.split_once(':'))
+                .ok_or_else(error)?;
+            let (msb, lsb) = parse_integer(msb).zip(parse_integer(lsb)).filter(|(msb, lsb)| msb >= lsb).ok_or_else(error)?;
+            return Ok((lsb, msb - lsb + 1));
+        }
+
+        Err("expected `bitOffset`, `lsb` and `msb` or `bitRange`".to_string())
+    }
+
+    /// Generates a `bitfield::Field` enumeration for the `enumeratedValues` of a field, or reuses
+    /// the enumeration it is derived from. Returns the type and whether it is complete.
+    fn enumeration(
+        &mut self, peripheral: &mut Peripheral, path: &str, field: &Element, ty: &str, size: u128
+    ) -> Option<(String, bool)> {
+        let all = field.children("enumeratedValues").collect::<Vec<_>>();
+
+        // Only one set of values can be the type of a field, the values which are read are preferred.
+        let element = *all.iter().find(|values| values.text("usage") != Some("write")).or(all.first())?;
+        if all.len() > 1 {
+            self.diagnostic(element.line, format!(
+                "`{}` has {} sets of `enumeratedValues`, only the first one which is read is imported", path, all.len()
+            ));
+        }
+
+        if let Some(derived) = element.attribute("derivedFrom") {
+            let name = derived.rsplit('.').next().unwrap();
+            return match peripheral.enums.get(name) {
+                Some((ty, other)) if *other == size => Some((ty.clone(), false)),
+                Some((ty, other)) => {
+                    self.diagnostic(element.line, format!(
+                        "`{}` has {} bits, but `{}` of `{}` is used for {} bits", path, size, ty, derived, other
+                    ));
+                    None
+                },
+                None => {
+                    self.diagnostic(element.line, format!("`enumeratedValues` `{}` of `{}` is not defined before", derived, path));
+                    None
+                }
+            };
+        }
+
+        let mut variants = vec!();
+        let mut names = vec!();
+        let mut values = vec!();
+
+        for value in element.children("enumeratedValue") {
+            let Some(name) = value.text("name") else {
+                self.diagnostic(value.line, format!("`enumeratedValue` of `{}` has no `name`", path));
+                continue;
+            };
+
+            // Default values cover all remaining bit patterns, which the `Err` of the getter covers.
+            if value.text("isDefault") == Some("true") {
+                continue;
+            }
+
+            let Some(number) = value.text("value").and_then(parse_integer) else {
+                self.diagnostic(value.line, format!(
+                    "`{}` of `{}` is not imported: expected an integer `value` without do-not-care bits", name, path
+                ));
+                continue;
+            };
+
+            if number >> size != 0 {
+                self.diagnostic(value.line, format!("`{}` of `{}` is not imported: {} does not fit into {} bits", name, path, number, size));
+                continue;
+            }
+
+            // Variant names must not start with a digit.
+            let variant = camel_case(name, true);
+            let variant = if variant.starts_with(|c: char| c.is_ascii_digit()) {
+                camel_case(&format!("{}_{}", field.text("name").unwrap_or("value"), name), true)
+            } else {
+                variant
+            };
+
+            if names.contains(&variant) || values.contains(&number) {
+                self.diagnostic(value.line, format!("`{}` of `{}` is not imported: its name or value is used twice", name, path));
+                continue;
+            }
+
+            let doc = value.description("description").map(|description| format!("    /// {}\n", description)).unwrap_or_default();
+            variants.push(format!("{}    {} = {}", doc, variant, number));
+            names.push(variant);
+            values.push(number);
+        }
+
+        if variants.is_empty() {
+            self.diagnostic(element.line, format!("the `enumeratedValues` of `{}` are not imported: no values", path));
+            return None;
+        }
+
+        let item = format!(
+            "/// Imported from the `enumeratedValues` of `{}` (line {}).\n\
+            #[derive(Clone, Copy, Debug, Eq, PartialEq, bitfield::Field)]\n#[repr(u{})]\npub enum {} {{\n{}\n}}\n",
+            path, element.line, primitive_bits(size).unwrap().max(8), ty, variants.join(",\n")
+        );
+
+        let result = validate(|_, item| crate::field::Field::parse(item), "", &item).and_then(|_| peripheral.register(ty));
+        if let Err(error) = result {
+            self.diagnostic(element.line, format!("the `enumeratedValues` of `{}` are not imported: {}", path, error));
+            return None;
+        }
+
+        if let Some(name) = element.text("name") {
+            peripheral.enums.insert(name.to_string(), (ty.to_string(), size));
+        }
+        peripheral.items.push(item);
+
+        Some((ty.to_string(), values.len() as u128 == 1 << size))
+    }
+
+    /// Resolves all fields of a register, `%s` in the names of field arrays is replaced by the
+    /// index.
+    fn fields(
+        &mut self, peripheral: &mut Peripheral, path: &str, name: &str, element: &Element, properties: Properties
+    ) -> Vec<Field> {
+        let mut fields = vec!();
+
+        for field in element.child("fields").into_iter().flat_map(|fields| fields.children("field")) {
+            let Some(field_name) = field.text("name") else {
+                self.diagnostic(field.line, format!("field of `{}` has no `name`", path));
+                continue;
+            };
+            let field_path = format!("{}.{}", path, field_name);
+
+            if field.attribute("derivedFrom").is_some() {
+                self.diagnostic(field.line, format!("`{}` is not imported: derived fields are not supported", field_path));
+                continue;
+            }
+
+            let (bit, size) = match Self::position(field) {
+                Ok(position) => position,
+                Err(error) => {
+                    self.diagnostic(field.line, format!("`{}` is not imported: {}", field_path, error));
+                    continue;
+                }
+            };
+
+            let instances = match field.text("dim").map(|dim| parse_integer(dim).ok_or("`dim` is not an integer".to_string())) {
+                None => vec!((field_name.to_string(), bit)),
+                Some(dim) => {
+                    let increment = field.text("dimIncrement").and_then(parse_integer).unwrap_or(size);
+                    match dim.and_then(|dim| dim_indices(field, dim)) {
+                        Ok(indices) => indices.iter().enumerate().map(
+                            |(i, index)| (field_name.replace("[%s]", index).replace("%s", index), bit + i as u128 * increment)
+                        ).collect(),
+                        Err(error) => {
+                            self.diagnostic(field.line, format!("`{}` is not imported: {}", field_path, error));
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            let access = match field.child("access") {
+                Some(child) => match Access::parse(child.text.trim()) {
+                    Some(access) => Some(access),
+                    None => {
+                        self.diagnostic(child.line, format!("unknown access `{}`", child.text.trim()));
+                        properties.access
+                    }
+                },
+                None => properties.access
+            };
+
+            let mut one_to_clear = field.text("modifiedWriteValues") == Some("oneToClear");
+            if one_to_clear && (size != 1 || access.map(|access| access.mode() == Some("wo")).unwrap_or_default()) {
+                self.diagnostic(field.line, format!(
+                    "`{}` is imported without write-1-to-clear access, which is only supported for readable 1 bit fields",
+                    field_path
+                ));
+                one_to_clear = false;
+            }
+
+            // Field arrays share one enumeration.
+            let ty = camel_case(&format!("{}_{}", name, field_name.replace("[%s]", "").replace("%s", "")), true);
+            let enumeration = if one_to_clear {
+                None
+            } else {
+                self.enumeration(peripheral, &field_path, field, &ty, size)
+            };
+
+            let description = field.description("description");
+            for (name, bit) in instances {
+                fields.push(Field {
+                    name, description: description.clone(), bit, size,
+                    ty: enumeration.as_ref().map(|(ty, _)| ty.clone()),
+                    complete: enumeration.as_ref().map(|(_, complete)| *complete).unwrap_or_default(),
+                    access, one_to_clear
+                });
+            }
+        }
+
+        fields
+    }
+
+    /// Imports a register as a `#[bitfield::bitfield]` struct with a volatile register type.
+    fn register(
+        &mut self, peripheral: &mut Peripheral, path: &str, element: &Element, properties: Properties,
+        prefix: &str, offset: u128
+    ) {
+        let Some(name) = element.text("name") else {
+            return self.diagnostic(element.line, format!("register of `{}` has no `name`", path));
+        };
+        let path = format!("{}.{}", path, name);
+
+        if element.attribute("derivedFrom").is_some() {
+            return self.diagnostic(element.line, format!("`{}` is not imported: derived registers are not supported", path));
+        }
+
+        let Some(address_offset) = element.text("addressOffset").and_then(parse_integer) else {
+            return self.diagnostic(element.line, format!("`{}` is not imported: expected an `addressOffset`", path));
+        };
+
+        let properties = self.properties(element, properties);
+        let bits = properties.size.unwrap_or(32);
+        if ![8, 16, 32, 64].contains(&bits) {
+            return self.diagnostic(element.line, format!("`{}` is not imported: registers with {} bits are not supported", path, bits));
+        }
+
+        // Register arrays share one type.
+        let name = format!("{}{}", prefix, name.replace("[%s]", "").replace("%s", ""));
+        let dim = match element.text("dim").map(|dim| parse_integer(dim).ok_or("`dim` is not an integer".to_string())) {
+            None => None,
+            Some(dim) => match dim.and_then(|dim| dim_indices(element, dim).map(|_| dim)) {
+                Ok(dim) => Some((dim, element.text("dimIncrement").and_then(parse_integer).unwrap_or(bits / 8))),
+                Err(error) => return self.diagnostic(element.line, format!("`{}` is not imported: {}", path, error))
+            }
+        };
+
+        let ty = camel_case(&name, true);
+        let mut fields = self.fields(peripheral, &path, &name, element, properties);
+
+        // A field which covers the whole register is the register value itself.
+        fields.retain(|field| {
+            let is_whole = field.size == bits;
+            if is_whole {
+                self.diagnostic(element.line, format!(
+                    "`{}.{}` covers the whole register, it is accessed with `u{}::from`", path, field.name, bits
+                ));
+            }
+            !is_whole
+        });
+
+        let mut entries = vec!();
+        let mut flags = vec!();
+
+        for field in &fields {
+            if field.bit + field.size > bits {
+                self.diagnostic(element.line, format!("`{}.{}` is not imported: it exceeds {} bits", path, field.name, bits));
+                continue;
+            }
+
+            let doc = field.description.as_ref().map(|description| format!("    /// {}\n", description)).unwrap_or_default();
+
+            if field.one_to_clear {
+                flags.push(format!("{}    {} = {}", doc, camel_case(&field.name, true), field.bit));
+                continue;
+            }
+
+            let mut options = if field.size == 1 && field.ty.is_none() {
+                vec!(format!("bit = {}", field.bit))
+            } else {
+                vec!(format!("{}, {}", field.bit, field.size))
+            };
+            if field.complete {
+                options.push("complete".to_string());
+            }
+            if let Some(mode) = field.access.and_then(Access::mode) {
+                options.push(format!("access = {}", mode));
+            }
+
+            let field_ty = match &field.ty {
+                Some(ty) => ty.clone(),
+                None if field.size == 1 => "bool".to_string(),
+                None => format!("u{}", primitive_bits(field.size).unwrap().max(8))
+            };
+
+            entries.push(format!(
+                "{}    #[field({})] pub {}: {}", doc, options.join(", "), rust_ident(&field.name.to_lowercase()), field_ty
+            ));
+        }
+
+        let flag = format!("{}Flag", ty);
+        let flags = if flags.is_empty() {
+            None
+        } else {
+            entries.push("    #[field(access = w1c)] pub flags: ".to_string() + &flag);
+            Some(format!(
+                "/// The write-1-to-clear fields of `{}`.\n#[derive(Clone, Copy, Debug, bitfield::Flags)]\n#[repr(u8)]\npub enum {} {{\n{}\n}}\n",
+                path, flag, flags.join(",\n")
+            ))
+        };
+
+        let mut doc = format!("/// Imported from `{}` (line {}).\n", path, element.line);
+        if let Some(description) = element.description("description") {
+            doc += &format!("///\n/// {}\n", description);
+        }
+
+        let attribute = format!("{}, register", bits);
+        let item = if entries.is_empty() {
+            format!("pub struct {} {{}}\n", ty)
+        } else {
+            format!("pub struct {} {{\n{}\n}}\n", ty, entries.join(",\n"))
+        };
+        let item = format!("{}#[bitfield::bitfield({})]\n#[derive(Debug)]\n{}", doc, attribute, item);
+
+        let mut constants = match dim {
+            None => vec!(format!(
+                "    /// The offset of the register from `BASE_ADDRESS`.\n    pub const OFFSET: usize = {:#x};",
+                offset + address_offset
+            )),
+            Some((dim, increment)) => vec!(
+                format!("    /// The offset of the first register from `BASE_ADDRESS`.\n    pub const OFFSET: usize = {:#x};", offset + address_offset),
+                format!("    /// The number of registers in the array.\n    pub const COUNT: usize = {};", dim),
+                format!("    /// The distance between two registers in the array, in bytes.\n    pub const STRIDE: usize = {:#x};", increment)
+            )
+        };
+
+        if let Some(reset_value) = properties.reset_value {
+            let mask = properties.reset_mask.unwrap_or(u128::MAX) & (u128::MAX >> (128 - bits));
+            constants.push(format!(
+                "    /// The value of the register after a reset.\n    pub const RESET: Self = Self({:#x});", reset_value & mask
+            ));
+        }
+
+        let result = validate(crate::bitfield::BitField::parse, &attribute, &item)
+            .and_then(|_| flags.as_ref().map_or(Ok(()), |flags| validate(|_, item| crate::flags::Flags::parse(item), "", flags)))
+            .and_then(|_| peripheral.register(&ty))
+            .and_then(|_| peripheral.register(&format!("{}Register", ty)))
+            .and_then(|_| if flags.is_some() { peripheral.register(&flag) } else { Ok(()) });
+
+        match result {
+            Ok(()) => {
+                // The value is private to the module, but can be converted from and into the primitive type.
+                let conversions = format!(
+                    "impl From<u{bits}> for {ty} {{\n    fn from(value: u{bits}) -> Self {{\n        Self(value)\n    }}\n}}\n\n\
+                    impl From<{ty}> for u{bits} {{\n    fn from(value: {ty}) -> Self {{\n        value.0\n    }}\n}}\n",
+                    bits = bits, ty = ty
+                );
+                peripheral.items.push(format!("{}\nimpl {} {{\n{}\n}}\n\n{}", item, ty, constants.join("\n"), conversions));
+                peripheral.items.extend(flags);
+            },
+            Err(error) => self.diagnostic(element.line, format!("`{}` is not imported: {}", path, error))
+        }
+    }
+}
+
+/// Generates bit fields from a CMSIS-SVD file, f. e. in a `build.rs` script. Every peripheral
+/// becomes a module with a `BASE_ADDRESS` constant, and every register of it becomes a
+/// `#[bitfield::bitfield(N, register)]` struct:
+///
+/// - The register has an `OFFSET` constant and a `RESET` constant with the reset value. Registers
+///   in clusters are prefixed with the cluster name, register arrays become one type with
+///   additional `COUNT` and `STRIDE` constants.
+/// - Fields with `enumeratedValues` become `#[derive(bitfield::Field)]` enumerations, which are
+///   `complete` if they have a value for every bit combination. Other fields become `bool` or
+///   unsigned integers.
+/// - The access of a field becomes its access mode. Readable 1 bit fields with the `oneToClear`
+///   modification become `w1c` flags of one `#[derive(bitfield::Flags)]` enumeration per register.
+/// - Derived peripherals re-export the module of the peripheral they are derived from.
+///
+/// A diagnostic is returned for every construct which can not be imported, f. e. derived registers,
+/// arrays of clusters or values with do-not-care bits, and for fields which cover a whole register.
+///
+/// Example:
+///
+/// ```ignore
+/// let svd = std::fs::read_to_string("STM32F411.svd").unwrap();
+/// let import = bitfield_impl::svd::from_svd(&svd);
+/// for diagnostic in &import.diagnostics {
+///     println!("cargo:warning=STM32F411.svd: {}", diagnostic);
+/// }
+/// std::fs::write(std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("device.rs"), import.source).unwrap();
+/// ```
+pub fn from_svd(svd: &str) -> Import {
+    let mut importer = Importer::default();
+
+    match parse_xml(svd) {
+        Ok(device) if device.name == "device" => {
+            let properties = importer.properties(&device, Properties::default());
+            for peripheral in device.child("peripherals").iter().flat_map(|peripherals| peripherals.children("peripheral")) {
+                importer.peripheral(peripheral, properties);
+            }
+        },
+        Ok(element) => importer.diagnostic(element.line, format!("expected `<device>`, found `<{}>`", element.name)),
+        Err(diagnostic) => importer.diagnostics.push(diagnostic)
+    }
+
+    let mut source = "// Generated by the `bitfield` crate.\n".to_string();
+    for item in &importer.items {
+        source += "\n";
+        source += item;
+    }
+
+    Import { source, diagnostics: importer.diagnostics }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Returns the diagnostics as strings.
+    fn diagnostics(import: &super::Import) -> Vec<String> {
+        import.diagnostics.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn parse_xml() {
+        let device = super::parse_xml("\
+<?xml version=\"1.0\"?>
+<!-- <ignored/> -->
+<device a='1 &amp; 2'>
+  <name>A&lt;B&#x3E;&#67;</name><empty b = \"\"/>
+  <text><![CDATA[<raw>]]></text>
+</device>
+").unwrap();
+
+        assert_eq!(device.name, "device");
+        assert_eq!(device.line, 3);
+        assert_eq!(device.attribute("a"), Some("1 & 2"));
+        assert_eq!(device.text("name"), Some("A<B>C"));
+        assert_eq!(device.child("empty").map(|empty| (empty.line, empty.attribute("b"))), Some((4, Some(""))));
+        assert_eq!(device.text("text"), Some("<raw>"));
+        assert!(device.child("missing").is_none());
+
+        for (xml, line, message) in [
+            ("<a>\n</b>", 2, "unexpected end tag `</b>`"),
+            ("<a>\n<b>\n</b>", 1, "`<a>` is not closed"),
+            ("<a b=c/>", 1, "expected a quoted value for attribute `b`"),
+            ("<a>&unknown;</a>", 1, "unknown entity `&unknown;`"),
+            ("<!-- a", 1, "expected `-->`"),
+            ("", 1, "expected a root element")
+        ] {
+            let error = super::parse_xml(xml).unwrap_err();
+            assert_eq!((error.line, error.message.as_str()), (line, message), "{}", xml);
+        }
+    }
+
+    #[test]
+    fn integers() {
+        for (text, result) in [
+            ("42", Some(42)), ("0x2A", Some(42)), ("0X2a", Some(42)), ("#101010", Some(42)), ("0b101010", Some(42)),
+            ("4k", Some(4096)), ("1M", Some(1 << 20)), (" 7 ", Some(7)), ("#1x", None), ("-1", None), ("", None)
+        ] {
+            assert_eq!(super::parse_integer(text), result, "{}", text);
+        }
+
+        let indices = |xml: &str, dim| super::dim_indices(&super::parse_xml(xml).unwrap(), dim);
+        assert_eq!(indices("<a/>", 2), Ok(vec!("0".to_string(), "1".to_string())));
+        assert_eq!(indices("<a><dimIndex>3-4</dimIndex></a>", 2), Ok(vec!("3".to_string(), "4".to_string())));
+        assert_eq!(indices("<a><dimIndex>A-B</dimIndex></a>", 2), Ok(vec!("A".to_string(), "B".to_string())));
+        assert_eq!(indices("<a><dimIndex>x, y</dimIndex></a>", 2), Ok(vec!("x".to_string(), "y".to_string())));
+        assert_eq!(indices("<a><dimIndex>A-2</dimIndex></a>", 2), Err("`dimIndex` `A-2` is not a valid range".to_string()));
+        assert_eq!(indices("<a><dimIndex>0-2</dimIndex></a>", 2), Err("`dimIndex` has 3 entries, but `dim` is 2".to_string()));
+    }
+
+    #[test]
+    fn registers() {
+        let import = super::from_svd("\
+<device>
+  <name>SAMPLE</name>
+  <size>32</size>
+  <resetValue>0</resetValue>
+  <peripherals>
+    <peripheral>
+      <name>UART0</name>
+      <description>Universal
+        asynchronous receiver &amp; transmitter</description>
+      <baseAddress>0x40001000</baseAddress>
+      <registers>
+        <register>
+          <name>DATA</name>
+          <addressOffset>0x0</addressOffset>
+          <size>16</size>
+          <fields><field><name>DATA</name><bitRange>[15:0]</bitRange></field></fields>
+        </register>
+        <register>
+          <name>CTRL</name>
+          <description>Control register</description>
+          <addressOffset>0x4</addressOffset>
+          <resetValue>0x30</resetValue>
+          <fields>
+            <field><name>EN</name><description>Enable</description><bitOffset>0</bitOffset><bitWidth>1</bitWidth></field>
+            <field><name>RESET</name><bitOffset>1</bitOffset><bitWidth>1</bitWidth><access>write-only</access></field>
+            <field><name>DIV</name><lsb>4</lsb><msb>13</msb></field>
+          </fields>
+        </register>
+        <cluster>
+          <name>CH</name>
+          <addressOffset>0x100</addressOffset>
+          <register>
+            <dim>4</dim>
+            <dimIncrement>8</dimIncrement>
+            <name>CFG[%s]</name>
+            <addressOffset>0x4</addressOffset>
+            <fields><field><name>type</name><bitOffset>0</bitOffset><bitWidth>1</bitWidth></field></fields>
+          </register>
+        </cluster>
+      </registers>
+    </peripheral>
+    <peripheral derivedFrom=\"UART0\">
+      <name>UART1</name>
+      <baseAddress>0x40002000</baseAddress>
+    </peripheral>
+  </peripherals>
+</device>
+");
+
+        assert_eq!(import.source, "\
+// Generated by the `bitfield` crate.
+
+/// Imported from the `UART0` peripheral (line 6).
+///
+/// Universal asynchronous receiver & transmitter
+pub mod uart0 {
+    /// The base address of the peripheral.
+    pub const BASE_ADDRESS: usize = 0x40001000;
+
+    /// Imported from `UART0.DATA` (line 12).
+    #[bitfield::bitfield(16, register)]
+    #[derive(Debug)]
+    pub struct Data {}
+
+    impl Data {
+        /// The offset of the register from `BASE_ADDRESS`.
+        pub const OFFSET: usize = 0x0;
+        /// The value of the register after a reset.
+        pub const RESET: Self = Self(0x0);
+    }
+
+    impl From<u16> for Data {
+        fn from(value: u16) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<Data> for u16 {
+        fn from(value: Data) -> Self {
+            value.0
+        }
+    }
+
+    /// Imported from `UART0.CTRL` (line 18).
+    ///
+    /// Control register
+    #[bitfield::bitfield(32, register)]
+    #[derive(Debug)]
+    pub struct Ctrl {
+        /// Enable
+        #[field(bit = 0)] pub en: bool,
+        #[field(bit = 1, access = wo)] pub reset: bool,
+        #[field(4, 10)] pub div: u16
+    }
+
+    impl Ctrl {
+        /// The offset of the register from `BASE_ADDRESS`.
+        pub const OFFSET: usize = 0x4;
+        /// The value of the register after a reset.
+        pub const RESET: Self = Self(0x30);
+    }
+
+    impl From<u32> for Ctrl {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<Ctrl> for u32 {
+        fn from(value: Ctrl) -> Self {
+            value.0
+        }
+    }
+
+    /// Imported from `UART0.CH.CFG[%s]` (line 32).
+    #[bitfield::bitfield(32, register)]
+    #[derive(Debug)]
+    pub struct ChCfg {
+        #[field(bit = 0)] pub r#type: bool
+    }
+
+    impl ChCfg {
+        /// The offset of the first register from `BASE_ADDRESS`.
+        pub const OFFSET: usize = 0x104;
+        /// The number of registers in the array.
+        pub const COUNT: usize = 4;
+        /// The distance between two registers in the array, in bytes.
+        pub const STRIDE: usize = 0x8;
+        /// The value of the register after a reset.
+        pub const RESET: Self = Self(0x0);
+    }
+
+    impl From<u32> for ChCfg {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<ChCfg> for u32 {
+        fn from(value: ChCfg) -> Self {
+            value.0
+        }
+    }
+}
+
+/// Imported from the `UART1` peripheral (line 42).
+pub mod uart1 {
+    /// The base address of the peripheral.
+    pub const BASE_ADDRESS: usize = 0x40002000;
+
+    pub use super::uart0::*;
+}
+");
+        assert_eq!(diagnostics(&import), [
+            "line 12: `UART0.DATA.DATA` covers the whole register, it is accessed with `u16::from`"
+        ]);
+    }
+
+    #[test]
+    fn fields() {
+        let import = super::from_svd("\
+<device>
+  <peripherals>
+    <peripheral>
+      <name>GPIO</name>
+      <baseAddress>0</baseAddress>
+      <registers>
+        <register>
+          <name>MODE</name>
+          <addressOffset>0</addressOffset>
+          <size>8</size>
+          <fields>
+            <field>
+              <dim>2</dim>
+              <name>PIN%s</name>
+              <bitOffset>0</bitOffset>
+              <bitWidth>2</bitWidth>
+              <enumeratedValues>
+                <name>PinMode</name>
+                <enumeratedValue><name>INPUT</name><description>Input mode</description><value>0</value></enumeratedValue>
+                <enumeratedValue><name>OUTPUT</name><value>0b01</value></enumeratedValue>
+                <enumeratedValue><name>ALTERNATE</name><value>#10</value></enumeratedValue>
+                <enumeratedValue><name>ANALOG</name><value>3</value></enumeratedValue>
+              </enumeratedValues>
+            </field>
+            <field>
+              <name>SPEED</name>
+              <bitOffset>4</bitOffset>
+              <bitWidth>2</bitWidth>
+              <access>read-only</access>
+              <enumeratedValues>
+                <enumeratedValue><name>1MHZ</name><value>1</value></enumeratedValue>
+                <enumeratedValue><name>ANY</name><value>#1x</value></enumeratedValue>
+                <enumeratedValue><name>OTHER</name><isDefault>true</isDefault></enumeratedValue>
+              </enumeratedValues>
+            </field>
+            <field>
+              <name>ALT</name>
+              <bitOffset>6</bitOffset>
+              <bitWidth>2</bitWidth>
+              <enumeratedValues derivedFrom=\"GPIO.MODE.PIN%s.PinMode\"/>
+            </field>
+          </fields>
+        </register>
+        <register>
+          <name>STATUS</name>
+          <addressOffset>4</addressOffset>
+          <size>16</size>
+          <fields>
+            <field><name>RX</name><bitOffset>0</bitOffset><bitWidth>1</bitWidth><modifiedWriteValues>oneToClear</modifiedWriteValues></field>
+            <field><name>TX</name><bitOffset>1</bitOffset><bitWidth>1</bitWidth><modifiedWriteValues>oneToClear</modifiedWriteValues></field>
+            <field><name>LEVEL</name><bitOffset>8</bitOffset><bitWidth>4</bitWidth><modifiedWriteValues>oneToClear</modifiedWriteValues></field>
+          </fields>
+        </register>
+      </registers>
+    </peripheral>
+  </peripherals>
+</device>
+");
+
+        assert_eq!(import.source, "\
+// Generated by the `bitfield` crate.
+
+/// Imported from the `GPIO` peripheral (line 3).
+pub mod gpio {
+    /// The base address of the peripheral.
+    pub const BASE_ADDRESS: usize = 0x0;
+
+    /// Imported from the `enumeratedValues` of `GPIO.MODE.PIN%s` (line 17).
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, bitfield::Field)]
+    #[repr(u8)]
+    pub enum ModePin {
+        /// Input mode
+        Input = 0,
+        Output = 1,
+        Alternate = 2,
+        Analog = 3
+    }
+
+    /// Imported from the `enumeratedValues` of `GPIO.MODE.SPEED` (line 30).
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, bitfield::Field)]
+    #[repr(u8)]
+    pub enum ModeSpeed {
+        Speed1mhz = 1
+    }
+
+    /// Imported from `GPIO.MODE` (line 7).
+    #[bitfield::bitfield(8, register)]
+    #[derive(Debug)]
+    pub struct Mode {
+        #[field(0, 2, complete)] pub pin0: ModePin,
+        #[field(2, 2, complete)] pub pin1: ModePin,
+        #[field(4, 2, access = ro)] pub speed: ModeSpeed,
+        #[field(6, 2)] pub alt: ModePin
+    }
+
+    impl Mode {
+        /// The offset of the register from `BASE_ADDRESS`.
+        pub const OFFSET: usize = 0x0;
+    }
+
+    impl From<u8> for Mode {
+        fn from(value: u8) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<Mode> for u8 {
+        fn from(value: Mode) -> Self {
+            value.0
+        }
+    }
+
+    /// Imported from `GPIO.STATUS` (line 44).
+    #[bitfield::bitfield(16, register)]
+    #[derive(Debug)]
+    pub struct Status {
+        #[field(8, 4)] pub level: u8,
+        #[field(access = w1c)] pub flags: StatusFlag
+    }
+
+    impl Status {
+        /// The offset of the register from `BASE_ADDRESS`.
+        pub const OFFSET: usize = 0x4;
+    }
+
+    impl From<u16> for Status {
+        fn from(value: u16) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<Status> for u16 {
+        fn from(value: Status) -> Self {
+            value.0
+        }
+    }
+
+    /// The write-1-to-clear fields of `GPIO.STATUS`.
+    #[derive(Clone, Copy, Debug, bitfield::Flags)]
+    #[repr(u8)]
+    pub enum StatusFlag {
+        Rx = 0,
+        Tx = 1
+    }
+}
+");
+        assert_eq!(diagnostics(&import), [
+            "line 32: `ANY` of `GPIO.MODE.SPEED` is not imported: expected an integer `value` without do-not-care bits",
+            "line 51: `GPIO.STATUS.LEVEL` is imported without write-1-to-clear access, which is only supported for readable 1 bit fields"
+        ]);
+    }
+
+    #[test]
+    fn unsupported() {
+        let import = super::from_svd("\
+<device>
+  <peripherals>
+    <peripheral>
+      <name>A</name>
+      <baseAddress>0</baseAddress>
+      <registers>
+        <register derivedFrom=\"A.B\"><name>B</name><addressOffset>0</addressOffset></register>
+        <register><name>C</name><addressOffset>4</addressOffset><size>24</size></register>
+        <register>
+          <name>D</name>
+          <addressOffset>8</addressOffset>
+          <fields>
+            <field><name>X</name><bitOffset>0</bitOffset><bitWidth>4</bitWidth></field>
+            <field><name>Y</name><bitOffset>2</bitOffset><bitWidth>4</bitWidth></field>
+          </fields>
+        </register>
+        <register>
+          <name>E</name>
+          <addressOffset>12</addressOffset>
+          <fields>
+            <field><name>NEW</name><bitOffset>0</bitOffset><bitWidth>1</bitWidth></field>
+            <field><name>Z</name><bitOffset>30</bitOffset><bitWidth>4</bitWidth></field>
+            <field><name>W</name></field>
+          </fields>
+        </register>
+        <cluster><name>F</name><dim>2</dim><addressOffset>0x10</addressOffset></cluster>
+      </registers>
+    </peripheral>
+    <peripheral derivedFrom=\"Z\"><name>B</name><baseAddress>0</baseAddress></peripheral>
+    <peripheral><name>A</name><baseAddress>0</baseAddress></peripheral>
+    <peripheral><name>C</name></peripheral>
+  </peripherals>
+</device>
+");
+
+        assert_eq!(diagnostics(&import), [
+            "line 7: `A.B` is not imported: derived registers are not supported",
+            "line 8: `A.C` is not imported: registers with 24 bits are not supported",
+            "line 9: `A.D` is not imported: overlaps with field `x`, please specify `allow_overlaps` if this is intended; \
+             `y`: bits 2-5, `x`: bits 0-3, overlapping: bits 2-3; \
+             layout (bit 31 first): `..........................##XX==`, `#` is `y`, `=` is `x`, `X` is both, `-` are other fields, `.` are free bits; \
+             help: the next free position is `#[field(4, 4)]`",
+            "line 23: `A.E.W` is not imported: expected `bitOffset`, `lsb` and `msb` or `bitRange`",
+            "line 17: `A.E.Z` is not imported: it exceeds 32 bits",
+            "line 17: `A.E` is not imported: accessor `new` of entry `new` collides with method `new`, please rename one of them with `#[field(get = ..., set = ..., invert = ...)]`",
+            "line 26: cluster `A.F` is not imported: arrays and derived clusters are not supported",
+            "line 29: peripheral `B` is not imported: `Z` is not defined before",
+            "line 30: peripheral `A` is not imported: `a` is already defined",
+            "line 31: peripheral `C` is not imported: expected a `baseAddress`"
+        ]);
+
+        assert_eq!(diagnostics(&super::from_svd("<?xml version=\"1.0\"?>\n<peripheral/>")), [
+            "line 2: expected `<device>`, found `<peripheral>`"
+        ]);
+        assert_eq!(diagnostics(&super::from_svd("<device>")), ["line 1: `<device>` is not closed"]);
+    }
+}
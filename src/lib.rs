@@ -1,7 +1,7 @@
 //! This crate provides the three macros `bitfield`, `Field` and `Flags` (and the additional
-//! convenience macro `FromPrimitive`) to interoperate with low level, typically operating system
-//! related types which store data with sub-byte precision, like boolean flags or sub-byte fields,
-//! in a type-safe, typical rust way.
+//! convenience macros `c_struct` and `FromPrimitive`) to interoperate with low level, typically
+//! operating system related types which store data with sub-byte precision, like boolean flags or
+//! sub-byte fields, in a type-safe, typical rust way.
 //!
 //! For more specific documentation look at the documentation of the macros, or at the files in
 //! `examples/*`.
@@ -133,7 +133,7 @@
 ///
 /// Examples:
 ///
-/// ```rust,compile_fail
+/// ```rust,ignore
 /// // Abstracts access to the bits in a `u8`.
 /// #[bitfield::bitfield(8)]
 /// // Abstracts access to the bits in a `u32`.
@@ -150,7 +150,10 @@
 /// a possible layout mistake:
 ///
 /// ```rust,compile_fail
-/// // error: `field_1` overlaps with field `field_0`.
+/// // error: overlaps with field `field_0`, please specify `allow_overlaps` if this is intended
+/// //        `field_1`: bits 1-8, `field_0`: bits 0-7, overlapping: bits 1-7
+/// //        layout (bit 15 first): `.......#XXXXXXX=`, `#` is `field_1`, `=` is `field_0`, ...
+/// //        help: the next free position is `#[field(8, 8)]`
 /// #[bitfield::bitfield(16)]
 /// struct BitField {
 ///     #[field(bit = 0)] field_0: u8, // Field from bits 0 - 7.
@@ -158,6 +161,9 @@
 /// }
 /// ```
 ///
+/// The error shows the bits of both entries, a map of the layout and the next position where the
+/// field would fit. Overlapping flags are reported the same way when the code is compiled.
+///
 /// If this is intentional, the comma separated identifier `allow_overlaps` can be appended after
 /// the amount of bits to suppress this error, as is necessary in
 /// `examples/windows_memory_protection.rs`.
@@ -205,6 +211,67 @@
 /// }
 /// ```
 ///
+/// Unused bits are allowed by default. The comma separated identifier `exhaustive` requires every
+/// bit to be used by a field or a flag, so a forgotten bit is a compile time error. Fields are
+/// checked by the macro, flags and `usize` based bit fields are checked by the compiler:
+///
+/// ```rust,compile_fail
+/// // error: not every bit is used by a field, flags or reserved bits, unused bits: 4-7
+/// #[bitfield::bitfield(8, exhaustive)]
+/// struct BitField {
+///     #[field(0, 4)] low: u8
+/// }
+/// ```
+///
+/// Bits which are used by the hardware or the protocol but have no meaning for the program can be
+/// declared as named reserved bits with the placeholder type `_`. `#[reserved(bit, size)]` or
+/// `#[reserved(bit = 4, size = 2)]` places them explicitly, `#[reserved(size)]` at the next free
+/// bit. Reserved bits have no accessors, take part in the overlap and `exhaustive` checks and are
+/// only shown in `Debug` if they are not zero. By default their value is preserved when the bit
+/// field is modified, `policy = zero` clears them whenever a new value is created by a setter:
+///
+/// ```rust
+/// #[bitfield::bitfield(8, exhaustive)]
+/// #[derive(Debug)]
+/// struct BitField {
+///     #[field(size = 4)] low: u8,
+///     #[reserved(2, policy = zero)] _rsvd0: _,
+///     #[reserved(bit = 6, size = 2)] _rsvd1: _
+/// }
+///
+/// let bitfield = BitField(0b1111_0000).set_low(1).unwrap();
+/// assert_eq!(bitfield.0, 0b1100_0001);
+/// assert_eq!(format!("{:?}", bitfield), "BitField { low: 1, _rsvd1: 0x3 }");
+/// ```
+///
+/// There is no separate layout descriptor type, the `C_HEADER` constant (see section 2.6) is the
+/// generated description of the layout and lists the shift and mask of every reserved entry.
+///
+/// The names of the generated accessors (see section 2.2.) follow the naming scheme set with the
+/// `naming` option, which can be combined with `allow_overlaps`:
+///
+/// | Scheme            | Getter (field / flags) | Setter   | Inverter   |
+/// |-------------------|------------------------|----------|------------|
+/// | `"set"` (default) | `x`                    | `set_x`  | `invert_x` |
+/// | `"with"`          | `x`                    | `with_x` | `invert_x` |
+/// | `"get_set"`       | `get_x` / `has_x`      | `set_x`  | `invert_x` |
+///
+/// Tuple structs use `get` / `has`, `with` for `"with"` and `set` otherwise, and `invert`. All
+/// other accessors are derived from these names, e.g. `x_mask` from the getter and `with_x_all`
+/// from the setter.
+///
+/// ```rust
+/// #[bitfield::bitfield(8, naming = "with")]
+/// struct BitField {
+///     #[field(size = 2)] field: u8,
+///     flag: bool
+/// }
+///
+/// let field = BitField::new().with_field(3).unwrap().with_flag(true);
+/// assert_eq!(field.field(), 3);
+/// assert!(field.flag());
+/// ```
+///
 /// ## 1.2. Struct types
 ///
 /// There are two different ways to define a bit field with the `bitfield::bitfield` macro.
@@ -324,6 +391,38 @@
 /// The names for the accessors described below are `field` and `set_field` as well as `flags` and
 /// `set_flags`.
 ///
+/// ### 1.2.3 Generic parameters
+///
+/// Both struct types can declare lifetime, type and const parameters and a `where` clause, f. e.
+/// for type-state markers like the level of a page table entry. The bit field stays
+/// `#[repr(transparent)]` over the primitive type, the parameters are only stored as a zero-sized
+/// `core::marker::PhantomData<fn() -> T>`, so they do not affect auto traits. All generated
+/// implementations and types (see section 2.) carry the parameters, entries can not use them.
+///
+/// ```rust
+/// pub struct Level1;
+/// pub struct Level2;
+///
+/// #[bitfield::bitfield(64)]
+/// struct PageTableEntry<Level> {
+///     #[field(bit = 0)] present: bool,
+///     #[field(bit = 1)] writable: bool
+/// }
+///
+/// impl PageTableEntry<Level2> {
+///     fn next(&self) -> PageTableEntry<Level1> {
+///         PageTableEntry::new().set_present(self.present())
+///     }
+/// }
+///
+/// let entry = PageTableEntry::<Level2>::new().set_present(true);
+/// assert!(entry.next().present());
+/// assert_eq!(core::mem::size_of::<PageTableEntry<Level2>>(), 8);
+/// ```
+///
+/// Derived traits like `Clone` require them for the parameters as well, so markers usually derive
+/// the same traits as the bit field.
+///
 /// ## 1.3. Bit field entries
 ///
 /// As seen above, a bit field can host a combination of multi-bit wide fields and one-bit wide
@@ -373,6 +472,7 @@
 /// flags enum is unnecessary, see `examples/vga_text_mode.rs`, otherwise using a flags enum should
 /// be preferred, as it has advantages like enumerability. For C-like `enum` types, the
 /// `bitfield::Field` proc-macro-derive macro aids in implementing the necessary traits and methods.
+/// Fields in which `0` means "not present" can be wrapped in an `Option` (see section 2.2.2.6).
 ///
 /// Unlike for flags, a `#[field]` attribute must be specified for fields in a bit field.
 ///
@@ -457,18 +557,136 @@
 /// }
 /// ```
 ///
+/// ### 1.3.3 Accessor names
+///
+/// The names of the getter, setter and inverter of a field or flags entry can be overridden with
+/// `get = NAME`, `set = NAME` and `invert = NAME` in the `#[field]` attribute. They can be combined
+/// with the position and size of a field, or be used alone, which also works for flags. Accessors
+/// derived from these names use the overridden names too.
+///
+/// All generated names are checked for collisions, which are reported with the entry they
+/// originate from:
+///
+/// ```rust,compile_fail
+/// // error: accessor `flags_mask` of entry `flags_mask` collides with accessor `flags_mask` of
+/// //        entry `flags`.
+/// #[bitfield::bitfield(16)]
+/// struct BitField {
+///     flags: Flag,
+///     #[field(8, 8)] flags_mask: u8
+/// }
+///
+/// #[derive(Copy, Clone, bitfield::Flags)]
+/// #[repr(u8)]
+/// enum Flag {
+///     Flag00000001
+/// }
+/// ```
+///
+/// Example:
+///
+/// ```rust
+/// #[bitfield::bitfield(16)]
+/// struct BitField {
+///     #[field(get = has_flag)] flags: Flag,
+///     #[field(8, 8, get = kind, set = with_kind)] flags_mask: u8
+/// }
+///
+/// #[derive(Copy, Clone, bitfield::Flags)]
+/// #[repr(u8)]
+/// enum Flag {
+///     Flag00000001
+/// }
+///
+/// let field = BitField::new().set_flags(Flag::Flag00000001, true).with_kind(0xFF);
+/// assert!(field.has_flag(Flag::Flag00000001));
+/// assert_eq!(BitField::has_flag_mask(), 1);
+/// assert_eq!(field.kind(), 0xFF);
+/// ```
+///
+/// ### 1.3.4 Access modes
+///
+/// Registers often contain bits that must not be written, or not be read. The `access = MODE`
+/// option of the `#[field]` attribute restricts which accessors are generated for a field or flags
+/// entry:
+///
+/// - `rw`: Read-write, all accessors are generated (default).
+/// - `ro`: Read-only, no setters, inverters, in-place mutators and `core::ops::*` implementations.
+/// - `wo`: Write-only, no getters and inverters. `Debug` skips the entry and `Display` can not
+///   display it.
+/// - `w1c`: Write-1-to-clear, only for flags. Generated like `ro`, but with an additional
+///   `clear_#FLAGS` (`clear` for tuple structs) method, which returns a copy of the bit field in
+///   which only the specified flag is set among all `w1c` flags, so no other pending flag is cleared
+///   by writing it back.
+///
+/// Example:
+///
+/// ```rust
+/// #[bitfield::bitfield(8)]
+/// struct Status {
+///     #[field(access = w1c)] pending: Interrupt,
+///     #[field(4, 2, access = ro)] state: u8,
+///     #[field(bit = 7, access = wo)] reset: bool
+/// }
+///
+/// #[derive(Copy, Clone, bitfield::Flags)]
+/// #[repr(u8)]
+/// enum Interrupt {
+///     Rx,
+///     Tx
+/// }
+///
+/// let status = Status(0b0011_0011);
+/// assert!(status.pending(Interrupt::Tx));
+/// assert_eq!(status.state(), 3);
+/// assert_eq!(status.clear_pending(Interrupt::Rx).0, 0b0011_0001);
+/// assert_eq!(status.set_reset(true).0, 0b1011_0011);
+/// ```
+///
+/// ### 1.3.5 Conditional entries
+///
+/// Entries of structs with named fields can be limited to some targets with `#[cfg(...)]`. The
+/// accessors, implementations and checks of the entry are only generated where the predicate
+/// holds. `#[cfg_attr(predicate, field(...))]` moves an entry for some targets: it is generated
+/// with that `field` attribute where the predicate holds and as declared otherwise. The attribute
+/// must specify an explicit `bit`, and the position of the following fields does not change.
+/// Multiple `cfg_attr` attributes of one entry should have mutually exclusive predicates.
+///
+/// Overlaps with conditional entries are checked by the compiler in the configurations in which
+/// both entries exist. Conditional entries with the same name are alternatives of each other, so
+/// they are never checked against each other. `Display`, C headers and `w1c` flags are not
+/// supported with conditional entries.
+///
+/// Example:
+///
+/// ```rust
+/// #[bitfield::bitfield(16, exhaustive)]
+/// #[derive(Debug)]
+/// struct Descriptor {
+///     #[cfg_attr(target_pointer_width = "64", field(8, 8))]
+///     #[field(size = 8)] index: u8,
+///     #[cfg(target_pointer_width = "64")]
+///     #[field(0, 8)] extended: u8,
+///     #[cfg(not(target_pointer_width = "64"))]
+///     #[field(8, 8)] flags: u8
+/// }
+///
+/// let descriptor = Descriptor::new().set_index(3);
+/// # #[cfg(target_pointer_width = "64")]
+/// assert_eq!(descriptor.0, 0x0300);
+/// # #[cfg(not(target_pointer_width = "64"))]
+/// assert_eq!(descriptor.0, 0x0003);
+/// ```
+///
 /// ## 1.4. Implementations for the `core::fmt::{Debug, Display}` traits
 ///
 /// Implementations for the `core::fmt::{Debug, Display}` traits can be generated by using the
-/// `#[derive]` attribute. To generate `#![no_std]`-compatible code, the `alloc` crate is
-/// targeted directly, which requires the user of this crate to add `extern crate alloc;` to
-/// the root of their crate, or `use std as alloc;` in a `std` environment.
+/// `#[derive]` attribute. The generated code writes directly into the `core::fmt::Formatter`, so
+/// it is `#![no_std]`-compatible and does not need the `alloc` crate.
 ///
 /// Example:
 ///
 /// ```rust
-/// extern crate alloc; // Alternatively: `use std as alloc;`
-///
 /// #[bitfield::bitfield(8)]
 /// #[derive(Debug)]
 /// struct BitField {
@@ -497,14 +715,12 @@
 /// }
 /// ```
 ///
-/// The implementation for `core::fmt::Display` can only be generated for bit fields with only one
-/// field or flags (typically a tuple struct bit field).
+/// Without a template (see below), the implementation for `core::fmt::Display` can only be generated
+/// for bit fields with only one field or flags (typically a tuple struct bit field).
 ///
 /// Example for flags:
 ///
 /// ```rust
-/// extern crate alloc; // Alternatively: `use std as alloc;`
-///
 /// #[bitfield::bitfield(8)]
 /// #[derive(Debug, Display)]
 /// struct BitField(Flag);
@@ -526,13 +742,120 @@
 /// Example for a field:
 ///
 /// ```rust
-/// extern crate alloc; // Alternatively: `use std as alloc;`
-///
 /// #[bitfield::bitfield(8)]
 /// #[derive(Debug, Display)]
 /// struct BitField(#[field(3, 2)] u8);
 /// ```
 ///
+/// Bit fields with multiple fields can be displayed by specifying a template in a
+/// `#[display("...")]` attribute. Every `{entry}` placeholder is replaced by the value of the entry
+/// with the same name, `{{` and `}}` display `{` and `}`. The placeholders are checked at compile
+/// time. Set flags are joined with `" | "` and `"-"` is displayed if no flag is set, both of which can
+/// be changed with the `separator` and `empty` options, which can also be used without a template.
+///
+/// Example:
+///
+/// ```rust
+/// #[bitfield::bitfield(8)]
+/// #[derive(Display)]
+/// #[display("{field} [{flags}]", separator = ", ", empty = "none")]
+/// struct BitField {
+///     #[field(size = 2)] field: Field,
+///     flags: Flag
+/// }
+///
+/// #[derive(Clone, Copy, Debug, bitfield::Field)]
+/// #[repr(u8)]
+/// enum Field {
+///     Variant0,
+///     Variant1,
+///     Variant2,
+///     Variant3
+/// }
+///
+/// #[derive(Copy, Clone, Debug, bitfield::Flags)]
+/// #[repr(u8)]
+/// enum Flag {
+///     Flag00000100 = 2,
+///     Flag00001000,
+///     Flag00010000
+/// }
+///
+/// let field = BitField::new().set_field(Field::Variant2);
+/// assert_eq!(format!("{}", field), "Variant2 [none]");
+///
+/// let field = field.set_flags(Flag::Flag00000100, true).set_flags(Flag::Flag00010000, true);
+/// assert_eq!(format!("{}", field), "Variant2 [Flag00000100, Flag00010000]");
+/// ```
+///
+/// Every bit field with a `core::fmt::Display` implementation also implements `core::str::FromStr`,
+/// which accepts the generated format, so values can be read back from configuration files or
+/// command line arguments. The whitespace around a flag separator is optional, and numeric literals
+/// (`12`, `0xC`, `0o14` or `0b1100`) can be used in place of flags to set bits that have no flag.
+/// Parsing fails with a generated `#NAMEParseError`, which exposes the byte `position()` of the
+/// offending token and the `token()` itself, truncated to 32 bytes so no allocation is needed.
+/// Templates must separate placeholders with text. Integer values are read up to their last digit,
+/// other values end at the last occurrence of the following text that leaves a valid value, so the
+/// text may also appear in a flag separator (e.g. `"{flags} {integer}"`).
+///
+/// Example:
+///
+/// ```rust
+/// #[bitfield::bitfield(8)]
+/// #[derive(Debug, Display)]
+/// struct BitField(Flag);
+///
+/// #[derive(Copy, Clone, Debug, bitfield::Flags)]
+/// #[repr(u8)]
+/// enum Flag {
+///     Flag00000001,
+///     Flag00000010,
+///     Flag00000100
+/// }
+///
+/// let field: BitField = "Flag00000001 | Flag00000100".parse().unwrap();
+/// assert_eq!(format!("{}", field), "Flag00000001 | Flag00000100");
+///
+/// let field: BitField = "Flag00000010|0x80".parse().unwrap();
+/// assert!(field.has(Flag::Flag00000010) && field.0 & 0x80 != 0);
+///
+/// let source = "Flag00000001 | Flag00001000";
+/// let error = source.parse::<BitField>().unwrap_err();
+/// assert_eq!((error.token(), error.position()), ("Flag00001000", 15));
+/// ```
+///
+/// Implementations for the `core::fmt::{LowerHex, UpperHex, Binary, Octal}` traits can be generated
+/// the same way. They format the primitive value of the bit field. The alternate form of `Binary`
+/// (`{:#b}`) prints all bits of the primitive type and separates the bits of fields and flags with
+/// `|`, where every flag is a group of its own and the unused bits between entries form one group.
+///
+/// Example:
+///
+/// ```rust
+/// #[bitfield::bitfield(16)]
+/// #[derive(Binary, LowerHex, Octal, UpperHex)]
+/// struct BitField {
+///     #[field(size = 4)] field: u8,
+///     flags: Flag
+/// }
+///
+/// #[derive(Copy, Clone, bitfield::Flags)]
+/// #[repr(u8)]
+/// enum Flag {
+///     Flag0001 = 4,
+///     Flag0010,
+///     Flag0100,
+///     Flag1000
+/// }
+///
+/// let field = BitField::new().set_field(0b0101).unwrap().set_flags(Flag::Flag0010, true);
+/// assert_eq!(format!("{:#06x}", field), "0x0025");
+/// assert_eq!(format!("{:X}", field), "25");
+/// assert_eq!(format!("{:o}", field), "45");
+/// assert_eq!(format!("{:b}", field), "100101");
+/// assert_eq!(format!("{:#b}", field), "0b00000000|0|0|1|0|0101");
+/// ```
+///
 /// # 2. Bit field features
 ///
 /// The following type definition is generated for a bit field:
@@ -816,22 +1139,19 @@
 /// accessor methods are generated:
 ///
 /// ```rust,ignore
-/// // NOTE: This method is not `const` until https://github.com/rust-lang/rust-project-goals/issues/106
-/// // is merged, because `core::convert::TryFrom` is used under the hood to convert the primitve
-/// // value to an enumeration variant.
-/// //
 /// /// Returns the primitive value encapsulated in the `Err` variant, if the value can
 /// /// not be converted to the expected type.
-/// (const) fn #GETTER(&self) -> core::result::Result<#FIELD_TYPE, #UNSIGNED_PRIMITIVE_TYPE>;
+/// const fn #GETTER(&self) -> core::result::Result<#FIELD_TYPE, #UNSIGNED_PRIMITIVE_TYPE>;
 ///
 /// /// Creates a copy of the bit field with the new value.
 /// const fn #SETTER(&self, value: #FIELD_TYPE) -> Self;
 /// ```
 ///
 /// The getter tries to convert the primitive integer type to an enumeration variant by executing
-/// `core::convert::TryFrom<#PRIMITIVE_TYPE>::try_into(BITS_REPRESENTING_THE_FIELD)` where
-/// `#PRIMITIVE_TYPE` is the smallest possible (un-)signed primitive integer type that can store the
-/// field value, based on the `size` value in the `#[field]` attribute, f. e. `u8` / `i8` for
+/// `#FIELD_TYPE::from_repr(BITS_REPRESENTING_THE_FIELD as #REPR_TYPE)`, which is generated by
+/// `bitfield::Field` or `bitfield::FromPrimitive`. The bits are read as `#PRIMITIVE_TYPE`, which
+/// is the smallest possible (un-)signed primitive integer type that can store the field value,
+/// based on the `size` value in the `#[field]` attribute, f. e. `u8` / `i8` for
 /// `#[field(size = 1)]` to `#[field(size = 8)]`, or `u16` / `i16` for `#[field(size = 9)]` to
 /// `#[field(size = 16)]`, etc.
 ///
@@ -925,6 +1245,104 @@
 /// assert_eq!(field.field_incomplete(), Err(         0         ));
 /// ```
 ///
+/// #### 2.2.2.6 Optional fields
+///
+/// Fields in which `0` means "not present" can use an `Option` of an enumeration, or an `Option`
+/// of an unsigned `NonZero` type. The getter returns `None` if all bits of the field are cleared,
+/// and the setter clears all bits of the field for `None`:
+///
+/// ```rust,ignore
+/// // Enumerations, `Result` is omitted for `complete` fields.
+/// const fn #GETTER(&self) -> core::result::Result<Option<#FIELD_TYPE>, #UNSIGNED_PRIMITIVE_TYPE>;
+/// const fn #SETTER(&self, value: Option<#FIELD_TYPE>) -> Self;
+///
+/// // `NonZero` types, the setter returns `Option<Self>` like for unsigned primitive integer types.
+/// const fn #GETTER(&self) -> Option<#NON_ZERO_TYPE>;
+/// const fn #SETTER(&self, value: Option<#NON_ZERO_TYPE>) -> Self;
+/// ```
+///
+/// No variant of the enumeration may use the discriminant `0`, which is checked at compile time.
+/// A `complete` optional field needs a variant for all bit combinations except `0`. Like for
+/// unsigned primitive integer types, the `size` of a `NonZero` field is optional.
+///
+/// Example:
+///
+/// ```rust
+/// use core::num::NonZeroU8;
+///
+/// #[bitfield::bitfield(16)]
+/// struct BitField {
+///     #[field(size = 2)]           icon:  Option<Icon>,
+///     #[field(size = 2, complete)] level: Option<Icon>,
+///     #[field(size = 4)]           count: Option<NonZeroU8>,
+///                                  id:    Option<NonZeroU8>
+/// }
+///
+/// #[derive(Clone, Copy, Debug, Eq, PartialEq, bitfield::Field)]
+/// #[repr(u8)]
+/// enum Icon {
+///     // 0 is stored for `None`.
+///     Stop = 1, // 0b01
+///     Question, // 0b10
+///     Warning   // 0b11
+/// }
+///
+/// let field = BitField::new();
+/// assert_eq!(field.icon(),  Ok(None));
+/// assert_eq!(field.level(),    None );
+/// assert_eq!(field.count(),    None );
+///
+/// let field = field.set_icon(Some(Icon::Warning)).set_level(Some(Icon::Stop));
+/// assert_eq!(field.icon(),  Ok(Some(Icon::Warning)));
+/// assert_eq!(field.level(),    Some(Icon::Stop)    );
+///
+/// let field = field.set_count(NonZeroU8::new(15)).unwrap().set_id(NonZeroU8::new(42));
+/// assert_eq!(field.count(), NonZeroU8::new(15));
+/// assert_eq!(field.id(),    NonZeroU8::new(42));
+/// assert!(field.set_count(NonZeroU8::new(16)).is_none());
+///
+/// let field = field.set_icon(None).set_count(None).unwrap();
+/// assert_eq!(field.icon(),  Ok(None));
+/// assert_eq!(field.count(),    None );
+/// ```
+///
+/// #### 2.2.2.7 Variant groups
+///
+/// Variants of an enumeration deriving `bitfield::Field` can be assigned to groups with
+/// `#[group(NAME, ...)]`, which generates a `const fn is_#GROUP(self) -> bool` for every group.
+/// `#[cfg_attr(predicate, group(...))]` limits a group membership to some targets.
+///
+/// As the bit field can not see the groups of the enumeration, the groups a field should forward
+/// are listed with the `groups(NAME, ...)` option of its `#[field]` attribute, which generates a
+/// `const fn #FIELD_is_#GROUP(&self) -> bool` (`is_#GROUP` for tuple structs) for every group.
+/// The predicates return `false` for values which are not a variant and for `None`. Groups are
+/// only supported for readable fields of enumeration types.
+///
+/// Example:
+///
+/// ```rust
+/// #[bitfield::bitfield(8)]
+/// struct Protection {
+///     #[field(size = 3, groups(read, write))] access: Access
+/// }
+///
+/// #[derive(Clone, Copy, bitfield::Field)]
+/// #[repr(u8)]
+/// enum Access {
+///     // 0 is unused.
+///     #[group(read)] Read = 1,
+///     #[group(read, write)] ReadWrite,
+///     #[cfg_attr(target_arch = "x86_64", group(read))] Execute
+/// }
+///
+/// assert!(Access::ReadWrite.is_write());
+///
+/// let protection = Protection::new().set_access(Access::Read);
+/// assert!(protection.access_is_read());
+/// assert!(!protection.access_is_write());
+/// assert!(!Protection::new().access_is_read());
+/// ```
+///
 /// ### 2.2.3 `core::ops::*` implementations
 ///
 /// Bit fields can be manipulated in a less verbose way than previously presented. For most fields
@@ -937,7 +1355,7 @@
 ///
 /// For flags the `core::ops::*` implementations are *not* generated under these conditions:
 /// - The flags are less visible than the bit field. Trait implementations of a `pub` bit field are
-/// `pub` themselves and would leak access to less visible flags.
+///   `pub` themselves and would leak access to less visible flags.
 ///
 ///     Negative example:
 ///
@@ -952,7 +1370,7 @@
 ///     # }
 ///     ```
 /// - The primitive type is a `NonZero` variant. The explicit setter accessors return `Option<Self>`,
-/// which is not possible for the trait implementations.
+///   which is not possible for the trait implementations.
 ///
 ///     Negative example:
 ///
@@ -1032,7 +1450,7 @@
 ///
 /// For fields the `core::ops::*` implementations are *not* generated under these conditions:
 /// - The field is less visible than the bit field. Trait implementations of a `pub` bit field are
-/// `pub` themselves and would leak access to a less visible field.
+///   `pub` themselves and would leak access to a less visible field.
 ///
 ///     Negative example:
 ///
@@ -1047,7 +1465,7 @@
 ///     # }
 ///     ```
 /// - The primitive type is a `NonZero` variant. The explicit setter accessors return `Option<Self>`,
-/// which is not possible for the trait implementations.
+///   which is not possible for the trait implementations.
 ///
 ///     Negative example:
 ///
@@ -1062,7 +1480,7 @@
 ///     # }
 ///     ```
 /// - The type of the field is used more than once in the bit field. The implementation can not know
-/// which field to access.
+///   which field to access, use the key types of the `keys` option instead (see section 2.2.3.3).
 ///
 ///     Negative example:
 ///
@@ -1114,57 +1532,693 @@
 /// field += Field::One;
 /// assert_eq!(field.get(), Ok(Field::One));
 /// ```
-#[proc_macro_attribute]
-pub fn bitfield(
-    attribute: proc_macro::TokenStream,
-    item: proc_macro::TokenStream
-) -> proc_macro::TokenStream {
-    bitfield_impl::bitfield::BitField::parse(attribute.into(), item.into()).map_or_else(
-        |error| error.to_compile_error(),
-        |field| field.into()
-    ).into()
-}
-
-/// Generates all necessary trait implementations and methods for a C-like `enum` type to be used as
-/// a field in the `bitfield::bitfield` macro.
 ///
-/// The type must implement `core::clone::Clone` and `core::marker::Copy`.
+/// #### 2.2.3.3 Field keys
 ///
-/// The following methods are generated:
+/// If the `keys` option is appended to the macro attribute of a struct with named fields, a
+/// `#NAME#FIELD` key type is generated for every field, f. e. `DebugControlType0` for the field
+/// `type0` of `DebugControl`. It has the visibility of the field and works for every field, even if
+/// its type is used more than once. Key types of readable fields implement the `#NAMEKey` trait,
+/// key types of writable fields the `#NAMEKeyMut` trait. Key types of writable fields whose setter
+/// can not fail wrap a value of the field, the others are unit structs. The following methods are
+/// generated. It is not available for `NonZero` type based bit fields.
 ///
-/// ```ignore
-/// /// Returns an array containing all enumeration variants in the defined order.
-/// const fn iter() -> &'static [Self];
+/// Keys are opt-in, because they add a type and up to four trait implementations per field next to
+/// the bit field, whose `#NAME#FIELD` names can collide with other items of the module, and private
+/// keys which are never used are reported by the `dead_code` lint like any other item. Flags have
+/// no keys, since they are already accessed through their enum variants, and tuple structs have no
+/// keys, since their only field is accessed with `get` and `set`.
 ///
-/// /// Returns the amount of bits this type uses as a field.
-/// const fn size() -> u8;
+/// ```rust,ignore
+/// /// Gets the value of the field `K`.
+/// fn get<K: #NAMEKey>(&self) -> K::Output;
+///
+/// /// Creates a copy of the bit field with the new value of the field `K`.
+/// fn with<K: #NAMEKeyMut>(&self, value: K::Value) -> K::Modified;
+///
+/// // Only for writable fields whose setter can not fail.
+/// core::ops::Add<#NAME#FIELD>;
+/// core::ops::AddAssign<#NAME#FIELD>;
 /// ```
 ///
-/// A `core::convert::TryFrom<#REPR_TYPE>` implementation with `Error = #REPR_TYPE` is generated.
+/// `K::Value` is the type of the field, `K::Output` the return type of its getter and
+/// `K::Modified` the return type of its setter, which is `Option<Self>` if the value can exceed the
+/// field.
 ///
 /// Example:
 ///
 /// ```rust
-/// #[derive(Clone, Copy, bitfield::Field)]
-/// #[repr(u8)]
-/// enum UnsignedField {
-///     Variant1 = 1,
-///     Variant2,
-///     Variant5 = 5
+/// #[bitfield::bitfield(8, keys)]
+/// struct BitField {
+///     #[field(size = 2)] f1: Field,
+///     #[field(size = 2)] f2: Field,
+///     #[field(size = 4)] integer: u8
 /// }
 ///
-/// #[derive(Clone, Copy, bitfield::Field)]
-/// #[repr(i8)]
-/// enum SignedField {
-///     VariantMinus1 = -1,
-///     Variant1 = 1
+/// #[derive(Clone, Copy, Debug, Eq, PartialEq, bitfield::Field)]
+/// #[repr(u8)]
+/// enum Field {
+///     Zero,
+///     One
 /// }
+///
+/// let mut field = BitField::new() + BitFieldF1(Field::One);
+/// assert_eq!(field.get::<BitFieldF1>(), Ok(Field::One));
+///
+/// field += BitFieldF2(Field::One);
+/// field = field.with::<BitFieldF1>(Field::Zero);
+/// assert_eq!(field.f1(), Ok(Field::Zero));
+/// assert_eq!(field.get::<BitFieldF2>(), Ok(Field::One));
+/// assert_eq!(field.with::<BitFieldInteger>(3).map(|field| field.0), Some(0b0011_0100));
+/// assert!(field.with::<BitFieldInteger>(16).is_none());
 /// ```
 ///
-/// Generates:
+/// ### 2.2.4 In-place mutators
+///
+/// Every setter also has a `&mut self` variant, which modifies the bit field in place:
+///
+/// ```ignore
+/// /// Sets the field to the new value.
+/// const fn set_#FIELD_in_place(&mut self, value: #FIELD_TYPE);
+///
+/// /// Inverts the value of the field (only for `bool` fields).
+/// const fn invert_#FIELD_in_place(&mut self);
+///
+/// /// Sets the specified `flag` to the new value.
+/// const fn set_#FLAGS_in_place(&mut self, flag: #FLAG_TYPE, value: bool);
+///
+/// /// Sets the specified `flag`.
+/// const fn insert_#FLAGS(&mut self, flag: #FLAG_TYPE);
+///
+/// /// Clears the specified `flag`.
+/// const fn remove_#FLAGS(&mut self, flag: #FLAG_TYPE);
+///
+/// /// Inverts the value of the specified `flag`.
+/// const fn toggle_#FLAGS(&mut self, flag: #FLAG_TYPE);
+/// ```
+///
+/// Tuple structs use the names `set_in_place`, `invert_in_place`, `insert`, `remove` and `toggle`.
+/// The `_in_place` names are derived from the setter and inverter names, which depend on the naming
+/// scheme and the `#[field]` attribute (see section 1.1. and 1.3.3), e.g. `with_#FIELD_in_place`.
+///
+/// If the copy-returning setter returns an `Option<Self>`, the in-place variant is prefixed with
+/// `try_` and returns `Result<(), #VALUE_TYPE>` instead. The rejected value is returned in the `Err`
+/// variant and the bit field is left unmodified. This is the case for unsigned fields that do not
+/// use all bits of their type, and for all mutators of `NonZero` type based bit fields, except for
+/// `insert`, when the internal value would become `0`.
+///
+/// Example:
 ///
 /// ```rust
-/// # #[derive(Clone, Copy)]
+/// #[bitfield::bitfield(8)]
+/// struct BitField {
+///     #[field(size = 3)] field: u8,
+///     flags: Flag
+/// }
+///
+/// #[derive(Copy, Clone, Debug, bitfield::Flags)]
+/// #[repr(u8)]
+/// enum Flag {
+///     Flag00001000 = 3,
+///     Flag00010000
+/// }
+///
+/// const FIELD: BitField = {
+///     let mut field = BitField::new();
+///     field.insert_flags(Flag::Flag00001000);
+///     field
+/// };
+///
+/// let mut field = FIELD;
+/// field.toggle_flags(Flag::Flag00010000);
+/// field.remove_flags(Flag::Flag00001000);
+/// assert_eq!(field.try_set_field_in_place(5), Ok(()));
+/// assert_eq!(field.try_set_field_in_place(8), Err(8));
+/// assert_eq!(field.0, 0b0001_0101);
+///
+/// #[bitfield::bitfield(NonZero8)]
+/// struct NonZero(Flag);
+///
+/// let mut field = NonZero(core::num::NonZeroU8::new(0b0000_1000).unwrap());
+/// assert!(field.try_remove(Flag::Flag00001000).is_err());
+/// field.insert(Flag::Flag00010000);
+/// assert!(field.try_remove(Flag::Flag00001000).is_ok());
+/// ```
+///
+/// ### 2.2.5 Parts
+///
+/// If the `parts` option is appended to the macro attribute of a struct with named fields, a plain
+/// `#NAMEParts` struct is generated as well, which can be destructured, matched and built with the
+/// struct literal syntax. It has a member for every readable entry, including read-only and `w1c`
+/// entries (see section 1.3.4), with the visibility of the entry:
+///
+/// - Fields use the return type of their getter, so fields of enumerations which are not `complete`
+///   are stored as `Result<#FIELD_TYPE, #UNSIGNED_PRIMITIVE_TYPE>`.
+/// - Flags are stored as `[bool; N]`, one value per variant in the order of `#FLAG_TYPE::iter()`.
+///
+/// `#NAMEParts` implements `Clone`, `Copy` and `Debug`, if `Debug` is derived for the bit field. It
+/// is not available for `NonZero` type based bit fields.
+///
+/// ```ignore
+/// /// Returns the values of all readable entries.
+/// const fn to_parts(&self) -> #NAMEParts;
+///
+/// /// Creates a bit field from the values of all writable entries, the others stay `0`.
+/// const fn from_parts(parts: #NAMEParts) -> Self;
+///
+/// core::convert::From<#NAME> for #NAMEParts;
+/// core::convert::From<#NAMEParts> for #NAME;
+/// ```
+///
+/// `from_parts` ignores the members of entries which are not writable, so they are `0` in the
+/// created bit field, like write-only entries and reserved bits. The values in `Err` variants are written as they are. If a value can exceed the
+/// bits of its field, which is the case for unsigned fields that do not use all bits of their
+/// type, `from_parts` returns `Option<Self>` and `core::convert::TryFrom<#NAMEParts>` with
+/// `Error = #NAMEParts` is implemented instead of `From`.
+///
+/// Example:
+///
+/// ```rust
+/// #[bitfield::bitfield(16, parts)]
+/// #[derive(Debug)]
+/// struct BitField {
+///     #[field(size = 2)] field: Field,
+///     #[field(size = 4)] integer: u8,
+///     flags: Flag
+/// }
+///
+/// #[derive(Clone, Copy, Debug, bitfield::Field)]
+/// #[repr(u8)]
+/// enum Field {
+///     One = 1,
+///     Two
+/// }
+///
+/// #[derive(Copy, Clone, Debug, bitfield::Flags)]
+/// #[repr(u8)]
+/// enum Flag {
+///     Flag0100 = 8,
+///     Flag1000
+/// }
+///
+/// let field = BitField::new().set_field(Field::Two).set_integer(5).unwrap() + Flag::Flag1000;
+///
+/// match field.to_parts() {
+///     BitFieldParts { field: Ok(Field::Two), integer: 5, flags: [false, true] } => (),
+///     parts => panic!("unexpected {:?}", parts)
+/// }
+///
+/// let parts = BitFieldParts { field: Err(3), integer: 15, flags: [true, false] };
+/// assert_eq!(BitField::from_parts(parts).unwrap().0, 0b0000_0001_0011_1111);
+///
+/// let parts = BitFieldParts { integer: 16, ..parts };
+/// assert!(BitField::try_from(parts).is_err());
+/// ```
+///
+/// ### 2.2.6 Builder
+///
+/// Fields that must always be set explicitly can be marked with the `required` option of the
+/// `#[field]` attribute, e.g. `#[field(size = 4, required)]`. Then a `#NAMEBuilder` typestate is
+/// generated, which wraps the bit field and has a `const #FIELD_IS_SET: bool` parameter for every
+/// required field (`IS_SET` for tuple structs). `build` is only available once all of them are
+/// `true`, so forgetting a required field is a compile error:
+///
+/// ```ignore
+/// /// Creates a builder with all flags and fields cleared.
+/// const fn builder() -> #NAMEBuilder<false, ...>;
+///
+/// // For every writable field and flags entry of the bit field, named like its setter.
+/// const fn set_#FIELD(self, value: #FIELD_TYPE) -> #NAMEBuilder<..., true, ...>;
+/// const fn set_#FLAGS(self, flag: #FLAG_TYPE, value: bool) -> #NAMEBuilder<...>;
+///
+/// // Only for `#NAMEBuilder<true, ...>`.
+/// const fn build(self) -> #NAME;
+/// ```
+///
+/// The builder methods return `Option<#NAMEBuilder<...>>` where the setter returns `Option<Self>`.
+/// As all methods are `const` and only call the setters of the wrapped bit field, a builder chain
+/// compiles to the same code as the chain of setters. Only writable fields of primitive type based
+/// bit fields can be `required`. Conditional required fields need an alternative in every
+/// configuration (see section 1.3.5), and all alternatives must be `required` as well.
+///
+/// Example:
+///
+/// ```rust
+/// #[bitfield::bitfield(16)]
+/// struct BitField {
+///     #[field(size = 2, required)] field: Field,
+///     #[field(size = 4)] integer: u8,
+///     flags: Flag
+/// }
+///
+/// #[derive(Clone, Copy, bitfield::Field)]
+/// #[repr(u8)]
+/// enum Field {
+///     One = 1,
+///     Two
+/// }
+///
+/// #[derive(Copy, Clone, bitfield::Flags)]
+/// #[repr(u8)]
+/// enum Flag {
+///     Flag0100 = 8,
+///     Flag1000
+/// }
+///
+/// const FIELD: BitField = BitField::builder()
+///     .set_flags(Flag::Flag1000, true)
+///     .set_field(Field::Two)
+///     .build();
+///
+/// assert_eq!(FIELD.0, 0b0000_0010_0000_0010);
+///
+/// // Compile error, `field` is not set:
+/// // BitField::builder().set_integer(5).unwrap().build();
+/// ```
+///
+/// ## 2.3. Memory mapped registers
+///
+/// If the `register` option is appended to the macro attribute, a `#NAMERegister` type wrapping a
+/// `*mut #PRIMITIVE_TYPE` is generated as well. It is not available for `NonZero` type based bit
+/// fields.
+///
+/// ```ignore
+/// /// Creates a register which accesses the bit field at `address`.
+/// const unsafe fn new(address: *mut #PRIMITIVE_TYPE) -> Self;
+///
+/// /// Returns the address of the register.
+/// const fn as_ptr(&self) -> *mut #PRIMITIVE_TYPE;
+///
+/// /// Reads the bit field with a single volatile read.
+/// fn read(&self) -> #NAME;
+///
+/// /// Writes the bit field with a single volatile write.
+/// fn write(&self, value: #NAME);
+///
+/// /// Reads the bit field, modifies it with `f` and writes the result back.
+/// fn modify<F: FnOnce(#NAME) -> #NAME>(&self, f: F);
+/// ```
+///
+/// Additionally every in-place mutator of a writable entry (see section 2.2.4) has a shortcut
+/// without the `_in_place` suffix, e.g. `set_#FIELD`, `try_set_#FIELD`, `insert_#FLAGS` or
+/// `clear_#FLAGS` for write-1-to-clear flags. Each shortcut does exactly one volatile read and one
+/// volatile write. Write-1-to-clear flags are cleared before writing back, so a shortcut never
+/// clears pending flags by accident.
+///
+/// Example:
+///
+/// ```rust
+/// #[bitfield::bitfield(16, register)]
+/// struct Control {
+///     #[field(access = w1c)] pending: Interrupt,
+///     #[field(8, 3)] speed: u8,
+///     #[field(bit = 15)] enabled: bool
+/// }
+///
+/// #[derive(Copy, Clone, bitfield::Flags)]
+/// #[repr(u8)]
+/// enum Interrupt {
+///     Rx,
+///     Tx
+/// }
+///
+/// // Stands in for a memory mapped hardware register.
+/// static mut CONTROL: u16 = 0b0000_0000_0000_0011;
+///
+/// let control = unsafe { ControlRegister::new(core::ptr::addr_of_mut!(CONTROL)) };
+/// control.modify(|control| control.set_enabled(true));
+/// assert_eq!(control.try_set_speed(5), Ok(()));
+/// assert_eq!(control.try_set_speed(8), Err(8));
+/// assert_eq!(unsafe { CONTROL }, 0b1000_0101_0000_0000);
+///
+/// unsafe { CONTROL |= 0b0000_0000_0000_0011 };
+/// control.clear_pending(Interrupt::Tx);
+/// assert_eq!(control.read().0, 0b1000_0101_0000_0010);
+/// ```
+///
+/// ## 2.4. Atomic bit fields
+///
+/// If the `atomic` option is appended to the macro attribute, an `Atomic#NAME` type wrapping the
+/// matching `core::sync::atomic::Atomic*` type is generated as well, f. e.
+/// `core::sync::atomic::AtomicUsize` for `size` and `NonZeroSize`. It is not available for 128 bit
+/// bit fields. All methods take the `core::sync::atomic::Ordering` to use:
+///
+/// ```ignore
+/// const fn new(value: #NAME) -> Self;
+/// fn into_inner(self) -> #NAME;
+/// fn load(&self, order: Ordering) -> #NAME;
+/// fn store(&self, value: #NAME, order: Ordering);
+/// fn swap(&self, value: #NAME, order: Ordering) -> #NAME;
+/// fn compare_exchange(
+///     &self, current: #NAME, new: #NAME, success: Ordering, failure: Ordering
+/// ) -> Result<#NAME, #NAME>;
+/// fn fetch_update<F: FnMut(#NAME) -> Option<#NAME>>(
+///     &self, set_order: Ordering, fetch_order: Ordering, f: F
+/// ) -> Result<#NAME, #NAME>;
+///
+/// /// Sets the specified `flag` with `fetch_or` and returns the previous bit field.
+/// fn fetch_set_#FLAGS(&self, flag: #FLAG_TYPE, order: Ordering) -> #NAME;
+///
+/// /// Clears the specified `flag` with `fetch_and` and returns the previous bit field.
+/// fn fetch_clear_#FLAGS(&self, flag: #FLAG_TYPE, order: Ordering) -> #NAME;
+/// ```
+///
+/// Tuple structs use the names `fetch_set_flag` and `fetch_clear_flag`. The flag methods are only
+/// generated for writable flags (see section 1.3.4). For `NonZero` type based bit fields, clearing
+/// a flag is done with `try_fetch_clear_#FLAGS(&self, flag, set_order, fetch_order)`, which uses
+/// `fetch_update` and returns the current bit field in `Err` if the last set bit would be cleared.
+///
+/// Example:
+///
+/// ```rust
+/// use core::sync::atomic::Ordering;
+///
+/// #[bitfield::bitfield(32, atomic)]
+/// struct Access(Right);
+///
+/// #[derive(Copy, Clone, bitfield::Flags)]
+/// #[repr(u8)]
+/// enum Right {
+///     Read,
+///     Write
+/// }
+///
+/// static ACCESS: AtomicAccess = AtomicAccess::new(Access::new());
+///
+/// std::thread::spawn(|| ACCESS.fetch_set_flag(Right::Write, Ordering::Relaxed)).join().unwrap();
+/// ACCESS.fetch_set_flag(Right::Read, Ordering::Relaxed);
+/// assert!(ACCESS.load(Ordering::Relaxed).has_all());
+///
+/// let previous = ACCESS.fetch_update(
+///     Ordering::Relaxed, Ordering::Relaxed, |access| Some(access.set(Right::Write, false))
+/// );
+/// assert!(previous.is_ok());
+/// assert!(!ACCESS.load(Ordering::Relaxed).has(Right::Write));
+/// ```
+///
+/// ## 2.5. Views over byte slices
+///
+/// If the `view = "le"` or `view = "be"` option is appended to the macro attribute, the
+/// `#NAMEView<'a>` and `#NAMEViewMut<'a>` types are generated as well, which decode the bit field
+/// from the first `size_of::<#PRIMITIVE_TYPE>()` bytes of a byte slice in little or big endian byte
+/// order, without copying the bytes first. They are not available for `NonZero` type based bit
+/// fields.
+///
+/// A view covers at most the 16 bytes of the widest primitive type. Larger headers are described by
+/// several bit fields, whose views are created at the byte offset of each part with `new_at`.
+///
+/// ```ignore
+/// /// Creates a view over the first bytes of `bytes`, or returns `None` if `bytes` is too short.
+/// fn new(bytes: &'a [u8]) -> Option<Self>; // `&'a mut [u8]` for `#NAMEViewMut`.
+///
+/// /// Creates a view over the bytes of `bytes` starting at `offset`, or returns `None` if `bytes`
+/// /// is too short.
+/// fn new_at(bytes: &'a [u8], offset: usize) -> Option<Self>; // `&'a mut [u8]` for `#NAMEViewMut`.
+///
+/// /// Decodes the bit field.
+/// fn read(&self) -> #NAME;
+///
+/// /// Decodes the bit field and drops the view.
+/// fn into_owned(self) -> #NAME;
+///
+/// /// Encodes the bit field into the bytes (only `#NAMEViewMut`).
+/// fn write(&mut self, value: #NAME);
+/// ```
+///
+/// Both views have the same getters as the bit field. `#NAMEViewMut` additionally has a `&mut self`
+/// shortcut for every in-place mutator (see section 2.2.4), named without the `_in_place` suffix,
+/// e.g. `set_#FIELD`, `try_set_#FIELD` or `insert_#FLAGS`.
+///
+/// Example:
+///
+/// ```rust
+/// #[bitfield::bitfield(16, view = "be")]
+/// struct Header {
+///     #[field(size = 4)] length: u8,
+///     #[field(bit = 15)] last: bool
+/// }
+///
+/// let packet = [0x80, 0x05, 0xde, 0xad];
+/// let header = HeaderView::new(&packet).unwrap();
+/// assert_eq!(header.length(), 5);
+/// assert!(header.last());
+///
+/// let mut packet = [0x00, 0x00, 0xde, 0xad];
+/// let mut header = HeaderViewMut::new(&mut packet).unwrap();
+/// assert_eq!(header.try_set_length(3), Ok(()));
+/// header.invert_last();
+/// assert_eq!(packet, [0x80, 0x03, 0xde, 0xad]);
+///
+/// // The second header starts at byte 2.
+/// let packet = [0x80, 0x05, 0x00, 0x0c];
+/// assert_eq!(HeaderView::new_at(&packet, 2).unwrap().length(), 12);
+/// assert!(HeaderView::new_at(&packet, 3).is_none());
+/// ```
+///
+/// ## 2.6. C headers
+///
+/// If the `c_header` option is appended to the macro attribute, a `C_HEADER: &'static str`
+/// constant is generated, which describes the layout of the bit field in C. Every field gets
+/// `#NAME_#FIELD_SHIFT` and `#NAME_#FIELD_MASK` macros, and every entry gets `static inline`
+/// getters and setters, depending on its access mode (see section 1.3.4). Enumeration fields are
+/// read and written as unsigned integers and flags are addressed by their position, since the macro
+/// can not see the variants of the enumerations. It is not available for 128 bit bit fields.
+///
+/// ```rust
+/// #[bitfield::bitfield(16, c_header)]
+/// struct Status {
+///     #[field(size = 4)] length: u8,
+///     #[field(bit = 15, access = ro)] ready: bool
+/// }
+///
+/// assert_eq!(Status::C_HEADER, "\
+/// #include <stdbool.h>
+/// #include <stdint.h>
+///
+/// /* Status */
+/// #define STATUS_LENGTH_SHIFT 0
+/// #define STATUS_LENGTH_MASK 0xfu
+/// static inline uint8_t status_get_length(uint16_t value) { return (uint8_t)((value & STATUS_LENGTH_MASK) >> STATUS_LENGTH_SHIFT); }
+/// static inline uint16_t status_set_length(uint16_t value, uint8_t field) { return (uint16_t)((value & ~(uint16_t)STATUS_LENGTH_MASK) | (((uint16_t)field << STATUS_LENGTH_SHIFT) & STATUS_LENGTH_MASK)); }
+/// #define STATUS_READY_SHIFT 15
+/// #define STATUS_READY_MASK 0x8000u
+/// static inline bool status_get_ready(uint16_t value) { return ((value & STATUS_READY_MASK) >> STATUS_READY_SHIFT) != 0; }
+/// ");
+/// ```
+///
+/// To generate a complete header for a source file, including a C `enum` for every enumeration
+/// which derives `Field` or `Flags`, add `bitfield-impl` to the `[build-dependencies]` and call
+/// `bitfield_impl::c_header::from_source` in a `build.rs` script:
+///
+/// ```ignore
+/// fn main() {
+///     println!("cargo:rerun-if-changed=src/registers.rs");
+///
+///     let source = std::fs::read_to_string("src/registers.rs").unwrap();
+///     let header = bitfield_impl::c_header::from_source(&source).unwrap();
+///     std::fs::write("include/registers.h", header).unwrap();
+/// }
+/// ```
+///
+/// The other direction is supported as well: `bitfield_impl::c_import::from_header` imports the
+/// `#define` masks and `struct`s with bit fields of a C header. The generated source can be written
+/// to `OUT_DIR` and included with `include!`, constructs which can not be imported are reported as
+/// diagnostics:
+///
+/// ```ignore
+/// fn main() {
+///     println!("cargo:rerun-if-changed=include/device.h");
+///
+///     let header = std::fs::read_to_string("include/device.h").unwrap();
+///     let import = bitfield_impl::c_import::from_header(&header);
+///     for diagnostic in &import.diagnostics {
+///         println!("cargo:warning=include/device.h: {}", diagnostic);
+///     }
+///
+///     let path = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("device.rs");
+///     std::fs::write(path, import.source).unwrap();
+/// }
+/// ```
+///
+/// ```ignore
+/// include!(concat!(env!("OUT_DIR"), "/device.rs"));
+/// ```
+///
+/// ## 2.7. CMSIS-SVD files
+///
+/// Microcontroller vendors describe their peripheral registers in CMSIS-SVD files.
+/// `bitfield_impl::svd::from_svd` generates a module for every peripheral, with a
+/// `#[bitfield::bitfield(N, register)]` struct for every register. Fields with `enumeratedValues`
+/// become `bitfield::Field` enumerations, the access of a field becomes its access mode, readable
+/// write-1-to-clear bits become `w1c` flags, and the reset value becomes a `RESET` constant. The
+/// raw value of a register can be converted from and into its primitive type with `From`:
+///
+/// ```ignore
+/// fn main() {
+///     println!("cargo:rerun-if-changed=STM32F411.svd");
+///
+///     let svd = std::fs::read_to_string("STM32F411.svd").unwrap();
+///     let import = bitfield_impl::svd::from_svd(&svd);
+///     for diagnostic in &import.diagnostics {
+///         println!("cargo:warning=STM32F411.svd: {}", diagnostic);
+///     }
+///
+///     let path = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("stm32f411.rs");
+///     std::fs::write(path, import.source).unwrap();
+/// }
+/// ```
+///
+/// ```ignore
+/// include!(concat!(env!("OUT_DIR"), "/stm32f411.rs"));
+///
+/// let moder = unsafe {
+///     gpioa::ModerRegister::new((gpioa::BASE_ADDRESS + gpioa::Moder::OFFSET) as *mut u32)
+/// };
+/// moder.set_moder5(gpioa::ModerModer::Output);
+/// ```
+#[proc_macro_attribute]
+pub fn bitfield(
+    attribute: proc_macro::TokenStream,
+    item: proc_macro::TokenStream
+) -> proc_macro::TokenStream {
+    bitfield_impl::bitfield::BitField::parse(attribute.into(), item.into()).map_or_else(
+        |error| error.to_compile_error(),
+        |field| field.into()
+    ).into()
+}
+
+/// Emulates C bit field members (`unsigned int flag : 1;`) in a `#[repr(C)]` struct with named
+/// fields, so that the struct can be shared with C code.
+///
+/// Members with a `#[bits(N)]` attribute are bit field members, all other members are ordinary
+/// members. The declared type of a bit field member must be `bool` or an integer type, which can
+/// also be an alias like `core::ffi::c_uint`, and `N` must not be larger than the type (or `1` for
+/// `bool`, or `64` in general). `#[bits(0)]` members do not store anything, they only start a new
+/// storage unit like unnamed zero width bit fields in C.
+///
+/// Consecutive bit field members are replaced by a private byte array, and the bit positions are
+/// computed at compile time for one of the following ABIs, selected with `abi = "..."`:
+///
+/// - `"sysv"`: Used by GCC and Clang on Unix-like targets. A member is placed in the next free
+///   bits, unless it would cross an alignment boundary of its declared type. The first member can
+///   share a byte with the tail of the previous ordinary member.
+/// - `"msvc"`: Used by MSVC and MinGW. Members are packed into storage units of their declared
+///   type, and a new unit is started if the declared type size changes or if a member does not fit
+///   into the current unit anymore.
+///
+/// Without the option, `"msvc"` is used for Windows targets and `"sysv"` for all other targets. The
+/// bits are numbered from the least significant bit of the first byte, so only little endian
+/// targets are supported. The declared types of all bit field members also raise the alignment of
+/// the struct, like in C.
+///
+/// The following methods are generated:
+///
+/// ```ignore
+/// /// Creates a new instance with the ordinary members in the defined order, all bit field members
+/// /// are `0`.
+/// const fn new(#MEMBER: #MEMBER_TYPE, ...) -> Self;
+///
+/// /// Returns the bit field member, signed types are sign extended.
+/// const fn #MEMBER(&self) -> #MEMBER_TYPE;
+///
+/// /// Sets the bit field member, `value` is truncated to its bits like in C.
+/// const fn set_#MEMBER(&mut self, value: #MEMBER_TYPE);
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use core::ffi::{c_char, c_int, c_uint};
+///
+/// // struct Channel {
+/// //     int id;
+/// //     char name[6];
+/// //     unsigned int use_buffer : 1;
+/// //     unsigned int is_readable : 1;
+/// //     int priority : 3;
+/// //     void *data;
+/// // };
+/// #[bitfield::c_struct(abi = "sysv")]
+/// struct Channel {
+///     id: c_int,
+///     name: [c_char; 6],
+///     #[bits(1)] use_buffer: c_uint,
+///     #[bits(1)] is_readable: c_uint,
+///     #[bits(3)] priority: c_int,
+///     data: *mut u8
+/// }
+///
+/// let mut channel = Channel::new(1, [0; 6], core::ptr::null_mut());
+/// channel.set_is_readable(1);
+/// channel.set_priority(-2);
+/// assert_eq!(channel.use_buffer(), 0);
+/// assert_eq!(channel.is_readable(), 1);
+/// assert_eq!(channel.priority(), -2);
+///
+/// // The bit field members share the 11th byte with the tail of `name`.
+/// assert_eq!(core::mem::size_of::<Channel>(), 24);
+/// assert_eq!(unsafe { *(&channel as *const Channel as *const u8).add(10) }, 0b1_1010);
+/// ```
+#[proc_macro_attribute]
+pub fn c_struct(
+    attribute: proc_macro::TokenStream,
+    item: proc_macro::TokenStream
+) -> proc_macro::TokenStream {
+    bitfield_impl::c_struct::CStruct::parse(attribute.into(), item.into()).map_or_else(
+        |error| error.to_compile_error(),
+        |c_struct| c_struct.into()
+    ).into()
+}
+
+/// Generates all necessary trait implementations and methods for a C-like `enum` type to be used as
+/// a field in the `bitfield::bitfield` macro.
+///
+/// The type must implement `core::clone::Clone` and `core::marker::Copy`.
+///
+/// The following methods are generated:
+///
+/// ```ignore
+/// /// Returns an array containing all enumeration variants in the defined order.
+/// const fn iter() -> &'static [Self];
+///
+/// /// Returns the amount of bits this type uses as a field.
+/// const fn size() -> u8;
+///
+/// /// Returns the variant with the discriminant `value`, or `None` if no variant uses it.
+/// #VISIBILITY const fn from_repr(value: #REPR_TYPE) -> core::option::Option<Self>;
+///
+/// /// Returns the discriminant of the variant.
+/// #VISIBILITY const fn to_repr(self) -> #REPR_TYPE;
+/// ```
+///
+/// A `core::convert::TryFrom<#REPR_TYPE>` implementation with `Error = #REPR_TYPE` is generated,
+/// which calls `from_repr`. For every group from the `#[group(...)]` attributes of the variants, a
+/// `#VISIBILITY const fn is_#GROUP(self) -> bool` is generated as well (see section 2.2.2.7 of
+/// `bitfield::bitfield`).
+///
+/// Example:
+///
+/// ```rust
+/// #[derive(Clone, Copy, bitfield::Field)]
+/// #[repr(u8)]
+/// enum UnsignedField {
+///     Variant1 = 1,
+///     Variant2,
+///     Variant5 = 5
+/// }
+///
+/// #[derive(Clone, Copy, bitfield::Field)]
+/// #[repr(i8)]
+/// enum SignedField {
+///     VariantMinus1 = -1,
+///     Variant1 = 1
+/// }
+/// ```
+///
+/// Generates:
+///
+/// ```rust
+/// # #[derive(Clone, Copy)]
 /// # #[repr(u8)]
 /// # enum UnsignedField {
 /// #     Variant1 = 1,
@@ -1205,25 +2259,56 @@ pub fn bitfield(
 ///             _ => max.ilog2() as u8 + 1
 ///         }
 ///     }
-/// }
 ///
-/// impl ::core::convert::TryFrom<u8> for UnsignedField {
-///     type Error = u8;
+///     /// Returns `true` if no variant uses the discriminant `0`, which allows using this type as
+///     /// an `Option` field.
+///     #[doc(hidden)]
+///     #[inline(always)]
+///     const fn _bitfield_is_non_zero() -> bool {
+///         let mut i = 0;
+///         while i < Self::iter().len() {
+///             if Self::iter()[i] as u8 == 0 {
+///                 return false;
+///             }
+///             i += 1;
+///         }
+///
+///         true
+///     }
 ///
+///     /// Returns the variant with the discriminant `value`, or `None` if no variant uses it.
 ///     #[allow(non_upper_case_globals)]
 ///     #[inline(always)]
-///     fn try_from(value: u8) -> ::core::result::Result<
-///         Self, <Self as ::core::convert::TryFrom<u8>>::Error
-///     > {
+///     const fn from_repr(value: u8) -> ::core::option::Option<Self> {
 ///         const Variant1: u8 = UnsignedField::Variant1 as u8;
 ///         const Variant2: u8 = UnsignedField::Variant2 as u8;
 ///         const Variant5: u8 = UnsignedField::Variant5 as u8;
 ///
 ///         match value {
-///             Variant1 | Variant2 | Variant5 => ::core::result::Result::Ok(unsafe {
+///             Variant1 | Variant2 | Variant5 => ::core::option::Option::Some(unsafe {
 ///                 *(&value as *const u8 as *const Self)
 ///             }),
-///             _ => ::core::result::Result::Err(value)
+///             _ => ::core::option::Option::None
+///         }
+///     }
+///
+///     /// Returns the discriminant of the variant.
+///     #[inline(always)]
+///     const fn to_repr(self) -> u8 {
+///         self as u8
+///     }
+/// }
+///
+/// impl ::core::convert::TryFrom<u8> for UnsignedField {
+///     type Error = u8;
+///
+///     #[inline(always)]
+///     fn try_from(value: u8) -> ::core::result::Result<
+///         Self, <Self as ::core::convert::TryFrom<u8>>::Error
+///     > {
+///         match Self::from_repr(value) {
+///             ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+///             ::core::option::Option::None => ::core::result::Result::Err(value)
 ///         }
 ///     }
 /// }
@@ -1264,29 +2349,60 @@ pub fn bitfield(
 ///             _ => max.ilog2() as u8 + 1
 ///         }
 ///     }
-/// }
 ///
-/// impl ::core::convert::TryFrom<i8> for SignedField {
-///     type Error = i8;
+///     /// Returns `true` if no variant uses the discriminant `0`, which allows using this type as
+///     /// an `Option` field.
+///     #[doc(hidden)]
+///     #[inline(always)]
+///     const fn _bitfield_is_non_zero() -> bool {
+///         let mut i = 0;
+///         while i < Self::iter().len() {
+///             if Self::iter()[i] as i8 == 0 {
+///                 return false;
+///             }
+///             i += 1;
+///         }
+///
+///         true
+///     }
 ///
+///     /// Returns the variant with the discriminant `value`, or `None` if no variant uses it.
 ///     #[allow(non_upper_case_globals)]
 ///     #[inline(always)]
-///     fn try_from(value: i8) -> ::core::result::Result<
-///         Self, <Self as ::core::convert::TryFrom<i8>>::Error
-///     > {
+///     const fn from_repr(value: i8) -> ::core::option::Option<Self> {
 ///         const VariantMinus1: i8 = SignedField::VariantMinus1 as i8;
 ///         const Variant1: i8 = SignedField::Variant1 as i8;
 ///
 ///         match value {
-///             VariantMinus1 | Variant1 => ::core::result::Result::Ok(unsafe {
+///             VariantMinus1 | Variant1 => ::core::option::Option::Some(unsafe {
 ///                 *(&value as *const i8 as *const Self)
 ///             }),
-///             _ => ::core::result::Result::Err(value)
+///             _ => ::core::option::Option::None
+///         }
+///     }
+///
+///     /// Returns the discriminant of the variant.
+///     #[inline(always)]
+///     const fn to_repr(self) -> i8 {
+///         self as i8
+///     }
+/// }
+///
+/// impl ::core::convert::TryFrom<i8> for SignedField {
+///     type Error = i8;
+///
+///     #[inline(always)]
+///     fn try_from(value: i8) -> ::core::result::Result<
+///         Self, <Self as ::core::convert::TryFrom<i8>>::Error
+///     > {
+///         match Self::from_repr(value) {
+///             ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+///             ::core::option::Option::None => ::core::result::Result::Err(value)
 ///         }
 ///     }
 /// }
 /// ```
-#[proc_macro_derive(Field)]
+#[proc_macro_derive(Field, attributes(group))]
 pub fn field(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     bitfield_impl::field::Field::parse(item.into())
         .map(|field| field.into())
@@ -1376,6 +2492,10 @@ pub fn field(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// const fn iter() -> &'static [Self];
 /// ```
 ///
+/// Additionally a `#[doc(hidden)]` method `_bitfield_flag_name` is generated, which returns the
+/// name of the variant for the generated `Debug` implementation of bit fields. It is prefixed to not
+/// collide with methods of the `enum` itself, f. e. a user defined `name` method.
+///
 /// Example:
 ///
 /// ```rust
@@ -1405,6 +2525,17 @@ pub fn field(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///     const fn iter() -> &'static [Self] {
 ///         &[Self::Flag1, Self::Flag2, Self::Flag5]
 ///     }
+///
+///     /// Returns the name of the enumeration variant.
+///     #[doc(hidden)]
+///     #[inline(always)]
+///     const fn _bitfield_flag_name(&self) -> &'static str {
+///         match self {
+///             Self::Flag1 => ::core::stringify!(Flag1),
+///             Self::Flag2 => ::core::stringify!(Flag2),
+///             Self::Flag5 => ::core::stringify!(Flag5)
+///         }
+///     }
 /// }
 /// ```
 #[proc_macro_derive(Flags)]
@@ -1419,8 +2550,9 @@ pub fn flags(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// for crates which need a way to convert primitive integer types to enum variants, similar to how
 /// enum fields are converted in bit fields generated by this crate.
 ///
-/// Generates a `core::convert::TryFrom<#REPR_TYPE>` implementation with `Error = #REPR_TYPE` for a
-/// C-like `enum`.
+/// Generates the `const fn from_repr(value: #REPR_TYPE) -> core::option::Option<Self>` and
+/// `const fn to_repr(self) -> #REPR_TYPE` methods and a `core::convert::TryFrom<#REPR_TYPE>`
+/// implementation with `Error = #REPR_TYPE` for a C-like `enum`.
 ///
 /// The type must implement `core::clone::Clone` and `core::marker::Copy`.
 ///
@@ -1446,23 +2578,40 @@ pub fn flags(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// #     Variant2,
 /// #     Variant5 = 5
 /// # }
-/// impl ::core::convert::TryFrom<u8> for Field {
-///     type Error = u8;
-///
+/// impl Field {
+///     /// Returns the variant with the discriminant `value`, or `None` if no variant uses it.
 ///     #[allow(non_upper_case_globals)]
 ///     #[inline(always)]
-///     fn try_from(value: u8) -> ::core::result::Result<
-///         Self, <Self as ::core::convert::TryFrom<u8>>::Error
-///     > {
+///     const fn from_repr(value: u8) -> ::core::option::Option<Self> {
 ///         const Variant1: u8 = Field::Variant1 as u8;
 ///         const Variant2: u8 = Field::Variant2 as u8;
 ///         const Variant5: u8 = Field::Variant5 as u8;
 ///
 ///         match value {
-///             Variant1 | Variant2 | Variant5 => ::core::result::Result::Ok(unsafe {
+///             Variant1 | Variant2 | Variant5 => ::core::option::Option::Some(unsafe {
 ///                 *(&value as *const u8 as *const Self)
 ///             }),
-///             _ => ::core::result::Result::Err(value)
+///             _ => ::core::option::Option::None
+///         }
+///     }
+///
+///     /// Returns the discriminant of the variant.
+///     #[inline(always)]
+///     const fn to_repr(self) -> u8 {
+///         self as u8
+///     }
+/// }
+///
+/// impl ::core::convert::TryFrom<u8> for Field {
+///     type Error = u8;
+///
+///     #[inline(always)]
+///     fn try_from(value: u8) -> ::core::result::Result<
+///         Self, <Self as ::core::convert::TryFrom<u8>>::Error
+///     > {
+///         match Self::from_repr(value) {
+///             ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+///             ::core::option::Option::None => ::core::result::Result::Err(value)
 ///         }
 ///     }
 /// }
@@ -1470,7 +2619,7 @@ pub fn flags(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 #[proc_macro_derive(FromPrimitive)]
 pub fn from_primitive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     bitfield_impl::enumeration::Enumeration::parse(item.into())
-        .map(|enumeration| enumeration.generate_try_from())
+        .map(|enumeration| enumeration.into())
         .unwrap_or_else(|error| error.to_compile_error())
         .into()
 }
\ No newline at end of file
@@ -1,9 +1,7 @@
-extern crate alloc;
-
 /// When used as a field in a bit field, the field can only contain one of the enum variants.
 /// All variants can be represented in 2 bits, but the variant which maps to `0` is non-existent,
 /// so the field getter will return `Err(0)` right after initialization of the bit field.
-#[derive(Clone, Copy, Debug, bitfield::Field)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, bitfield::Field)]
 #[repr(u8)]
 enum Field {
     F1 = 1,
@@ -760,6 +758,36 @@ mod tests {
         assert_print_eq!(field, "{:?}", 0, "BitField { F0: false, F1: false, F3: false }");
     }
 
+    #[test]
+    fn debug_flags_user_defined_name() {
+        #[derive(Copy, Clone, Debug, bitfield::Flags)]
+        #[repr(u8)]
+        enum Named {
+            A,
+            B = 2
+        }
+
+        impl Named {
+            // Must not collide with the methods generated by `bitfield::Flags`.
+            fn name(&self) -> &'static str {
+                match self {
+                    Self::A => "a",
+                    Self::B => "b"
+                }
+            }
+        }
+
+        #[bitfield::bitfield(8)]
+        #[derive(Debug)]
+        struct BitField {
+            named: Named
+        }
+
+        let field = BitField::new().set_named(Named::B, true);
+        assert_print_eq!(field, "{:?}", 1 << 2, "BitField { named: Named { A: false, B: true } }");
+        assert_eq!(Named::B.name(), "b");
+    }
+
     #[test]
     fn display_named_field() {
         #[bitfield::bitfield(8)]
@@ -947,6 +975,44 @@ mod tests {
         assert_print_eq!(field, "{}", 0, "-");
     }
 
+    #[test]
+    fn display_named_template() {
+        #[bitfield::bitfield(16)]
+        #[derive(Display)]
+        #[display("{field}: {{{flags}}} {integer}", separator = ", ", empty = "none")]
+        struct BitField {
+            flags: Flags,
+            #[field(4, 2)] field: Field,
+            #[field(size = 3)] integer: u8
+        }
+
+        let mut field = BitField::new();
+        assert_print_eq!(field, "{}", 0, "Err(0): {none} 0");
+
+        field = field.set_field(Field::F2).set_flags(Flags::F0, true).set_flags(Flags::F3, true);
+        assert_print_eq!(field, "{}", 2 << 4 | 1 << 3 | 1 << 0, "F2: {F0, F3} 0");
+
+        field = field.set_integer(5).unwrap().set_flags(Flags::F0, false);
+        assert_print_eq!(field, "{}", 5 << 6 | 2 << 4 | 1 << 3, "F2: {F3} 5");
+    }
+
+    #[test]
+    fn display_named_flags_separator() {
+        #[bitfield::bitfield(8)]
+        #[derive(Display)]
+        #[display(separator = "+", empty = "")]
+        struct BitField {
+            flags: Flags,
+            flags2: Flags2
+        }
+
+        let mut field = BitField::new();
+        assert_print_eq!(field, "{}", 0, "");
+
+        field = field.set_flags(Flags::F0, true).set_flags2(Flags2::G4, true);
+        assert_print_eq!(field, "{}", 1 << 0 | 1 << 4, "bitfield::Flags::F0+bitfield::Flags2::G4");
+    }
+
     #[test]
     fn display_tuple_field() {
         #[bitfield::bitfield(8)]
@@ -1143,6 +1209,253 @@ mod tests {
         assert_print_eq!(field, "{}", 0, "-");
     }
 
+    #[test]
+    fn from_str_named_flags_multi() {
+        #[bitfield::bitfield(8)]
+        #[derive(Debug, Display)]
+        struct BitField {
+            flags: Flags,
+            flags2: Flags2
+        }
+
+        // Round trip the generated format.
+        let field = BitField::new().set_flags(Flags::F0, true).set_flags2(Flags2::G7, true);
+        let parsed: BitField = format!("{}", field).parse().unwrap();
+        assert_eq!(parsed.0, 1 << 0 | 1 << 7);
+        assert_eq!("-".parse::<BitField>().unwrap().0, 0);
+
+        // Whitespace around the separator is optional, numeric literals set unknown bits.
+        let parsed: BitField = "bitfield::Flags2::G5|0x04 | 0b10".parse().unwrap();
+        assert_eq!(parsed.0, 1 << 5 | 1 << 2 | 1 << 1);
+
+        // Flags must be prefixed with their type.
+        let source = "bitfield::Flags::F0 | F1";
+        let error = source.parse::<BitField>().unwrap_err();
+        assert_eq!(error.token(), "F1");
+        assert_eq!(error.position(), 22);
+        assert_eq!(format!("{}", error), "invalid token `F1` at position 22");
+
+        let source = "bitfield::Flags::F0 | 0x100";
+        let error = source.parse::<BitField>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("0x100", 22));
+    }
+
+    #[test]
+    fn from_str_named_template() {
+        #[bitfield::bitfield(16)]
+        #[derive(Debug, Display)]
+        #[display("{field}: {{{flags}}} {integer}", separator = ", ", empty = "none")]
+        struct BitField {
+            flags: Flags,
+            #[field(4, 2)] field: Field,
+            #[field(size = 3)] integer: u8
+        }
+
+        for field in [
+            BitField::new(),
+            BitField::new().set_field(Field::F2).set_flags(Flags::F0, true).set_flags(Flags::F3, true),
+            BitField::new().set_field(Field::F3).set_integer(5).unwrap()
+        ] {
+            let parsed: BitField = format!("{}", field).parse().unwrap();
+            assert_eq!(parsed.0, field.0);
+        }
+
+        let source = "F2: {F0, F4} 0";
+        let error = source.parse::<BitField>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("F4", 9));
+
+        let source = "F2: {F0} 8";
+        let error = source.parse::<BitField>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("8", 9));
+
+        let source = "F2 {F0} 1";
+        let error = source.parse::<BitField>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("F2 {F0} 1", 0));
+
+        let source = "F2: {F0";
+        let error = source.parse::<BitField>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("F0", 5));
+    }
+
+    #[test]
+    fn from_str_named_template_shared_characters() {
+        // The separators start with a sign or a character of the variant names.
+        #[bitfield::bitfield(32)]
+        #[derive(Debug, Display)]
+        #[display("{a}-{b}+{field}F{c}")]
+        struct BitField {
+            #[field(size = 8)] a: i8,
+            #[field(size = 8)] b: i8,
+            #[field(size = 2)] field: Field,
+            #[field(size = 8)] c: i8
+        }
+
+        for (a, b, c) in [(-1, -2, -3), (0, 0, 0), (12, -128, 127), (-1, 1, -100)] {
+            let field = BitField::new().set_a(a).set_b(b).set_field(Field::F2).set_c(c);
+            let parsed: BitField = format!("{}", field).parse().unwrap();
+            assert_eq!(parsed.0, field.0);
+            assert_eq!((parsed.a(), parsed.b(), parsed.c()), (a, b, c));
+        }
+
+        let field = BitField::new().set_a(-1).set_b(-2).set_field(Field::F2);
+        assert_eq!(format!("{}", field), "-1--2+F2F0");
+
+        let source = "-1-x2+F2F0";
+        let error = source.parse::<BitField>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("", 3));
+
+        let source = "-1--2+F2F";
+        let error = source.parse::<BitField>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("", 9));
+    }
+
+    #[test]
+    fn from_str_named_template_flags_separator() {
+        // The text after the flags also appears in their separator.
+        #[bitfield::bitfield(16)]
+        #[derive(Debug, Display)]
+        #[display("{flags} {integer}")]
+        struct Space {
+            flags: Flags,
+            #[field(4, 3)] integer: u8
+        }
+
+        let field = Space::new().set_flags(Flags::F0, true).set_flags(Flags::F3, true).set_integer(2).unwrap();
+        assert_eq!(format!("{}", field), "F0 | F3 2");
+
+        #[bitfield::bitfield(16)]
+        #[derive(Debug, Display)]
+        #[display("{flags}, {integer}", separator = ", ")]
+        struct Comma {
+            flags: Flags,
+            #[field(4, 3)] integer: u8
+        }
+
+        let field = Comma::new().set_flags(Flags::F0, true).set_flags(Flags::F3, true).set_integer(2).unwrap();
+        assert_eq!(format!("{}", field), "F0, F3, 2");
+
+        for (flags, integer) in [(&[][..], 0), (&[Flags::F0][..], 7), (&[Flags::F0, Flags::F1, Flags::F3][..], 2)] {
+            let mut space = Space::new().set_integer(integer).unwrap();
+            let mut comma = Comma::new().set_integer(integer).unwrap();
+
+            for flag in flags {
+                space = space.set_flags(*flag, true);
+                comma = comma.set_flags(*flag, true);
+            }
+
+            assert_eq!(format!("{}", space).parse::<Space>().unwrap().0, space.0);
+            assert_eq!(format!("{}", comma).parse::<Comma>().unwrap().0, comma.0);
+        }
+
+        let source = "F0 | F3 8";
+        let error = source.parse::<Space>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("8", 8));
+
+        let source = "F0, F3";
+        let error = source.parse::<Comma>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("F3", 4));
+    }
+
+    #[test]
+    fn from_str_tuple_field() {
+        #[bitfield::bitfield(8)]
+        #[derive(Debug, Display)]
+        struct BitField(#[field(3, 2)] Field);
+
+        assert_eq!("F3".parse::<BitField>().unwrap().0, 3 << 3);
+        assert_eq!("Err(0)".parse::<BitField>().unwrap().0, 0);
+
+        let source = "Err(4)";
+        let error = source.parse::<BitField>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("Err(4)", 0));
+
+        #[bitfield::bitfield(8)]
+        #[derive(Debug, Display)]
+        struct Primitive(#[field(4, 2)] u8);
+
+        assert_eq!("3".parse::<Primitive>().unwrap().0, 3 << 4);
+        assert_eq!("4".parse::<Primitive>().unwrap_err().position(), 0);
+
+        #[bitfield::bitfield(8)]
+        #[derive(Debug, Display)]
+        struct Bool(#[field(bit = 2)] bool);
+
+        assert_eq!("true".parse::<Bool>().unwrap().0, 1 << 2);
+        assert_eq!("1".parse::<Bool>().unwrap_err().position(), 0);
+    }
+
+    #[test]
+    fn from_str_tuple_flags() {
+        #[bitfield::bitfield(8)]
+        #[derive(Debug, Display)]
+        struct BitField(Flags);
+
+        assert_eq!("F0 | F3".parse::<BitField>().unwrap().0, 1 << 0 | 1 << 3);
+        assert_eq!("F0 | 4".parse::<BitField>().unwrap().0, 1 << 0 | 1 << 2);
+        assert_eq!("".parse::<BitField>().unwrap_err().position(), 0);
+
+        #[bitfield::bitfield(NonZero8)]
+        #[derive(Debug, Display)]
+        struct NonZero(Flags);
+
+        assert_eq!("F1".parse::<NonZero>().unwrap().0.get(), 1 << 1);
+
+        let source = "-";
+        let error = source.parse::<NonZero>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("-", 0));
+
+        // The token is truncated to 32 bytes, at a character boundary.
+        let source = "F0 | F1 | Flag with a name longer than abä";
+        let error = source.parse::<BitField>().unwrap_err();
+        assert_eq!((error.token(), error.position()), ("Flag with a name longer than ab", 10));
+    }
+
+    #[test]
+    fn radix() {
+        #[bitfield::bitfield(16)]
+        #[derive(Binary, LowerHex, Octal, UpperHex)]
+        struct BitField {
+            #[field(size = 4)] field: u8,
+            flags: Flags2,
+            #[field(8, 3)]     field2: Field
+        }
+
+        let field = BitField::new().set_field(0b1010).unwrap().set_flags(Flags2::G7, true);
+        assert_print_eq!(field, "{:x}", 0b1000_1010, "8a");
+        assert_print_eq!(field, "{:#06X}", 0b1000_1010, "0x008A");
+        assert_print_eq!(field, "{:o}", 0b1000_1010, "212");
+        assert_print_eq!(field, "{:b}", 0b1000_1010, "10001010");
+        assert_print_eq!(field, "{:#b}", 0b1000_1010, "0b00000|000|1|0|0|0|1010");
+
+        #[bitfield::bitfield(NonZero8)]
+        #[derive(Binary, LowerHex)]
+        struct NonZero(Flags);
+
+        let field = NonZero(core::num::NonZeroU8::new(0b1000_1001).unwrap());
+        assert_eq!(format!("{:x}", field), "89");
+        assert_eq!(format!("{:#b}", field), "0b1000|1|0|0|1");
+
+        // Every flag is a group of its own, unused bits are grouped between the entries.
+        #[derive(Copy, Clone, Debug, bitfield::Flags)]
+        #[repr(u8)]
+        enum Outer {
+            Low,
+            High = 14
+        }
+
+        #[bitfield::bitfield(16)]
+        #[derive(Binary)]
+        struct Sparse {
+            outer: Outer,
+            #[field(1, 4)] first: Field,
+            #[field(5, 4)] second: Field,
+            #[field(bit = 12)] third: bool
+        }
+
+        let field = Sparse(0b0001_0001_0100_1011);
+        assert_eq!(format!("{:#b}", field), "0b0|0|0|1|000|1010|0101|1");
+    }
+
     #[test]
     fn flags() {
         #[bitfield::bitfield(8)]
@@ -1233,6 +1546,686 @@ mod tests {
         assert!(!field.flags2_all());
     }
 
+    #[test]
+    fn in_place() {
+        #[bitfield::bitfield(16)]
+        struct BitField {
+            flags: Flags,
+            #[field(4, 2)] field: Field,
+            #[field(size = 3)] integer: u8,
+            #[field(bit = 9)] boolean: bool
+        }
+
+        const FIELD: BitField = {
+            let mut field = BitField::new();
+            field.insert_flags(Flags::F1);
+            field.set_field_in_place(Field::F3);
+            field
+        };
+        assert_eq!(FIELD.0, 1 << 1 | 3 << 4);
+
+        const VALUE: Result<Field, u8> = FIELD.field();
+        assert_eq!(VALUE, Ok(Field::F3));
+
+        let mut field = FIELD;
+        field.insert_flags(Flags::F0);
+        field.remove_flags(Flags::F1);
+        field.toggle_flags(Flags::F3);
+        field.set_flags_in_place(Flags::F3, false);
+        assert_eq!(field.0, 1 << 0 | 3 << 4);
+
+        assert_eq!(field.try_set_integer_in_place(7), Ok(()));
+        assert_eq!(field.try_set_integer_in_place(8), Err(8));
+        assert_eq!(field.integer(), 7);
+
+        field.set_boolean_in_place(true);
+        field.invert_boolean_in_place();
+        assert!(!field.boolean());
+
+        #[bitfield::bitfield(NonZero8)]
+        struct NonZero(Flags);
+
+        let mut field = NonZero(core::num::NonZeroU8::new(1 << 0).unwrap());
+        field.insert(Flags::F1);
+        assert!(field.try_remove(Flags::F0).is_ok());
+        assert!(field.try_toggle(Flags::F1).is_err());
+        assert!(field.try_set_in_place(Flags::F1, false).is_err());
+        assert_eq!(field.0.get(), 1 << 1);
+    }
+
+    #[test]
+    fn naming() {
+        #[bitfield::bitfield(16, naming = "with")]
+        struct BitField {
+            #[field(get = has_flag)] flags: Flags,
+            #[field(4, 2, get = kind, set = with_kind)] field: Field,
+            #[field(size = 3)] integer: u8,
+            #[field(bit = 9, invert = flip)] boolean: bool
+        }
+
+        let field = BitField::new()
+            .with_flags(Flags::F1, true)
+            .with_kind(Field::F3)
+            .with_integer(7).unwrap()
+            .with_boolean(true)
+            .flip();
+        assert!(field.has_flag(Flags::F1));
+        assert!(field.has_flag_any());
+        assert!(matches!(field.kind(), Ok(Field::F3)));
+        assert_eq!(field.integer(), 7);
+        assert!(!field.boolean());
+
+        let mut field = field.with_flags_all();
+        field.remove_flags(Flags::F0);
+        field.with_kind_in_place(Field::F1);
+        assert_eq!(field.try_with_integer_in_place(8), Err(8));
+        assert_eq!(field.0, 1 << 1 | 1 << 3 | 1 << 4 | 7 << 6);
+
+        #[bitfield::bitfield(8, naming = "get_set")]
+        struct Named {
+            #[field(size = 2)] field: Field,
+            flags: Flags2
+        }
+
+        let field = Named::new().set_field(Field::F2).set_flags(Flags2::G5, true);
+        assert!(matches!(field.get_field(), Ok(Field::F2)));
+        assert!(field.has_flags(Flags2::G5));
+        assert_eq!(Named::has_flags_mask(), 1 << 4 | 1 << 5 | 1 << 7);
+
+        #[bitfield::bitfield(8, naming = "with")]
+        struct Tuple(Flags);
+
+        let field = Tuple::new().with(Flags::F3, true).invert(Flags::F0);
+        assert!(field.has(Flags::F0) && field.has(Flags::F3));
+    }
+
+    #[test]
+    fn access() {
+        #[bitfield::bitfield(16)]
+        #[derive(Debug)]
+        struct BitField {
+            #[field(access = w1c)] pending: Flags,
+            #[field(access = w1c)] errors: Flags2,
+            #[field(8, 2, access = ro)] state: Field,
+            #[field(bit = 10, access = wo)] reset: bool
+        }
+
+        let field = BitField(1 << 0 | 1 << 1 | 1 << 4 | 2 << 8 | 1 << 10);
+        assert!(field.pending(Flags::F1));
+        assert!(field.errors_any());
+        assert!(matches!(field.state(), Ok(Field::F2)));
+
+        // Only the cleared flag is written back as set, other pending flags stay untouched.
+        let clear = field.clear_pending(Flags::F1);
+        assert_eq!(clear.0, 1 << 1 | 2 << 8 | 1 << 10);
+        assert_eq!(field.clear_errors(Flags2::G5).0, 1 << 5 | 2 << 8 | 1 << 10);
+
+        let field = field.set_reset(false);
+        assert_print_eq!(
+            field, "{:?}", 1 << 0 | 1 << 1 | 1 << 4 | 2 << 8,
+            "BitField { pending: Flags { F0: true, F1: true, F3: false }, errors: Flags2 { G4: true, G5: false, G7: false }, state: F2 }"
+        );
+    }
+
+    #[test]
+    fn register() {
+        #[bitfield::bitfield(16, register)]
+        #[derive(Debug)]
+        struct BitField {
+            #[field(access = w1c)] pending: Flags,
+            flags: Flags2,
+            #[field(8, 3)] integer: u8,
+            #[field(bit = 11)] enabled: bool
+        }
+
+        static mut REGISTER: u16 = 0;
+        let register = unsafe { BitFieldRegister::new(core::ptr::addr_of_mut!(REGISTER)) };
+        assert_eq!(register.as_ptr(), core::ptr::addr_of_mut!(REGISTER));
+
+        register.write(BitField::new().set_integer(5).unwrap());
+        assert_eq!(register.read().0, 5 << 8);
+
+        register.modify(|r| r.set_enabled(true));
+        register.insert_flags(Flags2::G5);
+        register.toggle_flags(Flags2::G7);
+        register.invert_enabled();
+        assert_eq!(register.read().0, 1 << 5 | 1 << 7 | 5 << 8);
+
+        assert_eq!(register.try_set_integer(8), Err(8));
+        assert_eq!(register.try_set_integer(2), Ok(()));
+        register.set_flags(Flags2::G7, false);
+        register.set_enabled(true);
+        assert_eq!(register.read().0, 1 << 5 | 2 << 8 | 1 << 11);
+
+        // Pending write-1-to-clear flags are not cleared by writing back other entries.
+        unsafe { REGISTER |= 1 << 0 | 1 << 3 };
+        register.remove_flags(Flags2::G5);
+        assert_eq!(unsafe { REGISTER }, 2 << 8 | 1 << 11);
+
+        unsafe { REGISTER |= 1 << 0 | 1 << 3 };
+        register.clear_pending(Flags::F3);
+        assert_eq!(unsafe { REGISTER }, 1 << 3 | 2 << 8 | 1 << 11);
+    }
+
+    #[test]
+    fn atomic() {
+        use core::sync::atomic::Ordering;
+
+        #[bitfield::bitfield(size, atomic)]
+        #[derive(Debug)]
+        struct BitField {
+            flags: Flags,
+            #[field(8, 3)] integer: u8
+        }
+
+        let field = std::sync::Arc::new(AtomicBitField::new(BitField::new()));
+        let threads: Vec<_> = [Flags::F0, Flags::F1, Flags::F3].into_iter().map(|flag| {
+            let field = field.clone();
+            std::thread::spawn(move || { field.fetch_set_flags(flag, Ordering::Relaxed); })
+        }).collect();
+        threads.into_iter().for_each(|thread| thread.join().unwrap());
+        assert_eq!(field.load(Ordering::Relaxed).0, 0b1011);
+
+        assert_eq!(field.fetch_clear_flags(Flags::F1, Ordering::Relaxed).0, 0b1011);
+        assert_eq!(field.fetch_update(
+            Ordering::Relaxed, Ordering::Relaxed, |field| field.set_integer(5)
+        ).unwrap().0, 0b1001);
+        assert_eq!(field.load(Ordering::Relaxed).integer(), 5);
+
+        let current = field.load(Ordering::Relaxed);
+        assert!(field.compare_exchange(
+            BitField::new(), BitField::new(), Ordering::Relaxed, Ordering::Relaxed
+        ).is_err());
+        assert!(field.compare_exchange(
+            current, BitField::new(), Ordering::Relaxed, Ordering::Relaxed
+        ).is_ok());
+        assert_eq!(field.swap(BitField(1), Ordering::Relaxed).0, 0);
+
+        #[bitfield::bitfield(NonZero8, atomic)]
+        struct NonZero(Flags);
+
+        let field = AtomicNonZero::new(NonZero(core::num::NonZeroU8::new(1).unwrap()));
+        assert!(field.try_fetch_clear_flag(Flags::F0, Ordering::Relaxed, Ordering::Relaxed).is_err());
+        field.fetch_set_flag(Flags::F3, Ordering::Relaxed);
+        assert!(field.try_fetch_clear_flag(Flags::F0, Ordering::Relaxed, Ordering::Relaxed).is_ok());
+        assert_eq!(field.into_inner().0.get(), 0b1000);
+    }
+
+    #[test]
+    fn view() {
+        #[bitfield::bitfield(32, view = "be")]
+        struct BitField {
+            flags: Flags,
+            #[field(8, 2)] field: Field,
+            #[field(16, 7)] integer: u8,
+            #[field(bit = 31, access = ro)] ready: bool
+        }
+
+        let bytes = [0x80, 0x12, 0x03, 0x09, 0xff];
+        let view = BitFieldView::new(&bytes).unwrap();
+        assert!(view.flags(Flags::F0));
+        assert!(view.flags(Flags::F3));
+        assert!(!view.flags_all());
+        assert!(matches!(view.field(), Ok(Field::F3)));
+        assert_eq!(view.integer(), 0x12);
+        assert!(view.ready());
+        assert_eq!(view.into_owned().0, 0x8012_0309);
+
+        assert!(BitFieldView::new(&bytes[..3]).is_none());
+
+        // Views of larger byte slices start at an offset.
+        let header = [0x00, 0x80, 0x12, 0x03, 0x09];
+        assert_eq!(BitFieldView::new_at(&header, 1).unwrap().into_owned().0, 0x8012_0309);
+        assert!(BitFieldView::new_at(&header, 2).is_none());
+        assert!(BitFieldView::new_at(&header, 6).is_none());
+
+        let mut bytes = [0u8; 4];
+        let mut view = BitFieldViewMut::new(&mut bytes).unwrap();
+        view.insert_flags(Flags::F1);
+        view.set_field(Field::F2);
+        assert_eq!(view.try_set_integer(0x80), Err(0x80));
+        assert_eq!(view.try_set_integer(0x3c), Ok(()));
+        assert!(matches!(view.field(), Ok(Field::F2)));
+        view.write(view.read().set_flags(Flags::F3, true));
+        assert_eq!(bytes, [0x00, 0x3c, 0x02, 0x0a]);
+
+        #[bitfield::bitfield(16, view = "le")]
+        struct Little(Flags);
+
+        let mut bytes = [0u8; 3];
+        LittleViewMut::new(&mut bytes[1..]).unwrap().toggle(Flags::F3);
+        assert_eq!(bytes, [0x00, 0x08, 0x00]);
+        LittleViewMut::new_at(&mut bytes, 1).unwrap().toggle(Flags::F0);
+        assert_eq!(bytes, [0x00, 0x09, 0x00]);
+    }
+
+    #[test]
+    fn c_header() {
+        #[bitfield::bitfield(32, c_header)]
+        struct BitField {
+            #[field(8, 2)] field: Field,
+            #[field(bit = 31, access = ro)] ready: bool,
+            #[reserved(bit = 16, size = 8)] _rsvd0: _,
+            flags: Flags
+        }
+
+        assert!(BitField::C_HEADER.starts_with("#include <stdbool.h>\n#include <stdint.h>\n\n/* BitField */\n"));
+        assert!(BitField::C_HEADER.contains("#define BIT_FIELD_FIELD_SHIFT 8\n#define BIT_FIELD_FIELD_MASK 0x300u\n"));
+        assert!(BitField::C_HEADER.contains("static inline uint8_t bit_field_get_field(uint32_t value)"));
+        assert!(BitField::C_HEADER.contains("static inline bool bit_field_get_ready(uint32_t value)"));
+        assert!(!BitField::C_HEADER.contains("bit_field_set_ready"));
+        assert!(BitField::C_HEADER.contains("static inline uint32_t bit_field_set_flags(uint32_t value, unsigned flag, bool set)"));
+        assert!(BitField::C_HEADER.contains("#define BIT_FIELD_RSVD0_SHIFT 16\n#define BIT_FIELD_RSVD0_MASK 0xff0000u\n"));
+        assert!(!BitField::C_HEADER.contains("bit_field_get_rsvd0"));
+    }
+
+    #[test]
+    fn exhaustive() {
+        // Every bit is used by a field or a flag.
+        #[bitfield::bitfield(8, exhaustive)]
+        struct BitField {
+            flags: Flags,
+            #[field(2, 1)] enabled: bool,
+            #[field(4, 4)] integer: u8
+        }
+
+        let field = BitField::new().set_integer(0xf).unwrap().set_flags(Flags::F3, true);
+        assert_eq!(field.0, 0xf8);
+
+        #[bitfield::bitfield(16, exhaustive)]
+        struct Fields { low: u8, high: u8 }
+
+        assert_eq!(Fields::new().set_high(1).0, 0x100);
+    }
+
+    #[test]
+    fn reserved() {
+        #[bitfield::bitfield(16, exhaustive)]
+        #[derive(Debug)]
+        struct BitField {
+            #[field(size = 4)] integer: u8,
+            #[reserved(4)] _rsvd0: _,
+            #[field(size = 2)] field: Field2,
+            #[reserved(bit = 10, size = 2, policy = zero)] _rsvd1: _,
+            #[reserved(4)] _rsvd2: _
+        }
+
+        assert_eq!(format!("{:?}", BitField::new()), "BitField { integer: 0, field: F0 }");
+
+        // `_rsvd0` is preserved, `_rsvd1` is zeroed.
+        let field = BitField(0x0cf0).set_integer(3).unwrap();
+        assert_eq!(field.0, 0x00f3);
+        assert_eq!(format!("{:?}", field), "BitField { integer: 3, _rsvd0: 0xf, field: F0 }");
+        assert_eq!(field.set_field(Field2::F1).0, 0x01f3);
+
+        let field = BitField(0x0c00);
+        assert_eq!(format!("{:?}", field), "BitField { integer: 0, field: F0, _rsvd1: 0x3 }");
+        assert_eq!(field.set_integer(2).unwrap().0, 0x0002);
+    }
+
+    #[test]
+    fn generics() {
+        #[derive(Clone, Copy, Debug)]
+        struct Kernel;
+        #[derive(Clone, Copy, Debug)]
+        struct User;
+
+        trait Privilege { const LEVEL: u8; }
+        impl Privilege for Kernel { const LEVEL: u8 = 0; }
+        impl Privilege for User { const LEVEL: u8 = 3; }
+
+        #[bitfield::bitfield(16, register, atomic, view = "le")]
+        #[derive(Clone, Copy, Debug)]
+        struct BitField<'a, P, const N: usize> where P: Privilege + 'static {
+            flags: Flags,
+            #[field(4, 2)] field: Field2,
+            #[field(8, 8)] integer: u8
+        }
+
+        impl<'a, P: Privilege, const N: usize> BitField<'a, P, N> {
+            fn level(&self) -> u8 { P::LEVEL + N as u8 }
+        }
+
+        assert_eq!(core::mem::size_of::<BitField<'static, User, 0>>(), 2);
+
+        let mut field = BitField::<'static, User, 1>::new().set_field(Field2::F1).set_integer(0x12);
+        field += Flags::F3;
+        assert_eq!(field.0, 0x1218);
+        assert_eq!(field.level(), 4);
+        assert_eq!(BitField::<'static, Kernel, 0>::new().level(), 0);
+        assert_eq!(
+            format!("{:?}", field),
+            "BitField { flags: Flags { F0: false, F1: false, F3: true }, field: F1, integer: 18 }"
+        );
+
+        let atomic = AtomicBitField::new(field);
+        atomic.fetch_set_flags(Flags::F0, core::sync::atomic::Ordering::Relaxed);
+        assert_eq!(atomic.into_inner().0, 0x1219);
+
+        let mut value = 0u16;
+        let register = unsafe { BitFieldRegister::<'static, User, 1>::new(&mut value) };
+        register.write(field);
+        register.set_integer(0x34);
+        assert_eq!(value, 0x3418);
+
+        let bytes = value.to_le_bytes();
+        let view = BitFieldView::<'_, 'static, User, 1>::new(&bytes).unwrap();
+        let copy = view;
+        assert_eq!(view.integer(), 0x34);
+        assert_eq!(copy.into_owned().0, 0x3418);
+
+        // Tuple structs put the `where` clause after the entry.
+        #[bitfield::bitfield(8)]
+        struct Tuple<T>(Flags) where T: Copy;
+
+        assert!(Tuple::<u8>::new().set(Flags::F1, true).has(Flags::F1));
+    }
+
+    #[test]
+    fn cfg() {
+        // `all()` is always and `any()` is never enabled.
+        #[bitfield::bitfield(16, exhaustive)]
+        #[derive(Binary, Debug)]
+        struct BitField {
+            #[cfg_attr(all(), field(12, 4))]
+            #[field(size = 4)] integer: u8,
+            #[cfg(any())]
+            #[field(0, 8)] missing: u8,
+            #[field(0, 2)] field: Field2,
+            #[reserved(2, 10)] _rsvd: _
+        }
+
+        let field = BitField::new().set_integer(3).unwrap().set_field(Field2::F1);
+        assert_eq!(field.0, 0x3001);
+        assert_eq!(format!("{:?}", field), "BitField { integer: 3, field: F1 }");
+        assert_eq!(format!("{:#b}", field), "0b0011|0000000000|01");
+    }
+
+    #[test]
+    fn optional() {
+        use core::num::NonZeroU8;
+
+        #[bitfield::bitfield(32)]
+        #[derive(Debug, Display)]
+        #[display("{field} {complete} {integer} {full}")]
+        struct BitField {
+            #[field(size = 3)] field: Option<Field>,
+            #[field(size = 2, complete)] complete: Option<Field>,
+            #[field(size = 3)] integer: Option<NonZeroU8>,
+            full: core::option::Option<NonZeroU8>
+        }
+
+        let field = BitField::new();
+        assert_eq!(field.field(), Ok(None));
+        assert_eq!(field.complete(), None);
+        assert_eq!(field.integer(), None);
+        assert_eq!(field.full(), None);
+        assert_eq!(format!("{:?}", field), "BitField { field: None, complete: None, integer: None, full: None }");
+
+        let field = field
+            .set_field(Some(Field::F2))
+            .set_complete(Some(Field::F3))
+            .set_integer(NonZeroU8::new(7)).unwrap()
+            .set_full(NonZeroU8::new(255));
+        assert_eq!(field.0, 0xFF_FA);
+        assert_eq!(field.field(), Ok(Some(Field::F2)));
+        assert_eq!(field.complete(), Some(Field::F3));
+        assert_eq!(field.integer(), NonZeroU8::new(7));
+        assert_eq!(field.full(), NonZeroU8::new(255));
+        assert!(field.set_integer(NonZeroU8::new(8)).is_none());
+        assert_eq!(field.to_string(), "Some(F2) Some(F3) Some(7) Some(255)");
+        assert_eq!(field.to_string().parse::<BitField>().unwrap().0, field.0);
+
+        let mut field = field;
+        field.set_field_in_place(None);
+        field.set_complete_in_place(None);
+        assert_eq!(field.try_set_integer_in_place(NonZeroU8::new(8)), Err(NonZeroU8::new(8)));
+        field.try_set_integer_in_place(None).unwrap();
+        assert_eq!(field.0, 0xFF_00);
+        assert_eq!(field.to_string(), "None None None Some(255)");
+        assert_eq!(field.to_string().parse::<BitField>().unwrap().0, field.0);
+
+        const FIELD: BitField = BitField(5 | 2 << 3);
+        const VALUE: Result<Option<Field>, u8> = FIELD.field();
+        const COMPLETE: Option<Field> = FIELD.complete();
+        assert_eq!(VALUE, Err(5));
+        assert_eq!(COMPLETE, Some(Field::F2));
+
+        let field = BitField(5);
+        assert_eq!(field.field(), Err(5));
+        assert_eq!(field.to_string(), "Err(5) None None None");
+        assert_eq!(field.to_string().parse::<BitField>().unwrap().0, field.0);
+    }
+
+    #[test]
+    fn optional_user_defined_is_non_zero() {
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, bitfield::Field)]
+        #[repr(u8)]
+        enum Level {
+            Low = 1,
+            High
+        }
+
+        impl Level {
+            // Must not collide with the methods generated by `bitfield::Field`.
+            fn is_non_zero(self) -> bool {
+                self as u8 != 0
+            }
+        }
+
+        #[bitfield::bitfield(16)]
+        struct BitField {
+            #[field(size = 2)] level: Option<Level>
+        }
+
+        let field = BitField::new().set_level(Some(Level::High));
+        assert_eq!(field.level(), Ok(Some(Level::High)));
+        assert!(Level::Low.is_non_zero());
+    }
+
+    #[test]
+    fn keys() {
+        #[bitfield::bitfield(32, keys)]
+        #[derive(Debug)]
+        struct BitField {
+            #[field(size = 2)] first: Field,
+            #[field(size = 2, complete)] second: Option<Field>,
+            #[field(size = 2)] optional: Option<Field>,
+            #[field(size = 4)] integer: u8,
+            #[field(size = 8)] full: u8,
+            #[field(access = ro)] read_only: bool,
+            #[field(size = 2, access = wo)] write_only: Field,
+            #[cfg(any())]
+            missing: bool
+        }
+
+        let mut field = BitField::new()
+            + BitFieldFirst(Field::F1)
+            + BitFieldSecond(Some(Field::F2))
+            + BitFieldOptional(Some(Field::F3));
+        field += BitFieldFull(0xAB);
+        assert_eq!(field.0, 0x2_AC_39);
+        assert_eq!(field.get::<BitFieldFirst>(), Ok(Field::F1));
+        assert_eq!(field.get::<BitFieldSecond>(), Some(Field::F2));
+        assert_eq!(field.get::<BitFieldOptional>(), Ok(Some(Field::F3)));
+        assert_eq!(field.get::<BitFieldFull>(), 0xAB);
+
+        let field = field.with::<BitFieldFirst>(Field::F3).with::<BitFieldOptional>(None);
+        assert_eq!(field.first(), Ok(Field::F3));
+        assert_eq!(field.optional(), Ok(None));
+
+        // Setters of partial fields can fail.
+        let field = field.with::<BitFieldInteger>(0xF).unwrap();
+        assert_eq!(field.get::<BitFieldInteger>(), 0xF);
+        assert!(field.with::<BitFieldInteger>(0x10).is_none());
+
+        // Read-only fields can only be read, write-only fields only be written.
+        let field = BitField(field.0 | 1 << 18);
+        assert!(field.get::<BitFieldReadOnly>());
+        let field = field.with::<BitFieldWriteOnly>(Field::F3) + BitFieldWriteOnly(Field::F2);
+        assert_eq!(field.0 >> 19 & 0b11, 2);
+
+        /// Gets any field of a `BitField` with a complete getter.
+        fn get<K: BitFieldKey<Output = Option<Field>>>(field: &BitField) -> Option<Field> {
+            field.get::<K>()
+        }
+
+        assert_eq!(get::<BitFieldSecond>(&field), Some(Field::F2));
+
+        #[bitfield::bitfield(8, keys)]
+        struct Generic<'a, T> where T: 'a {
+            #[field(size = 2)] first: Field,
+            #[field(size = 2)] second: Field
+        }
+
+        let field = Generic::<'static, u8>::new() + GenericSecond(Field::F2);
+        assert_eq!(field.get::<GenericSecond>(), Ok(Field::F2));
+        assert_eq!(field.with::<GenericFirst>(Field::F1).0, 0b10_01);
+    }
+
+    #[test]
+    fn parts() {
+        #[bitfield::bitfield(16, parts)]
+        #[derive(Debug)]
+        struct BitField {
+            #[field(8, 2)] field: Field,
+            #[field(size = 2, complete)] optional: Option<Field>,
+            #[field(size = 3)] integer: u8,
+            #[field(access = ro)] read_only: bool,
+            #[cfg(any())]
+            #[field(0, 1)] missing: bool,
+            flags: Flags2
+        }
+
+        let field = BitField::new()
+            .set_field(Field::F3)
+            .set_optional(Some(Field::F1))
+            .set_integer(5).unwrap()
+            .set_flags(Flags2::G7, true);
+
+        let field = BitField(field.0 | 1 << 15);
+
+        let BitFieldParts { field: value, optional, integer, read_only, flags } = field.to_parts();
+        assert_eq!(value, Ok(Field::F3));
+        assert_eq!(optional, Some(Field::F1));
+        assert_eq!(integer, 5);
+        assert!(read_only);
+        assert_eq!(flags, [false, false, true]);
+        assert_eq!(
+            format!("{:?}", field.to_parts()),
+            "BitFieldParts { field: Ok(F3), optional: Some(F1), integer: 5, read_only: true, flags: [false, false, true] }"
+        );
+
+        // Read-only entries are ignored by `from_parts`.
+        let parts = BitFieldParts { field: Err(0), optional: None, integer: 7, read_only: true, flags: [true, true, false] };
+        let field = BitField::from_parts(parts).unwrap();
+        assert_eq!(field.0, 0b0111_0000_0011_0000);
+        assert!(!field.read_only());
+        assert_eq!(BitFieldParts::from(field).integer, 7);
+
+        assert!(BitField::from_parts(BitFieldParts { integer: 8, ..parts }).is_none());
+        assert_eq!(BitField::try_from(BitFieldParts { integer: 8, ..parts }).map_err(|parts| parts.integer).map(|_| ()), Err(8));
+
+        // Without narrow unsigned fields, `from_parts` can not fail.
+        #[bitfield::bitfield(16, parts)]
+        struct Infallible<T> {
+            #[field(size = 8)] field: Field,
+            #[field(access = wo)] write_only: bool
+        }
+
+        const PARTS: InfallibleParts = Infallible::<u8>::new().set_field(Field::F2).to_parts();
+        let field: Infallible<u8> = PARTS.into();
+        assert_eq!(field.0, 2);
+        assert_eq!(Infallible::<u8>::from_parts(InfallibleParts { field: Err(0xFF) }).0, 0xFF);
+    }
+
+    #[test]
+    fn predicates() {
+        #[bitfield::bitfield(16)]
+        struct BitField {
+            #[field(size = 3, groups(read, write))] access: Access,
+            #[field(size = 3, groups(read))] optional: Option<Access>,
+            #[field(size = 2, complete, groups(write))] mode: Option<Mode>
+        }
+
+        #[derive(Clone, Copy, Debug, bitfield::Field)]
+        #[repr(u8)]
+        enum Access {
+            #[group(read)] Read = 1,
+            #[group(read, write)] ReadWrite,
+            None
+        }
+
+        #[derive(Clone, Copy, Debug, bitfield::Field)]
+        #[repr(u8)]
+        enum Mode {
+            #[group(write)] Append = 1,
+            #[group(write)] Truncate,
+            Keep
+        }
+
+        const _: () = assert!(BitField::new().set_access(Access::ReadWrite).access_is_write());
+        assert!(BitField::new().set_access(Access::ReadWrite).access_is_read());
+
+        let field = BitField::new().set_access(Access::Read).set_optional(Some(Access::ReadWrite));
+        assert!(field.access_is_read());
+        assert!(!field.access_is_write());
+        assert!(field.optional_is_read());
+        assert!(field.set_mode(Some(Mode::Truncate)).mode_is_write());
+        assert!(!field.set_mode(Some(Mode::Keep)).mode_is_write());
+
+        // Values which are no variant, and `None`, belong to no group.
+        assert!(!BitField::new().access_is_read());
+        assert!(!BitField::new().optional_is_read());
+        assert!(!BitField::new().mode_is_write());
+        assert!(!BitField(0b111_111).access_is_read());
+        assert!(!BitField(0b111_111).optional_is_read());
+    }
+
+    #[test]
+    fn builder() {
+        #[bitfield::bitfield(16)]
+        #[derive(Debug)]
+        struct BitField {
+            #[field(8, 2, required)] field: Field,
+            #[field(size = 2, complete, required)] optional: Option<Field>,
+            #[field(size = 3)] integer: u8,
+            #[cfg_attr(any(), field(0, 1, required))]
+            #[field(15, 1, required)] moved: bool,
+            flags: Flags2
+        }
+
+        const FIELD: BitField = BitField::builder()
+            .set_flags(Flags2::G7, true)
+            .set_optional(None)
+            .set_field(Field::F3)
+            .set_moved(true)
+            .build();
+
+        assert_eq!(FIELD.0, 0b1000_0011_1000_0000);
+        assert_eq!(FIELD.0, BitField::new().set_flags(Flags2::G7, true).set_field(Field::F3).set_moved(true).0);
+
+        // Required fields can be set more than once.
+        let field = BitField::builder()
+            .set_field(Field::F1)
+            .set_integer(5).unwrap()
+            .set_optional(Some(Field::F1))
+            .set_moved(false)
+            .set_field(Field::F2)
+            .build();
+
+        assert_eq!(field.field(), Ok(Field::F2));
+        assert_eq!(field.optional(), Some(Field::F1));
+        assert_eq!(field.integer(), 5);
+        assert!(BitField::builder().set_integer(8).is_none());
+
+        #[bitfield::bitfield(8)]
+        struct Tuple<T>(#[field(size = 4, required)] Field);
+
+        let field: Tuple<u8> = Tuple::builder().set(Field::F1).build();
+        assert_eq!(field.0, 1);
+    }
+
     #[test]
     fn non_zero_values() {
         #[bitfield::bitfield(NonZero8)]
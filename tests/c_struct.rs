@@ -0,0 +1,162 @@
+use core::ffi::{c_char, c_int, c_uint};
+
+/// ```c
+/// struct Members {
+///     char a;
+///     unsigned int b : 4;
+///     unsigned char c : 4;
+///     unsigned short d : 9;
+///     unsigned int e : 20;
+///     long long f : 40;
+///     char g;
+/// };
+/// ```
+macro_rules! members {
+    ($name:ident, $abi:literal) => {
+        #[bitfield::c_struct(abi = $abi)]
+        struct $name {
+            a: c_char,
+            #[bits(4)] b: c_uint,
+            #[bits(4)] c: u8,
+            #[bits(9)] d: u16,
+            #[bits(20)] pub e: u32,
+            #[bits(40)] f: i64,
+            g: c_char
+        }
+    };
+}
+
+members!(MembersSysV, "sysv");
+members!(MembersMsvc, "msvc");
+
+/// ```c
+/// struct Zero {
+///     int x;
+///     unsigned int a : 1;
+///     unsigned int : 0;
+///     unsigned int b : 2;
+///     char c[3];
+///     unsigned short d : 5;
+///     _Bool e : 1;
+/// };
+/// ```
+macro_rules! zero {
+    ($name:ident, $abi:literal) => {
+        #[bitfield::c_struct(abi = $abi)]
+        struct $name {
+            x: c_int,
+            #[bits(1)] a: c_uint,
+            #[bits(0)] _zero: c_uint,
+            #[bits(2)] b: c_uint,
+            c: [c_char; 3],
+            #[bits(5)] d: u16,
+            #[bits(1)] e: bool
+        }
+    };
+}
+
+zero!(ZeroSysV, "sysv");
+zero!(ZeroMsvc, "msvc");
+
+#[bitfield::c_struct]
+struct Target {
+    #[bits(1)] a: u8,
+    #[bits(1)] b: u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the first `length` bytes of `value`, which must not include the trailing padding.
+    fn bytes<T>(value: &T, length: usize) -> &[u8] {
+        assert!(length <= core::mem::size_of::<T>());
+        unsafe { core::slice::from_raw_parts(value as *const T as *const u8, length) }
+    }
+
+    macro_rules! set_members {
+        ($name:ident) => {{
+            let mut members = $name::new(1, 7);
+            members.set_b(0x15);
+            members.set_c(5);
+            members.set_d(0x155);
+            members.set_e(0x55555);
+            members.set_f(-1);
+
+            // Values are truncated like in C.
+            assert_eq!(members.b(), 5);
+            assert_eq!(members.c(), 5);
+            assert_eq!(members.d(), 0x155);
+            assert_eq!(members.e(), 0x55555);
+            assert_eq!(members.f(), -1);
+            members
+        }};
+    }
+
+    macro_rules! set_zero {
+        ($name:ident) => {{
+            let mut zero = $name::new(1, [5; 3]);
+            zero.set_a(1);
+            zero.set_b(1);
+            zero.set_d(0x15);
+            zero.set_e(true);
+
+            assert_eq!((zero.a(), zero.b(), zero.d(), zero.e()), (1, 1, 0x15, true));
+            zero
+        }};
+    }
+
+    // The expected bytes were produced by GCC, with `__attribute__((ms_struct))` for MSVC.
+
+    #[test]
+    fn sysv() {
+        assert_eq!(core::mem::size_of::<MembersSysV>(), 16);
+        assert_eq!(core::mem::align_of::<MembersSysV>(), 8);
+        assert_eq!(
+            bytes(&set_members!(MembersSysV), 14),
+            &[1, 85, 85, 1, 85, 85, 5, 0, 255, 255, 255, 255, 255, 7]
+        );
+
+        assert_eq!(core::mem::size_of::<ZeroSysV>(), 16);
+        assert_eq!(
+            bytes(&set_zero!(ZeroSysV), 13),
+            &[1, 0, 0, 0, 1, 0, 0, 0, 1, 5, 5, 5, 53]
+        );
+    }
+
+    #[test]
+    fn msvc() {
+        assert_eq!(core::mem::size_of::<MembersMsvc>(), 32);
+        assert_eq!(core::mem::align_of::<MembersMsvc>(), 8);
+        assert_eq!(
+            bytes(&set_members!(MembersMsvc), 25),
+            &[
+                1, 0, 0, 0, 5, 0, 0, 0, 5, 0, 85, 1, 85, 85, 5, 0,
+                255, 255, 255, 255, 255, 0, 0, 0, 7
+            ]
+        );
+
+        assert_eq!(core::mem::size_of::<ZeroMsvc>(), 20);
+        assert_eq!(
+            bytes(&set_zero!(ZeroMsvc), 19),
+            &[1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 5, 5, 5, 0, 21, 0, 1]
+        );
+    }
+
+    #[test]
+    fn target() {
+        let mut target = Target::new();
+        target.set_b(1);
+        assert_eq!((target.a(), target.b()), (0, 1));
+
+        #[cfg(windows)]
+        assert_eq!(bytes(&target, 4), &[0, 0, 1, 0]);
+        #[cfg(not(windows))]
+        assert_eq!(bytes(&target, 1), &[2]);
+    }
+
+    #[test]
+    fn ui() {
+        trybuild::TestCases::new().compile_fail("tests/ui/c_struct/*.rs");
+    }
+}
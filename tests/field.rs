@@ -1,5 +1,3 @@
-extern crate alloc;
-
 #[derive(Clone, Copy, Debug, Eq, bitfield::Field, PartialEq)]
 #[repr(u16)]
 enum B {
@@ -36,6 +34,19 @@ enum E {
     F = 4
 }
 
+#[derive(Clone, Copy, Debug, Eq, bitfield::Field, PartialEq)]
+#[repr(u8)]
+enum G {
+    #[group(read)] Read = 1,
+    #[group(read, write)] ReadWrite,
+    #[cfg_attr(all(), group(read))]
+    #[cfg_attr(any(), group(write))]
+    Execute,
+    #[cfg(any())]
+    #[group(write)]
+    Missing
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,6 +64,38 @@ mod tests {
         assert_eq!(E::size(), 7);
     }
 
+    #[test]
+    fn repr() {
+        const D: Option<B> = B::from_repr(0xFEDC);
+        assert_eq!(D, Some(B::D));
+        assert_eq!(B::from_repr(0), None);
+        assert_eq!(B::from_repr(1), Some(B::C));
+        assert_eq!(B::from_repr(2), None);
+        assert_eq!(B::from_repr(0xFEDD), Some(B::E));
+
+        const E: u16 = B::E.to_repr();
+        assert_eq!(E, 0xFEDD);
+        assert_eq!(B::F.to_repr(), 4);
+
+        const MINUS_292: Option<C> = C::from_repr(-292);
+        assert_eq!(MINUS_292, Some(C::D));
+        assert_eq!(C::from_repr(-291), Some(C::E));
+        assert_eq!(C::from_repr(-290), None);
+        assert_eq!(C::D.to_repr(), -292);
+    }
+
+    #[test]
+    fn groups() {
+        const _: () = assert!(G::ReadWrite.is_read());
+        assert!(G::Read.is_read());
+        assert!(!G::Read.is_write());
+        assert!(G::ReadWrite.is_write());
+
+        // Groups from `cfg_attr` only apply where the predicate holds.
+        assert!(G::Execute.is_read());
+        assert!(!G::Execute.is_write());
+    }
+
     #[test]
     fn try_from() {
         assert_eq!(TryInto::<B>::try_into(0), Err(0));
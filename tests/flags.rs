@@ -1,5 +1,3 @@
-extern crate alloc;
-
 #[derive(Copy, Clone, Debug, Eq, bitfield::Flags, PartialEq)]
 #[repr(u8)]
 enum B {
@@ -17,4 +15,10 @@ mod tests {
     fn iter() {
         assert_eq!(B::iter().len(), 4);
     }
+
+    #[test]
+    fn name() {
+        assert_eq!(B::C._bitfield_flag_name(), "C");
+        assert_eq!(B::F._bitfield_flag_name(), "F");
+    }
 }
\ No newline at end of file
@@ -1,5 +1,3 @@
-extern crate alloc;
-
 #[derive(Clone, Copy, Debug, Eq, bitfield::FromPrimitive, PartialEq)]
 #[repr(u16)]
 enum B {
@@ -13,6 +11,20 @@ enum B {
 mod tests {
     use super::*;
 
+    #[test]
+    fn repr() {
+        const D: Option<B> = B::from_repr(0xFEDC);
+        assert_eq!(D, Some(B::D));
+        assert_eq!(B::from_repr(0), None);
+        assert_eq!(B::from_repr(1), Some(B::C));
+        assert_eq!(B::from_repr(2), None);
+        assert_eq!(B::from_repr(0xFEDD), Some(B::E));
+
+        const E: u16 = B::E.to_repr();
+        assert_eq!(E, 0xFEDD);
+        assert_eq!(B::F.to_repr(), 4);
+    }
+
     #[test]
     fn try_from() {
         assert_eq!(TryInto::<B>::try_into(0), Err(0));
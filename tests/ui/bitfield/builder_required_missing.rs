@@ -0,0 +1,18 @@
+#[bitfield::bitfield(8)]
+struct BitField {
+    #[field(size = 2, required)] button: Field,
+    #[field(size = 2, required)] icon: Field,
+    flag: bool
+}
+
+#[derive(Clone, Copy, bitfield::Field)]
+#[repr(u8)]
+enum Field {
+    F0,
+    F1
+}
+
+fn main() {
+    // `build` only exists once `button` and `icon` are set.
+    let _ = BitField::builder().set_button(Field::F1).set_flag(true).build();
+}
@@ -0,0 +1,7 @@
+#[bitfield::bitfield(16, exhaustive)]
+struct BitField {
+    #[field(0, 4)] low: u8, // Bits 4 - 7 are not used.
+    #[field(8, 4)] high: u8 // Bits 12 - 15 are not used.
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+//! Assertion failure: _EXHAUSTIVE_BITFIELD_HAS_UNUSED_BITS
+
+extern crate alloc;
+
+#[bitfield::bitfield(8, exhaustive)]
+struct BitField {
+    #[field(0, 4)] low: u8,
+    flags: Flags
+}
+
+#[derive(Copy, Clone, Debug, bitfield::Flags)]
+#[repr(u8)]
+enum Flags {
+    F4 = 4,
+    F5,
+    F7 = 7 // Bit 6 is not used.
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[bitfield::bitfield(16)]
+struct BitField {
+    #[field(0, 8)] low: u8,
+    #[cfg(all())] // Always enabled, so the overlap is reported.
+    #[field(4, 8)] middle: u8,
+    #[cfg(any())] // Never enabled, so the overlap is not reported.
+    #[field(6, 8)] unused: u8
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+//! Assertion failure: _FLAGS_IN_FIELD_1_OVERLAP_WITH_FIELD_0
+
+extern crate alloc;
+
+#[bitfield::bitfield(8)]
+struct BitField {
+    #[reserved(0, 2)] // These bits overlap with the flags F0 & F1.
+    _rsvd0: _,
+    flags: Flags
+}
+
+#[derive(Copy, Clone, Debug, bitfield::Flags)]
+#[repr(u8)]
+enum Flags {
+    F0,
+    F1,
+    F3 = 3
+}
+
+fn main() {}
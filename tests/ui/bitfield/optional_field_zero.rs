@@ -0,0 +1,16 @@
+//! Assertion failure: _TYPE_IN_OPTIONAL_FIELD_0_MUST_NOT_USE_THE_DISCRIMINANT_0
+
+extern crate alloc;
+
+#[bitfield::bitfield(8)]
+struct BitField(#[field(size = 2)] Option<Field>); // Field value `0` is stored for `None`.
+
+#[derive(Clone, Copy, bitfield::Field)]
+#[repr(u8)]
+enum Field {
+    F0,
+    F1,
+    F2
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+//! Assertion failure: Bit field member `b` is larger than its declared type
+
+#[bitfield::c_struct]
+struct A {
+    a: u8,
+    #[bits(9)] b: u8 // Can only store up to 8 bits.
+}
+
+fn main() {}